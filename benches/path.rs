@@ -1,9 +1,10 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use polyanya::Mesh;
+use polyanya::{maps, Mesh};
 
 macro_rules! assert_delta {
     ($x:expr, $y:expr) => {
-        let val = $x;
+        let val = $x.unwrap();
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
         if !((val.len - $y).abs() < 0.001) {
             assert_eq!(val.len, $y);
         }
@@ -12,7 +13,7 @@ macro_rules! assert_delta {
 }
 
 fn get_path(c: &mut Criterion) {
-    let mesh = Mesh::from_file("meshes/aurora-merged.mesh".into());
+    let mesh = Mesh::from_file("meshes/aurora-merged.mesh").unwrap();
 
     [
         ([993.0, 290.0], [34.0, 622.0], 1123.2226),
@@ -33,5 +34,26 @@ fn get_path(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, get_path);
+fn get_path_maze(c: &mut Criterion) {
+    let mesh = maps::maze(30, 30, 0);
+
+    c.bench_function("get path maze", |b| {
+        b.iter(|| {
+            black_box(mesh.path([1.5, 1.5], [59.5, 59.5]));
+        })
+    });
+}
+
+fn get_path_comb(c: &mut Criterion) {
+    // the open area has 801 successors when crossing it
+    let mesh = maps::comb(200, 30);
+
+    c.bench_function("get path comb", |b| {
+        b.iter(|| {
+            black_box(mesh.path([0.5, 0.5], [398.5, 31.5]));
+        })
+    });
+}
+
+criterion_group!(benches, get_path, get_path_maze, get_path_comb);
 criterion_main!(benches);
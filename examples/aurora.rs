@@ -4,7 +4,8 @@ use polyanya::Mesh;
 
 macro_rules! assert_delta {
     ($x:expr, $y:expr) => {
-        let val = $x;
+        let val = $x.unwrap();
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
         if !((val.len - $y).abs() < 0.001) {
             assert_eq!(val.len, $y);
         }
@@ -19,7 +20,7 @@ fn main() {
     )
     .expect("set up the subscriber");
 
-    let mesh = Mesh::from_file("meshes/aurora-merged.mesh".into());
+    let mesh = Mesh::from_file("meshes/aurora-merged.mesh").unwrap();
 
     let now = Instant::now();
 
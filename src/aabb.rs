@@ -0,0 +1,136 @@
+/// An axis-aligned bounding box.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    /// Corner with the smallest coordinates.
+    pub min: [f32; 2],
+    /// Corner with the largest coordinates.
+    pub max: [f32; 2],
+}
+
+impl Default for Aabb {
+    fn default() -> Self {
+        Aabb::EMPTY
+    }
+}
+
+impl Aabb {
+    /// A bounding box containing nothing. Expanding it with a point gives a box around that point.
+    pub const EMPTY: Aabb = Aabb {
+        min: [f32::INFINITY, f32::INFINITY],
+        max: [f32::NEG_INFINITY, f32::NEG_INFINITY],
+    };
+
+    /// Create a bounding box from its two corners.
+    pub fn new(min: [f32; 2], max: [f32; 2]) -> Self {
+        Aabb { min, max }
+    }
+
+    /// Smallest bounding box containing all the points.
+    pub fn from_points(points: impl IntoIterator<Item = [f32; 2]>) -> Self {
+        let mut aabb = Aabb::EMPTY;
+        for point in points {
+            aabb.expand(point);
+        }
+        aabb
+    }
+
+    /// Grow the bounding box to contain the point.
+    #[inline(always)]
+    pub fn expand(&mut self, point: [f32; 2]) {
+        self.min = [self.min[0].min(point[0]), self.min[1].min(point[1])];
+        self.max = [self.max[0].max(point[0]), self.max[1].max(point[1])];
+    }
+
+    /// Smallest bounding box containing both bounding boxes.
+    pub fn union(&self, other: &Aabb) -> Aabb {
+        Aabb {
+            min: [self.min[0].min(other.min[0]), self.min[1].min(other.min[1])],
+            max: [self.max[0].max(other.max[0]), self.max[1].max(other.max[1])],
+        }
+    }
+
+    /// `true` if the bounding box doesn't contain any point.
+    pub fn is_empty(&self) -> bool {
+        self.min[0] > self.max[0] || self.min[1] > self.max[1]
+    }
+
+    /// Width and height of the bounding box.
+    pub fn size(&self) -> [f32; 2] {
+        if self.is_empty() {
+            [0.0, 0.0]
+        } else {
+            [self.max[0] - self.min[0], self.max[1] - self.min[1]]
+        }
+    }
+
+    /// Center of the bounding box.
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) / 2.0,
+            (self.min[1] + self.max[1]) / 2.0,
+        ]
+    }
+
+    /// Check if a point is inside the bounding box, borders included.
+    #[inline(always)]
+    pub fn contains(&self, point: [f32; 2]) -> bool {
+        (self.min[0]..=self.max[0]).contains(&point[0])
+            && (self.min[1]..=self.max[1]).contains(&point[1])
+    }
+
+    /// Distance from a point to the bounding box, `0.0` if the point is inside.
+    pub fn distance_to(&self, point: [f32; 2]) -> f32 {
+        let dx = (self.min[0] - point[0])
+            .max(point[0] - self.max[0])
+            .max(0.0);
+        let dy = (self.min[1] - point[1])
+            .max(point[1] - self.max[1])
+            .max(0.0);
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Check if two bounding boxes overlap, borders included.
+    #[inline(always)]
+    pub fn intersects(&self, other: &Aabb) -> bool {
+        self.min[0] <= other.max[0]
+            && other.min[0] <= self.max[0]
+            && self.min[1] <= other.max[1]
+            && other.min[1] <= self.max[1]
+    }
+
+    /// Bounding box grown by `margin` on every side.
+    pub fn inflate(&self, margin: f32) -> Aabb {
+        Aabb {
+            min: [self.min[0] - margin, self.min[1] - margin],
+            max: [self.max[0] + margin, self.max[1] + margin],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Aabb;
+
+    #[test]
+    fn from_points() {
+        let aabb = Aabb::from_points([[1.0, 2.0], [-1.0, 5.0], [3.0, 0.0]]);
+        assert_eq!(aabb, Aabb::new([-1.0, 0.0], [3.0, 5.0]));
+        assert_eq!(aabb.size(), [4.0, 5.0]);
+        assert_eq!(aabb.center(), [1.0, 2.5]);
+        assert!(Aabb::from_points([]).is_empty());
+    }
+
+    #[test]
+    fn contains_and_intersects() {
+        let aabb = Aabb::new([0.0, 0.0], [1.0, 1.0]);
+        assert!(aabb.contains([0.0, 1.0]));
+        assert!(aabb.contains([0.5, 0.5]));
+        assert!(!aabb.contains([1.1, 0.5]));
+        assert_eq!(aabb.distance_to([0.5, 0.5]), 0.0);
+        assert_eq!(aabb.distance_to([4.0, 5.0]), 5.0);
+        assert!(aabb.intersects(&Aabb::new([1.0, 1.0], [2.0, 2.0])));
+        assert!(!aabb.intersects(&Aabb::new([1.5, 0.0], [2.0, 2.0])));
+        assert!(!Aabb::EMPTY.intersects(&aabb));
+    }
+}
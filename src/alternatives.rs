@@ -0,0 +1,174 @@
+use hashbrown::HashSet;
+
+use crate::{counted, helpers::distance_between, Aabb, Mesh, Path, PolygonId};
+
+impl Mesh {
+    /// Compute up to `k` different paths from `from` to `to`, starting with the shortest one.
+    ///
+    /// Each path is found by searching again with a penalty on the polygons the previous ones
+    /// went through, and is kept only if at least a quarter of its polygons are not on any
+    /// previous path. Paths are sorted by length, which is their actual length without the
+    /// penalties. Returns an empty list if one of the points is not in the mesh, or if `to` can't
+    /// be reached from `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a pillar in the middle of a corridor, that can be passed on both sides
+    /// let mesh = Mesh::from_grid(10, 3, |x, y| x != 5 || y != 1);
+    /// let paths = mesh.alternative_paths([0.5, 1.5], [9.5, 1.5], 3);
+    /// assert_eq!(paths.len(), 2);
+    /// assert_eq!(paths[0], mesh.path([0.5, 1.5], [9.5, 1.5]).unwrap());
+    /// assert_ne!(paths[0].path, paths[1].path);
+    /// ```
+    pub fn alternative_paths(&self, from: [f32; 2], to: [f32; 2], k: usize) -> Vec<Path> {
+        counted(Vec::is_empty, || {
+            let Some(shortest) = self.path(from, to).filter(|_| k > 0) else {
+                return vec![];
+            };
+            let mut crossed = vec![self.polygons_crossed(from, &shortest.path)];
+            let mut paths = vec![shortest];
+            // times each polygon was on a path, penalized as many times
+            let mut visits = vec![0_u32; self.polygons.len()];
+
+            // give up after as many searches again, in case they keep finding the same paths
+            for _ in 1..2 * k {
+                if paths.len() == k {
+                    break;
+                }
+                for polygon in crossed.last().unwrap() {
+                    visits[*polygon] += 1;
+                }
+                let penalty = |_: PolygonId, to: PolygonId, _: [[f32; 2]; 2]| {
+                    let aabb = self.polygon_aabbs[to.index()];
+                    let diagonal = distance_between(aabb.min, aabb.max);
+                    Some(visits[to.index()] as f32 * diagonal)
+                };
+                let Some(path) = self.path_with_cost_model(from, to, &penalty) else {
+                    break;
+                };
+                let polygons = self.polygons_crossed(from, &path.path);
+                let different = crossed
+                    .iter()
+                    .all(|previous| polygons.difference(previous).count() * 4 >= polygons.len());
+                if different {
+                    let mut len = 0.0;
+                    let mut last = from;
+                    for point in &path.path {
+                        len += distance_between(last, *point);
+                        last = *point;
+                    }
+                    paths.push(Path {
+                        path: path.path,
+                        len,
+                    });
+                }
+                crossed.push(polygons);
+            }
+            paths.sort_by(|a, b| a.len.total_cmp(&b.len));
+            paths
+        })
+    }
+
+    /// Indices of the polygons the path going from `from` through `path` goes through or along.
+    fn polygons_crossed(&self, from: [f32; 2], path: &[[f32; 2]]) -> HashSet<usize> {
+        let mut crossed = HashSet::new();
+        let mut last = from;
+        for point in path {
+            let segment = [last, *point];
+            for (index, polygon) in self.polygons.iter().enumerate() {
+                if !self.polygon_aabbs[index]
+                    .inflate(1.0e-5)
+                    .intersects(&Aabb::from_points(segment))
+                {
+                    continue;
+                }
+                let vertices: Vec<[f32; 2]> = polygon
+                    .vertices
+                    .iter()
+                    .map(|vertex| self.vertices[*vertex].p())
+                    .collect();
+                if segment_crosses_convex(segment, &vertices) {
+                    crossed.insert(index);
+                }
+            }
+            last = *point;
+        }
+        crossed
+    }
+}
+
+/// Check if a segment goes through a convex polygon or along one of its edges, and not only
+/// through a single point.
+fn segment_crosses_convex(segment: [[f32; 2]; 2], polygon: &[[f32; 2]]) -> bool {
+    let n = polygon.len();
+    let double_area: f32 = (0..n)
+        .map(|i| {
+            let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+            a[0] * b[1] - b[0] * a[1]
+        })
+        .sum();
+    let orientation = double_area.signum();
+    let direction = [segment[1][0] - segment[0][0], segment[1][1] - segment[0][1]];
+    // clip the segment by each edge
+    let (mut enter, mut exit) = (0.0_f32, 1.0_f32);
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let inward = [-(b[1] - a[1]) * orientation, (b[0] - a[0]) * orientation];
+        let start = inward[0] * (segment[0][0] - a[0]) + inward[1] * (segment[0][1] - a[1]);
+        let along = inward[0] * direction[0] + inward[1] * direction[1];
+        let length = distance_between(a, b);
+        if along.abs() < 1.0e-9 {
+            // parallel to the edge: outside of it, or along it or inside
+            if start < -1.0e-5 * length {
+                return false;
+            }
+            continue;
+        }
+        let t = -start / along;
+        if along > 0.0 {
+            enter = enter.max(t);
+        } else {
+            exit = exit.min(t);
+        }
+        if enter >= exit {
+            return false;
+        }
+    }
+    (exit - enter) * distance_between(segment[0], segment[1]) > 1.0e-4
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segment_crosses_convex;
+    use crate::maps::random_obstacles;
+
+    #[test]
+    fn different_paths() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let (from, to) = ([1.5, 1.5], [28.5, 28.5]);
+        let paths = mesh.alternative_paths(from, to, 4);
+        assert!(paths.len() > 1);
+        assert_eq!(paths[0], mesh.path(from, to).unwrap());
+        for pair in paths.windows(2) {
+            assert!(pair[0].len <= pair[1].len);
+            assert_ne!(pair[0].path, pair[1].path);
+        }
+        for path in &paths {
+            assert_eq!(path.path.last(), Some(&to));
+        }
+
+        assert!(mesh.alternative_paths(from, to, 0).is_empty());
+        assert!(mesh.alternative_paths([-1.0, 0.0], to, 3).is_empty());
+    }
+
+    #[test]
+    fn crossing_polygons() {
+        let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        assert!(segment_crosses_convex([[-1.0, 0.5], [2.0, 0.5]], &square));
+        assert!(segment_crosses_convex([[0.2, 0.2], [0.3, 0.3]], &square));
+        assert!(segment_crosses_convex([[-1.0, 0.0], [2.0, 0.0]], &square));
+        // through a corner, or next to the square
+        assert!(!segment_crosses_convex([[-1.0, 1.0], [1.0, -1.0]], &square));
+        assert!(!segment_crosses_convex([[2.0, 0.5], [3.0, 0.5]], &square));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{Mesh, PolygonId};
+
+/// Keeps track of the polygon an agent is in as it moves on a [`Mesh`].
+///
+/// Agents usually move only a little between two updates, so the polygon they were in and its
+/// neighbours are checked before falling back to locating the position on the whole mesh.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AgentAnchor {
+    polygon: Option<PolygonId>,
+}
+
+impl AgentAnchor {
+    /// Create an anchor for an agent at `position`.
+    pub fn new(mesh: &Mesh, position: [f32; 2]) -> Self {
+        let mut anchor = AgentAnchor::default();
+        anchor.update(mesh, position);
+        anchor
+    }
+
+    /// Polygon the agent was in at the last update, or `None` if it was outside of the mesh.
+    pub fn polygon(&self) -> Option<PolygonId> {
+        self.polygon
+    }
+
+    /// Update the anchor with the new position of the agent, and return the polygon it's in.
+    pub fn update(&mut self, mesh: &Mesh, position: [f32; 2]) -> Option<PolygonId> {
+        self.polygon = mesh.locate_with_hint(position, self.polygon);
+        self.polygon
+    }
+}
+
+impl Mesh {
+    /// Find the polygon containing a point, starting from `hint`, the polygon it was last known to
+    /// be in.
+    ///
+    /// The hinted polygon and the polygons sharing a vertex with it are checked before falling
+    /// back to locating the point on the whole mesh, so a stale hint is only slower.
+    pub fn locate_with_hint(&self, point: [f32; 2], hint: Option<PolygonId>) -> Option<PolygonId> {
+        hint.filter(|polygon| polygon.index() < self.polygons.len())
+            .and_then(|current| {
+                if self.polygon_contains(current.index(), point) {
+                    return Some(current);
+                }
+                self.polygons[current.index()]
+                    .vertices
+                    .iter()
+                    .flat_map(|vertex| self.vertices[*vertex].polygons.iter().flatten())
+                    .copied()
+                    .filter(|neighbour| *neighbour != current)
+                    .find(|neighbour| self.polygon_contains(neighbour.index(), point))
+            })
+            .or_else(|| self.point_in_polygon(point))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AgentAnchor;
+    use crate::{tests::mesh_u_grid, PolygonId};
+
+    #[test]
+    fn follow_agent() {
+        let mesh = mesh_u_grid();
+        let p = PolygonId::new;
+        let mut anchor = AgentAnchor::new(&mesh, [0.5, 0.5]);
+        assert_eq!(anchor.polygon(), Some(p(0)));
+        assert_eq!(anchor.update(&mesh, [0.7, 0.5]), Some(p(0)));
+        // neighbour
+        assert_eq!(anchor.update(&mesh, [1.2, 0.5]), Some(p(1)));
+        // neighbour through a vertex
+        assert_eq!(anchor.update(&mesh, [2.5, 1.2]), Some(p(4)));
+        // teleported far away
+        assert_eq!(anchor.update(&mesh, [0.5, 1.5]), Some(p(3)));
+        assert_eq!(anchor.update(&mesh, [1.5, 1.5]), None);
+        assert_eq!(anchor.update(&mesh, [2.5, 0.5]), Some(p(2)));
+    }
+
+    #[test]
+    fn path_with_hint() {
+        let mesh = mesh_u_grid();
+        let p = PolygonId::new;
+        let from = [0.5, 1.5];
+        let to = [2.5, 1.5];
+        assert_eq!(mesh.locate_with_hint(to, Some(p(2))), Some(p(4)));
+        assert_eq!(mesh.locate_with_hint(to, Some(p(100))), Some(p(4)));
+        assert_eq!(mesh.locate_with_hint([1.5, 1.5], Some(p(1))), None);
+        assert_eq!(
+            mesh.path_with_hint(from, to, Some(p(0)), Some(p(2))),
+            mesh.path(from, to)
+        );
+        assert_eq!(
+            mesh.path_with_hint(from, to, None, Some(p(0))),
+            mesh.path(from, to)
+        );
+        assert_eq!(
+            mesh.path_with_hint(from, [1.5, 1.5], Some(p(3)), None),
+            None
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{Mesh, Path, PolygonId, SearchOptions};
+
+/// Weight of the heuristic under which the next search is for the shortest path.
+const LAST_WEIGHT: f32 = 1.05;
+
+/// Paths from a point to another, each shorter than the previous one, as returned by
+/// [`Mesh::anytime_path`].
+///
+/// The first path is found quickly by inflating the heuristic of the search, which is then
+/// lowered for each following path, until the last one is the shortest path. Each path is a new
+/// search, so the iterator can be dropped as soon as there is no time left, keeping the best path
+/// found so far.
+///
+/// ```
+/// # use polyanya::Mesh;
+/// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+/// let mut paths = mesh.anytime_path([1.0, 3.0], [4.0, 2.0], 3.0);
+/// let first = paths.next().unwrap();
+/// assert!(paths.bound() <= 3.0);
+///
+/// // when there is more time
+/// let best = paths.last().unwrap_or(first);
+/// assert_eq!(best, mesh.path([1.0, 3.0], [4.0, 2.0]).unwrap());
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnytimePath<'m> {
+    mesh: &'m Mesh,
+    from: ([f32; 2], Option<PolygonId>),
+    to: ([f32; 2], Option<PolygonId>),
+    weight: f32,
+    bound: f32,
+    best: Option<f32>,
+}
+
+impl AnytimePath<'_> {
+    /// How many times longer than the shortest path the last path returned can be. It's `1.0`
+    /// once the shortest path has been found.
+    pub fn bound(&self) -> f32 {
+        self.bound
+    }
+}
+
+impl Iterator for AnytimePath<'_> {
+    type Item = Path;
+
+    fn next(&mut self) -> Option<Path> {
+        // counted when the paths were asked for
+        #[cfg(feature = "counters")]
+        let query = crate::counters::Query::resume();
+        while self.weight >= 1.0 {
+            let weight = self.weight;
+            self.weight = if weight == 1.0 {
+                0.0
+            } else if weight < LAST_WEIGHT {
+                1.0
+            } else {
+                1.0 + (weight - 1.0) / 2.0
+            };
+
+            let Some(path) = self.mesh.path_between_polygons(
+                self.from.0,
+                self.to.0,
+                self.from.1,
+                self.to.1,
+                SearchOptions {
+                    heuristic_weight: weight,
+                    ..SearchOptions::default()
+                },
+                |_| (),
+            ) else {
+                // with any weight, the search fails only if there is no path
+                self.weight = 0.0;
+                #[cfg(feature = "counters")]
+                if self.best.is_none() {
+                    query.fail();
+                }
+                return None;
+            };
+            self.bound = weight;
+            if self.best.is_none_or(|best| path.len < best) {
+                self.best = Some(path.len);
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+impl Mesh {
+    /// Compute paths from `from` to `to` getting shorter, to use the best one found when there is
+    /// no time left.
+    ///
+    /// The first path is at most `weight` times longer than the shortest path, and the last one
+    /// is the shortest path. With a `weight` of `1.0` or less, the first path is the shortest.
+    pub fn anytime_path(&self, from: [f32; 2], to: [f32; 2], weight: f32) -> AnytimePath<'_> {
+        #[cfg(feature = "counters")]
+        crate::counters::Query::start();
+        AnytimePath {
+            mesh: self,
+            from: (from, self.point_in_polygon(from)),
+            to: (to, self.point_in_polygon(to)),
+            weight: weight.max(1.0),
+            bound: f32::INFINITY,
+            best: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::random_obstacles;
+
+    #[test]
+    fn improving_paths() {
+        let mesh = random_obstacles(40, 40, 30, 4, 7);
+        let (from, to) = ([0.5, 0.5], [39.5, 39.5]);
+        let shortest = mesh.path(from, to).unwrap();
+
+        let mut paths = mesh.anytime_path(from, to, 4.0);
+        let found: Vec<_> =
+            std::iter::from_fn(|| paths.next().map(|path| (path, paths.bound()))).collect();
+        assert!(found.len() > 1);
+        for (i, (path, bound)) in found.iter().enumerate() {
+            assert!(path.len <= shortest.len * bound + 1.0e-3);
+            if i > 0 {
+                assert!(path.len < found[i - 1].0.len);
+            }
+        }
+        assert_eq!(found.last().map(|(path, _)| path), Some(&shortest));
+        assert_eq!(paths.bound(), 1.0);
+        assert_eq!(paths.next(), None);
+
+        let mut paths = mesh.anytime_path(from, to, 0.5);
+        assert_eq!(paths.next(), Some(shortest));
+        assert_eq!(paths.next(), None);
+
+        assert_eq!(mesh.anytime_path(from, [-1.0, -1.0], 2.0).next(), None);
+    }
+}
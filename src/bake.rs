@@ -0,0 +1,1014 @@
+//! Baking of navigation meshes from 3D level geometry, in the style of Recast.
+//!
+//! Triangles are voxelized into a heightfield: columns of cells on the ground plane, each with the
+//! spans of height covered by geometry. The top of a span is walkable if the triangle it comes
+//! from isn't too steep, there is room for an agent above it, and it isn't at the edge of a drop
+//! too high to climb. Walkable cells closer to a wall or a drop than the radius of the agents are
+//! removed, then walkable cells are grouped in regions of cells agents can move between, and the
+//! outline of each region is simplified and triangulated. Finally triangles are merged in
+//! convex polygons.
+//!
+//! The `y` axis goes up, and the `x` and `z` coordinates become the `x` and `y` coordinates of the
+//! mesh. As meshes are flat, where several walkable surfaces are above each other, like under a
+//! bridge, only the lowest one is kept.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{bitmap, AgentSizes, Mesh, MeshBuildError, MeshBuilder, TiledMesh};
+
+/// Parameters of [`from_triangles`]. Distances are in the units of the geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BakeConfig {
+    /// Width and depth of the cells of the heightfield.
+    pub cell_size: f32,
+    /// Height of the cells of the heightfield.
+    pub cell_height: f32,
+    /// Steepest slope that can be walked on, in degrees.
+    pub max_slope: f32,
+    /// Height of the agents: surfaces with less room above them can't be walked on.
+    pub agent_height: f32,
+    /// Radius of the agents: the walkable area is shrunk by it, so that paths keep away from walls
+    /// and obstacles. `0.0` bakes a mesh for agents without a size.
+    pub agent_radius: f32,
+    /// Highest step the agents can climb.
+    pub max_climb: f32,
+    /// Largest distance between the outlines of the mesh and the cells they follow.
+    pub max_edge_error: f32,
+}
+
+impl Default for BakeConfig {
+    fn default() -> Self {
+        BakeConfig {
+            cell_size: 0.3,
+            cell_height: 0.2,
+            max_slope: 45.0,
+            agent_height: 2.0,
+            agent_radius: 0.0,
+            max_climb: 0.9,
+            max_edge_error: 0.4,
+        }
+    }
+}
+
+impl BakeConfig {
+    fn check(&self) -> Result<(), BakeError> {
+        let positive = |value: f32| value.is_finite() && value > 0.0;
+        let non_negative = |value: f32| value.is_finite() && value >= 0.0;
+        for (field, valid) in [
+            ("cell_size", positive(self.cell_size)),
+            ("cell_height", positive(self.cell_height)),
+            (
+                "max_slope",
+                non_negative(self.max_slope) && self.max_slope < 90.0,
+            ),
+            ("agent_height", non_negative(self.agent_height)),
+            ("agent_radius", non_negative(self.agent_radius)),
+            ("max_climb", non_negative(self.max_climb)),
+            ("max_edge_error", non_negative(self.max_edge_error)),
+        ] {
+            if !valid {
+                return Err(BakeError::InvalidConfig { field });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when a mesh can't be baked.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BakeError {
+    /// A field of the [`BakeConfig`] has an invalid value.
+    InvalidConfig { field: &'static str },
+    /// The geometry is invalid, or the mesh built from it.
+    InvalidMesh(MeshBuildError),
+}
+
+impl Display for BakeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BakeError::InvalidConfig { field } => write!(f, "invalid configuration: {}", field),
+            BakeError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+impl Error for BakeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BakeError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<MeshBuildError> for BakeError {
+    fn from(error: MeshBuildError) -> Self {
+        BakeError::InvalidMesh(error)
+    }
+}
+
+/// Bake a mesh from 3D triangles, given by their `vertices` and `indices`, three per triangle.
+///
+/// Triangles can be in any winding order. Non finite vertices are reported as
+/// [`MeshBuildError::NonFiniteVertex`], and indices out of range as
+/// [`MeshBuildError::VertexOutOfRange`] with the index of the triangle.
+///
+/// ```
+/// # use polyanya::bake::{self, BakeConfig};
+/// // a 10 x 10 floor
+/// let vertices = [
+///     [0.0, 0.0, 0.0],
+///     [10.0, 0.0, 0.0],
+///     [10.0, 0.0, 10.0],
+///     [0.0, 0.0, 10.0],
+/// ];
+/// let mesh = bake::from_triangles(&vertices, &[0, 1, 2, 0, 2, 3], &BakeConfig::default()).unwrap();
+/// assert!(mesh.path([1.0, 1.0], [9.0, 9.0]).is_some());
+/// ```
+pub fn from_triangles(
+    vertices: &[[f32; 3]],
+    indices: &[u32],
+    config: &BakeConfig,
+) -> Result<Mesh, BakeError> {
+    config.check()?;
+    let geometry = Geometry::new(vertices, indices)?;
+    if geometry.triangles.is_empty() {
+        return Ok(MeshBuilder::new().build()?);
+    }
+    let width = (((geometry.max[0] - geometry.min[0]) / config.cell_size).ceil() as usize).max(1);
+    let depth = (((geometry.max[2] - geometry.min[2]) / config.cell_size).ceil() as usize).max(1);
+    let heightfield = Heightfield::new(geometry.min, width, depth, config.cell_size, config);
+    Ok(heightfield.bake(&geometry, config, (0, 0, width, depth))?)
+}
+
+/// Bake a mesh for agents of each radius in `radii` from 3D triangles given like in
+/// [`from_triangles`], replacing [`BakeConfig::agent_radius`].
+///
+/// ```
+/// # use polyanya::bake::{self, BakeConfig};
+/// let vertices = [
+///     [0.0, 0.0, 0.0],
+///     [10.0, 0.0, 0.0],
+///     [10.0, 0.0, 10.0],
+///     [0.0, 0.0, 10.0],
+/// ];
+/// let sizes = bake::sizes_from_triangles(
+///     &vertices,
+///     &[0, 1, 2, 0, 2, 3],
+///     &BakeConfig::default(),
+///     &[0.0, 1.0],
+/// )
+/// .unwrap();
+/// assert!(sizes.mesh(0.0).unwrap().point_in_mesh([0.5, 5.0]));
+/// assert!(!sizes.mesh(0.6).unwrap().point_in_mesh([0.5, 5.0]));
+/// ```
+pub fn sizes_from_triangles(
+    vertices: &[[f32; 3]],
+    indices: &[u32],
+    config: &BakeConfig,
+    radii: &[f32],
+) -> Result<AgentSizes, BakeError> {
+    let mut sizes = AgentSizes::new();
+    for radius in radii {
+        let config = BakeConfig {
+            agent_radius: *radius,
+            ..*config
+        };
+        sizes.insert(*radius, from_triangles(vertices, indices, &config)?);
+    }
+    Ok(sizes)
+}
+
+/// Cells added around a tile, so that filters see the geometry of its neighbours, in addition to
+/// the radius of the agents.
+const TILE_BORDER: usize = 1;
+
+/// Bake the tile `tile` of a [`TiledMesh`] with tiles of size `tile_size`, from 3D triangles given
+/// like in [`from_triangles`].
+///
+/// The tile is baked with the geometry around it, so that tiles baked separately match along
+/// their borders. Its cells are resized so that a whole number of them fits in the tile.
+pub fn tile_from_triangles(
+    vertices: &[[f32; 3]],
+    indices: &[u32],
+    config: &BakeConfig,
+    tile_size: f32,
+    tile: [i32; 2],
+) -> Result<Mesh, BakeError> {
+    config.check()?;
+    if !tile_size.is_finite() || tile_size <= 0.0 {
+        return Err(BakeError::InvalidConfig { field: "tile_size" });
+    }
+    let geometry = Geometry::new(vertices, indices)?;
+    bake_tile(&geometry, config, tile_size, tile)
+}
+
+/// Bake all the tiles of size `tile_size` covering 3D triangles given like in
+/// [`from_triangles`].
+///
+/// ```
+/// # use polyanya::bake::{self, BakeConfig};
+/// let vertices = [
+///     [0.0, 0.0, 0.0],
+///     [10.0, 0.0, 0.0],
+///     [10.0, 0.0, 10.0],
+///     [0.0, 0.0, 10.0],
+/// ];
+/// let tiles =
+///     bake::tiled_from_triangles(&vertices, &[0, 1, 2, 0, 2, 3], &BakeConfig::default(), 4.0)
+///         .unwrap();
+/// assert_eq!(tiles.tiles().len(), 9);
+/// assert!(tiles.mesh().path([1.0, 1.0], [9.0, 9.0]).is_some());
+/// ```
+pub fn tiled_from_triangles(
+    vertices: &[[f32; 3]],
+    indices: &[u32],
+    config: &BakeConfig,
+    tile_size: f32,
+) -> Result<TiledMesh, BakeError> {
+    config.check()?;
+    if !tile_size.is_finite() || tile_size <= 0.0 {
+        return Err(BakeError::InvalidConfig { field: "tile_size" });
+    }
+    let geometry = Geometry::new(vertices, indices)?;
+    let mut tiles = vec![];
+    if !geometry.triangles.is_empty() {
+        let tile = |coordinate: f32| (coordinate / tile_size).floor() as i32;
+        for y in tile(geometry.min[2])..=tile(geometry.max[2]) {
+            for x in tile(geometry.min[0])..=tile(geometry.max[0]) {
+                let mesh = bake_tile(&geometry, config, tile_size, [x, y])?;
+                if !mesh.polygons.is_empty() {
+                    tiles.push(([x, y], mesh));
+                }
+            }
+        }
+    }
+    Ok(TiledMesh::from_tiles(tile_size, tiles))
+}
+
+fn bake_tile(
+    geometry: &Geometry,
+    config: &BakeConfig,
+    tile_size: f32,
+    tile: [i32; 2],
+) -> Result<Mesh, BakeError> {
+    if geometry.triangles.is_empty() {
+        return Ok(MeshBuilder::new().build()?);
+    }
+    let cells = ((tile_size / config.cell_size).round() as usize).max(1);
+    let cell_size = tile_size / cells as f32;
+    let border = TILE_BORDER + (config.agent_radius / cell_size).ceil() as usize;
+    let origin = [
+        tile[0] as f32 * tile_size - border as f32 * cell_size,
+        geometry.min[1],
+        tile[1] as f32 * tile_size - border as f32 * cell_size,
+    ];
+    let size = cells + 2 * border;
+    let heightfield = Heightfield::new(origin, size, size, cell_size, config);
+    let inner = (border, border, border + cells, border + cells);
+    Ok(heightfield.bake(geometry, config, inner)?)
+}
+
+/// Triangles of the geometry to bake, with their bounds.
+struct Geometry {
+    triangles: Vec<[[f32; 3]; 3]>,
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+impl Geometry {
+    fn new(vertices: &[[f32; 3]], indices: &[u32]) -> Result<Self, BakeError> {
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        for (index, vertex) in vertices.iter().enumerate() {
+            if vertex.iter().any(|coordinate| !coordinate.is_finite()) {
+                return Err(MeshBuildError::NonFiniteVertex { vertex: index }.into());
+            }
+            for axis in 0..3 {
+                min[axis] = min[axis].min(vertex[axis]);
+                max[axis] = max[axis].max(vertex[axis]);
+            }
+        }
+        let mut triangles = Vec::with_capacity(indices.len() / 3);
+        for (triangle, corners) in indices.chunks_exact(3).enumerate() {
+            let mut points = [[0.0; 3]; 3];
+            for (point, corner) in points.iter_mut().zip(corners) {
+                *point =
+                    *vertices
+                        .get(*corner as usize)
+                        .ok_or(MeshBuildError::VertexOutOfRange {
+                            polygon: triangle,
+                            vertex: *corner as usize,
+                        })?;
+            }
+            triangles.push(points);
+        }
+        Ok(Geometry {
+            triangles,
+            min,
+            max,
+        })
+    }
+}
+
+/// Heights covered by geometry in a column of the heightfield, in cells from its bottom.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Span {
+    min: i32,
+    max: i32,
+    walkable: bool,
+    /// Walkable but at the edge of a drop: agents can't stand on it, but the walkable area goes
+    /// up to the drop.
+    ledge: bool,
+}
+
+/// Height above the top of the last span of a column.
+const OPEN: i32 = i32::MAX / 2;
+
+#[derive(Debug, Clone)]
+struct Heightfield {
+    origin: [f32; 3],
+    width: usize,
+    depth: usize,
+    cell_size: f32,
+    cell_height: f32,
+    /// Highest step that can be climbed, in cells.
+    climb: i32,
+    /// Room needed above walkable spans, in cells.
+    clearance: i32,
+    /// Radius of the agents, in cells.
+    radius: f32,
+    max_edge_error: f32,
+    /// Spans of each column, from the bottom, with the columns row by row.
+    columns: Vec<Vec<Span>>,
+}
+
+/// Split a convex polygon by the plane where coordinate `axis` is `offset`, returning the parts
+/// below and above it.
+fn split(polygon: &[[f32; 3]], axis: usize, offset: f32) -> (Vec<[f32; 3]>, Vec<[f32; 3]>) {
+    let mut below = vec![];
+    let mut above = vec![];
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let (da, db) = (a[axis] - offset, b[axis] - offset);
+        if da <= 0.0 {
+            below.push(*a);
+        }
+        if da >= 0.0 {
+            above.push(*a);
+        }
+        if (da < 0.0 && db > 0.0) || (da > 0.0 && db < 0.0) {
+            let t = da / (da - db);
+            let point = [0, 1, 2].map(|k| a[k] + (b[k] - a[k]) * t);
+            below.push(point);
+            above.push(point);
+        }
+    }
+    (below, above)
+}
+
+impl Heightfield {
+    fn new(
+        origin: [f32; 3],
+        width: usize,
+        depth: usize,
+        cell_size: f32,
+        config: &BakeConfig,
+    ) -> Self {
+        Heightfield {
+            origin,
+            width,
+            depth,
+            cell_size,
+            cell_height: config.cell_height,
+            climb: (config.max_climb / config.cell_height).floor() as i32,
+            clearance: (config.agent_height / config.cell_height).ceil() as i32,
+            radius: config.agent_radius / cell_size,
+            max_edge_error: config.max_edge_error,
+            columns: vec![vec![]; width * depth],
+        }
+    }
+
+    /// Rasterize the geometry, filter the walkable spans and build the mesh of the cells from
+    /// `inner.0, inner.1` included to `inner.2, inner.3` excluded.
+    fn bake(
+        mut self,
+        geometry: &Geometry,
+        config: &BakeConfig,
+        inner: (usize, usize, usize, usize),
+    ) -> Result<Mesh, MeshBuildError> {
+        let min_normal_y = config.max_slope.to_radians().cos();
+        for triangle in &geometry.triangles {
+            self.rasterize(triangle, min_normal_y);
+        }
+        self.filter();
+        self.mesh(inner)
+    }
+
+    /// Add the spans covered by a triangle, walkable if the vertical component of its normal is at
+    /// least `min_normal_y`.
+    fn rasterize(&mut self, triangle: &[[f32; 3]; 3], min_normal_y: f32) {
+        let [a, b, c] = *triangle;
+        let (u, v) = (
+            [0, 1, 2].map(|k| b[k] - a[k]),
+            [0, 1, 2].map(|k| c[k] - a[k]),
+        );
+        let normal = [
+            u[1] * v[2] - u[2] * v[1],
+            u[2] * v[0] - u[0] * v[2],
+            u[0] * v[1] - u[1] * v[0],
+        ];
+        let length = normal.iter().map(|n| n * n).sum::<f32>().sqrt();
+        if length == 0.0 {
+            return;
+        }
+        let walkable = normal[1].abs() / length >= min_normal_y;
+
+        let (origin, cell_size) = (self.origin, self.cell_size);
+        let cell = |coordinate: f32, axis: usize, cells: usize| {
+            let cell = ((coordinate - origin[axis]) / cell_size).floor().max(0.0) as usize;
+            // the cell the planes splitting the triangle put it in: they keep points on a plane
+            // in the cell below it, while the division can round up to the next cell
+            let cell = if cell > 0 && origin[axis] + cell as f32 * cell_size >= coordinate {
+                cell - 1
+            } else {
+                cell
+            };
+            cell.min(cells - 1)
+        };
+        let (x_min, x_max) = (a[0].min(b[0]).min(c[0]), a[0].max(b[0]).max(c[0]));
+        let (z_min, z_max) = (a[2].min(b[2]).min(c[2]), a[2].max(b[2]).max(c[2]));
+        if x_max < origin[0]
+            || z_max < origin[2]
+            || x_min > origin[0] + self.width as f32 * cell_size
+            || z_min > origin[2] + self.depth as f32 * cell_size
+        {
+            return;
+        }
+        let (_, mut rest) = split(triangle, 2, origin[2]);
+        for z in cell(z_min, 2, self.depth)..=cell(z_max, 2, self.depth) {
+            let (row, above) = split(&rest, 2, self.origin[2] + (z + 1) as f32 * self.cell_size);
+            rest = above;
+            if row.len() < 3 {
+                continue;
+            }
+            let x_min = row.iter().map(|p| p[0]).fold(f32::INFINITY, f32::min);
+            let x_max = row.iter().map(|p| p[0]).fold(f32::NEG_INFINITY, f32::max);
+            let (_, mut rest) = split(&row, 0, origin[0]);
+            for x in cell(x_min, 0, self.width)..=cell(x_max, 0, self.width) {
+                let (polygon, right) =
+                    split(&rest, 0, self.origin[0] + (x + 1) as f32 * self.cell_size);
+                rest = right;
+                if polygon.len() < 3 {
+                    continue;
+                }
+                let y_min = polygon.iter().map(|p| p[1]).fold(f32::INFINITY, f32::min);
+                let y_max = polygon
+                    .iter()
+                    .map(|p| p[1])
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let min = ((y_min - self.origin[1]) / self.cell_height)
+                    .floor()
+                    .max(0.0) as i32;
+                let max = ((y_max - self.origin[1]) / self.cell_height).ceil() as i32;
+                self.add_span(
+                    z * self.width + x,
+                    Span {
+                        min,
+                        max: max.max(min + 1),
+                        walkable,
+                        ledge: false,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Add a span to a column, merging it with the spans it overlaps. When their tops are within
+    /// climbing height, the merged span is walkable if either is, otherwise the highest top wins.
+    fn add_span(&mut self, column: usize, mut span: Span) {
+        let climb = self.climb;
+        let spans = &mut self.columns[column];
+        let mut i = 0;
+        while i < spans.len() {
+            let current = spans[i];
+            if current.min > span.max {
+                break;
+            }
+            if current.max < span.min {
+                i += 1;
+                continue;
+            }
+            if (current.max - span.max).abs() <= climb {
+                span.walkable |= current.walkable;
+            } else if current.max > span.max {
+                span.walkable = current.walkable;
+            }
+            span.min = span.min.min(current.min);
+            span.max = span.max.max(current.max);
+            spans.remove(i);
+        }
+        spans.insert(i, span);
+    }
+
+    /// Bottom of the span above the `i`-th one of a column.
+    fn ceiling(spans: &[Span], i: usize) -> i32 {
+        spans.get(i + 1).map_or(OPEN, |span| span.min)
+    }
+
+    fn neighbour(&self, x: usize, z: usize, direction: usize) -> Option<usize> {
+        let (dx, dz) = [(-1, 0), (0, -1), (1, 0), (0, 1)][direction];
+        let (x, z) = (x as isize + dx, z as isize + dz);
+        (x >= 0 && z >= 0 && (x as usize) < self.width && (z as usize) < self.depth)
+            .then(|| z as usize * self.width + x as usize)
+    }
+
+    /// Mark spans that can't be walked on after rasterization: low obstacles on walkable spans
+    /// become walkable, then spans at the edge of a drop or without room above become unwalkable.
+    fn filter(&mut self) {
+        for spans in &mut self.columns {
+            let mut previous: Option<Span> = None;
+            for span in spans.iter_mut() {
+                let stepped = !span.walkable
+                    && previous.is_some_and(|previous| {
+                        previous.walkable && span.max - previous.max <= self.climb
+                    });
+                previous = Some(*span);
+                if stepped {
+                    span.walkable = true;
+                    // only one step at a time
+                    previous = None;
+                }
+            }
+        }
+
+        let mut ledges = vec![];
+        for z in 0..self.depth {
+            for x in 0..self.width {
+                let column = z * self.width + x;
+                for (i, span) in self.columns[column].iter().enumerate() {
+                    if !span.walkable {
+                        continue;
+                    }
+                    let (floor, ceiling) = (span.max, Self::ceiling(&self.columns[column], i));
+                    let mut lowest = OPEN;
+                    let (mut accessible_min, mut accessible_max) = (floor, floor);
+                    for direction in 0..4 {
+                        let Some(neighbour) = self.neighbour(x, z, direction) else {
+                            lowest = lowest.min(-self.climb - floor);
+                            continue;
+                        };
+                        let spans = &self.columns[neighbour];
+                        // the space below the first span of the neighbour is a drop
+                        let top = spans.first().map_or(OPEN, |span| span.min);
+                        if ceiling.min(top) - floor.max(-self.climb) > self.clearance {
+                            lowest = lowest.min(-self.climb - floor);
+                        }
+                        for j in 0..spans.len() {
+                            let (bottom, top) = (spans[j].max, Self::ceiling(spans, j));
+                            if ceiling.min(top) - floor.max(bottom) > self.clearance {
+                                lowest = lowest.min(bottom - floor);
+                                if (bottom - floor).abs() <= self.climb {
+                                    accessible_min = accessible_min.min(bottom);
+                                    accessible_max = accessible_max.max(bottom);
+                                }
+                            }
+                        }
+                    }
+                    // a drop, or neighbours too steep to climb from one to the other
+                    if lowest < -self.climb || accessible_max - accessible_min > self.climb {
+                        ledges.push((column, i));
+                    }
+                }
+            }
+        }
+        for (column, i) in ledges {
+            let span = &mut self.columns[column][i];
+            span.walkable = false;
+            span.ledge = true;
+        }
+
+        for spans in &mut self.columns {
+            for i in 0..spans.len() {
+                if Self::ceiling(spans, i) - spans[i].max < self.clearance {
+                    spans[i].walkable = false;
+                    spans[i].ledge = false;
+                }
+            }
+        }
+    }
+
+    /// Floor and ceiling of the lowest span of each column with `kind`.
+    fn floors(&self, kind: impl Fn(&Span) -> bool) -> Vec<Option<(i32, i32)>> {
+        self.columns
+            .iter()
+            .map(|spans| {
+                let i = spans.iter().position(&kind)?;
+                Some((spans[i].max, Self::ceiling(spans, i)))
+            })
+            .collect()
+    }
+
+    /// Check if agents can move between neighbouring cells with these floors and ceilings.
+    fn connected(
+        &self,
+        (floor, ceiling): (i32, i32),
+        (other_floor, other_ceiling): (i32, i32),
+    ) -> bool {
+        (other_floor - floor).abs() <= self.climb
+            && ceiling.min(other_ceiling) - floor.max(other_floor) >= self.clearance
+    }
+
+    /// Remove the floors closer to a wall or a drop than the radius of the agents, measuring
+    /// distances with a chamfer distance transform, in half cells.
+    ///
+    /// Floors next to a wall are at a distance of zero from it. Ledges are walkable up to the
+    /// drop, so floors next to one are a cell away from the drop.
+    fn erode(&self, floors: &mut [Option<(i32, i32)>], ledges: &[Option<(i32, i32)>]) {
+        if self.radius == 0.0 {
+            return;
+        }
+        let mut distances: Vec<i32> = (0..floors.len())
+            .map(|cell| {
+                let Some(floor) = floors[cell] else {
+                    return 0;
+                };
+                let inside = (0..4).all(|direction| {
+                    self.neighbour(cell % self.width, cell / self.width, direction)
+                        .and_then(|neighbour| floors[neighbour].or(ledges[neighbour]))
+                        .is_some_and(|other| self.connected(floor, other))
+                });
+                if inside {
+                    i32::MAX / 2
+                } else {
+                    0
+                }
+            })
+            .collect();
+        let (width, depth) = (self.width as isize, self.depth as isize);
+        let mut relax = |x: isize, z: isize, neighbours: [(isize, isize, i32); 4]| {
+            let cell = (z * width + x) as usize;
+            for (dx, dz, cost) in neighbours {
+                let (nx, nz) = (x + dx, z + dz);
+                if nx >= 0 && nz >= 0 && nx < width && nz < depth {
+                    let distance = distances[(nz * width + nx) as usize] + cost;
+                    distances[cell] = distances[cell].min(distance);
+                }
+            }
+        };
+        for z in 0..depth {
+            for x in 0..width {
+                relax(x, z, [(-1, 0, 2), (0, -1, 2), (-1, -1, 3), (1, -1, 3)]);
+            }
+        }
+        for z in (0..depth).rev() {
+            for x in (0..width).rev() {
+                relax(x, z, [(1, 0, 2), (0, 1, 2), (1, 1, 3), (-1, 1, 3)]);
+            }
+        }
+        for (floor, distance) in floors.iter_mut().zip(distances) {
+            if (distance as f32) < 2.0 * self.radius {
+                *floor = None;
+            }
+        }
+    }
+
+    /// Group the walkable cells in regions agents can move in, returning the region of each cell
+    /// and the number of regions.
+    fn regions(&self, floors: &[Option<(i32, i32)>]) -> (Vec<Option<usize>>, usize) {
+        let mut regions = vec![None; floors.len()];
+        let mut count = 0;
+        for start in 0..floors.len() {
+            if floors[start].is_none() || regions[start].is_some() {
+                continue;
+            }
+            regions[start] = Some(count);
+            let mut stack = vec![start];
+            while let Some(cell) = stack.pop() {
+                let floor = floors[cell].unwrap();
+                for direction in 0..4 {
+                    let Some(neighbour) =
+                        self.neighbour(cell % self.width, cell / self.width, direction)
+                    else {
+                        continue;
+                    };
+                    let Some(other) = floors[neighbour] else {
+                        continue;
+                    };
+                    if regions[neighbour].is_none() && self.connected(floor, other) {
+                        regions[neighbour] = Some(count);
+                        stack.push(neighbour);
+                    }
+                }
+            }
+            count += 1;
+        }
+        (regions, count)
+    }
+
+    /// Build the mesh of the walkable regions in the cells from `inner.0, inner.1` included to
+    /// `inner.2, inner.3` excluded. Regions don't share vertices, so their borders are walls.
+    ///
+    /// Outlines keep their points where they leave the border of `inner`, so that meshes of
+    /// neighbouring tiles can be stitched.
+    fn mesh(&self, inner: (usize, usize, usize, usize)) -> Result<Mesh, MeshBuildError> {
+        let mut floors = self.floors(|span| span.walkable);
+        self.erode(&mut floors, &self.floors(|span| span.ledge));
+        let (regions, count) = self.regions(&floors);
+        let mut extents = vec![(usize::MAX, usize::MAX, 0, 0); count];
+        for z in inner.1..inner.3 {
+            for x in inner.0..inner.2 {
+                if let Some(region) = regions[z * self.width + x] {
+                    let extent = &mut extents[region];
+                    *extent = (
+                        extent.0.min(x),
+                        extent.1.min(z),
+                        extent.2.max(x + 1),
+                        extent.3.max(z + 1),
+                    );
+                }
+            }
+        }
+        // the border of `inner` each point is on, as a coordinate and an axis
+        let borders = |[x, z]: [f32; 2]| {
+            [
+                (x == inner.0 as f32 || x == inner.2 as f32).then_some((x, 0)),
+                (z == inner.1 as f32 || z == inner.3 as f32).then_some((z, 1)),
+            ]
+        };
+
+        let mut builder = MeshBuilder::new();
+        let mut offset = 0;
+        for (region, (x0, z0, x1, z1)) in extents.into_iter().enumerate() {
+            if x0 >= x1 {
+                continue;
+            }
+            let outlines: Vec<Vec<[f32; 2]>> = bitmap::outlines(
+                x1 - x0,
+                z1 - z0,
+                |x, z| regions[(z0 + z) * self.width + x0 + x] == Some(region),
+                self.max_edge_error / self.cell_size,
+                |x, z| {
+                    x0 + x == inner.0 || x0 + x == inner.2 || z0 + z == inner.1 || z0 + z == inner.3
+                },
+            )
+            .into_iter()
+            .map(|outline| {
+                let cells: Vec<[f32; 2]> = outline
+                    .into_iter()
+                    .map(|[x, z]| [x0 as f32 + x, z0 as f32 + z])
+                    .collect();
+                let n = cells.len();
+                // only the ends of the parts along a border are needed
+                (0..n)
+                    .filter(|i| {
+                        let [previous, next] =
+                            [(i + n - 1) % n, (i + 1) % n].map(|k| borders(cells[k]));
+                        !borders(cells[*i]).iter().flatten().any(|border| {
+                            previous.contains(&Some(*border)) && next.contains(&Some(*border))
+                        })
+                    })
+                    .map(|i| {
+                        [
+                            self.origin[0] + cells[i][0] * self.cell_size,
+                            self.origin[2] + cells[i][1] * self.cell_size,
+                        ]
+                    })
+                    .collect()
+            })
+            .collect();
+            let mesh = bitmap::triangulate(&outlines)?;
+            for vertex in &mesh.vertices {
+                builder.add_vertex(vertex.p())?;
+            }
+            for polygon in &mesh.polygons {
+                builder.add_polygon(polygon.vertices.iter().map(|v| v + offset))?;
+            }
+            offset += mesh.vertices.len();
+        }
+        let mut mesh = builder.build()?;
+        mesh.merge_convex();
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_triangles, tile_from_triangles, tiled_from_triangles, BakeConfig, BakeError};
+    use crate::{Mesh, MeshBuildError};
+
+    #[derive(Default)]
+    struct Level {
+        vertices: Vec<[f32; 3]>,
+        indices: Vec<u32>,
+    }
+
+    impl Level {
+        fn quad(mut self, corners: [[f32; 3]; 4]) -> Self {
+            let first = self.vertices.len() as u32;
+            self.vertices.extend(corners);
+            self.indices
+                .extend([0, 1, 2, 0, 2, 3].map(|corner| first + corner));
+            self
+        }
+
+        fn floor(self, size: f32) -> Self {
+            self.quad([
+                [0.0, 0.0, 0.0],
+                [size, 0.0, 0.0],
+                [size, 0.0, size],
+                [0.0, 0.0, size],
+            ])
+        }
+
+        fn cuboid(self, min: [f32; 3], max: [f32; 3]) -> Self {
+            let [x0, y0, z0] = min;
+            let [x1, y1, z1] = max;
+            self.quad([[x0, y1, z0], [x1, y1, z0], [x1, y1, z1], [x0, y1, z1]])
+                .quad([[x0, y0, z0], [x1, y0, z0], [x1, y1, z0], [x0, y1, z0]])
+                .quad([[x0, y0, z1], [x1, y0, z1], [x1, y1, z1], [x0, y1, z1]])
+                .quad([[x0, y0, z0], [x0, y0, z1], [x0, y1, z1], [x0, y1, z0]])
+                .quad([[x1, y0, z0], [x1, y0, z1], [x1, y1, z1], [x1, y1, z0]])
+        }
+
+        fn bake(&self) -> Mesh {
+            self.bake_with(&BakeConfig::default())
+        }
+
+        fn bake_with(&self, config: &BakeConfig) -> Mesh {
+            let mesh = from_triangles(&self.vertices, &self.indices, config).unwrap();
+            assert!(mesh.validate().is_empty());
+            mesh
+        }
+    }
+
+    #[test]
+    fn floor() {
+        let mesh = Level::default().floor(10.0).bake();
+        assert_eq!(mesh.polygons.len(), 1);
+        assert!(mesh.point_in_mesh([5.0, 5.0]));
+        let path = mesh.path([1.0, 1.0], [9.0, 9.0]).unwrap();
+        assert!((path.len - 8.0 * 2.0f32.sqrt()).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn obstacle() {
+        let mesh = Level::default()
+            .floor(10.0)
+            .cuboid([4.0, 0.0, 4.0], [6.0, 3.0, 6.0])
+            .bake();
+        let path = mesh.path([5.0, 1.0], [5.0, 9.0]).unwrap();
+        assert!(path.len > 8.3);
+        assert!(path.path.len() > 2);
+        // the top of the box is walkable, but it can't be reached
+        assert!(mesh.point_in_mesh([5.0, 5.0]));
+        assert_eq!(mesh.path([5.0, 1.0], [5.0, 5.0]), None);
+    }
+
+    #[test]
+    fn steps() {
+        let step = Level::default()
+            .floor(10.0)
+            .cuboid([4.0, 0.0, 0.0], [6.0, 0.4, 10.0])
+            .bake();
+        assert!(step.path([2.0, 5.0], [8.0, 5.0]).is_some());
+
+        let wall = Level::default()
+            .floor(10.0)
+            .cuboid([4.0, 0.0, 0.0], [6.0, 1.5, 10.0])
+            .bake();
+        assert!(wall.point_in_mesh([2.0, 5.0]));
+        assert!(wall.point_in_mesh([8.0, 5.0]));
+        assert_eq!(wall.path([2.0, 5.0], [8.0, 5.0]), None);
+    }
+
+    #[test]
+    fn slopes() {
+        let ramp = |height: f32| {
+            Level::default()
+                .quad([
+                    [0.0, 0.0, 0.0],
+                    [10.0, height, 0.0],
+                    [10.0, height, 10.0],
+                    [0.0, 0.0, 10.0],
+                ])
+                .bake()
+        };
+        assert!(ramp(3.0).path([1.0, 5.0], [9.0, 5.0]).is_some());
+        assert!(ramp(20.0).polygons.is_empty());
+    }
+
+    #[test]
+    fn low_ceiling() {
+        let mesh = Level::default()
+            .floor(10.0)
+            .quad([
+                [3.0, 1.0, 0.0],
+                [7.0, 1.0, 0.0],
+                [7.0, 1.0, 10.0],
+                [3.0, 1.0, 10.0],
+            ])
+            .bake();
+        assert!(mesh.point_in_mesh([1.0, 5.0]));
+        assert!(mesh.point_in_mesh([9.0, 5.0]));
+        assert_eq!(mesh.path([1.0, 5.0], [9.0, 5.0]), None);
+    }
+
+    #[test]
+    fn agent_radius() {
+        let level = Level::default()
+            .floor(10.0)
+            .cuboid([4.0, 0.0, 4.0], [6.0, 3.0, 6.0]);
+        let config = BakeConfig {
+            agent_radius: 0.5,
+            ..Default::default()
+        };
+        let mesh = level.bake_with(&config);
+        for point in [[0.3, 5.0], [5.0, 9.7], [3.7, 5.0], [4.3, 5.0]] {
+            assert!(!mesh.point_in_mesh(point));
+        }
+        for point in [[0.8, 5.0], [3.2, 5.0], [5.0, 6.8]] {
+            assert!(mesh.point_in_mesh(point));
+        }
+        let path = mesh.path([5.0, 1.0], [5.0, 9.0]).unwrap();
+        for point in &path.path {
+            let distance = (point[0] - 5.0).abs().max((point[1] - 5.0).abs());
+            assert!(distance > 1.4);
+        }
+
+        let tiled = tiled_from_triangles(&level.vertices, &level.indices, &config, 5.0).unwrap();
+        assert!(tiled.mesh().validate().is_empty());
+        assert!(!tiled.mesh().point_in_mesh([3.7, 5.0]));
+        let expected = path.len;
+        assert!((tiled.mesh().path([5.0, 1.0], [5.0, 9.0]).unwrap().len - expected).abs() < 0.3);
+    }
+
+    #[test]
+    fn tiles() {
+        let level = Level::default()
+            .floor(10.0)
+            .cuboid([4.0, 0.0, 3.0], [6.0, 3.0, 6.0]);
+        let config = BakeConfig::default();
+        let whole = from_triangles(&level.vertices, &level.indices, &config).unwrap();
+        let tiled = tiled_from_triangles(&level.vertices, &level.indices, &config, 5.0).unwrap();
+        assert_eq!(
+            tiled.tiles().map(|(tile, _)| tile).collect::<Vec<_>>(),
+            vec![[0, 0], [0, 1], [1, 0], [1, 1]]
+        );
+        let mesh = tiled.mesh();
+        assert!(mesh.validate().is_empty());
+        for (from, to) in [
+            ([1.0, 1.0], [9.0, 9.0]),
+            ([5.0, 1.0], [5.0, 9.0]),
+            ([1.0, 4.5], [9.0, 4.5]),
+        ] {
+            let expected = whole.path(from, to).unwrap().len;
+            assert!((mesh.path(from, to).unwrap().len - expected).abs() < 0.3);
+        }
+
+        let tile =
+            tile_from_triangles(&level.vertices, &level.indices, &config, 5.0, [1, 0]).unwrap();
+        assert_eq!(
+            tile.bounding_box(),
+            tiled.tile([1, 0]).unwrap().bounding_box()
+        );
+        assert!(tile.bounding_box().min[0] >= 5.0);
+        assert_eq!(
+            tile_from_triangles(&level.vertices, &level.indices, &config, 0.0, [0, 0]).unwrap_err(),
+            BakeError::InvalidConfig { field: "tile_size" }
+        );
+    }
+
+    #[test]
+    fn invalid_input() {
+        let floor = Level::default().floor(10.0);
+        let config = BakeConfig {
+            cell_size: 0.0,
+            ..Default::default()
+        };
+        assert_eq!(
+            from_triangles(&floor.vertices, &floor.indices, &config).unwrap_err(),
+            BakeError::InvalidConfig { field: "cell_size" }
+        );
+        assert_eq!(
+            from_triangles(&floor.vertices, &[0, 1, 2, 0, 2, 4], &Default::default()).unwrap_err(),
+            BakeError::InvalidMesh(MeshBuildError::VertexOutOfRange {
+                polygon: 1,
+                vertex: 4
+            })
+        );
+        let mut vertices = floor.vertices.clone();
+        vertices[2][1] = f32::NAN;
+        assert_eq!(
+            from_triangles(&vertices, &floor.indices, &Default::default()).unwrap_err(),
+            BakeError::InvalidMesh(MeshBuildError::NonFiniteVertex { vertex: 2 })
+        );
+        assert!(from_triangles(&[], &[], &Default::default())
+            .unwrap()
+            .polygons
+            .is_empty());
+    }
+}
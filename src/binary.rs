@@ -0,0 +1,518 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, Read, Write},
+};
+
+use crate::{
+    builder::check_polygon, grid::PolygonGrid, Aabb, EdgeId, Mesh, PointSnap, Polygon, PolygonId,
+    SnapPattern, Vertex,
+};
+
+/// First bytes of a mesh in the binary format.
+pub(crate) const MAGIC: &[u8; 4] = b"PLYA";
+/// Version of the binary format written by [`Mesh::save_binary`].
+const VERSION: u32 = 1;
+
+/// Flag set when the output quantization of the mesh is stored.
+const FLAG_QUANTIZATION: u32 = 1;
+/// Flag set when the bounding boxes and the point location grid are stored.
+const FLAG_ACCELERATION: u32 = 1 << 1;
+/// Flag set when the area flags of the polygons are stored.
+const FLAG_AREAS: u32 = 1 << 2;
+/// Flag set when the off mesh clamp of the mesh is stored.
+const FLAG_CLAMP: u32 = 1 << 3;
+/// Flag set when the point snapping of the mesh is stored.
+const FLAG_SNAP: u32 = 1 << 4;
+/// Flag set when the secondary costs of the polygons are stored.
+const FLAG_SECONDARY: u32 = 1 << 5;
+/// Flag set when closed edges are stored.
+const FLAG_CLOSED_EDGES: u32 = 1 << 6;
+/// Flag set when one-way edges are stored.
+const FLAG_ONE_WAY_EDGES: u32 = 1 << 7;
+/// Flag set when the secondary costs of the edges are stored.
+const FLAG_SECONDARY_EDGES: u32 = 1 << 8;
+/// All the flags this version can read. Data with other flags was written by a later version, and
+/// is rejected instead of being read wrong.
+const KNOWN_FLAGS: u32 = FLAG_QUANTIZATION
+    | FLAG_ACCELERATION
+    | FLAG_AREAS
+    | FLAG_CLAMP
+    | FLAG_SNAP
+    | FLAG_SECONDARY
+    | FLAG_CLOSED_EDGES
+    | FLAG_ONE_WAY_EDGES
+    | FLAG_SECONDARY_EDGES;
+
+/// Error returned when a mesh can't be read from the binary format.
+#[derive(Debug)]
+pub enum MeshBinaryError {
+    /// The data couldn't be read.
+    Io(io::Error),
+    /// The data doesn't start with the magic header of the format.
+    InvalidMagic,
+    /// The data was written with a version of the format this version can't read.
+    UnsupportedVersion(u32),
+    /// The data is not a valid mesh.
+    Corrupted(&'static str),
+}
+
+impl Display for MeshBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshBinaryError::Io(error) => write!(f, "error reading binary mesh: {}", error),
+            MeshBinaryError::InvalidMagic => write!(f, "not a binary mesh"),
+            MeshBinaryError::UnsupportedVersion(version) => {
+                write!(f, "unsupported binary mesh version {}", version)
+            }
+            MeshBinaryError::Corrupted(reason) => write!(f, "corrupted binary mesh: {}", reason),
+        }
+    }
+}
+
+impl Error for MeshBinaryError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MeshBinaryError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MeshBinaryError {
+    fn from(error: io::Error) -> Self {
+        MeshBinaryError::Io(error)
+    }
+}
+
+/// Writes the little endian values of the binary format.
+pub(crate) struct BinaryWriter<W: Write>(pub(crate) W);
+
+impl<W: Write> BinaryWriter<W> {
+    pub(crate) fn u32(&mut self, value: u32) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    pub(crate) fn i32(&mut self, value: i32) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    pub(crate) fn f32(&mut self, value: f32) -> io::Result<()> {
+        self.0.write_all(&value.to_le_bytes())
+    }
+
+    pub(crate) fn len(&mut self, value: usize) -> io::Result<()> {
+        let value = u32::try_from(value)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "mesh too large"))?;
+        self.u32(value)
+    }
+
+    pub(crate) fn aabb(&mut self, aabb: Aabb) -> io::Result<()> {
+        for value in [aabb.min[0], aabb.min[1], aabb.max[0], aabb.max[1]] {
+            self.f32(value)?;
+        }
+        Ok(())
+    }
+
+    /// Write edges sorted, so that the same mesh is always written the same way.
+    fn edges(&mut self, edges: impl Iterator<Item = EdgeId>) -> io::Result<()> {
+        let mut edges: Vec<EdgeId> = edges.collect();
+        edges.sort_unstable();
+        self.len(edges.len())?;
+        for edge in edges {
+            self.len(edge[0])?;
+            self.len(edge[1])?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the little endian values of the binary format.
+pub(crate) struct BinaryReader<R: Read>(pub(crate) R);
+
+impl<R: Read> BinaryReader<R> {
+    fn bytes<const N: usize>(&mut self) -> io::Result<[u8; N]> {
+        let mut bytes = [0; N];
+        self.0.read_exact(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    pub(crate) fn u32(&mut self) -> io::Result<u32> {
+        self.bytes().map(u32::from_le_bytes)
+    }
+
+    pub(crate) fn i32(&mut self) -> io::Result<i32> {
+        self.bytes().map(i32::from_le_bytes)
+    }
+
+    pub(crate) fn f32(&mut self) -> io::Result<f32> {
+        self.bytes().map(f32::from_le_bytes)
+    }
+
+    pub(crate) fn len(&mut self) -> io::Result<usize> {
+        self.u32().map(|value| value as usize)
+    }
+
+    pub(crate) fn aabb(&mut self) -> io::Result<Aabb> {
+        Ok(Aabb::new(
+            [self.f32()?, self.f32()?],
+            [self.f32()?, self.f32()?],
+        ))
+    }
+
+    fn edge(&mut self) -> io::Result<EdgeId> {
+        Ok([self.len()?, self.len()?])
+    }
+
+    /// Read `count` values, without trusting `count` to allocate memory upfront.
+    pub(crate) fn many<T>(
+        &mut self,
+        count: usize,
+        mut read: impl FnMut(&mut Self) -> Result<T, MeshBinaryError>,
+    ) -> Result<Vec<T>, MeshBinaryError> {
+        let mut values = Vec::with_capacity(count.min(4096));
+        for _ in 0..count {
+            values.push(read(self)?);
+        }
+        Ok(values)
+    }
+}
+
+impl Mesh {
+    /// Write the mesh in a compact binary format, faster to load than the `.mesh` format.
+    ///
+    /// The bounding boxes and the grid used to locate points are stored too, so they don't have
+    /// to be computed again when loading the mesh with [`Mesh::load_binary`].
+    pub fn save_binary(&self, writer: impl Write) -> io::Result<()> {
+        let mut out = BinaryWriter(io::BufWriter::new(writer));
+        out.0.write_all(MAGIC)?;
+        out.u32(VERSION)?;
+        let mut flags = FLAG_ACCELERATION;
+        if self.output_quantization.is_some() {
+            flags |= FLAG_QUANTIZATION;
+        }
+        if self.off_mesh_clamp.is_some() {
+            flags |= FLAG_CLAMP;
+        }
+        if self.point_snap.is_some() {
+            flags |= FLAG_SNAP;
+        }
+        let has_areas = self.polygons.iter().any(|polygon| polygon.flags != 0);
+        if has_areas {
+            flags |= FLAG_AREAS;
+        }
+        let has_secondary = self
+            .polygons
+            .iter()
+            .any(|polygon| polygon.secondary_cost != 0.0);
+        if has_secondary {
+            flags |= FLAG_SECONDARY;
+        }
+        if !self.closed_edges.is_empty() {
+            flags |= FLAG_CLOSED_EDGES;
+        }
+        if !self.one_way_edges.is_empty() {
+            flags |= FLAG_ONE_WAY_EDGES;
+        }
+        if !self.secondary_edge_costs.is_empty() {
+            flags |= FLAG_SECONDARY_EDGES;
+        }
+        out.u32(flags)?;
+        out.len(self.vertices.len())?;
+        out.len(self.polygons.len())?;
+        if let Some(grid) = self.output_quantization {
+            out.f32(grid)?;
+        }
+        if let Some(max_distance) = self.off_mesh_clamp {
+            out.f32(max_distance)?;
+        }
+        if let Some(snap) = self.point_snap {
+            out.f32(snap.radius)?;
+            match snap.pattern {
+                SnapPattern::Square => out.u32(0)?,
+                SnapPattern::Circles { points, rings } => {
+                    out.u32(1)?;
+                    out.len(points)?;
+                    out.len(rings)?;
+                }
+            }
+        }
+
+        for vertex in &self.vertices {
+            out.f32(vertex.x)?;
+            out.f32(vertex.y)?;
+            out.len(vertex.polygons.len())?;
+            for polygon in &vertex.polygons {
+                out.i32(polygon.map_or(-1, |polygon| polygon.index() as i32))?;
+            }
+        }
+        for polygon in &self.polygons {
+            out.len(polygon.vertices.len())?;
+            for vertex in &polygon.vertices {
+                out.len(*vertex)?;
+            }
+            out.u32(polygon.is_one_way as u32)?;
+            if has_areas {
+                out.u32(polygon.flags)?;
+            }
+            if has_secondary {
+                out.f32(polygon.secondary_cost)?;
+            }
+        }
+        if !self.closed_edges.is_empty() {
+            out.edges(self.closed_edges.iter().copied())?;
+        }
+        if !self.one_way_edges.is_empty() {
+            out.edges(self.one_way_edges.iter().copied())?;
+        }
+        if !self.secondary_edge_costs.is_empty() {
+            let mut costs: Vec<_> = self.secondary_edge_costs.iter().collect();
+            costs.sort_unstable_by_key(|(edge, _)| **edge);
+            out.len(costs.len())?;
+            for (edge, cost) in costs {
+                out.len(edge[0])?;
+                out.len(edge[1])?;
+                out.f32(*cost)?;
+            }
+        }
+
+        out.aabb(self.aabb)?;
+        for aabb in &self.polygon_aabbs {
+            out.aabb(*aabb)?;
+        }
+        self.polygon_grid.save(&mut out)?;
+        out.0.flush()
+    }
+
+    /// Read a mesh written by [`Mesh::save_binary`].
+    ///
+    /// The reader is buffered internally, there is no need to wrap it in a [`io::BufReader`].
+    pub fn load_binary(reader: impl Read) -> Result<Mesh, MeshBinaryError> {
+        let mut input = BinaryReader(io::BufReader::new(reader));
+        if &input.bytes::<4>()? != MAGIC {
+            return Err(MeshBinaryError::InvalidMagic);
+        }
+        let version = input.u32()?;
+        if version != VERSION {
+            return Err(MeshBinaryError::UnsupportedVersion(version));
+        }
+        let flags = input.u32()?;
+        if flags & !KNOWN_FLAGS != 0 {
+            return Err(MeshBinaryError::Corrupted("unknown flags"));
+        }
+        let nb_vertices = input.len()?;
+        let nb_polygons = input.len()?;
+        let output_quantization = if flags & FLAG_QUANTIZATION != 0 {
+            Some(input.f32()?)
+        } else {
+            None
+        };
+        let off_mesh_clamp = if flags & FLAG_CLAMP != 0 {
+            Some(input.f32()?)
+        } else {
+            None
+        };
+        let point_snap = if flags & FLAG_SNAP != 0 {
+            let radius = input.f32()?;
+            let pattern = match input.u32()? {
+                0 => SnapPattern::Square,
+                1 => SnapPattern::Circles {
+                    points: input.len()?,
+                    rings: input.len()?,
+                },
+                _ => return Err(MeshBinaryError::Corrupted("unknown snap pattern")),
+            };
+            Some(PointSnap { radius, pattern })
+        } else {
+            None
+        };
+
+        let vertices = input.many(nb_vertices, |input| {
+            let x = input.f32()?;
+            let y = input.f32()?;
+            let nb = input.len()?;
+            let polygons = input.many(nb, |input| match input.i32()? {
+                -1 => Ok(None),
+                polygon if polygon >= 0 && (polygon as usize) < nb_polygons => {
+                    Ok(Some(PolygonId::new(polygon as usize)))
+                }
+                _ => Err(MeshBinaryError::Corrupted("unknown polygon around vertex")),
+            })?;
+            Ok(Vertex {
+                x,
+                y,
+                is_corner: polygons.contains(&None),
+                polygons,
+            })
+        })?;
+        let mut index = 0;
+        let polygons = input.many(nb_polygons, |input| {
+            let nb = input.len()?;
+            let vertices = input.many(nb, |input| Ok(input.len()?))?;
+            check_polygon(index, &vertices, nb_vertices)
+                .map_err(|_| MeshBinaryError::Corrupted("invalid polygon"))?;
+            index += 1;
+            let is_one_way = input.u32()? != 0;
+            let area_flags = if flags & FLAG_AREAS != 0 {
+                input.u32()?
+            } else {
+                0
+            };
+            let secondary_cost = if flags & FLAG_SECONDARY != 0 {
+                input.f32()?
+            } else {
+                0.0
+            };
+            Ok(Polygon {
+                vertices,
+                is_one_way,
+                flags: area_flags,
+                secondary_cost,
+            })
+        })?;
+        let closed_edges = if flags & FLAG_CLOSED_EDGES != 0 {
+            let nb = input.len()?;
+            input.many(nb, |input| Ok(input.edge()?))?
+        } else {
+            vec![]
+        };
+        let one_way_edges = if flags & FLAG_ONE_WAY_EDGES != 0 {
+            let nb = input.len()?;
+            input.many(nb, |input| Ok(input.edge()?))?
+        } else {
+            vec![]
+        };
+        let secondary_edge_costs = if flags & FLAG_SECONDARY_EDGES != 0 {
+            let nb = input.len()?;
+            input.many(nb, |input| Ok((input.edge()?, input.f32()?)))?
+        } else {
+            vec![]
+        };
+
+        let mut mesh = if flags & FLAG_ACCELERATION != 0 {
+            let aabb = input.aabb()?;
+            let polygon_aabbs = input.many(nb_polygons, |input| Ok(input.aabb()?))?;
+            let polygon_grid = PolygonGrid::load(&mut input, nb_polygons)?;
+            let mut mesh = Mesh::without_caches(vertices, polygons);
+            mesh.polygon_aabbs = polygon_aabbs;
+            mesh.aabb = aabb;
+            mesh.polygon_grid = polygon_grid;
+            mesh.update_islands();
+            mesh
+        } else {
+            Mesh::new(vertices, polygons)
+        };
+        mesh.set_output_quantization(output_quantization);
+        mesh.set_off_mesh_clamp(off_mesh_clamp);
+        mesh.set_point_snap(point_snap);
+        mesh.split_concave_polygons();
+        for edge in closed_edges {
+            if !mesh.set_edge_open(edge, false) {
+                return Err(MeshBinaryError::Corrupted(
+                    "closed edge not between two polygons",
+                ));
+            }
+        }
+        for edge in one_way_edges {
+            if !mesh.set_edge_one_way(edge, true) {
+                return Err(MeshBinaryError::Corrupted(
+                    "one-way edge not between two polygons",
+                ));
+            }
+        }
+        for (edge, cost) in secondary_edge_costs {
+            if !mesh.set_edge_secondary_cost(edge, cost) {
+                return Err(MeshBinaryError::Corrupted("invalid secondary cost of edge"));
+            }
+        }
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeshBinaryError;
+    use crate::{tests::mesh_u_grid, Mesh, PointSnap, SnapPattern};
+
+    #[test]
+    fn round_trip() {
+        let mut mesh = Mesh::from_file("meshes/arena-merged.mesh").unwrap();
+        mesh.set_output_quantization(Some(0.25));
+        mesh.set_off_mesh_clamp(Some(0.5));
+        let snap = PointSnap {
+            radius: 0.2,
+            pattern: SnapPattern::Circles {
+                points: 6,
+                rings: 2,
+            },
+        };
+        mesh.set_point_snap(Some(snap));
+        mesh.set_polygon_flags(crate::PolygonId::new(3), 0b101);
+        let door = mesh.interior_edges().next().unwrap().0;
+        assert!(mesh.set_edge_open(door, false));
+        let drop = mesh.interior_edges().nth(5).unwrap().0;
+        assert!(mesh.set_edge_one_way([drop[1], drop[0]], true));
+        let mut bytes = vec![];
+        mesh.save_binary(&mut bytes).unwrap();
+        let loaded = Mesh::load_binary(bytes.as_slice()).unwrap();
+        assert!(mesh.diff(&loaded, 0.0).is_empty());
+        assert_eq!(loaded.output_quantization(), Some(0.25));
+        assert_eq!(loaded.off_mesh_clamp(), Some(0.5));
+        assert_eq!(loaded.point_snap(), snap);
+        assert_eq!(loaded.bounding_box(), mesh.bounding_box());
+        assert_eq!(loaded.polygons[3].flags(), 0b101);
+        assert!(loaded.is_edge_closed(door));
+        assert!(loaded.can_cross([drop[1], drop[0]]));
+        assert!(!loaded.can_cross(drop));
+        assert_eq!(
+            loaded.path([1.0, 3.0], [4.0, 2.0]),
+            mesh.path([1.0, 3.0], [4.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn invalid_data() {
+        let mut bytes = vec![];
+        mesh_u_grid().save_binary(&mut bytes).unwrap();
+        assert!(matches!(
+            Mesh::load_binary(&b"mesh\n2\n"[..]),
+            Err(MeshBinaryError::InvalidMagic)
+        ));
+        let mut future = bytes.clone();
+        future[4] = 2;
+        assert!(matches!(
+            Mesh::load_binary(future.as_slice()),
+            Err(MeshBinaryError::UnsupportedVersion(2))
+        ));
+        assert!(matches!(
+            Mesh::load_binary(&bytes[..bytes.len() - 1]),
+            Err(MeshBinaryError::Io(_))
+        ));
+        // first polygon around the first vertex
+        let mut corrupted = bytes.clone();
+        corrupted[32..36].copy_from_slice(&7_i32.to_le_bytes());
+        assert!(matches!(
+            Mesh::load_binary(corrupted.as_slice()),
+            Err(MeshBinaryError::Corrupted(_))
+        ));
+        // flags of a later version
+        let mut future = bytes.clone();
+        future[11] = 0x80;
+        assert!(matches!(
+            Mesh::load_binary(future.as_slice()),
+            Err(MeshBinaryError::Corrupted("unknown flags"))
+        ));
+        // a polygon with 2 vertices
+        let mut two = vec![];
+        Mesh::new(vec![], vec![]).save_binary(&mut two).unwrap();
+        two.truncate(12);
+        // 2 vertices without polygons around them, and a polygon using them
+        for value in [2_u32, 1, 0, 0, 0, 0x3f80_0000, 0, 0, 2, 0, 1, 0] {
+            two.extend(value.to_le_bytes());
+        }
+        assert!(matches!(
+            Mesh::load_binary(two.as_slice()),
+            Err(MeshBinaryError::Corrupted("invalid polygon"))
+        ));
+    }
+}
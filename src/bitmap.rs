@@ -0,0 +1,412 @@
+//! Import of walkability bitmaps, like procedural maps rendered to a texture.
+//!
+//! The outlines of the walkable pixels are traced, simplified and triangulated with a constrained
+//! Delaunay triangulation. Pixel `(x, y)` covers the square from `[x, y]` to `[x + 1, y + 1]`,
+//! with `y` going down like the rows of the image. The mesh is made of triangles, that can be
+//! merged with [`Mesh::merge_convex`].
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{
+    cdt::Triangulation,
+    helpers::{distance_between, point_in_simple_polygon, project_on_segment},
+    Aabb, Mesh, MeshBuildError, MeshBuilder,
+};
+
+/// Error returned when a bitmap can't be imported.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BitmapError {
+    /// The buffer has fewer bytes than needed for the size of the image.
+    BufferTooSmall { expected: usize, found: usize },
+    /// The mesh built from the outlines is invalid.
+    InvalidMesh(MeshBuildError),
+}
+
+impl Display for BitmapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitmapError::BufferTooSmall { expected, found } => write!(
+                f,
+                "buffer too small: expected {} bytes, found {}",
+                expected, found
+            ),
+            BitmapError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+impl Error for BitmapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BitmapError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<MeshBuildError> for BitmapError {
+    fn from(error: MeshBuildError) -> Self {
+        BitmapError::InvalidMesh(error)
+    }
+}
+
+fn check_len(buffer: &[u8], expected: usize) -> Result<(), BitmapError> {
+    if buffer.len() < expected {
+        return Err(BitmapError::BufferTooSmall {
+            expected,
+            found: buffer.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Build a mesh from a grayscale image with one byte per pixel, row by row. Pixels at least as
+/// bright as `threshold` are walkable.
+///
+/// Outlines are simplified as long as they stay within `tolerance` pixels of the traced ones, `0.0`
+/// only removing the points in the middle of straight lines.
+///
+/// ```
+/// # use polyanya::bitmap;
+/// #[rustfmt::skip]
+/// let pixels = [
+///     0,   0,   0,   0,
+///     0, 255, 255,   0,
+///     0, 255,   0,   0,
+///     0, 255, 255, 255,
+/// ];
+/// let mesh = bitmap::from_gray(&pixels, 4, 4, 128, 0.0).unwrap();
+/// assert!(mesh.point_in_mesh([1.5, 2.5]));
+/// assert!(!mesh.point_in_mesh([2.5, 2.5]));
+/// ```
+pub fn from_gray(
+    pixels: &[u8],
+    width: usize,
+    height: usize,
+    threshold: u8,
+    tolerance: f32,
+) -> Result<Mesh, BitmapError> {
+    check_len(pixels, width * height)?;
+    Ok(from_walkable(
+        width,
+        height,
+        |x, y| pixels[y * width + x] >= threshold,
+        tolerance,
+    )?)
+}
+
+/// Build a mesh from a 1-bit image, with 8 pixels per byte starting from the most significant bit
+/// and each row starting on a new byte. Pixels set to `1` are walkable.
+///
+/// Outlines are simplified like in [`from_gray`].
+pub fn from_mono(
+    bits: &[u8],
+    width: usize,
+    height: usize,
+    tolerance: f32,
+) -> Result<Mesh, BitmapError> {
+    let stride = width.div_ceil(8);
+    check_len(bits, stride * height)?;
+    Ok(from_walkable(
+        width,
+        height,
+        |x, y| bits[y * stride + x / 8] & (0x80 >> (x % 8)) != 0,
+        tolerance,
+    )?)
+}
+
+/// Build a mesh from the walkable pixels of a `width` x `height` image.
+///
+/// Outlines are simplified like in [`from_gray`].
+pub fn from_walkable(
+    width: usize,
+    height: usize,
+    walkable: impl Fn(usize, usize) -> bool,
+    tolerance: f32,
+) -> Result<Mesh, MeshBuildError> {
+    triangulate(&outlines(width, height, walkable, tolerance, |_, _| false))
+}
+
+/// Trace and simplify the outlines of the walkable pixels of a `width` x `height` image, keeping
+/// the corners for which `anchored` is `true`.
+pub(crate) fn outlines(
+    width: usize,
+    height: usize,
+    walkable: impl Fn(usize, usize) -> bool,
+    tolerance: f32,
+    anchored: impl Fn(usize, usize) -> bool,
+) -> Vec<Vec<[f32; 2]>> {
+    trace(width, height, walkable)
+        .into_iter()
+        .map(|(outline, mut anchors)| {
+            anchors.extend(outline.iter().filter(|(x, y)| anchored(*x, *y)));
+            simplify(&outline, &anchors, tolerance.max(0.0))
+        })
+        .collect()
+}
+
+/// Triangulate the area inside an odd number of outlines.
+pub(crate) fn triangulate(outlines: &[Vec<[f32; 2]>]) -> Result<Mesh, MeshBuildError> {
+    if outlines.is_empty() {
+        return MeshBuilder::new().build();
+    }
+    let bounds: Vec<_> = outlines
+        .iter()
+        .map(|outline| Aabb::from_points(outline.iter().copied()))
+        .collect();
+    let mut triangulation = Triangulation::new(
+        bounds
+            .iter()
+            .fold(Aabb::EMPTY, |aabb, bounds| aabb.union(bounds)),
+    );
+    for outline in outlines {
+        triangulation.insert_outline(outline);
+    }
+    // each outline separates walkable pixels from blocked ones, and the outside of the image is
+    // blocked
+    triangulation.mesh(|point| {
+        outlines
+            .iter()
+            .zip(&bounds)
+            .filter(|(outline, bounds)| {
+                bounds.contains(point) && point_in_simple_polygon(point, outline)
+            })
+            .count()
+            % 2
+            == 1
+    })
+}
+
+type Corner = (usize, usize);
+
+/// Trace the boundaries between walkable and blocked pixels as closed outlines, with the corners
+/// shared by several outlines or visited twice by the same one.
+///
+/// Boundaries are followed with the walkable pixels on their left, turning left where two
+/// walkable pixels only touch by a corner.
+fn trace(
+    width: usize,
+    height: usize,
+    walkable: impl Fn(usize, usize) -> bool,
+) -> Vec<(Vec<Corner>, HashSet<Corner>)> {
+    let is_walkable = |x: isize, y: isize| {
+        x >= 0
+            && y >= 0
+            && (x as usize) < width
+            && (y as usize) < height
+            && walkable(x as usize, y as usize)
+    };
+    let mut outgoing: HashMap<Corner, Vec<Corner>> = HashMap::new();
+    for y in 0..height {
+        for x in 0..width {
+            if !walkable(x, y) {
+                continue;
+            }
+            let (ix, iy) = (x as isize, y as isize);
+            for (blocked, from, to) in [
+                (!is_walkable(ix, iy - 1), (x, y), (x + 1, y)),
+                (!is_walkable(ix + 1, iy), (x + 1, y), (x + 1, y + 1)),
+                (!is_walkable(ix, iy + 1), (x + 1, y + 1), (x, y + 1)),
+                (!is_walkable(ix - 1, iy), (x, y + 1), (x, y)),
+            ] {
+                if blocked {
+                    outgoing.entry(from).or_default().push(to);
+                }
+            }
+        }
+    }
+    let junctions: HashSet<Corner> = outgoing
+        .iter()
+        .filter(|(_, targets)| targets.len() > 1)
+        .map(|(corner, _)| *corner)
+        .collect();
+
+    let mut starts: Vec<Corner> = outgoing.keys().copied().collect();
+    starts.sort_unstable();
+    let mut outlines = vec![];
+    for start in starts {
+        while outgoing
+            .get(&start)
+            .is_some_and(|targets| !targets.is_empty())
+        {
+            let mut outline = vec![start];
+            let mut current = start;
+            let mut next = outgoing.get_mut(&start).unwrap().pop().unwrap();
+            while next != start {
+                let direction = (
+                    next.0 as isize - current.0 as isize,
+                    next.1 as isize - current.1 as isize,
+                );
+                outline.push(next);
+                current = next;
+                let Some(targets) = outgoing.get_mut(&current).filter(|t| !t.is_empty()) else {
+                    break;
+                };
+                // the turn to the left has the largest cross product with the direction
+                let turn = |target: &Corner| {
+                    direction.0 * (target.1 as isize - current.1 as isize)
+                        - direction.1 * (target.0 as isize - current.0 as isize)
+                };
+                let chosen = (0..targets.len())
+                    .max_by_key(|i| turn(&targets[*i]))
+                    .unwrap();
+                next = targets.swap_remove(chosen);
+            }
+            let anchors = outline
+                .iter()
+                .filter(|corner| junctions.contains(*corner))
+                .copied()
+                .collect();
+            outlines.push((outline, anchors));
+        }
+    }
+    outlines
+}
+
+/// Simplify a closed outline with the Douglas–Peucker algorithm, keeping its anchors.
+fn simplify(outline: &[Corner], anchors: &HashSet<Corner>, tolerance: f32) -> Vec<[f32; 2]> {
+    let points: Vec<[f32; 2]> = outline
+        .iter()
+        .map(|(x, y)| [*x as f32, *y as f32])
+        .collect();
+    let n = points.len();
+    let mut kept = vec![false; n];
+    for (i, corner) in outline.iter().enumerate() {
+        kept[i] = anchors.contains(corner);
+    }
+    // at least two points are needed to split the outline in chains
+    let first = kept.iter().position(|k| *k).unwrap_or(0);
+    kept[first] = true;
+    if kept.iter().filter(|k| **k).count() < 2 {
+        let furthest = (0..n)
+            .max_by(|a, b| {
+                distance_between(points[first], points[*a])
+                    .total_cmp(&distance_between(points[first], points[*b]))
+            })
+            .unwrap();
+        kept[furthest] = true;
+    }
+
+    let anchors: Vec<usize> = (0..n).filter(|i| kept[*i]).collect();
+    for (k, start) in anchors.iter().enumerate() {
+        let end = anchors[(k + 1) % anchors.len()];
+        let end = if end <= *start { end + n } else { end };
+        let mut stack = vec![(*start, end)];
+        while let Some((start, end)) = stack.pop() {
+            if end <= start + 1 {
+                continue;
+            }
+            let segment = [points[start % n], points[end % n]];
+            let (furthest, distance) = (start + 1..end)
+                .map(|i| {
+                    let point = points[i % n];
+                    (
+                        i,
+                        distance_between(point, project_on_segment(point, segment)),
+                    )
+                })
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap();
+            if distance > tolerance {
+                kept[furthest % n] = true;
+                stack.push((start, furthest));
+                stack.push((furthest, end));
+            }
+        }
+    }
+
+    let simplified: Vec<[f32; 2]> = (0..n).filter(|i| kept[*i]).map(|i| points[i]).collect();
+    if simplified.len() < 3 {
+        // too simplified to enclose anything, keep the corners
+        return (0..n)
+            .filter(|i| {
+                let [a, b, c] = [points[(i + n - 1) % n], points[*i], points[(i + 1) % n]];
+                (b[0] - a[0]) * (c[1] - b[1]) != (b[1] - a[1]) * (c[0] - b[0])
+            })
+            .map(|i| points[i])
+            .collect();
+    }
+    simplified
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_gray, from_mono, from_walkable, BitmapError};
+
+    #[test]
+    fn ring() {
+        // walkable square with a blocked center
+        let mut pixels = vec![255; 64];
+        for y in 3..5 {
+            for x in 3..5 {
+                pixels[y * 8 + x] = 0;
+            }
+        }
+        let mesh = from_gray(&pixels, 8, 8, 128, 0.0).unwrap();
+        assert!(mesh.validate().is_empty());
+        assert_eq!(mesh.vertices.len(), 8);
+        assert!(mesh.point_in_mesh([0.5, 0.5]));
+        assert!(!mesh.point_in_mesh([4.0, 4.0]));
+        let path = mesh.path([4.0, 1.0], [4.0, 7.0]).unwrap();
+        assert!((path.len - (2.0 + 2.0 * 1.0f32.hypot(2.0))).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn mono() {
+        // two rooms separated by a wall, with a pillar in the right one
+        #[rustfmt::skip]
+        let bits = [
+            0b1110_1111, 0b1000_0000,
+            0b1110_1001, 0b1000_0000,
+            0b1110_1111, 0b1000_0000,
+        ];
+        let mesh = from_mono(&bits, 9, 3, 0.0).unwrap();
+        assert!(mesh.validate().is_empty());
+        assert!(mesh.point_in_mesh([1.5, 1.5]));
+        assert!(!mesh.point_in_mesh([3.5, 1.5]));
+        assert!(!mesh.point_in_mesh([5.5, 1.5]));
+        assert!(mesh.point_in_mesh([4.5, 1.5]));
+        assert!(mesh.path([0.5, 0.5], [7.5, 0.5]).is_none());
+        assert!(mesh.path([4.5, 0.5], [7.5, 2.5]).is_some());
+
+        assert_eq!(
+            from_mono(&bits[..5], 9, 3, 0.0).unwrap_err(),
+            BitmapError::BufferTooSmall {
+                expected: 6,
+                found: 5
+            }
+        );
+    }
+
+    #[test]
+    fn touching_corners() {
+        let mesh = from_walkable(2, 2, |x, y| x == y, 0.0).unwrap();
+        assert!(mesh.validate().is_empty());
+        assert!(mesh.point_in_mesh([0.5, 0.5]));
+        assert!(mesh.point_in_mesh([1.5, 1.5]));
+        assert!(!mesh.point_in_mesh([1.5, 0.5]));
+
+        let empty = from_walkable(4, 4, |_, _| false, 0.0).unwrap();
+        assert!(empty.polygons.is_empty());
+    }
+
+    #[test]
+    fn simplified_disk() {
+        let inside =
+            |x: usize, y: usize| (x as f32 + 0.5 - 20.0).hypot(y as f32 + 0.5 - 20.0) < 15.0;
+        let exact = from_walkable(40, 40, inside, 0.0).unwrap();
+        let simplified = from_walkable(40, 40, inside, 1.0).unwrap();
+        assert!(simplified.vertices.len() * 2 < exact.vertices.len());
+        assert!(simplified.validate().is_empty());
+        for mesh in [&exact, &simplified] {
+            assert!(mesh.point_in_mesh([20.0, 20.0]));
+            assert!(!mesh.point_in_mesh([2.0, 2.0]));
+            assert!(mesh.path([8.0, 20.0], [32.0, 20.0]).is_some());
+        }
+    }
+}
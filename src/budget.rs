@@ -0,0 +1,82 @@
+use crate::{Mesh, Path, SearchOptions};
+
+/// Path found by a search with a limited number of expanded nodes, by [`Mesh::path_with_budget`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BudgetedPath {
+    /// The path, ending at the target if `complete`, or else at the closest point to the target
+    /// seen by the best node of the search when it ran out of budget.
+    pub path: Path,
+    /// `false` if the search ran out of budget before reaching the target.
+    pub complete: bool,
+}
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], expanding at most
+    /// `max_expanded` nodes.
+    ///
+    /// When the search runs out of budget, the path returned leads towards the target and is not
+    /// complete. It keeps queries from running for too long on huge or broken meshes. Returns
+    /// `None` if one of the points is not in the mesh, or if `to` can't be reached from `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+    /// let partial = mesh.path_with_budget([1.0, 3.0], [4.0, 2.0], 1).unwrap();
+    /// assert!(!partial.complete);
+    ///
+    /// let complete = mesh.path_with_budget([1.0, 3.0], [4.0, 2.0], 100).unwrap();
+    /// assert!(complete.complete);
+    /// assert_eq!(complete.path, mesh.path([1.0, 3.0], [4.0, 2.0]).unwrap());
+    /// ```
+    pub fn path_with_budget(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        max_expanded: usize,
+    ) -> Option<BudgetedPath> {
+        self.search_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions {
+                max_expanded,
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{helpers::distance_between, maps::maze};
+
+    #[test]
+    fn partial_paths() {
+        let mesh = maze(10, 10, 3);
+        let (from, to) = ([1.5, 1.5], [19.5, 19.5]);
+        let path = mesh.path(from, to).unwrap();
+
+        for budget in [0, 3, 10] {
+            let partial = mesh.path_with_budget(from, to, budget).unwrap();
+            assert!(!partial.complete);
+            // a path from `from` to a point in the mesh, not farther than the target
+            let end = *partial.path.path.last().unwrap();
+            assert!(mesh.point_in_mesh(end));
+            let mut last = from;
+            let mut len = 0.0;
+            for point in &partial.path.path {
+                len += distance_between(last, *point);
+                last = *point;
+            }
+            assert!((partial.path.len - len).abs() < 1.0e-3);
+            assert!(partial.path.len < path.len);
+        }
+
+        let complete = mesh.path_with_budget(from, to, usize::MAX).unwrap();
+        assert!(complete.complete);
+        assert_eq!(complete.path, path);
+        assert_eq!(mesh.path_with_budget(from, [-1.0, 0.0], 10), None);
+    }
+}
@@ -0,0 +1,445 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use hashbrown::HashMap;
+
+use crate::{merge::split_concave, validate::fix_winding, Mesh, Polygon, Vertex};
+
+/// Error returned when a [`MeshBuilder`] is given invalid data.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MeshBuildError {
+    /// A vertex has a coordinate that is not finite.
+    NonFiniteVertex { vertex: usize },
+    /// A polygon has fewer than 3 vertices.
+    TooFewVertices { polygon: usize },
+    /// A polygon uses a vertex that hasn't been added.
+    VertexOutOfRange { polygon: usize, vertex: usize },
+    /// A polygon uses the same vertex twice.
+    DuplicateVertex { polygon: usize, vertex: usize },
+    /// A vertex is next to a polygon that doesn't exist, `-1` being no polygon.
+    PolygonOutOfRange { vertex: usize, polygon: isize },
+}
+
+impl Display for MeshBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshBuildError::NonFiniteVertex { vertex } => {
+                write!(f, "vertex {} has a coordinate that is not finite", vertex)
+            }
+            MeshBuildError::TooFewVertices { polygon } => {
+                write!(f, "polygon {} has fewer than 3 vertices", polygon)
+            }
+            MeshBuildError::VertexOutOfRange { polygon, vertex } => {
+                write!(f, "polygon {} uses unknown vertex {}", polygon, vertex)
+            }
+            MeshBuildError::DuplicateVertex { polygon, vertex } => {
+                write!(
+                    f,
+                    "polygon {} uses vertex {} more than once",
+                    polygon, vertex
+                )
+            }
+            MeshBuildError::PolygonOutOfRange { vertex, polygon } => {
+                write!(
+                    f,
+                    "vertex {} is next to unknown polygon {}",
+                    vertex, polygon
+                )
+            }
+        }
+    }
+}
+
+impl Error for MeshBuildError {}
+
+/// Check that a polygon has at least 3 vertices, all different and less than `nb_vertices`.
+pub(crate) fn check_polygon(
+    polygon: usize,
+    vertices: &[usize],
+    nb_vertices: usize,
+) -> Result<(), MeshBuildError> {
+    if vertices.len() < 3 {
+        return Err(MeshBuildError::TooFewVertices { polygon });
+    }
+    for (i, vertex) in vertices.iter().enumerate() {
+        if *vertex >= nb_vertices {
+            return Err(MeshBuildError::VertexOutOfRange {
+                polygon,
+                vertex: *vertex,
+            });
+        }
+        if vertices[..i].contains(vertex) {
+            return Err(MeshBuildError::DuplicateVertex {
+                polygon,
+                vertex: *vertex,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Order the polygons `around` a vertex counterclockwise, with `-1` where there is a gap between two
+/// consecutive polygons, as in the `.mesh` format.
+pub(crate) fn polygons_around<'p>(
+    vertex: usize,
+    mut around: Vec<usize>,
+    polygon: impl Fn(usize) -> &'p [usize],
+    position: impl Fn(usize) -> [f32; 2],
+) -> Vec<isize> {
+    let point = position(vertex);
+    let angle = |polygon: &[usize]| {
+        let (x, y) = polygon.iter().fold((0.0, 0.0), |(x, y), v| {
+            (x + position(*v)[0], y + position(*v)[1])
+        });
+        let n = polygon.len() as f32;
+        (y / n - point[1]).atan2(x / n - point[0])
+    };
+    around.sort_by(|a, b| angle(polygon(*a)).total_cmp(&angle(polygon(*b))));
+    // polygon of `around` on the other side of the edge from `from` to the vertex
+    let neighbour = |from: usize| {
+        around.iter().copied().find(|other| {
+            let vertices = polygon(*other);
+            let n = vertices.len();
+            (0..n).any(|i| vertices[i] == vertex && vertices[(i + 1) % n] == from)
+        })
+    };
+
+    let mut polygons = vec![];
+    for (i, current) in around.iter().enumerate() {
+        polygons.push(*current as isize);
+        let vertices = polygon(*current);
+        let position = vertices.iter().position(|v| *v == vertex).unwrap();
+        let previous = vertices[(position + vertices.len() - 1) % vertices.len()];
+        if neighbour(previous) != Some(around[(i + 1) % around.len()]) {
+            polygons.push(-1);
+        }
+    }
+    polygons
+}
+
+/// Build a [`Mesh`] from its vertices and polygons.
+///
+/// Only the position of vertices and the vertices of polygons, in counterclockwise order, are
+/// needed. The polygons around each vertex, the neighbours of each polygon and the corners are
+/// computed when building the mesh. Clockwise polygons are reversed, and concave polygons are split
+/// into convex parts, the first one keeping the index of the polygon and the others added after
+/// the last polygon.
+///
+/// Polygons are checked as they are added, so their vertices must be added first. When using the
+/// chaining methods, the first error is kept and returned by [`MeshBuilder::build`].
+///
+/// ```
+/// # use polyanya::MeshBuilder;
+/// let mesh = MeshBuilder::new()
+///     .vertex([0.0, 0.0])
+///     .vertex([1.0, 0.0])
+///     .vertex([1.0, 1.0])
+///     .vertex([0.0, 1.0])
+///     .polygon([0, 1, 2, 3])
+///     .build()
+///     .unwrap();
+/// assert_eq!(mesh.polygons().len(), 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MeshBuilder {
+    vertices: Vec<[f32; 2]>,
+    polygons: Vec<Vec<usize>>,
+    /// Area flags of the polygons, `0` for the missing ones.
+    flags: Vec<u32>,
+    error: Option<MeshBuildError>,
+}
+
+impl MeshBuilder {
+    /// Create an empty builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a builder from vertices and polygons taken from an existing mesh, without checking
+    /// them again.
+    pub(crate) fn from_parts(vertices: Vec<[f32; 2]>, polygons: Vec<Vec<usize>>) -> Self {
+        MeshBuilder {
+            vertices,
+            polygons,
+            flags: vec![],
+            error: None,
+        }
+    }
+
+    /// Set the area flags of the polygons, kept by the parts they're split into.
+    pub(crate) fn with_flags(mut self, flags: Vec<u32>) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Add a vertex and return its index.
+    pub fn add_vertex(&mut self, point: [f32; 2]) -> Result<usize, MeshBuildError> {
+        let vertex = self.vertices.len();
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return Err(MeshBuildError::NonFiniteVertex { vertex });
+        }
+        self.vertices.push(point);
+        Ok(vertex)
+    }
+
+    /// Add a polygon from the indices of its vertices in counterclockwise order, and return its
+    /// index.
+    pub fn add_polygon(
+        &mut self,
+        vertices: impl IntoIterator<Item = usize>,
+    ) -> Result<usize, MeshBuildError> {
+        let polygon = self.polygons.len();
+        let vertices: Vec<usize> = vertices.into_iter().collect();
+        check_polygon(polygon, &vertices, self.vertices.len())?;
+        self.polygons.push(vertices);
+        Ok(polygon)
+    }
+
+    /// Add a polygon from the indices of its vertices in clockwise or counterclockwise order, and
+    /// return its index.
+    ///
+    /// Vertices in clockwise order are reversed, which is useful for data from tools using another
+    /// winding convention.
+    pub fn add_polygon_any_order(
+        &mut self,
+        vertices: impl IntoIterator<Item = usize>,
+    ) -> Result<usize, MeshBuildError> {
+        let mut vertices: Vec<usize> = vertices.into_iter().collect();
+        let points: Option<Vec<[f32; 2]>> = vertices
+            .iter()
+            .map(|v| self.vertices.get(*v).copied())
+            .collect();
+        if let Some(points) = points {
+            let n = points.len();
+            let area: f32 = (0..n)
+                .map(|i| {
+                    let (a, b) = (points[i], points[(i + 1) % n]);
+                    a[0] * b[1] - b[0] * a[1]
+                })
+                .sum();
+            if area < 0.0 {
+                vertices.reverse();
+            }
+        }
+        self.add_polygon(vertices)
+    }
+
+    /// Add a vertex.
+    pub fn vertex(mut self, point: [f32; 2]) -> Self {
+        if self.error.is_none() {
+            if let Err(error) = self.add_vertex(point) {
+                self.error = Some(error);
+            }
+        }
+        self
+    }
+
+    /// Add a polygon from the indices of its vertices in counterclockwise order.
+    pub fn polygon(mut self, vertices: impl IntoIterator<Item = usize>) -> Self {
+        if self.error.is_none() {
+            if let Err(error) = self.add_polygon(vertices) {
+                self.error = Some(error);
+            }
+        }
+        self
+    }
+
+    /// Build the mesh, or return the first error found while adding vertices and polygons.
+    ///
+    /// Polygons in clockwise order are reversed, and concave polygons are split into convex parts,
+    /// as paths are only correct on convex counterclockwise polygons.
+    pub fn build(mut self) -> Result<Mesh, MeshBuildError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        fix_winding(&self.vertices, &mut self.polygons);
+        let (polygons, origins, _) =
+            split_concave(&self.vertices, std::mem::take(&mut self.polygons));
+        self.polygons = polygons;
+        let flags: Vec<u32> = origins
+            .iter()
+            .map(|origin| self.flags.get(*origin).copied().unwrap_or(0))
+            .collect();
+
+        // polygon on the other side of each directed edge
+        let mut edges = HashMap::new();
+        for (polygon, vertices) in self.polygons.iter().enumerate() {
+            for i in 0..vertices.len() {
+                edges.insert((vertices[i], vertices[(i + 1) % vertices.len()]), polygon);
+            }
+        }
+        let neighbour = |from: usize, to: usize| edges.get(&(to, from)).map_or(-1, |p| *p as isize);
+
+        let mut around = vec![vec![]; self.vertices.len()];
+        for (polygon, vertices) in self.polygons.iter().enumerate() {
+            for vertex in vertices {
+                around[*vertex].push(polygon);
+            }
+        }
+
+        let vertices = self
+            .vertices
+            .iter()
+            .zip(around)
+            .enumerate()
+            .map(|(vertex, (point, around))| {
+                let polygons = polygons_around(
+                    vertex,
+                    around,
+                    |polygon| &self.polygons[polygon],
+                    |v| self.vertices[v],
+                );
+                Vertex::new(point[0], point[1], polygons)
+            })
+            .collect();
+
+        let polygons = self
+            .polygons
+            .iter()
+            .zip(flags)
+            .map(|(vertices, flags)| {
+                let mut data: Vec<isize> = vertices.iter().map(|v| *v as isize).collect();
+                for i in 0..vertices.len() {
+                    data.push(neighbour(vertices[i], vertices[(i + 1) % vertices.len()]));
+                }
+                let mut polygon = Polygon::new(vertices.len(), data);
+                polygon.flags = flags;
+                polygon
+            })
+            .collect();
+
+        Ok(Mesh::new(vertices, polygons))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{MeshBuildError, MeshBuilder};
+    use crate::PolygonId;
+
+    fn u_builder() -> MeshBuilder {
+        let mut builder = MeshBuilder::new();
+        for y in 0..3 {
+            for x in 0..4 {
+                builder = builder.vertex([x as f32, y as f32]);
+            }
+        }
+        builder
+            .polygon([0, 1, 5, 4])
+            .polygon([1, 2, 6, 5])
+            .polygon([2, 3, 7, 6])
+            .polygon([4, 5, 9, 8])
+            .polygon([6, 7, 11, 10])
+    }
+
+    #[test]
+    fn any_order() {
+        let mut builder = MeshBuilder::new();
+        for point in [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]] {
+            builder.add_vertex(point).unwrap();
+        }
+        builder.add_polygon_any_order([0, 3, 2]).unwrap();
+        builder.add_polygon_any_order([0, 1, 2]).unwrap();
+        let mesh = builder.build().unwrap();
+        assert_eq!(mesh.polygons[0].vertices, vec![2, 3, 0]);
+        assert_eq!(mesh.validate(), vec![]);
+    }
+
+    #[test]
+    fn clockwise_polygons() {
+        let mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([1.0, 1.0])
+            .vertex([0.0, 1.0])
+            .vertex([2.0, 0.0])
+            .vertex([2.0, 1.0])
+            .polygon([0, 3, 2, 1])
+            .polygon([1, 4, 5, 2])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.polygons[0].vertices, vec![1, 2, 3, 0]);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    }
+
+    #[test]
+    fn build_u() {
+        let mesh = u_builder().build().unwrap();
+        assert_eq!(mesh.vertices.len(), 12);
+        assert_eq!(mesh.validate(), vec![]);
+        let p = |i| Some(PolygonId::new(i));
+        assert_eq!(mesh.vertices[0].polygons, vec![p(0), None]);
+        assert!(mesh.vertices[0].is_corner);
+        let mut around = mesh.vertices[5].polygons.clone();
+        around.sort_unstable();
+        assert_eq!(around, vec![None, p(0), p(1), p(3)]);
+        assert!(mesh.polygons[3].is_one_way);
+        assert!(!mesh.polygons[1].is_one_way);
+        assert_eq!(mesh.point_in_polygon([2.5, 1.5]), p(4));
+        assert!(
+            (mesh.path([0.5, 1.5], [2.5, 1.5]).unwrap().len - (1.0 + 2.0_f32.sqrt())).abs()
+                < 1.0e-5
+        );
+    }
+
+    #[test]
+    fn interior_vertex() {
+        let mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([1.0, 1.0])
+            .vertex([0.0, 1.0])
+            .vertex([0.5, 0.5])
+            .polygon([0, 1, 4])
+            .polygon([1, 2, 4])
+            .polygon([2, 3, 4])
+            .polygon([3, 0, 4])
+            .build()
+            .unwrap();
+        assert!(!mesh.vertices[4].is_corner);
+        assert_eq!(mesh.vertices[4].polygons.len(), 4);
+        assert!(mesh.vertices[0].is_corner);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(
+            u_builder().polygon([0, 1]).build().unwrap_err(),
+            MeshBuildError::TooFewVertices { polygon: 5 }
+        );
+        assert_eq!(
+            u_builder().polygon([0, 1, 12]).build().unwrap_err(),
+            MeshBuildError::VertexOutOfRange {
+                polygon: 5,
+                vertex: 12
+            }
+        );
+        assert_eq!(
+            u_builder().polygon([0, 1, 0]).build().unwrap_err(),
+            MeshBuildError::DuplicateVertex {
+                polygon: 5,
+                vertex: 0
+            }
+        );
+        let mut builder = MeshBuilder::new();
+        assert_eq!(
+            builder.add_vertex([f32::NAN, 0.0]),
+            Err(MeshBuildError::NonFiniteVertex { vertex: 0 })
+        );
+        // the first error is kept
+        assert_eq!(
+            MeshBuilder::new()
+                .polygon([0, 1, 2])
+                .vertex([f32::INFINITY, 0.0])
+                .build()
+                .unwrap_err(),
+            MeshBuildError::VertexOutOfRange {
+                polygon: 0,
+                vertex: 0
+            }
+        );
+    }
+}
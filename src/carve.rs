@@ -0,0 +1,458 @@
+//! Removal of a polygon from the walkable area of a mesh, for obstacles placed at runtime.
+//!
+//! The polygons overlapping the obstacle are triangulated again with a constrained Delaunay
+//! triangulation, with their outline and the outline of the obstacle as constraints. The parts
+//! outside of the obstacle are merged into convex polygons and replace them. Polygons around them
+//! keep their edges, so the rest of the mesh is unchanged.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{
+    cdt::Triangulation, helpers::point_in_simple_polygon, Aabb, Mesh, MeshBuildError, MeshBuilder,
+};
+
+/// Error returned when a polygon can't be carved out of a mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CarveError {
+    /// The polygon has fewer than 3 points.
+    TooFewPoints,
+    /// A point of the polygon has a coordinate that is not finite.
+    NonFinitePoint { point: usize },
+    /// The radius of a disc is not positive and finite.
+    InvalidRadius,
+    /// The mesh left after carving is invalid.
+    InvalidMesh(MeshBuildError),
+}
+
+impl Display for CarveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CarveError::TooFewPoints => write!(f, "polygon has fewer than 3 points"),
+            CarveError::NonFinitePoint { point } => {
+                write!(f, "point {} has a coordinate that is not finite", point)
+            }
+            CarveError::InvalidRadius => write!(f, "radius is not positive and finite"),
+            CarveError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+impl Error for CarveError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CarveError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<MeshBuildError> for CarveError {
+    fn from(error: MeshBuildError) -> Self {
+        CarveError::InvalidMesh(error)
+    }
+}
+
+fn orient(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f64 {
+    let [a, b, c] = [a, b, c].map(|p| [p[0] as f64, p[1] as f64]);
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Check if two segments intersect, including when they only touch.
+fn segments_intersect([a, b]: [[f32; 2]; 2], [c, d]: [[f32; 2]; 2]) -> bool {
+    let (o1, o2, o3, o4) = (
+        orient(a, b, c),
+        orient(a, b, d),
+        orient(c, d, a),
+        orient(c, d, b),
+    );
+    if o1 * o2 < 0.0 && o3 * o4 < 0.0 {
+        return true;
+    }
+    let within = |p: [f32; 2], [u, v]: [[f32; 2]; 2]| {
+        p[0] >= u[0].min(v[0])
+            && p[0] <= u[0].max(v[0])
+            && p[1] >= u[1].min(v[1])
+            && p[1] <= u[1].max(v[1])
+    };
+    (o1 == 0.0 && within(c, [a, b]))
+        || (o2 == 0.0 && within(d, [a, b]))
+        || (o3 == 0.0 && within(a, [c, d]))
+        || (o4 == 0.0 && within(b, [c, d]))
+}
+
+/// Check that a polygon has at least three finite points.
+pub(crate) fn check_polygon(polygon: &[[f32; 2]]) -> Result<(), CarveError> {
+    if polygon.len() < 3 {
+        return Err(CarveError::TooFewPoints);
+    }
+    match polygon
+        .iter()
+        .position(|point| !point[0].is_finite() || !point[1].is_finite())
+    {
+        Some(point) => Err(CarveError::NonFinitePoint { point }),
+        None => Ok(()),
+    }
+}
+
+impl Mesh {
+    /// Remove a polygon from the walkable area, for example a building placed at runtime.
+    ///
+    /// `polygon` is a simple polygon in any winding order. Only the polygons of the mesh it
+    /// overlaps are replaced, by convex polygons covering what is left of them. Vertices keep
+    /// their index, new vertices are added after them, and polygons are renumbered.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mut mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// assert!(mesh.path([5.0, 1.0], [5.0, 9.0]).unwrap().path.len() == 1);
+    /// mesh.carve(&[[2.0, 4.0], [8.0, 4.0], [8.0, 6.0], [2.0, 6.0]])
+    ///     .unwrap();
+    /// assert!(!mesh.point_in_mesh([5.0, 5.0]));
+    /// assert!(mesh.path([5.0, 1.0], [5.0, 9.0]).unwrap().path.len() > 1);
+    /// ```
+    pub fn carve(&mut self, polygon: &[[f32; 2]]) -> Result<(), CarveError> {
+        check_polygon(polygon)?;
+        self.carve_all(&[polygon])
+    }
+
+    /// Remove polygons checked with [`check_polygon`] from the walkable area.
+    pub(crate) fn carve_all(&mut self, obstacles: &[&[[f32; 2]]]) -> Result<(), CarveError> {
+        let affected = self.overlapping(obstacles);
+        if affected.is_empty() {
+            return Ok(());
+        }
+        self.remesh(affected, obstacles, None, |_| None)
+    }
+
+    /// Give back to the walkable area the parts of `holes`, obstacles carved out of `base`, that
+    /// are walkable in `base` and not covered by one of the other `obstacles` carved out of it.
+    ///
+    /// Only the polygons touching the holes are replaced, as when carving.
+    pub(crate) fn uncarve(
+        &mut self,
+        base: &Mesh,
+        holes: &[&[[f32; 2]]],
+        obstacles: &[&[[f32; 2]]],
+    ) -> Result<(), CarveError> {
+        if base.overlapping(holes).is_empty() {
+            return Ok(());
+        }
+        let bounds: Vec<Aabb> = holes
+            .iter()
+            .map(|hole| Aabb::from_points(hole.iter().copied()))
+            .collect();
+        let affected = self.overlapping(holes);
+        let region = affected
+            .iter()
+            .map(|index| &self.polygon_aabbs[*index])
+            .chain(&bounds)
+            .fold(Aabb::EMPTY, |aabb, bounds| aabb.union(bounds));
+
+        // boundary of the walkable area of the base mesh around the affected polygons, and the
+        // borders between its areas
+        let walkable: Vec<usize> = (0..base.polygons.len())
+            .filter(|index| base.polygon_aabbs[*index].intersects(&region))
+            .collect();
+        let around = walkable.iter().fold(Aabb::EMPTY, |aabb, index| {
+            aabb.union(&base.polygon_aabbs[*index])
+        });
+        let edges: HashMap<(usize, usize), u32> = (0..base.polygons.len())
+            .filter(|index| base.polygon_aabbs[*index].intersects(&around))
+            .flat_map(|index| {
+                let flags = base.polygons[index].flags;
+                base.polygons[index]
+                    .edges_index()
+                    .into_iter()
+                    .map(move |edge| ((edge[0], edge[1]), flags))
+            })
+            .collect();
+        let boundary: Vec<[[f32; 2]; 2]> = walkable
+            .iter()
+            .flat_map(|index| base.polygons[*index].edges_index())
+            .filter(|edge| {
+                edges
+                    .get(&(edge[1], edge[0]))
+                    .is_none_or(|flags| *flags != edges[&(edge[0], edge[1])])
+            })
+            .map(|edge| edge.map(|vertex| base.vertices[vertex].p()))
+            .collect();
+
+        let obstacles: Vec<&[[f32; 2]]> = obstacles
+            .iter()
+            .filter(|obstacle| Aabb::from_points(obstacle.iter().copied()).intersects(&region))
+            .copied()
+            .collect();
+        self.remesh(affected, &obstacles, Some(&boundary), |point| {
+            if !holes.iter().zip(&bounds).any(|(hole, bounds)| {
+                bounds.contains(point) && point_in_simple_polygon(point, hole)
+            }) {
+                return None;
+            }
+            walkable
+                .iter()
+                .find(|index| base.polygon_contains(**index, point))
+                .map(|index| base.polygons[*index].flags)
+        })
+    }
+
+    /// Polygons overlapping or touching one of the obstacles.
+    fn overlapping(&self, obstacles: &[&[[f32; 2]]]) -> Vec<usize> {
+        let bounds: Vec<Aabb> = obstacles
+            .iter()
+            .map(|obstacle| Aabb::from_points(obstacle.iter().copied()))
+            .collect();
+        let edges: Vec<Vec<[[f32; 2]; 2]>> = obstacles
+            .iter()
+            .map(|obstacle| {
+                let n = obstacle.len();
+                (0..n)
+                    .map(|i| [obstacle[i], obstacle[(i + 1) % n]])
+                    .collect()
+            })
+            .collect();
+        let overlaps = |index: usize, obstacle: usize| {
+            if !self.polygon_aabbs[index].intersects(&bounds[obstacle]) {
+                return false;
+            }
+            let polygon = obstacles[obstacle];
+            let vertices = &self.polygons[index].vertices;
+            let points: Vec<[f32; 2]> = vertices.iter().map(|v| self.vertices[*v].p()).collect();
+            points
+                .iter()
+                .any(|point| point_in_simple_polygon(*point, polygon))
+                || polygon
+                    .iter()
+                    .any(|point| self.polygon_contains(index, *point))
+                || (0..points.len()).any(|i| {
+                    let edge = [points[i], points[(i + 1) % points.len()]];
+                    edges[obstacle]
+                        .iter()
+                        .any(|other| segments_intersect(edge, *other))
+                })
+        };
+        (0..self.polygons.len())
+            .filter(|index| (0..obstacles.len()).any(|obstacle| overlaps(*index, obstacle)))
+            .collect()
+    }
+
+    /// Replace the `affected` polygons by convex polygons covering what is left of them and of the
+    /// points `added` gives the area flags of, out of the obstacles.
+    ///
+    /// With `boundary`, the edges bounding the walkable area around the affected polygons replace
+    /// their boundary edges, so that the added points can extend the walkable area.
+    fn remesh(
+        &mut self,
+        affected: Vec<usize>,
+        obstacles: &[&[[f32; 2]]],
+        boundary: Option<&[[[f32; 2]; 2]]>,
+        added: impl Fn([f32; 2]) -> Option<u32>,
+    ) -> Result<(), CarveError> {
+        let bounds: Vec<Aabb> = obstacles
+            .iter()
+            .map(|obstacle| Aabb::from_points(obstacle.iter().copied()))
+            .collect();
+
+        // outline of the affected polygons: their edges without a twin in another one
+        let directed: HashSet<(usize, usize)> = affected
+            .iter()
+            .flat_map(|index| self.polygons[*index].edges_index())
+            .map(|edge| (edge[0], edge[1]))
+            .collect();
+        let mut outline: Vec<(usize, usize)> = directed
+            .iter()
+            .filter(|(from, to)| !directed.contains(&(*to, *from)))
+            .copied()
+            .collect();
+        if boundary.is_some() {
+            // only the edges shared with the other polygons
+            let around = outline.iter().fold(Aabb::EMPTY, |aabb, (from, to)| {
+                aabb.union(&Aabb::from_points([
+                    self.vertices[*from].p(),
+                    self.vertices[*to].p(),
+                ]))
+            });
+            let shared: HashSet<(usize, usize)> = (0..self.polygons.len())
+                .filter(|index| self.polygon_aabbs[*index].intersects(&around))
+                .flat_map(|index| self.polygons[index].edges_index())
+                .map(|edge| (edge[1], edge[0]))
+                .collect();
+            outline.retain(|edge| shared.contains(edge));
+        }
+        let constraints = boundary.unwrap_or_default();
+        // edges between areas with other flags, kept so that the new polygons are in one area
+        let area_flags: HashMap<(usize, usize), u32> = affected
+            .iter()
+            .flat_map(|index| {
+                let flags = self.polygons[*index].flags;
+                self.polygons[*index]
+                    .edges_index()
+                    .into_iter()
+                    .map(move |edge| ((edge[0], edge[1]), flags))
+            })
+            .collect();
+        let borders: Vec<(usize, usize)> = area_flags
+            .iter()
+            .filter(|((from, to), flags)| {
+                from < to
+                    && area_flags
+                        .get(&(*to, *from))
+                        .is_some_and(|other| other != *flags)
+            })
+            .map(|(edge, _)| *edge)
+            .collect();
+        let region = affected
+            .iter()
+            .map(|index| &self.polygon_aabbs[*index])
+            .chain(&bounds)
+            .fold(
+                Aabb::from_points(constraints.iter().flatten().copied()),
+                |aabb, bounds| aabb.union(bounds),
+            );
+        let mut triangulation = Triangulation::new(region);
+        for (from, to) in outline.iter().chain(&borders) {
+            let (a, b) = (
+                triangulation.insert(self.vertices[*from].p()),
+                triangulation.insert(self.vertices[*to].p()),
+            );
+            triangulation.insert_constraint(a, b);
+        }
+        for [from, to] in constraints {
+            let (a, b) = (triangulation.insert(*from), triangulation.insert(*to));
+            triangulation.insert_constraint(a, b);
+        }
+        for obstacle in obstacles {
+            triangulation.insert_outline(obstacle);
+        }
+        let mut carved = triangulation.mesh(|point| {
+            !obstacles.iter().zip(&bounds).any(|(obstacle, bounds)| {
+                bounds.contains(point) && point_in_simple_polygon(point, obstacle)
+            }) && (affected
+                .iter()
+                .any(|index| self.polygon_contains(*index, point))
+                || added(point).is_some())
+        })?;
+        for polygon in &mut carved.polygons {
+            let n = polygon.vertices.len() as f32;
+            let centroid = polygon.vertices.iter().fold([0.0, 0.0], |sum, vertex| {
+                let p = carved.vertices[*vertex].p();
+                [sum[0] + p[0] / n, sum[1] + p[1] / n]
+            });
+            polygon.flags = affected
+                .iter()
+                .find(|index| self.polygon_contains(**index, centroid))
+                .map(|index| self.polygons[*index].flags)
+                .or_else(|| added(centroid))
+                .unwrap_or(0);
+        }
+        carved.merge_convex();
+
+        // vertices of the new polygons, reusing the ones of the affected polygons
+        let mut existing: HashMap<(u32, u32), usize> = HashMap::new();
+        for index in &affected {
+            for vertex in &self.polygons[*index].vertices {
+                let p = self.vertices[*vertex].p();
+                existing.insert((p[0].to_bits(), p[1].to_bits()), *vertex);
+            }
+        }
+        let mut points: Vec<[f32; 2]> = self.vertices.iter().map(|vertex| vertex.p()).collect();
+        let ids: Vec<usize> = carved
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let p = vertex.p();
+                *existing
+                    .entry((p[0].to_bits(), p[1].to_bits()))
+                    .or_insert_with(|| {
+                        points.push(p);
+                        points.len() - 1
+                    })
+            })
+            .collect();
+
+        let affected: HashSet<usize> = affected.into_iter().collect();
+        let (polygons, flags): (Vec<Vec<usize>>, Vec<u32>) = self
+            .polygons
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !affected.contains(index))
+            .map(|(_, polygon)| (polygon.vertices.clone(), polygon.flags))
+            .chain(carved.polygons.iter().map(|polygon| {
+                (
+                    polygon.vertices.iter().map(|v| ids[*v]).collect(),
+                    polygon.flags,
+                )
+            }))
+            .unzip();
+        let kept = polygons.len() - carved.polygons.len();
+        self.replace(
+            MeshBuilder::from_parts(points, polygons)
+                .with_flags(flags)
+                .build()?,
+        );
+        self.notify_polygons((kept..self.polygons.len()).collect(), region, true);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CarveError;
+    use crate::Mesh;
+
+    #[test]
+    fn carve_buildings() {
+        let mut mesh = Mesh::from_grid(10, 10, |x, y| !(x == 5 && y < 8));
+        let polygons = mesh.polygons.len();
+        // away from the mesh
+        mesh.carve(&[[20.0, 20.0], [21.0, 20.0], [21.0, 21.0]])
+            .unwrap();
+        assert_eq!(mesh.polygons.len(), polygons);
+
+        // a concave building, overlapping the wall and the boundary of the mesh
+        mesh.carve(&[
+            [1.0, 2.0],
+            [6.0, 2.0],
+            [6.0, 3.0],
+            [2.0, 3.0],
+            [2.0, 6.0],
+            [1.0, 6.0],
+        ])
+        .unwrap();
+        assert!(mesh.validate().is_empty());
+        for point in [[1.5, 4.0], [4.0, 2.5]] {
+            assert!(!mesh.point_in_mesh(point));
+        }
+        for point in [[0.5, 4.0], [4.0, 3.5], [4.0, 1.5]] {
+            assert!(mesh.point_in_mesh(point));
+        }
+        let path = mesh.path([1.5, 2.5], [1.5, 7.0]);
+        assert!(path.is_none());
+        let path = mesh.path([3.0, 4.0], [8.0, 1.0]).unwrap();
+        assert!(path.path.contains(&[6.0, 8.0]));
+
+        // cut the mesh in two
+        mesh.carve(&[[-1.0, 8.0], [11.0, 8.0], [11.0, 9.0], [-1.0, 9.0]])
+            .unwrap();
+        assert!(mesh.validate().is_empty());
+        assert!(mesh.path([3.0, 4.0], [8.0, 1.0]).is_none());
+        assert!(mesh.path([3.0, 9.5], [8.0, 9.5]).is_some());
+    }
+
+    #[test]
+    fn invalid_polygons() {
+        let mut mesh = Mesh::from_grid(2, 2, |_, _| true);
+        assert_eq!(
+            mesh.carve(&[[0.0, 0.0], [1.0, 1.0]]),
+            Err(CarveError::TooFewPoints)
+        );
+        assert_eq!(
+            mesh.carve(&[[0.0, 0.0], [1.0, f32::NAN], [1.0, 1.0]]),
+            Err(CarveError::NonFinitePoint { point: 1 })
+        );
+    }
+}
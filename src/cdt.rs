@@ -0,0 +1,828 @@
+//! Constrained Delaunay triangulation, used to build meshes from outlines.
+//!
+//! Points are inserted in a Delaunay triangulation covered by a large triangle, legalizing edges
+//! with flips. Constraints are then inserted by flipping the edges they cross, and the Delaunay
+//! property is restored around them. A constraint crossing another one is split at their
+//! intersection.
+
+use std::collections::VecDeque;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{helpers::point_in_simple_polygon, Aabb, Mesh, MeshBuildError, MeshBuilder};
+
+/// Number of vertices of the triangle covering all the points.
+const SUPER_VERTICES: usize = 3;
+
+fn orient(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt()
+}
+
+/// Positive if `d` is inside the circumcircle of the counterclockwise triangle `a`, `b`, `c`.
+fn in_circle(a: [f64; 2], b: [f64; 2], c: [f64; 2], d: [f64; 2]) -> f64 {
+    let [adx, ady] = [a[0] - d[0], a[1] - d[1]];
+    let [bdx, bdy] = [b[0] - d[0], b[1] - d[1]];
+    let [cdx, cdy] = [c[0] - d[0], c[1] - d[1]];
+    let ad = adx * adx + ady * ady;
+    let bd = bdx * bdx + bdy * bdy;
+    let cd = cdx * cdx + cdy * cdy;
+    adx * (bdy * cd - bd * cdy) - ady * (bdx * cd - bd * cdx) + ad * (bdx * cdy - bdy * cdx)
+}
+
+/// Where a point is in a triangle.
+enum Location {
+    Inside(usize),
+    /// On the edge from the `i`-th vertex of the triangle to the next.
+    OnEdge(usize, usize),
+    OnVertex(usize),
+}
+
+/// A constrained Delaunay triangulation.
+#[derive(Debug, Clone)]
+pub(crate) struct Triangulation {
+    points: Vec<[f64; 2]>,
+    /// Triangles in counterclockwise order. Slots are reused when triangles are flipped or split.
+    triangles: Vec<[usize; 3]>,
+    /// Triangle having each directed edge.
+    edges: HashMap<(usize, usize), usize>,
+    /// A triangle around each vertex.
+    vertex_triangles: Vec<usize>,
+    /// Constrained edges, with their smallest vertex first.
+    constrained: HashSet<(usize, usize)>,
+    /// Tolerance under which a vertex is considered on a constraint.
+    epsilon: f64,
+    last: usize,
+}
+
+fn key(u: usize, v: usize) -> (usize, usize) {
+    (u.min(v), u.max(v))
+}
+
+impl Triangulation {
+    /// Start a triangulation of points inside a bounding box.
+    pub(crate) fn new(aabb: Aabb) -> Self {
+        let Aabb { min, max } = aabb;
+        let center = [
+            (min[0] as f64 + max[0] as f64) / 2.0,
+            (min[1] as f64 + max[1] as f64) / 2.0,
+        ];
+        let size = (max[0] as f64 - min[0] as f64)
+            .max(max[1] as f64 - min[1] as f64)
+            .max(1.0);
+        let far = size * 100.0;
+        let mut triangulation = Triangulation {
+            points: vec![
+                [center[0] - far, center[1] - far],
+                [center[0] + far, center[1] - far],
+                [center[0], center[1] + far],
+            ],
+            triangles: vec![],
+            edges: HashMap::new(),
+            vertex_triangles: vec![0; SUPER_VERTICES],
+            constrained: HashSet::new(),
+            epsilon: size * 1.0e-6,
+            last: 0,
+        };
+        triangulation.push([0, 1, 2]);
+        triangulation
+    }
+
+    fn push(&mut self, triangle: [usize; 3]) -> usize {
+        let index = self.triangles.len();
+        self.triangles.push(triangle);
+        self.link(index);
+        index
+    }
+
+    fn set(&mut self, index: usize, triangle: [usize; 3]) {
+        let old = self.triangles[index];
+        for i in 0..3 {
+            let edge = (old[i], old[(i + 1) % 3]);
+            if self.edges.get(&edge) == Some(&index) {
+                self.edges.remove(&edge);
+            }
+        }
+        self.triangles[index] = triangle;
+        self.link(index);
+    }
+
+    fn link(&mut self, index: usize) {
+        let triangle = self.triangles[index];
+        for i in 0..3 {
+            self.edges
+                .insert((triangle[i], triangle[(i + 1) % 3]), index);
+            self.vertex_triangles[triangle[i]] = index;
+        }
+    }
+
+    /// Vertex of a triangle that is not on its edge from `u` to `v`.
+    fn apex(&self, triangle: usize, u: usize, v: usize) -> usize {
+        *self.triangles[triangle]
+            .iter()
+            .find(|w| **w != u && **w != v)
+            .unwrap()
+    }
+
+    fn is_constrained(&self, u: usize, v: usize) -> bool {
+        self.constrained.contains(&key(u, v))
+    }
+
+    fn locate(&mut self, point: [f64; 2]) -> Location {
+        let mut current = self.last.min(self.triangles.len() - 1);
+        let mut steps = 0;
+        'walk: while steps <= self.triangles.len() {
+            steps += 1;
+            let triangle = self.triangles[current];
+            // starting from a different edge at each step keeps the walk from cycling
+            for k in 0..3 {
+                let i = (k + steps) % 3;
+                let (u, v) = (triangle[i], triangle[(i + 1) % 3]);
+                if orient(self.points[u], self.points[v], point) < 0.0 {
+                    if let Some(next) = self.edges.get(&(v, u)) {
+                        current = *next;
+                        continue 'walk;
+                    }
+                }
+            }
+            break;
+        }
+        if steps > self.triangles.len() {
+            // the walk can cycle once constraints broke the Delaunay property
+            current = (0..self.triangles.len())
+                .find(|t| {
+                    let triangle = self.triangles[*t];
+                    (0..3).all(|i| {
+                        orient(
+                            self.points[triangle[i]],
+                            self.points[triangle[(i + 1) % 3]],
+                            point,
+                        ) >= 0.0
+                    })
+                })
+                .unwrap_or(current);
+        }
+        self.last = current;
+
+        // points closer than the tolerance are merged, to avoid slivers
+        let triangle = self.triangles[current];
+        if let Some(vertex) = triangle
+            .iter()
+            .find(|v| distance(self.points[**v], point) <= self.epsilon)
+        {
+            return Location::OnVertex(*vertex);
+        }
+        for i in 0..3 {
+            let (u, v) = (triangle[i], triangle[(i + 1) % 3]);
+            let length = distance(self.points[u], self.points[v]);
+            if orient(self.points[u], self.points[v], point) <= self.epsilon * length {
+                return Location::OnEdge(current, i);
+            }
+        }
+        Location::Inside(current)
+    }
+
+    /// Insert a point and return its vertex, or the vertex already at this position.
+    pub(crate) fn insert(&mut self, point: [f32; 2]) -> usize {
+        let point = [point[0] as f64, point[1] as f64];
+        match self.locate(point) {
+            Location::OnVertex(vertex) => vertex,
+            Location::OnEdge(triangle, i) => {
+                let [u, v] = [
+                    self.triangles[triangle][i],
+                    self.triangles[triangle][(i + 1) % 3],
+                ];
+                self.split_edge(u, v, point)
+            }
+            Location::Inside(triangle) => {
+                let p = self.add_point(point);
+                let [a, b, c] = self.triangles[triangle];
+                self.set(triangle, [a, b, p]);
+                self.push([b, c, p]);
+                self.push([c, a, p]);
+                self.legalize(vec![(a, b), (b, c), (c, a)]);
+                p
+            }
+        }
+    }
+
+    fn add_point(&mut self, point: [f64; 2]) -> usize {
+        self.points.push(point);
+        self.vertex_triangles.push(0);
+        self.points.len() - 1
+    }
+
+    /// Split the edge from `u` to `v` at `point`, keeping it constrained if it was.
+    fn split_edge(&mut self, u: usize, v: usize, point: [f64; 2]) -> usize {
+        let p = self.add_point(point);
+        let mut to_legalize = vec![];
+        if let Some(&triangle) = self.edges.get(&(u, v)) {
+            let w = self.apex(triangle, u, v);
+            self.set(triangle, [u, p, w]);
+            self.push([p, v, w]);
+            to_legalize.extend([(v, w), (w, u)]);
+        }
+        if let Some(&triangle) = self.edges.get(&(v, u)) {
+            let x = self.apex(triangle, v, u);
+            self.set(triangle, [v, p, x]);
+            self.push([p, u, x]);
+            to_legalize.extend([(u, x), (x, v)]);
+        }
+        if self.constrained.remove(&key(u, v)) {
+            self.constrained.insert(key(u, p));
+            self.constrained.insert(key(p, v));
+        }
+        self.legalize(to_legalize);
+        p
+    }
+
+    /// Replace the edge from `u` to `v` by the other diagonal of its two triangles, returning it.
+    fn flip(&mut self, u: usize, v: usize) -> (usize, usize) {
+        let t = self.edges[&(u, v)];
+        let t2 = self.edges[&(v, u)];
+        let p = self.apex(t, u, v);
+        let x = self.apex(t2, v, u);
+        self.set(t, [p, u, x]);
+        self.set(t2, [x, v, p]);
+        (p, x)
+    }
+
+    /// Check if the two triangles of an edge form a strictly convex quadrilateral.
+    fn is_flippable(&self, u: usize, v: usize) -> bool {
+        let (Some(&t), Some(&t2)) = (self.edges.get(&(u, v)), self.edges.get(&(v, u))) else {
+            return false;
+        };
+        let p = self.points[self.apex(t, u, v)];
+        let x = self.points[self.apex(t2, v, u)];
+        orient(p, x, self.points[u]) * orient(p, x, self.points[v]) < 0.0
+    }
+
+    /// Flip edges until they are all locally Delaunay, starting from the edges in `stack`.
+    fn legalize(&mut self, mut stack: Vec<(usize, usize)>) {
+        // flips can cycle between cocircular points because of rounding errors
+        let mut budget = 8 * self.triangles.len() + 64;
+        while let Some((u, v)) = stack.pop() {
+            if budget == 0 {
+                return;
+            }
+            budget -= 1;
+            if self.is_constrained(u, v) {
+                continue;
+            }
+            let (Some(&t), Some(&t2)) = (self.edges.get(&(u, v)), self.edges.get(&(v, u))) else {
+                continue;
+            };
+            let p = self.apex(t, u, v);
+            let x = self.apex(t2, v, u);
+            let [pu, pv, pp, px] = [u, v, p, x].map(|vertex| self.points[vertex]);
+            if in_circle(pu, pv, pp, px) > 0.0 && self.is_flippable(u, v) {
+                self.flip(u, v);
+                stack.extend([(u, x), (x, v)]);
+            }
+        }
+    }
+
+    /// Triangles around a vertex, in counterclockwise order and rotated to start with it.
+    fn around(&self, vertex: usize) -> Vec<[usize; 3]> {
+        let start = self.vertex_triangles[vertex];
+        let rotate = |triangle: [usize; 3]| {
+            let i = triangle.iter().position(|v| *v == vertex).unwrap();
+            [triangle[i], triangle[(i + 1) % 3], triangle[(i + 2) % 3]]
+        };
+        let mut around = vec![rotate(self.triangles[start])];
+        loop {
+            let [_, _, next] = *around.last().unwrap();
+            match self.edges.get(&(vertex, next)) {
+                Some(&t) if t != start && around.len() <= self.triangles.len() => {
+                    around.push(rotate(self.triangles[t]))
+                }
+                _ => return around,
+            }
+        }
+    }
+
+    /// Distance from a point to the segment from `a` to `b`.
+    fn distance_to_segment(&self, point: usize, a: usize, b: usize) -> f64 {
+        let [p, a, b] = [point, a, b].map(|vertex| self.points[vertex]);
+        let ab = [b[0] - a[0], b[1] - a[1]];
+        let length = ab[0] * ab[0] + ab[1] * ab[1];
+        let t = (((p[0] - a[0]) * ab[0] + (p[1] - a[1]) * ab[1]) / length).clamp(0.0, 1.0);
+        let closest = [a[0] + t * ab[0], a[1] + t * ab[1]];
+        ((p[0] - closest[0]).powi(2) + (p[1] - closest[1]).powi(2)).sqrt()
+    }
+
+    /// Edges crossed by the segment from `a` to `b`, or a vertex on it.
+    fn crossed_edges(&self, a: usize, b: usize) -> Result<Vec<(usize, usize)>, usize> {
+        let [pa, pb] = [self.points[a], self.points[b]];
+        let mut crossed = vec![];
+        for [_, c, d] in self.around(a) {
+            for vertex in [c, d] {
+                if vertex >= SUPER_VERTICES
+                    && self.distance_to_segment(vertex, a, b) <= self.epsilon
+                    && (self.points[vertex][0] - pa[0]) * (pb[0] - pa[0])
+                        + (self.points[vertex][1] - pa[1]) * (pb[1] - pa[1])
+                        > 0.0
+                {
+                    return Err(vertex);
+                }
+            }
+            if orient(pa, self.points[c], pb) > 0.0 && orient(pa, self.points[d], pb) < 0.0 {
+                crossed.push((c, d));
+                break;
+            }
+        }
+        let Some(&(mut right, mut left)) = crossed.first() else {
+            return Ok(crossed);
+        };
+        while crossed.len() <= self.triangles.len() {
+            let Some(&t) = self.edges.get(&(left, right)) else {
+                return Ok(crossed);
+            };
+            let e = self.apex(t, left, right);
+            if e == b {
+                return Ok(crossed);
+            }
+            if self.distance_to_segment(e, a, b) <= self.epsilon {
+                return Err(e);
+            }
+            if orient(pa, pb, self.points[e]) > 0.0 {
+                left = e;
+            } else {
+                right = e;
+            }
+            crossed.push((right, left));
+        }
+        Ok(crossed)
+    }
+
+    /// Intersection of the segments from `a` to `b` and from `c` to `d`.
+    fn intersection(&self, [a, b, c, d]: [usize; 4]) -> [f64; 2] {
+        let [a, b, c, d] = [a, b, c, d].map(|vertex| self.points[vertex]);
+        let denominator = (b[0] - a[0]) * (d[1] - c[1]) - (b[1] - a[1]) * (d[0] - c[0]);
+        let t = ((c[0] - a[0]) * (d[1] - c[1]) - (c[1] - a[1]) * (d[0] - c[0])) / denominator;
+        [a[0] + t * (b[0] - a[0]), a[1] + t * (b[1] - a[1])]
+    }
+
+    /// Force the segment from `a` to `b` to be an edge of the triangulation. It is split where it
+    /// goes through a vertex or crosses another constraint.
+    pub(crate) fn insert_constraint(&mut self, a: usize, b: usize) {
+        let mut pending = vec![(a, b)];
+        // splitting can't go on forever, but rounding errors could make it
+        let mut budget = self.points.len() + 64;
+        while let Some((a, b)) = pending.pop() {
+            if budget == 0 {
+                return;
+            }
+            budget -= 1;
+            if a == b {
+                continue;
+            }
+            if self.edges.contains_key(&(a, b)) || self.edges.contains_key(&(b, a)) {
+                self.constrained.insert(key(a, b));
+                continue;
+            }
+            let crossed = match self.crossed_edges(a, b) {
+                Ok(crossed) => crossed,
+                Err(vertex) => {
+                    pending.extend([(vertex, b), (a, vertex)]);
+                    continue;
+                }
+            };
+            if let Some(&(u, v)) = crossed.iter().find(|(u, v)| self.is_constrained(*u, *v)) {
+                let point = self.intersection([a, b, u, v]);
+                let vertex = [u, v]
+                    .into_iter()
+                    .find(|vertex| distance(self.points[*vertex], point) <= self.epsilon)
+                    .unwrap_or_else(|| self.split_edge(u, v, point));
+                pending.extend([(vertex, b), (a, vertex)]);
+                continue;
+            }
+            self.flip_crossed(a, b, crossed);
+        }
+    }
+
+    /// Flip the edges crossed by the segment from `a` to `b` until it is an edge.
+    fn flip_crossed(&mut self, a: usize, b: usize, crossed: Vec<(usize, usize)>) {
+        let [pa, pb] = [self.points[a], self.points[b]];
+        let crosses = |triangulation: &Self, u: usize, v: usize| {
+            let [pu, pv] = [triangulation.points[u], triangulation.points[v]];
+            u != a
+                && u != b
+                && v != a
+                && v != b
+                && orient(pa, pb, pu) * orient(pa, pb, pv) < 0.0
+                && orient(pu, pv, pa) * orient(pu, pv, pb) < 0.0
+        };
+        let mut queue: VecDeque<_> = crossed.into_iter().collect();
+        let mut new_edges = vec![];
+        let mut budget = queue.len() * queue.len() + 16;
+        while let Some((u, v)) = queue.pop_front() {
+            if budget == 0 {
+                break;
+            }
+            budget -= 1;
+            if !self.is_flippable(u, v) {
+                queue.push_back((u, v));
+                continue;
+            }
+            let (p, x) = self.flip(u, v);
+            if crosses(self, p, x) {
+                queue.push_back((p, x));
+            } else {
+                new_edges.push((p, x));
+            }
+        }
+        if self.edges.contains_key(&(a, b)) || self.edges.contains_key(&(b, a)) {
+            self.constrained.insert(key(a, b));
+        }
+        self.legalize(
+            new_edges
+                .into_iter()
+                .flat_map(|(u, v)| [(u, v), (v, u)])
+                .collect(),
+        );
+    }
+
+    /// Group triangles in regions bounded by constraints, returning the region of each triangle
+    /// and the number of regions.
+    fn regions(&self) -> (Vec<usize>, usize) {
+        let mut regions = vec![usize::MAX; self.triangles.len()];
+        let mut count = 0;
+        for start in 0..self.triangles.len() {
+            if regions[start] != usize::MAX {
+                continue;
+            }
+            regions[start] = count;
+            let mut stack = vec![start];
+            while let Some(current) = stack.pop() {
+                let triangle = self.triangles[current];
+                for i in 0..3 {
+                    let (u, v) = (triangle[i], triangle[(i + 1) % 3]);
+                    if self.is_constrained(u, v) {
+                        continue;
+                    }
+                    if let Some(&next) = self.edges.get(&(v, u)) {
+                        if regions[next] == usize::MAX {
+                            regions[next] = count;
+                            stack.push(next);
+                        }
+                    }
+                }
+            }
+            count += 1;
+        }
+        (regions, count)
+    }
+
+    /// Build a mesh from the regions bounded by constraints for which `keep` is `true`, given a
+    /// point inside them.
+    pub(crate) fn mesh(&self, keep: impl Fn([f32; 2]) -> bool) -> Result<Mesh, MeshBuildError> {
+        let (regions, count) = self.regions();
+        // the largest triangle of each region is used to check if it is kept, as its centroid is
+        // the furthest from the constraints
+        let mut largest: Vec<Option<(f64, usize)>> = vec![None; count];
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            if triangle.iter().any(|v| *v < SUPER_VERTICES) {
+                continue;
+            }
+            let [a, b, c] = triangle.map(|vertex| self.points[vertex]);
+            let area = orient(a, b, c);
+            let best = &mut largest[regions[index]];
+            if best.is_none_or(|(best, _)| area > best) {
+                *best = Some((area, index));
+            }
+        }
+        let kept: Vec<bool> = largest
+            .into_iter()
+            .map(|best| {
+                best.is_some_and(|(_, index)| {
+                    let [a, b, c] = self.triangles[index].map(|vertex| self.points[vertex]);
+                    keep([
+                        ((a[0] + b[0] + c[0]) / 3.0) as f32,
+                        ((a[1] + b[1] + c[1]) / 3.0) as f32,
+                    ])
+                })
+            })
+            .collect();
+
+        let mut builder = MeshBuilder::new();
+        let mut vertices = HashMap::new();
+        for (index, triangle) in self.triangles.iter().enumerate() {
+            if !kept[regions[index]] || triangle.iter().any(|v| *v < SUPER_VERTICES) {
+                continue;
+            }
+            let [a, b, c] = triangle.map(|vertex| self.points[vertex]);
+            if orient(a, b, c) <= 0.0 {
+                continue;
+            }
+            let mut polygon = [0; 3];
+            for (id, vertex) in polygon.iter_mut().zip(triangle) {
+                *id = match vertices.get(vertex) {
+                    Some(id) => *id,
+                    None => {
+                        let point = self.points[*vertex];
+                        let id = builder.add_vertex([point[0] as f32, point[1] as f32])?;
+                        vertices.insert(*vertex, id);
+                        id
+                    }
+                };
+            }
+            builder.add_polygon(polygon)?;
+        }
+        builder.build()
+    }
+}
+
+impl Mesh {
+    /// Build a mesh from the outline of a walkable area and the outlines of its holes, with a
+    /// constrained Delaunay triangulation.
+    ///
+    /// Outlines can be in any winding order. Holes may overlap each other and the outline, their
+    /// intersections are computed. Non finite points are reported as [`MeshBuildError::NonFiniteVertex`]
+    /// with their index counting from the first point of `outer`, and outlines with fewer than
+    /// three points as [`MeshBuildError::TooFewVertices`], `0` being `outer` and `i + 1` the `i`-th
+    /// hole.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let outer = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+    /// let hole = vec![[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]];
+    /// let mesh = Mesh::from_polygons(&outer, &[hole]).unwrap();
+    /// assert!(!mesh.point_in_mesh([5.0, 5.0]));
+    /// assert!(mesh.path([5.0, 1.0], [5.0, 9.0]).is_some());
+    /// ```
+    pub fn from_polygons(
+        outer: &[[f32; 2]],
+        holes: &[Vec<[f32; 2]>],
+    ) -> Result<Mesh, MeshBuildError> {
+        let outlines: Vec<&[[f32; 2]]> = std::iter::once(outer)
+            .chain(holes.iter().map(|hole| hole.as_slice()))
+            .collect();
+        let mut triangulation = Triangulation::new(check_outlines(&outlines)?);
+        for outline in &outlines {
+            triangulation.insert_outline(outline);
+        }
+        let bounds: Vec<_> = holes
+            .iter()
+            .map(|hole| Aabb::from_points(hole.iter().copied()))
+            .collect();
+        triangulation.mesh(|point| {
+            point_in_simple_polygon(point, outer) && outside_all(point, holes, &bounds)
+        })
+    }
+
+    /// Build a mesh covering a rectangular area, except where there are obstacles.
+    ///
+    /// Obstacles are polygons in any winding order, that may overlap each other and extend past
+    /// `bounds`: the walkable area is `bounds` minus the union of the obstacles. It is triangulated
+    /// with a constrained Delaunay triangulation. Errors are reported like in
+    /// [`Mesh::from_polygons`], with `i` being the `i`-th obstacle.
+    ///
+    /// ```
+    /// # use polyanya::{Aabb, Mesh};
+    /// let bounds = Aabb::new([0.0, 0.0], [10.0, 10.0]);
+    /// let obstacles = [
+    ///     vec![[2.0, 2.0], [6.0, 2.0], [6.0, 6.0], [2.0, 6.0]],
+    ///     vec![[5.0, 5.0], [8.0, 5.0], [8.0, 8.0], [5.0, 8.0]],
+    /// ];
+    /// let mesh = Mesh::from_obstacles(bounds, &obstacles).unwrap();
+    /// assert!(!mesh.point_in_mesh([5.5, 5.5]));
+    /// assert!(mesh.path([1.0, 9.0], [9.0, 1.0]).is_some());
+    /// ```
+    pub fn from_obstacles(
+        bounds: Aabb,
+        obstacles: &[Vec<[f32; 2]>],
+    ) -> Result<Mesh, MeshBuildError> {
+        let outlines: Vec<&[[f32; 2]]> = obstacles
+            .iter()
+            .map(|obstacle| obstacle.as_slice())
+            .collect();
+        let aabb = check_outlines(&outlines)?.union(&bounds);
+        let mut triangulation = Triangulation::new(aabb);
+        triangulation.insert_outline(&[
+            bounds.min,
+            [bounds.max[0], bounds.min[1]],
+            bounds.max,
+            [bounds.min[0], bounds.max[1]],
+        ]);
+        for outline in &outlines {
+            triangulation.insert_outline(outline);
+        }
+        let obstacle_bounds: Vec<_> = obstacles
+            .iter()
+            .map(|obstacle| Aabb::from_points(obstacle.iter().copied()))
+            .collect();
+        triangulation
+            .mesh(|point| bounds.contains(point) && outside_all(point, obstacles, &obstacle_bounds))
+    }
+}
+
+/// Check that outlines have at least three finite points, and return their bounding box.
+fn check_outlines(outlines: &[&[[f32; 2]]]) -> Result<Aabb, MeshBuildError> {
+    let mut aabb = Aabb::EMPTY;
+    let mut vertex = 0;
+    for (polygon, outline) in outlines.iter().enumerate() {
+        if outline.len() < 3 {
+            return Err(MeshBuildError::TooFewVertices { polygon });
+        }
+        for point in outline.iter() {
+            if !point[0].is_finite() || !point[1].is_finite() {
+                return Err(MeshBuildError::NonFiniteVertex { vertex });
+            }
+            vertex += 1;
+            aabb.expand(*point);
+        }
+    }
+    Ok(aabb)
+}
+
+/// Check if a point is in none of the outlines, using their bounding boxes to skip most of them.
+fn outside_all(point: [f32; 2], outlines: &[Vec<[f32; 2]>], bounds: &[Aabb]) -> bool {
+    !outlines
+        .iter()
+        .zip(bounds)
+        .any(|(outline, bounds)| bounds.contains(point) && point_in_simple_polygon(point, outline))
+}
+
+impl Triangulation {
+    /// Insert the points of a closed outline, with its edges as constraints.
+    pub(crate) fn insert_outline(&mut self, outline: &[[f32; 2]]) {
+        let vertices: Vec<_> = outline.iter().map(|point| self.insert(*point)).collect();
+        for i in 0..vertices.len() {
+            self.insert_constraint(vertices[i], vertices[(i + 1) % vertices.len()]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{in_circle, Triangulation};
+    use crate::{Aabb, Mesh, MeshBuildError};
+
+    /// Check that no unconstrained edge has a vertex in the circumcircle of the triangle on its
+    /// other side.
+    fn assert_delaunay(triangulation: &Triangulation) {
+        for (&(u, v), &t) in &triangulation.edges {
+            if triangulation.is_constrained(u, v) {
+                continue;
+            }
+            let Some(&t2) = triangulation.edges.get(&(v, u)) else {
+                continue;
+            };
+            let p = triangulation.apex(t, u, v);
+            let x = triangulation.apex(t2, v, u);
+            let [pu, pv, pp, px] = [u, v, p, x].map(|vertex| triangulation.points[vertex]);
+            assert!(in_circle(pu, pv, pp, px) <= 1.0e-6, "edge {:?}", (u, v));
+        }
+    }
+
+    #[test]
+    fn delaunay() {
+        let mut triangulation = Triangulation::new(Aabb::new([0.0, 0.0], [10.0, 10.0]));
+        for i in 0..10 {
+            for j in 0..10 {
+                // jitter the grid to avoid cocircular points
+                let jitter = ((i * 7 + j * 13) % 10) as f32 * 0.03;
+                triangulation.insert([i as f32 + jitter, j as f32 - jitter]);
+            }
+        }
+        assert_eq!(triangulation.points.len(), 103);
+        assert_delaunay(&triangulation);
+        assert_eq!(triangulation.insert([0.0, 0.0]), 3);
+    }
+
+    #[test]
+    fn constraints() {
+        let mut triangulation = Triangulation::new(Aabb::new([0.0, 0.0], [10.0, 10.0]));
+        for i in 0..=10 {
+            triangulation.insert([i as f32, 0.0]);
+            triangulation.insert([i as f32, 10.0]);
+        }
+        let a = triangulation.insert([0.0, 5.0]);
+        let b = triangulation.insert([10.0, 5.0]);
+        triangulation.insert_constraint(a, b);
+        assert!(
+            triangulation.edges.contains_key(&(a, b)) || triangulation.edges.contains_key(&(b, a))
+        );
+        assert_delaunay(&triangulation);
+
+        // a crossing constraint is split at the intersection
+        let c = triangulation.insert([5.0, 0.0]);
+        let d = triangulation.insert([5.0, 10.0]);
+        triangulation.insert_constraint(c, d);
+        let middle = triangulation.insert([5.0, 5.0]);
+        assert_eq!(middle, triangulation.points.len() - 1);
+        assert_eq!(triangulation.constrained.len(), 4);
+        assert_delaunay(&triangulation);
+    }
+
+    #[test]
+    fn polygon_with_holes() {
+        let outer = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        let holes = vec![
+            vec![[2.0, 2.0], [2.0, 4.0], [4.0, 4.0], [4.0, 2.0]],
+            vec![[6.0, 2.0], [8.0, 2.0], [8.0, 8.0], [6.0, 8.0]],
+        ];
+        let mesh = Mesh::from_polygons(&outer, &holes).unwrap();
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.vertices.len(), 12);
+        // triangles of a polygon with h holes and n vertices
+        assert_eq!(mesh.polygons.len(), 12 + 2 * 2 - 2);
+        assert!(!mesh.point_in_mesh([3.0, 3.0]));
+        assert!(!mesh.point_in_mesh([7.0, 5.0]));
+        assert!(mesh.point_in_mesh([5.0, 5.0]));
+        let path = mesh.path([5.0, 1.0], [9.0, 9.0]).unwrap();
+        assert!((path.len - (10.0f32.sqrt() + 50.0f32.sqrt())).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn non_convex_outline() {
+        // a C shape, in clockwise order
+        let outer = [
+            [0.0, 0.0],
+            [0.0, 3.0],
+            [3.0, 3.0],
+            [3.0, 2.0],
+            [1.0, 2.0],
+            [1.0, 1.0],
+            [3.0, 1.0],
+            [3.0, 0.0],
+        ];
+        let mesh = Mesh::from_polygons(&outer, &[]).unwrap();
+        assert_eq!(mesh.polygons.len(), 6);
+        assert!(!mesh.point_in_mesh([2.0, 1.5]));
+        let path = mesh.path([2.5, 0.5], [2.5, 2.5]).unwrap();
+        assert_eq!(path.path.len(), 3);
+    }
+
+    #[test]
+    fn overlapping_holes() {
+        let outer = [[0.0, 0.0], [6.0, 0.0], [6.0, 6.0], [0.0, 6.0]];
+        let holes = vec![
+            vec![[1.0, 1.0], [4.0, 1.0], [4.0, 4.0], [1.0, 4.0]],
+            vec![[2.0, 2.0], [5.0, 2.0], [5.0, 5.0], [2.0, 5.0]],
+        ];
+        let mesh = Mesh::from_polygons(&outer, &holes).unwrap();
+        assert_eq!(mesh.validate(), vec![]);
+        for point in [[1.5, 1.5], [3.0, 3.0], [4.5, 4.5], [4.5, 2.5]] {
+            assert!(!mesh.point_in_mesh(point), "{:?}", point);
+        }
+        for point in [[4.5, 1.5], [1.5, 4.5], [5.5, 5.5]] {
+            assert!(mesh.point_in_mesh(point), "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn obstacles() {
+        let bounds = Aabb::new([0.0, 0.0], [10.0, 10.0]);
+        assert_eq!(Mesh::from_obstacles(bounds, &[]).unwrap().polygons.len(), 2);
+        let outside = vec![[20.0, 20.0], [30.0, 20.0], [25.0, 30.0]];
+        assert_eq!(
+            Mesh::from_obstacles(bounds, std::slice::from_ref(&outside))
+                .unwrap()
+                .polygons
+                .len(),
+            2
+        );
+
+        let obstacles = [
+            // overlapping squares, forming a wall with an L shape
+            vec![[2.0, 2.0], [6.0, 2.0], [6.0, 6.0], [2.0, 6.0]],
+            vec![[5.0, 5.0], [8.0, 5.0], [8.0, 8.0], [5.0, 8.0]],
+            // crossing the left side of the bounds
+            vec![[-2.0, 8.0], [1.0, 8.0], [1.0, 9.0], [-2.0, 9.0]],
+            outside,
+        ];
+        let mesh = Mesh::from_obstacles(bounds, &obstacles).unwrap();
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.bounding_box(), bounds);
+        for point in [[3.0, 3.0], [5.5, 5.5], [7.0, 7.0], [0.5, 8.5]] {
+            assert!(!mesh.point_in_mesh(point), "{:?}", point);
+        }
+        for point in [[1.0, 1.0], [7.0, 3.0], [3.0, 7.0], [0.5, 9.5], [9.5, 9.5]] {
+            assert!(mesh.point_in_mesh(point), "{:?}", point);
+        }
+        let path = mesh.path([4.0, 7.0], [1.0, 1.0]).unwrap();
+        assert_eq!(path.path, vec![[2.0, 6.0], [1.0, 1.0]]);
+        assert!((path.len - (2.0f32.hypot(1.0) + 1.0f32.hypot(5.0))).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn invalid_outlines() {
+        assert_eq!(
+            Mesh::from_polygons(&[[0.0, 0.0], [1.0, 0.0]], &[]).err(),
+            Some(MeshBuildError::TooFewVertices { polygon: 0 })
+        );
+        assert_eq!(
+            Mesh::from_polygons(
+                &[[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+                &[vec![[0.1, 0.1], [f32::NAN, 0.2], [0.2, 0.1]]]
+            )
+            .err(),
+            Some(MeshBuildError::NonFiniteVertex { vertex: 4 })
+        );
+    }
+}
@@ -0,0 +1,255 @@
+//! Cleanup of meshes exported from editors, which often have several vertices at the same position
+//! and vertices in the middle of straight walls. Both add intervals to expand during a search.
+
+use hashbrown::HashMap;
+
+use crate::{helpers::distance_between, Mesh, MeshBuilder};
+
+/// Distance from `point` to the line through `a` and `b`.
+fn distance_to_line(point: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    let length = distance_between(a, b);
+    if length == 0.0 {
+        return distance_between(point, a);
+    }
+    ((b[0] - a[0]) * (point[1] - a[1]) - (b[1] - a[1]) * (point[0] - a[0])).abs() / length
+}
+
+impl Mesh {
+    /// Merge the vertices closer than `epsilon` to each other, then rebuild the adjacency of
+    /// polygons and vertices. Returns the number of vertices removed.
+    ///
+    /// Polygons left with fewer than three different vertices are removed. Vertices are
+    /// renumbered, polygons keep their order.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// // two squares exported with their own vertices
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([2.0, 0.0])
+    ///     .vertex([2.0, 1.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .polygon([4, 5, 6, 7])
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_none());
+    /// assert_eq!(mesh.dedupe_vertices(1.0e-5), 2);
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    /// ```
+    pub fn dedupe_vertices(&mut self, epsilon: f32) -> usize {
+        let epsilon = epsilon.max(0.0);
+        let cell_size = epsilon.max(f32::EPSILON);
+        let cell = |point: [f32; 2]| {
+            (
+                (point[0] / cell_size).floor() as i64,
+                (point[1] / cell_size).floor() as i64,
+            )
+        };
+        let mut points: Vec<[f32; 2]> = vec![];
+        let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        let ids: Vec<usize> = self
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let point = vertex.p();
+                let (x, y) = cell(point);
+                let existing = (x - 1..=x + 1)
+                    .flat_map(|x| (y - 1..=y + 1).map(move |y| (x, y)))
+                    .filter_map(|cell| grid.get(&cell))
+                    .flatten()
+                    .find(|id| distance_between(point, points[**id]) <= epsilon)
+                    .copied();
+                existing.unwrap_or_else(|| {
+                    points.push(point);
+                    grid.entry((x, y)).or_default().push(points.len() - 1);
+                    points.len() - 1
+                })
+            })
+            .collect();
+        let removed = self.vertices.len() - points.len();
+
+        let (polygons, flags) = self
+            .polygons
+            .iter()
+            .filter_map(|polygon| {
+                let mut vertices: Vec<usize> = polygon.vertices.iter().map(|v| ids[*v]).collect();
+                vertices.dedup();
+                while vertices.len() > 1 && vertices.first() == vertices.last() {
+                    vertices.pop();
+                }
+                (vertices.len() >= 3).then_some((vertices, polygon.flags))
+            })
+            .unzip();
+        self.rebuild(
+            MeshBuilder::from_parts(points, polygons)
+                .with_flags(flags)
+                .build()
+                .expect("building from valid parts can't fail"),
+        );
+        removed
+    }
+
+    /// Remove the vertices in the middle of straight edges, less than `epsilon` from the line
+    /// through their neighbours, then rebuild the adjacency of polygons and vertices. Returns the
+    /// number of vertices removed.
+    ///
+    /// A vertex is only removed when it is in the middle of an edge for all its polygons, so that
+    /// polygons sharing it still share the edge without it. Vertices keep their index, polygons
+    /// keep their order.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([2.0, 0.0])
+    ///     .vertex([2.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .polygon([0, 1, 2, 3, 4])
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(mesh.remove_collinear_vertices(1.0e-5), 1);
+    /// assert_eq!(mesh.polygons()[0].vertices().len(), 4);
+    /// ```
+    pub fn remove_collinear_vertices(&mut self, epsilon: f32) -> usize {
+        let points: Vec<[f32; 2]> = self.vertices.iter().map(|vertex| vertex.p()).collect();
+        let mut polygons: Vec<Vec<usize>> = self
+            .polygons
+            .iter()
+            .map(|polygon| polygon.vertices.clone())
+            .collect();
+        // polygons using each vertex
+        let mut users: Vec<Vec<usize>> = vec![vec![]; points.len()];
+        for (index, polygon) in polygons.iter().enumerate() {
+            for vertex in polygon {
+                users[*vertex].push(index);
+            }
+        }
+
+        let mut removed = 0;
+        for (vertex, users) in users.iter().enumerate() {
+            if users.is_empty() || users.len() > 2 {
+                continue;
+            }
+            // neighbours of the vertex in each of its polygons
+            let Some(neighbours) = users
+                .iter()
+                .map(|polygon| {
+                    let vertices = &polygons[*polygon];
+                    let n = vertices.len();
+                    let i = vertices.iter().position(|v| *v == vertex)?;
+                    (n > 3).then_some((vertices[(i + n - 1) % n], vertices[(i + 1) % n]))
+                })
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let (previous, next) = neighbours[0];
+            // polygons on both sides go through the vertex in opposite directions
+            if neighbours.len() == 2 && neighbours[1] != (next, previous) {
+                continue;
+            }
+            let (a, point, b) = (points[previous], points[vertex], points[next]);
+            let forward =
+                (point[0] - a[0]) * (b[0] - point[0]) + (point[1] - a[1]) * (b[1] - point[1]);
+            if forward <= 0.0 || distance_to_line(point, a, b) > epsilon {
+                continue;
+            }
+            for polygon in users {
+                polygons[*polygon].retain(|v| *v != vertex);
+            }
+            removed += 1;
+        }
+
+        let flags = self.polygons.iter().map(|polygon| polygon.flags).collect();
+        self.rebuild(
+            MeshBuilder::from_parts(points, polygons)
+                .with_flags(flags)
+                .build()
+                .expect("building from valid parts can't fail"),
+        );
+        removed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, MeshBuilder};
+
+    #[test]
+    fn degenerate_polygons() {
+        let mut mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([1.0, 1.0])
+            .vertex([1.0, 1.0e-7])
+            .polygon([0, 1, 2])
+            .polygon([0, 1, 3])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.dedupe_vertices(1.0e-5), 1);
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.polygons.len(), 1);
+        assert!(mesh.validate().is_empty());
+    }
+
+    #[test]
+    fn straight_walls() {
+        // a 3 x 1 grid has two vertices in the middle of its top and bottom walls, shared by two
+        // squares each
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let path = mesh.path([0.5, 0.5], [2.5, 0.5]).unwrap();
+        assert_eq!(mesh.remove_collinear_vertices(1.0e-5), 0);
+
+        // once merged, they are only used by one polygon
+        mesh.merge_convex();
+        assert_eq!(mesh.polygons.len(), 1);
+        assert_eq!(mesh.remove_collinear_vertices(1.0e-5), 4);
+        assert_eq!(mesh.polygons[0].vertices.len(), 4);
+        assert!(mesh.validate().is_empty());
+        assert_eq!(mesh.path([0.5, 0.5], [2.5, 0.5]), Some(path));
+    }
+
+    #[test]
+    fn shared_edges() {
+        // the middle vertex of the top edge of the bottom square is in the middle of the bottom
+        // edges of the two top squares
+        let mut mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([2.0, 0.0])
+            .vertex([2.0, 1.0])
+            .vertex([1.0, 1.0])
+            .vertex([0.0, 1.0])
+            .vertex([2.0, 2.0])
+            .vertex([1.0, 2.0])
+            .vertex([0.0, 2.0])
+            .polygon([0, 1, 2, 3, 4])
+            .polygon([4, 3, 6, 7])
+            .polygon([3, 2, 5, 6])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.remove_collinear_vertices(1.0e-5), 0);
+
+        // with one polygon on top, it can be removed from both sides
+        let mut mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([2.0, 0.0])
+            .vertex([2.0, 1.0])
+            .vertex([1.0, 1.0])
+            .vertex([0.0, 1.0])
+            .vertex([2.0, 2.0])
+            .vertex([0.0, 2.0])
+            .polygon([0, 1, 2, 3, 4])
+            .polygon([4, 3, 2, 5, 6])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.remove_collinear_vertices(1.0e-5), 1);
+        assert!(mesh.validate().is_empty());
+        assert!(mesh.path([1.0, 0.5], [1.0, 1.5]).is_some());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, Path,
+};
+
+impl Mesh {
+    /// Distance from each segment of a path starting at `from` to the closest edge that can't be
+    /// crossed, in order.
+    ///
+    /// It's how far an agent following the segment can move aside, for example to avoid other
+    /// agents, without leaving the mesh. Paths turn on corners of the mesh: the edges of the
+    /// corners at the ends of a segment are ignored, or the clearance would be 0 for all the
+    /// segments but the straight ones. Near these corners, moving aside is still limited by their
+    /// edges.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a corridor 4 wide
+    /// let mesh = Mesh::from_grid(20, 4, |_, _| true);
+    /// let path = mesh.path([1.0, 1.0], [19.0, 1.0]).unwrap();
+    /// assert_eq!(mesh.path_clearance([1.0, 1.0], &path), vec![1.0]);
+    /// ```
+    pub fn path_clearance(&self, from: [f32; 2], path: &Path) -> Vec<f32> {
+        let boundaries: Vec<[[f32; 2]; 2]> = self
+            .boundary_edges()
+            .map(|edge| [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()])
+            .collect();
+        let mut last = from;
+        path.path
+            .iter()
+            .map(|point| {
+                let segment = [last, *point];
+                last = *point;
+                boundaries
+                    .iter()
+                    .filter(|edge| !edge.iter().any(|end| segment.contains(end)))
+                    .map(|edge| segments_distance(segment, *edge))
+                    .fold(f32::INFINITY, f32::min)
+            })
+            .collect()
+    }
+}
+
+/// Distance between two segments.
+fn segments_distance(a: [[f32; 2]; 2], b: [[f32; 2]; 2]) -> f32 {
+    let cross = |o: [f32; 2], p: [f32; 2], q: [f32; 2]| {
+        (p[0] - o[0]) * (q[1] - o[1]) - (p[1] - o[1]) * (q[0] - o[0])
+    };
+    // segments crossing each other
+    let (a0, a1) = (cross(b[0], b[1], a[0]), cross(b[0], b[1], a[1]));
+    let (b0, b1) = (cross(a[0], a[1], b[0]), cross(a[0], a[1], b[1]));
+    if a0 * a1 < 0.0 && b0 * b1 < 0.0 {
+        return 0.0;
+    }
+    [
+        distance_between(a[0], project_on_segment(a[0], b)),
+        distance_between(a[1], project_on_segment(a[1], b)),
+        distance_between(b[0], project_on_segment(b[0], a)),
+        distance_between(b[1], project_on_segment(b[1], a)),
+    ]
+    .into_iter()
+    .fold(f32::INFINITY, f32::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::segments_distance;
+    use crate::{
+        helpers::{distance_between, project_on_segment},
+        maps::{test_meshes, test_points},
+        Mesh,
+    };
+
+    #[test]
+    fn clearance_of_segments() {
+        for mesh in test_meshes() {
+            let from = [1.5, 1.5];
+            for to in test_points(30, [0.5, 0.5]) {
+                let Some(path) = mesh.path(from, to) else {
+                    continue;
+                };
+                let clearance = mesh.path_clearance(from, &path);
+                assert_eq!(clearance.len(), path.path.len());
+                let mut last = from;
+                for (point, clearance) in path.path.iter().zip(clearance) {
+                    // points along the segment are at least that far from the other edges
+                    let edges: Vec<_> = mesh
+                        .boundary_edges()
+                        .map(|edge| [mesh.vertices[edge[0]].p(), mesh.vertices[edge[1]].p()])
+                        .filter(|edge| !edge.contains(&last) && !edge.contains(point))
+                        .collect();
+                    let closest = (0..=20)
+                        .map(|step| {
+                            let t = step as f32 / 20.0;
+                            let p = [
+                                last[0] + (point[0] - last[0]) * t,
+                                last[1] + (point[1] - last[1]) * t,
+                            ];
+                            edges
+                                .iter()
+                                .map(|edge| distance_between(p, project_on_segment(p, *edge)))
+                                .fold(f32::INFINITY, f32::min)
+                        })
+                        .fold(f32::INFINITY, f32::min);
+                    assert!(clearance <= closest + 1.0e-4);
+                    assert!(clearance >= 0.0);
+                    last = *point;
+                }
+            }
+        }
+
+        // a pillar next to a corridor
+        let mesh = Mesh::from_grid(20, 4, |x, y| (x, y) != (10, 3));
+        let path = mesh.path([1.0, 1.0], [19.0, 1.0]).unwrap();
+        assert_eq!(mesh.path_clearance([1.0, 1.0], &path), vec![1.0]);
+        let path = mesh.path([1.0, 2.0], [19.0, 2.0]).unwrap();
+        assert_eq!(mesh.path_clearance([1.0, 2.0], &path), vec![1.0]);
+    }
+
+    #[test]
+    fn short_paths() {
+        let mesh = Mesh::from_grid(20, 4, |x, y| (x, y) != (10, 3));
+        // a path that doesn't move, away from the walls or on one
+        let path = mesh.path([1.5, 1.0], [1.5, 1.0]).unwrap();
+        assert_eq!(mesh.path_clearance([1.5, 1.0], &path), vec![1.0]);
+        let path = mesh.path([1.5, 0.0], [1.5, 0.0]).unwrap();
+        assert_eq!(mesh.path_clearance([1.5, 0.0], &path), vec![0.0]);
+        // along a wall
+        let path = mesh.path([1.5, 0.0], [5.5, 0.0]).unwrap();
+        assert_eq!(mesh.path_clearance([1.5, 0.0], &path), vec![0.0]);
+        // to the corner of the pillar, whose edges are ignored
+        let path = mesh.path([8.0, 3.0], [10.0, 3.0]).unwrap();
+        assert_eq!(mesh.path_clearance([8.0, 3.0], &path), vec![1.0]);
+
+        let mut empty = path.clone();
+        empty.path.clear();
+        assert!(mesh.path_clearance([8.0, 3.0], &empty).is_empty());
+    }
+
+    #[test]
+    fn distance_between_segments() {
+        let horizontal = [[0.0, 0.0], [2.0, 0.0]];
+        assert_eq!(
+            segments_distance(horizontal, [[1.0, -1.0], [1.0, 1.0]]),
+            0.0
+        );
+        assert_eq!(segments_distance(horizontal, [[1.0, 1.0], [1.0, 3.0]]), 1.0);
+        assert_eq!(segments_distance(horizontal, [[3.0, 0.0], [5.0, 0.0]]), 1.0);
+        assert_eq!(segments_distance(horizontal, [[0.0, 2.0], [2.0, 2.0]]), 2.0);
+        // overlapping, touching at an end, and reduced to a point
+        assert_eq!(segments_distance(horizontal, [[1.0, 0.0], [3.0, 0.0]]), 0.0);
+        assert_eq!(segments_distance(horizontal, [[2.0, 0.0], [2.0, 1.0]]), 0.0);
+        assert_eq!(segments_distance(horizontal, [[1.0, 3.0], [1.0, 3.0]]), 3.0);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, PolygonId,
+};
+
+impl Mesh {
+    /// Find the point of the mesh closest to `point`, with the polygon it's in.
+    ///
+    /// Points in the mesh are returned as they are, and points outside of it are moved to the
+    /// closest edge. Returns `None` only if the mesh is empty.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// let (closest, polygon) = mesh.closest_point([12.0, 5.0]).unwrap();
+    /// assert_eq!(closest, [10.0, 5.0]);
+    /// assert_eq!(mesh.locate_with_hint(closest, None), Some(polygon));
+    /// ```
+    pub fn closest_point(&self, point: [f32; 2]) -> Option<([f32; 2], PolygonId)> {
+        if let Some(polygon) = self.point_in_polygon(point) {
+            return Some((point, polygon));
+        }
+        self.closest_on_edges(point, |_| true)
+    }
+
+    /// Stop moving the points outside of the mesh given to [`Mesh::path`] onto it with `None`,
+    /// or move them to the closest point of the mesh when they are at most `max_distance` from
+    /// it.
+    ///
+    /// Paths then start or end at the moved points, and points farther from the mesh still fail
+    /// their query.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mut mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// assert!(mesh.path([1.0, 1.0], [10.5, 5.0]).is_none());
+    /// mesh.set_off_mesh_clamp(Some(1.0));
+    /// let path = mesh.path([1.0, 1.0], [10.5, 5.0]).unwrap();
+    /// assert_eq!(path.path.last(), Some(&[10.0, 5.0]));
+    /// ```
+    pub fn set_off_mesh_clamp(&mut self, max_distance: Option<f32>) {
+        self.off_mesh_clamp = max_distance.filter(|distance| *distance >= 0.0);
+    }
+
+    /// Maximum distance from which points outside of the mesh are moved onto it, if they are.
+    pub fn off_mesh_clamp(&self) -> Option<f32> {
+        self.off_mesh_clamp
+    }
+
+    /// Locate a point for a query, moving it onto the mesh if it's outside of it and close
+    /// enough for the off mesh clamp.
+    pub(crate) fn locate_or_clamp(&self, point: [f32; 2]) -> ([f32; 2], Option<PolygonId>) {
+        if let Some(polygon) = self.point_in_polygon(point) {
+            return (point, Some(polygon));
+        }
+        self.off_mesh_clamp
+            .and_then(|max_distance| {
+                self.closest_on_edges(point, |_| true)
+                    .filter(|(closest, _)| distance_between(point, *closest) <= max_distance)
+            })
+            .map_or((point, None), |(closest, polygon)| (closest, Some(polygon)))
+    }
+
+    /// Point closest to `point` on the edges of the polygons kept by `keep`, with the polygon of
+    /// this edge.
+    pub(crate) fn closest_on_edges(
+        &self,
+        point: [f32; 2],
+        keep: impl Fn(usize) -> bool,
+    ) -> Option<([f32; 2], PolygonId)> {
+        let mut candidates: Vec<(f32, usize)> = self
+            .polygon_aabbs
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| keep(*i))
+            .map(|(i, aabb)| (aabb.distance_to(point), i))
+            .collect();
+        candidates.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut nearest: Option<([f32; 2], usize, f32)> = None;
+        for (aabb_distance, i) in candidates {
+            // all the edges of this polygon are further than the best found so far
+            if nearest.map(|n| n.2 < aabb_distance).unwrap_or(false) {
+                break;
+            }
+            for edge in self.polygons[i].edges_index() {
+                let segment = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                let projected = project_on_segment(point, segment);
+                let distance = distance_between(point, projected);
+                if nearest.map(|n| distance < n.2).unwrap_or(true) {
+                    nearest = Some((projected, i, distance));
+                }
+            }
+        }
+        nearest.map(|(closest, polygon, _)| (closest, PolygonId::new(polygon)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::{grid_mesh, random_obstacles};
+
+    #[test]
+    fn closest_point() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        for i in 0..60 {
+            let point = [(i * 7 % 34) as f32 - 1.7, (i * 13 % 34) as f32 - 1.7];
+            let (closest, polygon) = mesh.closest_point(point).unwrap();
+            assert_eq!(mesh.locate_with_hint(closest, Some(polygon)), Some(polygon));
+            if mesh.point_in_mesh(point) {
+                assert_eq!(closest, point);
+            } else {
+                let (_, on_boundary, distance) = mesh.nearest_boundary(point).unwrap();
+                assert_eq!(closest, on_boundary);
+                assert!(distance > 0.0);
+            }
+        }
+        assert!(crate::Mesh::default().closest_point([0.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn clamp_off_mesh_points() {
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let (from, to) = ([-0.5, 0.5], [2.5, 1.2]);
+        assert!(mesh.path(from, to).is_none());
+        mesh.set_off_mesh_clamp(Some(0.3));
+        assert!(mesh.path(from, to).is_none());
+
+        mesh.set_off_mesh_clamp(Some(0.5));
+        assert_eq!(mesh.off_mesh_clamp(), Some(0.5));
+        let path = mesh.path(from, to).unwrap();
+        assert_eq!(path.path, vec![[2.5, 1.0]]);
+        assert!((path.len - 2.5_f32.hypot(0.5)).abs() < 1.0e-5);
+
+        // kept when the mesh is built again
+        mesh.merge_convex();
+        assert_eq!(mesh.off_mesh_clamp(), Some(0.5));
+        mesh.set_off_mesh_clamp(None);
+        assert!(mesh.path(from, to).is_none());
+    }
+}
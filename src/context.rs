@@ -0,0 +1,122 @@
+use std::{collections::BinaryHeap, task::Poll};
+
+use hashbrown::HashMap;
+
+use crate::{counted, Mesh, Path, PolygonId, Root, SearchInstance, SearchNode, SearchOptions};
+
+/// Memory used by searches, to be reused from one to the next with
+/// [`Mesh::path_with_context`].
+///
+/// Each search otherwise allocates its own queue of nodes and history of roots. Keeping a
+/// context per agent, or per thread, avoids these allocations for agents looking for a path
+/// every frame.
+#[derive(Default)]
+pub struct SearchContext {
+    pub(crate) queue: BinaryHeap<SearchNode>,
+    pub(crate) node_buffer: Vec<SearchNode>,
+    pub(crate) root_history: HashMap<Root, f32>,
+}
+
+impl SearchContext {
+    /// Create a context, with room for a small search.
+    pub fn new() -> Self {
+        SearchContext {
+            queue: BinaryHeap::with_capacity(15),
+            node_buffer: Vec::with_capacity(10),
+            root_history: HashMap::with_capacity(10),
+        }
+    }
+}
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], reusing the memory of
+    /// a context instead of allocating it.
+    ///
+    /// The memory of the context grows to the size of the biggest search it was used for.
+    ///
+    /// ```
+    /// # use polyanya::{Mesh, SearchContext};
+    /// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+    /// let mut context = SearchContext::new();
+    /// for _ in 0..10 {
+    ///     let path = mesh.path_with_context([1.0, 3.0], [4.0, 2.0], &mut context);
+    ///     assert_eq!(path, mesh.path([1.0, 3.0], [4.0, 2.0]));
+    /// }
+    /// ```
+    pub fn path_with_context(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        context: &mut SearchContext,
+    ) -> Option<Path> {
+        self.path_between_polygons_with_context(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            context,
+        )
+    }
+
+    /// Compute the shortest path between two points already located, reusing the memory of a
+    /// context.
+    pub(crate) fn path_between_polygons_with_context(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        from_polygon: Option<PolygonId>,
+        to_polygon: Option<PolygonId>,
+        context: &mut SearchContext,
+    ) -> Option<Path> {
+        counted(Option::is_none, || {
+            let mut search = match SearchInstance::start(
+                self,
+                context,
+                from,
+                to,
+                from_polygon,
+                to_polygon,
+                SearchOptions::default(),
+            ) {
+                Ok(search) => search,
+                Err(result) => return result.map(|result| result.path),
+            };
+            let result = search.expand(usize::MAX, |_| ());
+            search.finish(context);
+            match result {
+                Poll::Ready(result) => result.map(|result| result.path),
+                Poll::Pending => unreachable!("searches without budget are done when they return"),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SearchContext;
+    use crate::maps::{maze, random_obstacles};
+
+    #[test]
+    fn reused_between_searches() {
+        let mut context = SearchContext::default();
+        let maze = maze(10, 10, 3);
+        let obstacles = random_obstacles(30, 30, 20, 4, 1);
+        for (mesh, from, to) in [
+            (&maze, [1.5, 1.5], [19.5, 19.5]),
+            (&obstacles, [0.5, 0.5], [29.5, 29.5]),
+            (&maze, [1.5, 1.5], [1.2, 1.8]),
+            (&maze, [1.5, 1.5], [-1.0, 0.0]),
+            (&maze, [19.5, 19.5], [1.5, 1.5]),
+        ] {
+            assert_eq!(
+                mesh.path_with_context(from, to, &mut context),
+                mesh.path(from, to)
+            );
+            assert!(context.queue.is_empty());
+            assert!(context.node_buffer.is_empty());
+            assert!(context.root_history.is_empty());
+        }
+        assert!(context.queue.capacity() > 0);
+        assert!(context.root_history.capacity() > 0);
+    }
+}
@@ -0,0 +1,508 @@
+use std::task::Poll;
+
+use crate::{counted, Mesh, Path, PolygonId, SearchContext, SearchInstance, SearchOptions};
+
+/// An edge crossed by a corridor, with its ends on each side when going through it, as returned
+/// by [`Mesh::portals`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Portal {
+    /// End of the edge on the left.
+    pub left: [f32; 2],
+    /// End of the edge on the right.
+    pub right: [f32; 2],
+}
+
+/// Path with the polygons it goes through, as returned by [`Mesh::path_with_corridor`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorridorPath {
+    /// The path.
+    pub path: Path,
+    /// Polygons the path goes through, in order, from the one containing its start to the one
+    /// containing its end. Consecutive polygons are neighbours.
+    pub corridor: Vec<PolygonId>,
+}
+
+/// Polygons leading an agent from its position to its target, kept up to date as they both move,
+/// like the path corridor of Detour.
+///
+/// Character controllers steer towards the next corners of the corridor, and move the position
+/// and the target by small steps, which only changes the ends of the corridor. A search is only
+/// needed when one of them goes further than the neighbours of the corridor, or when the mesh
+/// changed and the corridor is not [valid](PathCorridor::is_valid) anymore.
+///
+/// ```
+/// # use polyanya::{Mesh, PathCorridor};
+/// // a pillar in the middle of a room
+/// let mesh = Mesh::from_grid(10, 10, |x, y| (x, y) != (5, 5));
+/// let mut corridor = PathCorridor::new(&mesh, [1.0, 5.8], [9.0, 5.8]).unwrap();
+/// let corners = corridor.corners(&mesh, usize::MAX).unwrap();
+/// assert_eq!(corners, mesh.path([1.0, 5.8], [9.0, 5.8]).unwrap().path);
+///
+/// // each frame, towards the next corner
+/// corridor.move_position(&mesh, [3.0, 5.9]).unwrap();
+/// corridor.move_target(&mesh, [9.0, 5.5]).unwrap();
+/// let corners = corridor.corners(&mesh, 3).unwrap();
+/// assert_eq!(corners, vec![[5.0, 6.0], [6.0, 6.0], [9.0, 5.5]]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathCorridor {
+    position: [f32; 2],
+    target: [f32; 2],
+    polygons: Vec<PolygonId>,
+}
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], with the polygons it
+    /// goes through.
+    ///
+    /// The search keeps the polygons each node went through, which is a bit slower than only
+    /// finding the path. Polygons the path only touches at a corner are not in the corridor.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+    /// let result = mesh.path_with_corridor([1.0, 3.0], [4.0, 2.0]).unwrap();
+    /// assert_eq!(result.path, mesh.path([1.0, 3.0], [4.0, 2.0]).unwrap());
+    /// assert_eq!(result.corridor.first(), mesh.locate_with_hint([1.0, 3.0], None).as_ref());
+    /// assert_eq!(result.corridor.last(), mesh.locate_with_hint([4.0, 2.0], None).as_ref());
+    /// ```
+    pub fn path_with_corridor(&self, from: [f32; 2], to: [f32; 2]) -> Option<CorridorPath> {
+        counted(Option::is_none, || {
+            let (from, from_polygon) = self.locate_or_clamp(from);
+            let (to, to_polygon) = self.locate_or_clamp(to);
+            let mut search = match SearchInstance::start(
+                self,
+                &mut SearchContext::new(),
+                from,
+                to,
+                from_polygon,
+                to_polygon,
+                SearchOptions {
+                    corridor: true,
+                    ..SearchOptions::default()
+                },
+            ) {
+                Ok(search) => search,
+                // both points in the same polygon
+                Err(result) => {
+                    return result.map(|result| CorridorPath {
+                        path: result.path,
+                        corridor: vec![from_polygon.unwrap()],
+                    })
+                }
+            };
+            // the first node reaching the target polygon ends the search
+            let mut corridor = vec![];
+            let result = search.expand(usize::MAX, |node| {
+                if corridor.is_empty() && node.polygon_to == to_polygon {
+                    corridor = node.corridor.clone();
+                }
+            });
+            match result {
+                Poll::Ready(result) => result.map(|result| CorridorPath {
+                    path: result.path,
+                    corridor,
+                }),
+                Poll::Pending => unreachable!("searches without budget are done when they return"),
+            }
+        })
+    }
+
+    /// Edges crossed by a corridor, with their left and right ends when going from each polygon
+    /// to the next one, or `None` if two consecutive polygons are not neighbours.
+    ///
+    /// They're the input of a funnel algorithm, to pull a path through the corridor with
+    /// custom rules, like keeping away from the corners for the radius of an agent.
+    ///
+    /// ```
+    /// # use polyanya::{Mesh, Portal};
+    /// // a pillar in the middle of a corridor, passed from above going right
+    /// let mesh = Mesh::from_grid(10, 3, |x, y| x != 5 || y != 1);
+    /// let result = mesh.path_with_corridor([0.5, 2.5], [9.5, 2.5]).unwrap();
+    /// let portals = mesh.portals(&result.corridor).unwrap();
+    /// assert_eq!(portals.len(), result.corridor.len() - 1);
+    /// assert_eq!(
+    ///     portals[0],
+    ///     Portal {
+    ///         left: [5.0, 3.0],
+    ///         right: [5.0, 2.0]
+    ///     }
+    /// );
+    /// ```
+    pub fn portals(&self, corridor: &[PolygonId]) -> Option<Vec<Portal>> {
+        corridor
+            .windows(2)
+            .map(|pair| {
+                let edge = self.edge_between(pair[0], pair[1])?;
+                let [a, b] = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                let polygon = &self.polygons[pair[0].index()];
+                let inside = polygon
+                    .vertices
+                    .iter()
+                    .map(|vertex| self.vertices[*vertex].p())
+                    .fold([0.0, 0.0], |sum, p| [sum[0] + p[0], sum[1] + p[1]]);
+                let n = polygon.vertices.len() as f32;
+                let inside = [inside[0] / n, inside[1] / n];
+                // facing the middle of the edge from inside of the polygon
+                let facing = [
+                    (a[0] + b[0]) / 2.0 - inside[0],
+                    (a[1] + b[1]) / 2.0 - inside[1],
+                ];
+                let to_a = [a[0] - inside[0], a[1] - inside[1]];
+                Some(if facing[0] * to_a[1] - facing[1] * to_a[0] > 0.0 {
+                    Portal { left: a, right: b }
+                } else {
+                    Portal { left: b, right: a }
+                })
+            })
+            .collect()
+    }
+}
+
+impl PathCorridor {
+    /// Compute the corridor of the shortest path from `from` to `to`, or `None` if one of the
+    /// points is not in the mesh, or if `to` can't be reached from `from`.
+    pub fn new(mesh: &Mesh, from: [f32; 2], to: [f32; 2]) -> Option<Self> {
+        let result = mesh.path_with_corridor(from, to)?;
+        Some(PathCorridor {
+            position: mesh.locate_or_clamp(from).0,
+            target: mesh.locate_or_clamp(to).0,
+            polygons: result.corridor,
+        })
+    }
+
+    /// Current position of the agent.
+    pub fn position(&self) -> [f32; 2] {
+        self.position
+    }
+
+    /// Current target of the agent.
+    pub fn target(&self) -> [f32; 2] {
+        self.target
+    }
+
+    /// Polygons from the one containing the position to the one containing the target.
+    /// Consecutive polygons are neighbours.
+    pub fn polygons(&self) -> &[PolygonId] {
+        &self.polygons
+    }
+
+    /// Next points where the shortest path through the corridor turns, at most `max`, ending with
+    /// the target when it's among them. Returns `None` if the polygons of the corridor are not
+    /// neighbours anymore.
+    pub fn corners(&self, mesh: &Mesh, max: usize) -> Option<Vec<[f32; 2]>> {
+        let portals = mesh.portals(&self.polygons)?;
+        let mut corners = funnel(self.position, self.target, &portals);
+        corners.dedup();
+        corners.truncate(max);
+        Some(corners)
+    }
+
+    /// Move the position towards `to`, sliding along the walls like
+    /// [`Mesh::move_along_surface`], and update the start of the corridor.
+    ///
+    /// The polygons left behind are removed from the corridor, and the polygon entered is added
+    /// to it if it's a neighbour of the first one. Further than that, the corridor is searched
+    /// again. Returns the position reached, or `None` if the corridor can't be followed anymore,
+    /// in which case it doesn't change.
+    pub fn move_position(&mut self, mesh: &Mesh, to: [f32; 2]) -> Option<[f32; 2]> {
+        let displacement = [to[0] - self.position[0], to[1] - self.position[1]];
+        let (position, polygon) = mesh.move_along_surface(self.position, displacement)?;
+        if let Some(index) = self
+            .polygons
+            .iter()
+            .rposition(|polygon| contains(mesh, *polygon, position))
+        {
+            self.polygons.drain(..index);
+        } else if can_cross(mesh, polygon, self.polygons[0]) {
+            self.polygons.insert(0, polygon);
+        } else {
+            *self = PathCorridor::new(mesh, position, self.target)?;
+        }
+        self.position = position;
+        Some(position)
+    }
+
+    /// Move the target towards `to`, sliding along the walls like
+    /// [`Mesh::move_along_surface`], and update the end of the corridor.
+    ///
+    /// Like for [`PathCorridor::move_position`], the corridor is only searched again when the
+    /// target goes further than the neighbours of its last polygon. Returns the target reached,
+    /// or `None` if the corridor can't be followed anymore, in which case it doesn't change.
+    pub fn move_target(&mut self, mesh: &Mesh, to: [f32; 2]) -> Option<[f32; 2]> {
+        let displacement = [to[0] - self.target[0], to[1] - self.target[1]];
+        let (target, polygon) = mesh.move_along_surface(self.target, displacement)?;
+        if let Some(index) = self
+            .polygons
+            .iter()
+            .position(|polygon| contains(mesh, *polygon, target))
+        {
+            self.polygons.truncate(index + 1);
+        } else if can_cross(mesh, *self.polygons.last().unwrap(), polygon) {
+            self.polygons.push(polygon);
+        } else {
+            *self = PathCorridor::new(mesh, self.position, target)?;
+        }
+        self.target = target;
+        Some(target)
+    }
+
+    /// Shorten the start of the corridor if `next` can be reached in a straight line from the
+    /// position, for example after the agent was pushed away from the shortest path.
+    ///
+    /// `next` is usually a corner a few corners ahead. The polygons of the corridor up to the
+    /// furthest one the segment goes through are replaced by the polygons along the segment.
+    /// Returns `true` if the corridor changed.
+    pub fn optimize_visibility(&mut self, mesh: &Mesh, next: [f32; 2]) -> bool {
+        let Some(along) = mesh.polygons_along(self.position, next) else {
+            return false;
+        };
+        let Some((end, index)) = along
+            .iter()
+            .enumerate()
+            .filter_map(|(i, polygon)| Some((i, self.polygons.iter().position(|p| p == polygon)?)))
+            .max_by_key(|(_, index)| *index)
+        else {
+            return false;
+        };
+        let shortcut = &along[..=end];
+        // going through a vertex is not a way through the corridor
+        if shortcut == &self.polygons[..=index]
+            || shortcut
+                .windows(2)
+                .any(|pair| !can_cross(mesh, pair[0], pair[1]))
+        {
+            return false;
+        }
+        self.polygons.splice(..=index, shortcut.iter().copied());
+        true
+    }
+
+    /// Check if the corridor can still be followed after the mesh changed: consecutive polygons
+    /// are still neighbours, the edges between them can be crossed, and the position and the
+    /// target are still in the first and last polygons.
+    pub fn is_valid(&self, mesh: &Mesh) -> bool {
+        // points on an edge can be located in the polygon on either side
+        let located = |polygon: PolygonId, point: [f32; 2]| {
+            contains(mesh, polygon, point) || mesh.point_in_polygon(point) == Some(polygon)
+        };
+        located(self.polygons[0], self.position)
+            && located(*self.polygons.last().unwrap(), self.target)
+            && self
+                .polygons
+                .windows(2)
+                .all(|pair| can_cross(mesh, pair[0], pair[1]))
+    }
+}
+
+/// Check if a point is in a polygon, that may not exist anymore.
+fn contains(mesh: &Mesh, polygon: PolygonId, point: [f32; 2]) -> bool {
+    polygon.index() < mesh.polygons.len() && mesh.polygon_contains(polygon.index(), point)
+}
+
+/// Check if a polygon is a neighbour of another that can be entered from it.
+fn can_cross(mesh: &Mesh, from: PolygonId, to: PolygonId) -> bool {
+    mesh.edge_between(from, to)
+        .is_some_and(|edge| mesh.can_cross(edge))
+}
+
+/// Pull a path through portals with the simple stupid funnel algorithm.
+fn funnel(from: [f32; 2], to: [f32; 2], portals: &[Portal]) -> Vec<[f32; 2]> {
+    let cross = |o: [f32; 2], a: [f32; 2], b: [f32; 2]| {
+        (a[0] - o[0]) * (b[1] - o[1]) - (a[1] - o[1]) * (b[0] - o[0])
+    };
+    let mut portals = portals.to_vec();
+    portals.push(Portal {
+        left: to,
+        right: to,
+    });
+    let mut path = vec![];
+    let (mut apex, mut left, mut right) = (from, from, from);
+    let (mut left_index, mut right_index) = (0, 0);
+    let mut i = 0;
+    while i < portals.len() {
+        let portal = portals[i];
+        // tighten the right side, or restart from the left side when crossing it
+        if cross(apex, right, portal.right) >= 0.0 {
+            if apex == right || cross(apex, left, portal.right) < 0.0 {
+                right = portal.right;
+                right_index = i + 1;
+            } else {
+                apex = left;
+                path.push(apex);
+                (right, right_index) = (apex, left_index);
+                i = left_index;
+                continue;
+            }
+        }
+        if cross(apex, left, portal.left) <= 0.0 {
+            if apex == left || cross(apex, right, portal.left) > 0.0 {
+                left = portal.left;
+                left_index = i + 1;
+            } else {
+                apex = right;
+                path.push(apex);
+                (left, left_index) = (apex, right_index);
+                i = right_index;
+                continue;
+            }
+        }
+        i += 1;
+    }
+    path.push(to);
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::funnel;
+    use crate::{
+        helpers::distance_between,
+        maps::{maze, random_obstacles, test_meshes, test_points},
+        Mesh, PathCorridor, PolygonId,
+    };
+
+    #[test]
+    fn polygons_of_the_path() {
+        for mesh in test_meshes() {
+            let from = [1.5, 1.5];
+            for to in test_points(60, [0.5, 0.5]) {
+                let Some(result) = mesh.path_with_corridor(from, to) else {
+                    assert!(mesh.path(from, to).is_none());
+                    continue;
+                };
+                assert_eq!(Some(&result.path), mesh.path(from, to).as_ref());
+                let corridor = &result.corridor;
+                assert_eq!(corridor.first(), mesh.point_in_polygon(from).as_ref());
+                assert_eq!(corridor.last(), mesh.point_in_polygon(to).as_ref());
+                for pair in corridor.windows(2) {
+                    assert!(mesh.edge_between(pair[0], pair[1]).is_some());
+                }
+                // the points where the path turns are on polygons of the corridor
+                for point in &result.path.path {
+                    assert!(corridor
+                        .iter()
+                        .any(|polygon| mesh.polygon_contains(polygon.index(), *point)));
+                }
+
+                // pulling a path through the portals gives the same length
+                let portals = mesh.portals(corridor).unwrap();
+                let pulled = funnel(from, to, &portals);
+                let mut len = 0.0;
+                let mut last = from;
+                for point in &pulled {
+                    len += distance_between(last, *point);
+                    last = *point;
+                }
+                assert!((len - result.path.len).abs() < 1.0e-3, "{:?}", result);
+            }
+        }
+        let mesh = maze(2, 2, 0);
+        assert!(mesh
+            .portals(&[PolygonId::new(0), PolygonId::new(0)])
+            .is_none());
+        assert_eq!(mesh.portals(&[PolygonId::new(0)]), Some(vec![]));
+    }
+
+    #[test]
+    fn short_and_unreachable() {
+        let mesh = Mesh::from_grid(10, 3, |x, _| x != 5);
+        let polygon = |point| mesh.point_in_polygon(point).unwrap();
+        // in the same polygon
+        let result = mesh.path_with_corridor([0.5, 0.5], [0.8, 0.9]).unwrap();
+        assert_eq!(result.corridor, vec![polygon([0.5, 0.5])]);
+        assert_eq!(mesh.portals(&result.corridor), Some(vec![]));
+        let result = mesh.path_with_corridor([0.5, 0.5], [0.5, 0.5]).unwrap();
+        assert_eq!(result.corridor, vec![polygon([0.5, 0.5])]);
+        assert_eq!(mesh.portals(&[]), Some(vec![]));
+
+        // behind the wall, or outside of the mesh
+        assert!(mesh.path_with_corridor([0.5, 0.5], [9.5, 0.5]).is_none());
+        assert!(mesh.path_with_corridor([-1.0, 0.5], [0.5, 0.5]).is_none());
+        assert!(mesh.path_with_corridor([0.5, 0.5], [0.5, 5.0]).is_none());
+        assert!(mesh
+            .portals(&[polygon([0.5, 0.5]), polygon([9.5, 0.5])])
+            .is_none());
+
+        // to a point on the wall, at the end of the corridor
+        let result = mesh.path_with_corridor([0.5, 0.5], [5.0, 1.5]).unwrap();
+        assert!(mesh.polygon_contains(result.corridor.last().unwrap().index(), [5.0, 1.5]));
+    }
+
+    #[test]
+    fn path_corridor() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let (from, to) = ([1.5, 1.5], [27.5, 26.5]);
+        let mut corridor = PathCorridor::new(&mesh, from, to).unwrap();
+        let path = mesh.path(from, to).unwrap();
+        let corners = corridor.corners(&mesh, usize::MAX).unwrap();
+        assert_eq!(corners.last(), Some(&to));
+        let mut len = 0.0;
+        let mut last = from;
+        for corner in &corners {
+            len += distance_between(last, *corner);
+            last = *corner;
+        }
+        assert!((len - path.len).abs() < 1.0e-3);
+        assert_eq!(corridor.corners(&mesh, 1).unwrap().len(), 1);
+
+        // walk towards the next corner, while the target moves
+        let check = |mesh: &Mesh, corridor: &PathCorridor| {
+            assert!(corridor.is_valid(mesh));
+            for pair in corridor.polygons().windows(2) {
+                assert!(mesh.edge_between(pair[0], pair[1]).is_some());
+            }
+        };
+        for frame in 0..200 {
+            let position = corridor.position();
+            let next = corridor.corners(&mesh, 1).unwrap()[0];
+            let distance = distance_between(position, next);
+            if distance < 1.0e-3 {
+                break;
+            }
+            let step = (0.5 / distance).min(1.0);
+            let moved = [
+                position[0] + (next[0] - position[0]) * step,
+                position[1] + (next[1] - position[1]) * step,
+            ];
+            assert!(corridor.move_position(&mesh, moved).is_some());
+            if frame % 4 == 0 {
+                let target = corridor.target();
+                corridor.move_target(&mesh, [target[0] - 0.1, target[1] + 0.05]);
+            }
+            check(&mesh, &corridor);
+        }
+        assert!(distance_between(corridor.position(), corridor.target()) < 1.0e-2);
+
+        // pushed away from the path, the corridor keeps the polygons on the way back to it
+        let mesh = Mesh::from_grid(10, 10, |x, y| (x, y) != (5, 5));
+        let to = [9.0, 5.8];
+        let mut corridor = PathCorridor::new(&mesh, [1.0, 5.8], to).unwrap();
+        for i in 1..=7 {
+            corridor.move_position(&mesh, [1.0, 5.8 - i as f32 * 0.5]);
+            check(&mesh, &corridor);
+        }
+        assert!(corridor.corners(&mesh, usize::MAX).unwrap().len() > 1);
+        let before = corridor.polygons().len();
+        assert!(corridor.optimize_visibility(&mesh, to));
+        check(&mesh, &corridor);
+        assert!(corridor.polygons().len() < before);
+        assert_eq!(corridor.corners(&mesh, usize::MAX).unwrap(), vec![to]);
+        assert!(!corridor.optimize_visibility(&mesh, to));
+
+        // a door closes on the corridor
+        let mut mesh = Mesh::from_grid(10, 3, |x, y| x != 5 || y == 1);
+        let corridor = PathCorridor::new(&mesh, [1.0, 1.5], [9.0, 1.5]).unwrap();
+        assert!(corridor.is_valid(&mesh));
+        let polygons = corridor.polygons();
+        let door = mesh.point_in_polygon([5.5, 1.5]).unwrap();
+        let index = polygons
+            .iter()
+            .position(|polygon| *polygon == door)
+            .unwrap();
+        let edge = mesh.edge_between(polygons[index - 1], door).unwrap();
+        mesh.set_edge_open(edge, false);
+        assert!(!corridor.is_valid(&mesh));
+        assert!(PathCorridor::new(&mesh, [1.0, 1.5], [9.0, 1.5]).is_none());
+    }
+}
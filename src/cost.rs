@@ -0,0 +1,113 @@
+use crate::{Mesh, Path, PolygonId, SearchOptions};
+
+/// Cost of crossing from a polygon to another, added to the length of the paths doing so.
+///
+/// It's called each time the search goes through an edge, so it can model any cost on top of
+/// the distance, like the danger of the areas of another faction or the crowd in a corridor.
+/// Closures taking the same arguments as [`CostModel::crossing_cost`] implement it.
+///
+/// Paths still only turn at the corners of the mesh, so the search finds the cheapest path among
+/// the ones it would consider for the distance alone.
+///
+/// ```
+/// # use polyanya::{Mesh, PolygonId};
+/// // a pillar in the middle of a room, with danger on one side
+/// let mesh = Mesh::from_grid(3, 3, |x, y| (x, y) != (1, 1));
+/// let danger = mesh.locate_with_hint([1.5, 0.5], None).unwrap();
+/// assert!(mesh.path([0.5, 1.5], [2.5, 1.5]).unwrap().path.contains(&[1.0, 1.0]));
+///
+/// let avoid_danger = |_: PolygonId, to: PolygonId, _: [[f32; 2]; 2]| {
+///     Some(if to == danger { 10.0 } else { 0.0 })
+/// };
+/// let path = mesh
+///     .path_with_cost_model([0.5, 1.5], [2.5, 1.5], &avoid_danger)
+///     .unwrap();
+/// assert_eq!(path.path, vec![[1.0, 2.0], [2.0, 2.0], [2.5, 1.5]]);
+/// ```
+pub trait CostModel {
+    /// Cost of going from polygon `from` to polygon `to` through `interval`, the part of the edge
+    /// between them that the search is crossing, or `None` if paths can't go this way.
+    ///
+    /// Negative costs are ignored, as the search needs paths to cost at least their length.
+    fn crossing_cost(&self, from: PolygonId, to: PolygonId, interval: [[f32; 2]; 2])
+        -> Option<f32>;
+}
+
+impl<F> CostModel for F
+where
+    F: Fn(PolygonId, PolygonId, [[f32; 2]; 2]) -> Option<f32>,
+{
+    fn crossing_cost(
+        &self,
+        from: PolygonId,
+        to: PolygonId,
+        interval: [[f32; 2]; 2],
+    ) -> Option<f32> {
+        self(from, to, interval)
+    }
+}
+
+impl Mesh {
+    /// Compute the cheapest path from `from` to `to`, adding the costs of a [`CostModel`] to its
+    /// length for each edge it crosses.
+    ///
+    /// The length of the path is its cost. Returns `None` if one of the points is not in the
+    /// mesh, or if `to` can't be reached from `from`.
+    pub fn path_with_cost_model(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        model: &(impl CostModel + Sync),
+    ) -> Option<Path> {
+        self.path_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions {
+                model: Some(model),
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, PolygonId};
+
+    #[test]
+    fn crossing_costs() {
+        // a corridor of three rooms, with a closed door between the last two
+        let mesh = grid_mesh(3, 1, |_, _| true);
+        let last = PolygonId::new(2);
+        let closed = |from: PolygonId, to: PolygonId, _: [[f32; 2]; 2]| {
+            (from != last && to != last).then_some(0.0)
+        };
+        assert!(mesh
+            .path_with_cost_model([0.5, 0.5], [2.5, 0.5], &closed)
+            .is_none());
+
+        let toll = |_: PolygonId, _: PolygonId, interval: [[f32; 2]; 2]| {
+            assert_eq!(interval[0][0], interval[1][0]);
+            Some(1.0)
+        };
+        let path = mesh
+            .path_with_cost_model([0.5, 0.5], [2.5, 0.5], &toll)
+            .unwrap();
+        assert_eq!(path.len, 4.0);
+        assert_eq!(path.path, vec![[2.5, 0.5]]);
+
+        // negative costs are ignored, and starting in the target polygon crosses nothing
+        let refund = |_: PolygonId, _: PolygonId, _: [[f32; 2]; 2]| Some(-1.0);
+        let path = mesh
+            .path_with_cost_model([0.5, 0.5], [2.5, 0.5], &refund)
+            .unwrap();
+        assert_eq!(path.len, 2.0);
+        let path = mesh
+            .path_with_cost_model([0.2, 0.5], [0.8, 0.5], &toll)
+            .unwrap();
+        assert!((path.len - 0.6).abs() < 1.0e-6);
+    }
+}
@@ -0,0 +1,131 @@
+//! Process-wide counters of the pathfinding activity, enabled with the `counters` feature.
+//!
+//! They are shared by all the meshes and threads, and are meant to be exported periodically to a
+//! monitoring system.
+
+use std::{
+    cell::Cell,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static QUERIES: AtomicU64 = AtomicU64::new(0);
+static NODES_EXPANDED: AtomicU64 = AtomicU64::new(0);
+static FAILURES: AtomicU64 = AtomicU64::new(0);
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Values of the counters at a given time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Counters {
+    /// Number of queries run, once for each call to the API even when it searches several times.
+    pub queries: u64,
+    /// Number of search nodes expanded. Nodes reaching the target end the search and are not
+    /// expanded.
+    pub nodes_expanded: u64,
+    /// Number of queries with a target that didn't find a path. Searches with no target, like
+    /// [`Mesh::distance_field`](crate::Mesh::distance_field), don't fail.
+    pub failures: u64,
+    /// Number of search nodes whose root was already reached by another node.
+    pub cache_hits: u64,
+}
+
+/// Read the current value of the counters.
+pub fn snapshot() -> Counters {
+    Counters {
+        queries: QUERIES.load(Ordering::Relaxed),
+        nodes_expanded: NODES_EXPANDED.load(Ordering::Relaxed),
+        failures: FAILURES.load(Ordering::Relaxed),
+        cache_hits: CACHE_HITS.load(Ordering::Relaxed),
+    }
+}
+
+/// Reset all the counters to zero, and return their values before the reset.
+pub fn reset() -> Counters {
+    Counters {
+        queries: QUERIES.swap(0, Ordering::Relaxed),
+        nodes_expanded: NODES_EXPANDED.swap(0, Ordering::Relaxed),
+        failures: FAILURES.swap(0, Ordering::Relaxed),
+        cache_hits: CACHE_HITS.swap(0, Ordering::Relaxed),
+    }
+}
+
+thread_local! {
+    /// Number of queries running on this thread, one inside the other.
+    static RUNNING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// A query running on this thread, until it's dropped. Queries it runs in turn are part of it,
+/// and are not counted.
+pub(crate) struct Query {
+    outermost: bool,
+}
+
+impl Query {
+    /// Start a query, counted unless it's part of another one.
+    pub(crate) fn start() -> Self {
+        let query = Query::resume();
+        if query.outermost {
+            QUERIES.fetch_add(1, Ordering::Relaxed);
+        }
+        query
+    }
+
+    /// Go on with a query already counted, like a search spread over several calls.
+    pub(crate) fn resume() -> Self {
+        let running = RUNNING.with(|running| running.replace(running.get() + 1));
+        Query {
+            outermost: running == 0,
+        }
+    }
+
+    /// Count the query as a failure, unless it's part of another one.
+    pub(crate) fn fail(&self) {
+        if self.outermost {
+            FAILURES.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Drop for Query {
+    fn drop(&mut self) {
+        RUNNING.with(|running| running.set(running.get() - 1));
+    }
+}
+
+#[inline(always)]
+pub(crate) fn node_expanded() {
+    NODES_EXPANDED.fetch_add(1, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub(crate) fn cache_hit() {
+    CACHE_HITS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::mesh_u_grid, PathFilter, PolygonId};
+
+    #[test]
+    fn count_queries() {
+        let mesh = mesh_u_grid();
+        let before = super::snapshot();
+        let _ = mesh.path([0.5, 1.5], [2.5, 1.5]);
+        let after = super::snapshot();
+        // other tests may run at the same time
+        assert!(after.queries > before.queries);
+        assert!(after.nodes_expanded > before.nodes_expanded);
+    }
+
+    #[test]
+    fn count_failures_before_searching() {
+        let mesh = mesh_u_grid();
+        let filter = PathFilter::new().avoid([PolygonId::new(0)]);
+        let before = super::snapshot();
+        // starting in an excluded polygon
+        assert!(mesh
+            .path_with_filter([0.5, 0.5], [2.5, 1.5], &filter)
+            .is_none());
+        let after = super::snapshot();
+        assert!(after.failures > before.failures);
+    }
+}
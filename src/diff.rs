@@ -0,0 +1,107 @@
+use crate::{helpers::distance_between, Mesh, PolygonId};
+
+/// Structural differences between two meshes, as returned by [`Mesh::diff`].
+///
+/// Vertices and polygons are matched by their index. Indices of added elements are in the new
+/// mesh, all others are in the old mesh.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MeshDiff {
+    /// Vertices only in the new mesh.
+    pub added_vertices: Vec<usize>,
+    /// Vertices only in the old mesh.
+    pub removed_vertices: Vec<usize>,
+    /// Vertices in both meshes, but at different positions.
+    pub moved_vertices: Vec<usize>,
+    /// Polygons only in the new mesh.
+    pub added_polygons: Vec<usize>,
+    /// Polygons only in the old mesh.
+    pub removed_polygons: Vec<usize>,
+    /// Polygons in both meshes, with different vertices or with vertices that moved.
+    pub changed_polygons: Vec<usize>,
+    /// Polygons in both meshes, with different neighbours.
+    pub adjacency_changes: Vec<usize>,
+}
+
+impl MeshDiff {
+    /// `true` if the meshes have the same structure.
+    pub fn is_empty(&self) -> bool {
+        self == &MeshDiff::default()
+    }
+}
+
+impl Mesh {
+    /// Compare this mesh with a new version of it.
+    ///
+    /// Vertices that moved by at most `tolerance` are considered unchanged.
+    pub fn diff(&self, other: &Mesh, tolerance: f32) -> MeshDiff {
+        let mut diff = MeshDiff {
+            added_vertices: (self.vertices.len()..other.vertices.len()).collect(),
+            removed_vertices: (other.vertices.len()..self.vertices.len()).collect(),
+            added_polygons: (self.polygons.len()..other.polygons.len()).collect(),
+            removed_polygons: (other.polygons.len()..self.polygons.len()).collect(),
+            ..Default::default()
+        };
+        diff.moved_vertices = self
+            .vertices
+            .iter()
+            .zip(other.vertices.iter())
+            .enumerate()
+            .filter(|(_, (old, new))| distance_between(old.p(), new.p()) > tolerance)
+            .map(|(i, _)| i)
+            .collect();
+
+        for (i, (old, new)) in self.polygons.iter().zip(other.polygons.iter()).enumerate() {
+            if old.vertices != new.vertices
+                || old
+                    .vertices
+                    .iter()
+                    .any(|v| diff.moved_vertices.binary_search(v).is_ok())
+            {
+                diff.changed_polygons.push(i);
+            }
+            let id = PolygonId::new(i);
+            if self.neighbours_of(id) != other.neighbours_of(id) {
+                diff.adjacency_changes.push(i);
+            }
+        }
+        diff
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, tests::mesh_u_grid, MeshDiff};
+
+    #[test]
+    fn same_mesh() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.diff(&mesh_u_grid(), 0.0).is_empty());
+    }
+
+    #[test]
+    fn moved_vertex() {
+        let mesh = mesh_u_grid();
+        let mut moved = mesh_u_grid();
+        moved.vertices[11].x = 3.05;
+        assert!(mesh.diff(&moved, 0.1).is_empty());
+        assert_eq!(
+            mesh.diff(&moved, 0.01),
+            MeshDiff {
+                moved_vertices: vec![11],
+                changed_polygons: vec![4],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn filled_gap() {
+        let mesh = grid_mesh(3, 2, |x, y| y == 0 || x != 1);
+        let filled = grid_mesh(3, 2, |_, _| true);
+        let diff = mesh.diff(&filled, 0.0);
+        assert_eq!(diff.added_polygons, vec![5]);
+        assert_eq!(diff.changed_polygons, vec![4]);
+        assert_eq!(diff.adjacency_changes, vec![1, 2, 3, 4]);
+        assert!(diff.added_vertices.is_empty());
+    }
+}
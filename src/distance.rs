@@ -0,0 +1,198 @@
+use std::task::Poll;
+
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, PolygonId, SearchContext, SearchInstance, SearchNode, SearchOptions,
+};
+
+/// Geodesic distances from a source to every polygon of a mesh, as returned by
+/// [`Mesh::distance_field`].
+///
+/// Unlike a [`TargetField`](crate::TargetField), distances are exact: they are the length of the
+/// shortest path from the source to the closest point of each polygon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceField {
+    source: [f32; 2],
+    polygon: PolygonId,
+    distances: Vec<f32>,
+}
+
+impl Mesh {
+    /// Compute the [`DistanceField`] from `source`, or `None` if it's not in the mesh.
+    ///
+    /// It's a single search from the source with no target, going on until it went through all
+    /// the polygons that can be reached.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9);
+    /// let field = mesh.distance_field([4.0, 1.0]).unwrap();
+    ///
+    /// let behind_wall = mesh.locate_with_hint([7.0, 1.0], None).unwrap();
+    /// // the polygon is 2 away as the crow flies, but the path goes around the wall
+    /// assert!(field.distance(behind_wall).unwrap() > 9.0);
+    /// assert_eq!(field.distance(field.source_polygon()), Some(0.0));
+    /// ```
+    pub fn distance_field(&self, source: [f32; 2]) -> Option<DistanceField> {
+        let polygon = self.point_in_polygon(source)?;
+        let mut field = DistanceField {
+            source,
+            polygon,
+            distances: vec![f32::INFINITY; self.polygons.len()],
+        };
+        field.distances[polygon.index()] = 0.0;
+
+        self.search_everywhere(source, polygon, f32::INFINITY, |polygon, node| {
+            // the closest point of the polygon from the root is on the interval entering it
+            let closest = project_on_segment(node.r, node.i);
+            let distance = node.f + node.penalty + distance_between(node.r, closest) * node.weight;
+            let known = &mut field.distances[polygon.index()];
+            *known = known.min(distance);
+        });
+        Some(field)
+    }
+
+    /// Search from `source` with no target, until the whole part of the mesh that can be reached
+    /// in `max_len` has been searched. `on_node` is called with each node and the polygon it
+    /// enters.
+    pub(crate) fn search_everywhere(
+        &self,
+        source: [f32; 2],
+        polygon: PolygonId,
+        max_len: f32,
+        mut on_node: impl FnMut(PolygonId, &SearchNode),
+    ) {
+        #[cfg(feature = "counters")]
+        let _query = crate::counters::Query::start();
+        let Ok(mut search) = SearchInstance::start(
+            self,
+            &mut SearchContext::new(),
+            source,
+            source,
+            Some(polygon),
+            None,
+            SearchOptions {
+                // no target to head to: expand nodes in order of their distance
+                heuristic_weight: 0.0,
+                exhaustive: true,
+                max_len,
+                ..SearchOptions::default()
+            },
+        ) else {
+            return;
+        };
+        let result = search.expand(usize::MAX, |node| {
+            if let Some(polygon) = node.polygon_to {
+                on_node(polygon, node);
+            }
+        });
+        debug_assert_eq!(result, Poll::Ready(None));
+    }
+}
+
+impl DistanceField {
+    /// The source of this field.
+    pub fn source(&self) -> [f32; 2] {
+        self.source
+    }
+
+    /// The polygon containing the source.
+    pub fn source_polygon(&self) -> PolygonId {
+        self.polygon
+    }
+
+    /// Check if a polygon can be reached from the source.
+    pub fn is_reachable(&self, polygon: PolygonId) -> bool {
+        self.distances
+            .get(polygon.index())
+            .map(|d| d.is_finite())
+            .unwrap_or(false)
+    }
+
+    /// Length of the shortest path from the source to the closest point of a polygon, or `None`
+    /// if it can't be reached.
+    pub fn distance(&self, polygon: PolygonId) -> Option<f32> {
+        self.is_reachable(polygon)
+            .then(|| self.distances[polygon.index()])
+    }
+
+    /// Iterate over the polygons that can be reached from the source, with their distance.
+    pub fn reachable(&self) -> impl Iterator<Item = (PolygonId, f32)> + '_ {
+        self.distances
+            .iter()
+            .enumerate()
+            .filter(|(_, distance)| distance.is_finite())
+            .map(|(index, distance)| (PolygonId::new(index), *distance))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{grid_mesh, maze, test_meshes, test_points},
+        PolygonId,
+    };
+
+    #[test]
+    fn no_longer_than_paths() {
+        for mesh in test_meshes() {
+            let source = [1.5, 1.5];
+            let field = mesh.distance_field(source).unwrap();
+            assert_eq!(field.distance(field.source_polygon()), Some(0.0));
+            for point in test_points(60, [0.5, 0.5]) {
+                let Some(polygon) = mesh.locate_with_hint(point, None) else {
+                    continue;
+                };
+                match (mesh.path(source, point), field.distance(polygon)) {
+                    (Some(path), Some(distance)) => assert!(distance <= path.len + 1.0e-3),
+                    (None, None) => (),
+                    (path, distance) => panic!("{:?} / {:?}", path, distance),
+                }
+            }
+            // a vertex of a polygon is its closest point from a path going through it
+            for (polygon, distance) in field.reachable().step_by(4) {
+                let closest = mesh.polygons[polygon.index()]
+                    .vertices
+                    .iter()
+                    .filter_map(|vertex| mesh.path(source, mesh.vertices[*vertex].p()))
+                    .map(|path| path.len)
+                    .fold(f32::INFINITY, f32::min);
+                assert!(distance <= closest + 1.0e-3);
+            }
+        }
+        assert!(maze(2, 2, 0).distance_field([-1.0, 0.0]).is_none());
+        assert!(!maze(2, 2, 0)
+            .distance_field([1.5, 1.5])
+            .unwrap()
+            .is_reachable(PolygonId::new(1000)));
+    }
+
+    #[test]
+    fn behind_a_wall() {
+        let walled = grid_mesh(10, 3, |x, _| x != 5);
+        let field = walled.distance_field([1.5, 1.5]).unwrap();
+        let polygon = |point| walled.point_in_polygon(point).unwrap();
+        assert_eq!(field.distance(polygon([3.5, 1.5])), Some(1.5));
+        assert_eq!(
+            field.distance(polygon([4.5, 0.5])),
+            Some(2.5_f32.hypot(0.5))
+        );
+        assert_eq!(field.distance(polygon([6.5, 1.5])), None);
+        assert_eq!(field.reachable().count(), 15);
+
+        // from a corner of the mesh, or on the wall
+        let field = walled.distance_field([0.0, 0.0]).unwrap();
+        assert_eq!(field.distance(polygon([3.5, 0.5])), Some(3.0));
+        let field = walled.distance_field([6.0, 1.5]).unwrap();
+        assert!(field.is_reachable(polygon([9.5, 2.5])));
+        assert!(!field.is_reachable(polygon([4.5, 1.5])));
+
+        // a mesh of a single polygon
+        let single = grid_mesh(1, 1, |_, _| true);
+        let field = single.distance_field([0.5, 0.5]).unwrap();
+        assert_eq!(
+            field.reachable().collect::<Vec<_>>(),
+            vec![(PolygonId::new(0), 0.0)]
+        );
+    }
+}
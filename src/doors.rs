@@ -0,0 +1,337 @@
+//! Doors and one-way edges: shared edges that can be closed, or crossed in only one direction, at
+//! runtime without rebuilding the mesh.
+//!
+//! An edge that can't be crossed is treated like a boundary of the mesh by the search, and its
+//! vertices become corners so that paths can turn around them.
+
+use hashbrown::HashMap;
+
+use crate::{Aabb, EdgeId, Mesh};
+
+/// Order the vertices of an edge so that both directions give the same key.
+pub(crate) fn key(edge: EdgeId) -> EdgeId {
+    [edge[0].min(edge[1]), edge[0].max(edge[1])]
+}
+
+impl Mesh {
+    /// Open or close an edge shared by two polygons, for example a door.
+    ///
+    /// Paths don't cross closed edges. Returns `false` if the edge is not shared by two polygons,
+    /// in which case nothing changes. Edges are open when the mesh is built. They stay closed
+    /// through edits like [`Mesh::carve`], unless the edit removes them.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// // two rooms with a door between them
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .vertex([2.0, 0.0])
+    ///     .vertex([2.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .polygon([1, 4, 5, 2])
+    ///     .build()
+    ///     .unwrap();
+    /// let door = [1, 2];
+    /// assert!(mesh.set_edge_open(door, false));
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_none());
+    /// assert!(mesh.set_edge_open(door, true));
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    /// ```
+    pub fn set_edge_open(&mut self, edge: EdgeId, open: bool) -> bool {
+        if self.polygons_of_edge(edge).count() != 2 {
+            return false;
+        }
+        let changed = if open {
+            self.closed_edges.remove(&key(edge))
+        } else {
+            self.closed_edges.insert(key(edge))
+        };
+        if changed {
+            self.update_corners(edge);
+            self.update_islands();
+            self.notify_edge(edge);
+        }
+        true
+    }
+
+    /// Make an edge shared by two polygons crossable in only one direction, for example a
+    /// drop-down, or in both directions again.
+    ///
+    /// When `one_way` is `true`, the edge can only be crossed from the polygon that has it in this
+    /// vertex order, the one on its left, to the other one. Returns `false` if the edge is not
+    /// shared by two polygons, in which case nothing changes. Like closed edges, one-way edges
+    /// stay one-way through the edits that don't remove them.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .vertex([2.0, 0.0])
+    ///     .vertex([2.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .polygon([1, 4, 5, 2])
+    ///     .build()
+    ///     .unwrap();
+    /// // from the left square to the right one only
+    /// assert!(mesh.set_edge_one_way([1, 2], true));
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    /// assert!(mesh.path([1.5, 0.5], [0.5, 0.5]).is_none());
+    /// ```
+    pub fn set_edge_one_way(&mut self, edge: EdgeId, one_way: bool) -> bool {
+        if self.polygons_of_edge(edge).count() != 2 {
+            return false;
+        }
+        let reversed = [edge[1], edge[0]];
+        let changed = if one_way {
+            self.one_way_edges.remove(&reversed) | self.one_way_edges.insert(edge)
+        } else {
+            self.one_way_edges.remove(&edge) | self.one_way_edges.remove(&reversed)
+        };
+        if changed {
+            self.update_corners(edge);
+            self.notify_edge(edge);
+        }
+        true
+    }
+
+    /// Notify that the polygons on both sides of an edge changed.
+    pub(crate) fn notify_edge(&mut self, edge: EdgeId) {
+        let polygons = self.polygons_of_edge(edge).map(|p| p.index()).collect();
+        self.notify_polygons(polygons, Aabb::EMPTY, false);
+    }
+
+    /// Update the corner flag of the vertices of an edge.
+    fn update_corners(&mut self, edge: EdgeId) {
+        for vertex in edge {
+            self.update_corner(vertex);
+        }
+    }
+
+    /// Mark a vertex as a corner if it is on the boundary of the mesh, or if an edge that can't
+    /// always be crossed ends at it.
+    pub(crate) fn update_corner(&mut self, vertex: usize) {
+        let is_corner = self.vertices[vertex].polygons.contains(&None)
+            || self
+                .closed_edges
+                .iter()
+                .chain(&self.one_way_edges)
+                .any(|gated| gated.contains(&vertex));
+        self.vertices[vertex].is_corner = is_corner;
+    }
+
+    /// Check if an edge is closed with [`Mesh::set_edge_open`], in either direction.
+    pub fn is_edge_closed(&self, edge: EdgeId) -> bool {
+        !self.closed_edges.is_empty() && self.closed_edges.contains(&key(edge))
+    }
+
+    /// Iterate over the closed edges, with their smallest vertex first.
+    pub fn closed_edges(&self) -> impl Iterator<Item = EdgeId> + '_ {
+        self.closed_edges.iter().copied()
+    }
+
+    /// Check if an edge can only be crossed in one direction, either way.
+    pub fn is_edge_one_way(&self, edge: EdgeId) -> bool {
+        !self.one_way_edges.is_empty()
+            && (self.one_way_edges.contains(&edge)
+                || self.one_way_edges.contains(&[edge[1], edge[0]]))
+    }
+
+    /// Iterate over the one-way edges, in the direction they can be crossed.
+    pub fn one_way_edges(&self) -> impl Iterator<Item = EdgeId> + '_ {
+        self.one_way_edges.iter().copied()
+    }
+
+    /// Check if an edge can be crossed from the polygon that has it in this vertex order: it is
+    /// not closed, and not one-way in the other direction.
+    ///
+    /// This doesn't check that the edge is shared by two polygons.
+    pub fn can_cross(&self, edge: EdgeId) -> bool {
+        !self.is_edge_closed(edge)
+            && (self.one_way_edges.is_empty() || !self.one_way_edges.contains(&[edge[1], edge[0]]))
+    }
+
+    /// Check if an edge is closed or one-way.
+    pub(crate) fn is_edge_gated(&self, edge: EdgeId) -> bool {
+        self.is_edge_closed(edge) || self.is_edge_one_way(edge)
+    }
+
+    /// Close the edges, make them one-way and give them the secondary costs they had in `old`,
+    /// for the edges of `old` that are still shared by two polygons after the mesh was built
+    /// again from it. Vertices are matched by their position, in case they were renumbered.
+    pub(crate) fn keep_edges(&mut self, old: &Mesh) {
+        if old.closed_edges.is_empty()
+            && old.one_way_edges.is_empty()
+            && old.secondary_edge_costs.is_empty()
+        {
+            return;
+        }
+        let positions: HashMap<(u32, u32), usize> = self
+            .vertices
+            .iter()
+            .enumerate()
+            .map(|(index, vertex)| ((vertex.x.to_bits(), vertex.y.to_bits()), index))
+            .collect();
+        let vertex = |vertex: usize| {
+            let p = old.vertices[vertex].p();
+            match self.vertices.get(vertex) {
+                Some(kept) if kept.p() == p => Some(vertex),
+                _ => positions.get(&(p[0].to_bits(), p[1].to_bits())).copied(),
+            }
+        };
+        let edge = |edge: &EdgeId| {
+            let edge = [vertex(edge[0])?, vertex(edge[1])?];
+            (self.polygons_of_edge(edge).count() == 2).then_some(edge)
+        };
+        let closed: Vec<EdgeId> = old.closed_edges.iter().filter_map(edge).collect();
+        let one_way: Vec<EdgeId> = old.one_way_edges.iter().filter_map(edge).collect();
+        let costs: Vec<(EdgeId, f32)> = old
+            .secondary_edge_costs
+            .iter()
+            .filter_map(|(kept, cost)| Some((edge(kept)?, *cost)))
+            .collect();
+
+        self.closed_edges = closed.iter().copied().map(key).collect();
+        self.one_way_edges = one_way.iter().copied().collect();
+        self.secondary_edge_costs = costs
+            .into_iter()
+            .map(|(edge, cost)| (key(edge), cost))
+            .collect();
+        for edge in closed.into_iter().chain(one_way) {
+            self.update_corners(edge);
+        }
+        self.update_islands();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::grid_mesh;
+
+    #[test]
+    fn doors() {
+        // a wall in the middle column, with a door at its top
+        let mut mesh = grid_mesh(3, 3, |x, y| !(x == 1 && y < 2));
+        let direct = mesh.path([0.5, 0.5], [2.5, 0.5]).unwrap();
+        let door = [mesh.vertices.len(), 0];
+        assert!(!mesh.set_edge_open(door, false));
+        let wall = mesh.boundary_edges().next().unwrap();
+        assert!(!mesh.set_edge_open(wall, false));
+
+        // close the left side of the top middle cell
+        let left = mesh.point_in_polygon([0.5, 2.5]).unwrap();
+        let middle = mesh.point_in_polygon([1.5, 2.5]).unwrap();
+        let right = mesh.point_in_polygon([2.5, 2.5]).unwrap();
+        let door = mesh.edge_between(left, middle).unwrap();
+        assert!(mesh.set_edge_open([door[1], door[0]], false));
+        assert!(mesh.is_edge_closed(door));
+        assert_eq!(mesh.closed_edges().count(), 1);
+        assert!(mesh.path([0.5, 0.5], [2.5, 0.5]).is_none());
+        assert!(mesh.path([0.5, 0.5], [0.5, 2.5]).is_some());
+        assert!(!mesh.target_field([0.5, 0.5]).unwrap().is_reachable(right));
+
+        assert!(mesh.set_edge_open(door, true));
+        assert_eq!(mesh.path([0.5, 0.5], [2.5, 0.5]), Some(direct));
+        assert!(mesh
+            .vertices
+            .iter()
+            .all(|vertex| vertex.is_corner() == vertex.polygons().contains(&None)));
+    }
+
+    #[test]
+    fn one_way_edges() {
+        // drop-downs in the middle column, the only way between both sides
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let left = mesh.point_in_polygon([0.5, 0.5]).unwrap();
+        let middle = mesh.point_in_polygon([1.5, 0.5]).unwrap();
+        let right = mesh.point_in_polygon([2.5, 0.5]).unwrap();
+        let down = mesh.edge_between(left, middle).unwrap();
+        assert!(mesh.set_edge_one_way(down, true));
+        assert!(mesh.is_edge_one_way([down[1], down[0]]));
+        assert!(mesh.can_cross(down));
+        assert!(!mesh.can_cross([down[1], down[0]]));
+        assert!(mesh.path([0.5, 0.5], [2.5, 0.5]).is_some());
+        assert!(mesh.path([2.5, 0.5], [0.5, 0.5]).is_none());
+        assert!(mesh.path([1.5, 0.5], [0.5, 0.5]).is_none());
+        let field = mesh.target_field([2.5, 0.5]).unwrap();
+        assert!(field.is_reachable(left));
+        let field = mesh.target_field([0.5, 0.5]).unwrap();
+        assert!(!field.is_reachable(right));
+
+        // reversing it
+        assert!(mesh.set_edge_one_way([down[1], down[0]], true));
+        assert_eq!(mesh.one_way_edges().count(), 1);
+        assert!(mesh.path([0.5, 0.5], [2.5, 0.5]).is_none());
+        assert!(mesh.path([2.5, 0.5], [0.5, 0.5]).is_some());
+
+        assert!(mesh.set_edge_one_way(down, false));
+        assert!(!mesh.is_edge_one_way(down));
+        assert!(mesh.path([0.5, 0.5], [2.5, 0.5]).is_some());
+        assert!(mesh
+            .vertices
+            .iter()
+            .all(|vertex| vertex.is_corner() == vertex.polygons().contains(&None)));
+    }
+
+    #[test]
+    fn turn_around_doors() {
+        // the vertices of a door in the middle of the mesh become corners when it's closed
+        let mut mesh = grid_mesh(3, 3, |_, _| true);
+        let below = mesh.point_in_polygon([1.5, 1.5]).unwrap();
+        let above = mesh.point_in_polygon([1.5, 2.5]).unwrap();
+        let door = mesh.edge_between(below, above).unwrap();
+        assert!(mesh.set_edge_open(door, false));
+        let path = mesh.path([1.5, 1.5], [1.5, 2.5]).unwrap();
+        assert_eq!(path.path.len(), 2);
+        assert!(path.path[0] == [1.0, 2.0] || path.path[0] == [2.0, 2.0]);
+        assert!((path.len - 2.0_f32.sqrt()).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn kept_through_carving() {
+        let mut mesh = grid_mesh(6, 3, |_, _| true);
+        let edge = |mesh: &crate::Mesh, from: [f32; 2], to: [f32; 2]| {
+            let from = mesh.point_in_polygon(from).unwrap();
+            let to = mesh.point_in_polygon(to).unwrap();
+            mesh.edge_between(from, to).unwrap()
+        };
+        let door = edge(&mesh, [0.5, 0.5], [1.5, 0.5]);
+        let drop = edge(&mesh, [0.5, 1.5], [1.5, 1.5]);
+        let toll = edge(&mesh, [0.5, 2.5], [1.5, 2.5]);
+        let exposed = mesh.point_in_polygon([0.5, 2.5]).unwrap();
+        assert!(mesh.set_edge_open(door, false));
+        assert!(mesh.set_edge_one_way(drop, true));
+        assert!(mesh.set_edge_secondary_cost(toll, 2.0));
+        assert!(mesh.set_polygon_secondary_cost(exposed, 3.0));
+
+        // an obstacle away from the edges, and one removing the door
+        mesh.carve(&[[4.2, 0.2], [4.8, 0.2], [4.8, 2.8], [4.2, 2.8]])
+            .unwrap();
+        assert!(mesh.is_edge_closed(door));
+        assert!(mesh.can_cross(drop) && !mesh.can_cross([drop[1], drop[0]]));
+        assert_eq!(mesh.edge_secondary_cost(toll), 2.0);
+        let exposed = mesh.point_in_polygon([0.5, 2.5]).unwrap();
+        assert_eq!(mesh.polygons[exposed.index()].secondary_cost(), 3.0);
+        assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).unwrap().len > 1.0);
+        assert!(mesh
+            .vertices
+            .iter()
+            .filter(|vertex| vertex.is_corner())
+            .any(|vertex| vertex.polygons().iter().all(Option::is_some)));
+
+        mesh.carve(&[[0.8, 0.2], [1.2, 0.2], [1.2, 0.8], [0.8, 0.8]])
+            .unwrap();
+        assert_eq!(mesh.closed_edges().count(), 0);
+        assert_eq!(mesh.one_way_edges().count(), 1);
+
+        // merging polygons doesn't remove the edges either
+        mesh.merge_convex();
+        assert_eq!(mesh.one_way_edges().count(), 1);
+        assert_eq!(mesh.edge_secondary_cost(toll), 2.0);
+    }
+}
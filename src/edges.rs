@@ -0,0 +1,549 @@
+use hashbrown::HashSet;
+
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Aabb, Mesh, Polygon, PolygonId,
+};
+
+/// An edge of the mesh, identified by the indices of its two vertices.
+pub type EdgeId = [usize; 2];
+
+impl Polygon {
+    #[inline(always)]
+    fn has_edge(&self, edge: EdgeId) -> bool {
+        self.edges_index()
+            .iter()
+            .any(|e| *e == edge || *e == [edge[1], edge[0]])
+    }
+}
+
+impl Mesh {
+    /// Polygons that have this edge as one of their sides.
+    pub(crate) fn polygons_of_edge(&self, edge: EdgeId) -> impl Iterator<Item = PolygonId> + '_ {
+        let start = self.vertices.get(edge[0]);
+        let end = self.vertices.get(edge[1]);
+        start
+            .into_iter()
+            .flat_map(|start| start.polygons.iter().flatten())
+            .filter(move |p| {
+                end.map(|end| end.polygons.contains(&Some(**p)))
+                    .unwrap_or(false)
+                    && self.polygons[p.index()].has_edge(edge)
+            })
+            .copied()
+    }
+
+    /// Polygon on the other side of each edge of a polygon, `None` when there is none.
+    pub(crate) fn neighbours_of(&self, polygon: PolygonId) -> Vec<Option<PolygonId>> {
+        self.polygons[polygon.index()]
+            .edges_index()
+            .into_iter()
+            .map(|edge| self.polygons_of_edge(edge).find(|p| *p != polygon))
+            .collect()
+    }
+
+    /// Check if an edge has a polygon on only one of its sides, meaning it can't be crossed.
+    ///
+    /// Returns `false` if this is not an edge of the mesh.
+    pub fn is_boundary_edge(&self, edge: EdgeId) -> bool {
+        self.polygons_of_edge(edge).count() == 1
+    }
+
+    /// Iterate over the edges that can't be crossed, in the vertex order of the polygon they
+    /// belong to.
+    pub fn boundary_edges(&self) -> impl Iterator<Item = EdgeId> + '_ {
+        self.polygons
+            .iter()
+            .flat_map(|polygon| polygon.edges_index())
+            .filter(|edge| self.is_boundary_edge(*edge))
+    }
+
+    /// Iterate over the edges shared by two polygons, with the polygons on each side.
+    ///
+    /// Each edge is listed once, in the vertex order of the first polygon.
+    pub fn interior_edges(&self) -> impl Iterator<Item = (EdgeId, PolygonId, PolygonId)> + '_ {
+        self.iter_polygons().flat_map(move |(id, polygon)| {
+            polygon.edges_index().into_iter().filter_map(move |edge| {
+                self.polygons_of_edge(edge)
+                    .find(|other| *other != id)
+                    .filter(|other| *other > id)
+                    .map(|other| (edge, id, other))
+            })
+        })
+    }
+
+    /// Find the edge shared by two polygons, in the vertex order of `polygon_a`.
+    ///
+    /// Returns `None` if the polygons are not neighbours.
+    pub fn edge_between(&self, polygon_a: PolygonId, polygon_b: PolygonId) -> Option<EdgeId> {
+        if polygon_a == polygon_b {
+            return None;
+        }
+        self.polygons
+            .get(polygon_a.index())?
+            .edges_index()
+            .into_iter()
+            .find(|edge| self.polygons_of_edge(*edge).any(|p| p == polygon_b))
+    }
+
+    /// Find the boundary edge closest to a point.
+    ///
+    /// Returns the edge, the point on it closest to `point`, and the distance between them, or
+    /// `None` if the mesh has no boundary edge.
+    pub fn nearest_boundary(&self, point: [f32; 2]) -> Option<(EdgeId, [f32; 2], f32)> {
+        let mut nearest: Option<(EdgeId, [f32; 2], f32)> = None;
+        let mut seen = HashSet::new();
+        for (ring_distance, polygons) in self.polygon_grid.rings(point) {
+            // the polygons of this ring and the next ones are further than the best found so far
+            if nearest.is_some_and(|n| n.2 < ring_distance) {
+                break;
+            }
+            for i in polygons {
+                if !seen.insert(i)
+                    || nearest.is_some_and(|n| n.2 < self.polygon_aabbs[i].distance_to(point))
+                {
+                    continue;
+                }
+                for edge in self.polygons[i].edges_index() {
+                    let segment = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                    let projected = project_on_segment(point, segment);
+                    let distance = distance_between(point, projected);
+                    if nearest.map(|n| distance < n.2).unwrap_or(true)
+                        && self.is_boundary_edge(edge)
+                    {
+                        nearest = Some((edge, projected, distance));
+                    }
+                }
+            }
+        }
+        nearest
+    }
+
+    /// Distance from a point to the closest edge that can't be crossed, or `None` if the mesh
+    /// has no boundary edge.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| (x, y) != (5, 5));
+    /// // away from the walls, to spawn agents
+    /// assert_eq!(mesh.distance_to_boundary([2.0, 5.5]), Some(2.0));
+    /// assert_eq!(mesh.distance_to_boundary([4.0, 5.5]), Some(1.0));
+    /// ```
+    pub fn distance_to_boundary(&self, point: [f32; 2]) -> Option<f32> {
+        self.nearest_boundary(point)
+            .map(|(_, _, distance)| distance)
+    }
+
+    /// Point of the edges that can't be crossed closest to a point, like the wall to take cover
+    /// behind, or `None` if the mesh has no boundary edge.
+    pub fn closest_boundary_point(&self, point: [f32; 2]) -> Option<[f32; 2]> {
+        self.nearest_boundary(point).map(|(_, closest, _)| closest)
+    }
+
+    /// Check if the segment from `from` to `to` stays on the mesh, never crossing a boundary edge.
+    ///
+    /// Touching a boundary, for example going through a corner, is allowed.
+    pub(crate) fn segment_on_mesh(&self, from: [f32; 2], to: [f32; 2]) -> bool {
+        if !self.point_in_mesh(from) || !self.point_in_mesh(to) {
+            return false;
+        }
+        let segment_aabb = Aabb::from_points([from, to]);
+        let direction = [to[0] - from[0], to[1] - from[1]];
+        let length2 = direction[0] * direction[0] + direction[1] * direction[1];
+        if length2 == 0.0 {
+            return true;
+        }
+
+        // parameters along the segment where it touches a boundary edge
+        let mut touching = vec![0.0, 1.0];
+        for i in self.polygon_grid.candidates_in(segment_aabb) {
+            if !self.polygon_aabbs[i].intersects(&segment_aabb) {
+                continue;
+            }
+            for edge in self.polygons[i].edges_index() {
+                let start = self.vertices[edge[0]].p();
+                let end = self.vertices[edge[1]].p();
+                if !Aabb::from_points([start, end]).intersects(&segment_aabb)
+                    || !(self.is_boundary_edge(edge) || self.is_edge_gated(edge))
+                {
+                    continue;
+                }
+                let edge_direction = [end[0] - start[0], end[1] - start[1]];
+                let denom = direction[0] * edge_direction[1] - direction[1] * edge_direction[0];
+                let offset = [start[0] - from[0], start[1] - from[1]];
+                if denom.abs() < 1.0e-6 {
+                    // parallel edge, it may overlap the segment
+                    for p in [start, end] {
+                        let t = ((p[0] - from[0]) * direction[0] + (p[1] - from[1]) * direction[1])
+                            / length2;
+                        if (0.0..=1.0).contains(&t) {
+                            touching.push(t);
+                        }
+                    }
+                } else {
+                    let t = (offset[0] * edge_direction[1] - offset[1] * edge_direction[0]) / denom;
+                    let u = (offset[0] * direction[1] - offset[1] * direction[0]) / denom;
+                    if (0.0..=1.0).contains(&t) && (-1.0e-5..=1.0 + 1.0e-5).contains(&u) {
+                        touching.push(t);
+                    }
+                }
+            }
+        }
+        touching.sort_unstable_by(|a, b| a.total_cmp(b));
+        touching.windows(2).all(|w| {
+            if w[1] - w[0] < 1.0e-6 {
+                return true;
+            }
+            let t = (w[0] + w[1]) / 2.0;
+            let middle = [from[0] + t * direction[0], from[1] + t * direction[1]];
+            self.point_in_polygon_unit(middle).is_some()
+        })
+    }
+
+    /// Check if the whole segment from `from` to `to` is on the mesh, walking through the
+    /// polygons along it.
+    ///
+    /// It's cheaper than a raycast when only the answer matters, like when checking if a path
+    /// can take a shortcut. Going through a corner between two polygons is allowed, but not
+    /// crossing a closed edge, or a one-way edge the wrong way.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // two rooms separated by a wall with a door
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 5);
+    /// assert!(mesh.is_segment_on_mesh([1.0, 1.0], [1.0, 9.0]));
+    /// assert!(!mesh.is_segment_on_mesh([1.0, 1.0], [9.0, 1.0]));
+    /// assert_eq!(mesh.first_exit([1.0, 1.0], [9.0, 1.0]), Some([5.0, 1.0]));
+    /// ```
+    pub fn is_segment_on_mesh(&self, from: [f32; 2], to: [f32; 2]) -> bool {
+        self.first_exit(from, to).is_none()
+    }
+
+    /// Find the first point where the segment from `from` to `to` leaves the mesh, or `None` if
+    /// it stays on it, see [`Mesh::is_segment_on_mesh`].
+    ///
+    /// Returns `from` if it's not in the mesh.
+    pub fn first_exit(&self, from: [f32; 2], to: [f32; 2]) -> Option<[f32; 2]> {
+        self.walk_segment(from, to, |_| ())
+    }
+
+    /// Polygons the segment from `from` to `to` goes through, in order, or `None` if it leaves
+    /// the mesh. Consecutive polygons can share only a vertex, where the segment goes through it.
+    pub(crate) fn polygons_along(&self, from: [f32; 2], to: [f32; 2]) -> Option<Vec<PolygonId>> {
+        let mut polygons = vec![];
+        match self.walk_segment(from, to, |polygon| polygons.push(polygon)) {
+            Some(_) => None,
+            None => Some(polygons),
+        }
+    }
+
+    /// Walk through the polygons along the segment from `from` to `to`, calling `on_polygon` with
+    /// each of them, and return where it leaves the mesh like [`Mesh::first_exit`].
+    fn walk_segment(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        mut on_polygon: impl FnMut(PolygonId),
+    ) -> Option<[f32; 2]> {
+        let Some(mut polygon) = self.point_in_polygon_unit(from) else {
+            return Some(from);
+        };
+        on_polygon(polygon);
+        let direction = [to[0] - from[0], to[1] - from[1]];
+        let length = direction[0].hypot(direction[1]);
+        let at = |t: f32| [from[0] + direction[0] * t, from[1] + direction[1] * t];
+        // a step along the segment, to find the polygon after an edge
+        let step = 1.0e-3 / length;
+        let mut t = 0.0;
+        // each polygon is entered at most once
+        for _ in 0..=self.polygons.len() {
+            let (exit, edge) = self.leaving(polygon, from, direction);
+            if exit >= 1.0 {
+                return None;
+            }
+            let ahead = at(exit.max(t) + step);
+            let through_edge = (self.can_cross(edge))
+                .then(|| self.polygons_of_edge(edge).find(|other| *other != polygon))
+                .flatten()
+                .filter(|other| self.is_ahead_in(*other, ahead));
+            // going through a vertex instead of the middle of the edge, which can't cross a
+            // closed edge
+            let through_vertex = || {
+                let exit = at(exit.max(t));
+                edge.iter()
+                    .map(|vertex| &self.vertices[*vertex])
+                    .filter(|vertex| distance_between(vertex.p(), exit) < 1.0e-3)
+                    .flat_map(|vertex| vertex.polygons.iter().flatten())
+                    .find(|other| **other != polygon && self.is_ahead_in(**other, ahead))
+                    .copied()
+            };
+            match through_edge.or_else(through_vertex) {
+                Some(next) => {
+                    polygon = next;
+                    on_polygon(polygon);
+                    t = exit.max(t);
+                }
+                None => return Some(at(exit.max(t))),
+            }
+        }
+        Some(at(t))
+    }
+
+    /// Where a segment starting at `from` going along `direction` leaves a polygon, as a fraction
+    /// of the direction, with the edge it leaves through.
+    fn leaving(&self, polygon: PolygonId, from: [f32; 2], direction: [f32; 2]) -> (f32, EdgeId) {
+        let mut leaving = (f32::INFINITY, [0, 0]);
+        for (edge, outside) in self.outside_of_edges(polygon) {
+            let start = outside(from);
+            let along = outside([from[0] + direction[0], from[1] + direction[1]]) - start;
+            // going towards the outside of this edge
+            if along > 0.0 {
+                let t = -start / along;
+                if t < leaving.0 {
+                    leaving = (t, edge);
+                }
+            }
+        }
+        leaving
+    }
+
+    /// Check if a point just after an edge is in a polygon. Unlike `polygon_contains`, this
+    /// doesn't treat points close to the line of an edge as outside.
+    fn is_ahead_in(&self, polygon: PolygonId, point: [f32; 2]) -> bool {
+        self.outside_of_edges(polygon)
+            .all(|(_, outside)| outside(point) <= 1.0e-6)
+    }
+
+    /// For each edge of a polygon, a function giving how far a point is on the outer side of the
+    /// line of that edge, scaled by its length. Works whatever the winding of the polygon.
+    fn outside_of_edges(
+        &self,
+        polygon: PolygonId,
+    ) -> impl Iterator<Item = (EdgeId, impl Fn([f32; 2]) -> f32)> + '_ {
+        let polygon = &self.polygons[polygon.index()];
+        let n = polygon.vertices.len() as f32;
+        let inside = polygon
+            .vertices
+            .iter()
+            .map(|vertex| self.vertices[*vertex].p())
+            .fold([0.0, 0.0], |sum, p| [sum[0] + p[0] / n, sum[1] + p[1] / n]);
+        polygon.edges_index().into_iter().map(move |edge| {
+            let [a, b] = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+            let cross =
+                move |p: [f32; 2]| (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0]);
+            let side = -cross(inside).signum();
+            (edge, move |p: [f32; 2]| cross(p) * side)
+        })
+    }
+
+    /// Check if a point is within `tolerance` of an edge that can't be crossed.
+    pub fn is_point_on_boundary(&self, point: [f32; 2], tolerance: f32) -> bool {
+        let around = Aabb::from_points([point]).inflate(tolerance);
+        self.polygon_grid
+            .candidates_in(around)
+            .into_iter()
+            .any(|i| {
+                if !self.polygon_aabbs[i].inflate(tolerance).contains(point) {
+                    return false;
+                }
+                self.polygons[i].edges_index().into_iter().any(|edge| {
+                    let segment = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                    distance_between(point, project_on_segment(point, segment)) <= tolerance
+                        && self.is_boundary_edge(edge)
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::{distance_between, project_on_segment},
+        maps::{grid_mesh, random_obstacles, test_meshes, test_points},
+        tests::mesh_u_grid,
+        PolygonId,
+    };
+
+    #[test]
+    fn boundary_edges() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.is_boundary_edge([0, 1]));
+        assert!(!mesh.is_boundary_edge([5, 4]));
+        assert!(!mesh.is_boundary_edge([4, 5]));
+        assert!(mesh.is_boundary_edge([5, 6]));
+        // not an edge of the mesh
+        assert!(!mesh.is_boundary_edge([0, 5]));
+    }
+
+    #[test]
+    fn iter_edges() {
+        let mesh = mesh_u_grid();
+        let p = PolygonId::new;
+        assert_eq!(mesh.boundary_edges().count(), 12);
+        assert!(mesh
+            .boundary_edges()
+            .all(|edge| mesh.is_boundary_edge(edge)));
+        assert_eq!(
+            mesh.interior_edges().collect::<Vec<_>>(),
+            vec![
+                ([1, 5], p(0), p(1)),
+                ([5, 4], p(0), p(3)),
+                ([2, 6], p(1), p(2)),
+                ([7, 6], p(2), p(4)),
+            ]
+        );
+    }
+
+    #[test]
+    fn edge_between() {
+        let mesh = mesh_u_grid();
+        let p = PolygonId::new;
+        assert_eq!(mesh.edge_between(p(0), p(1)), Some([1, 5]));
+        assert_eq!(mesh.edge_between(p(1), p(0)), Some([5, 1]));
+        assert_eq!(mesh.edge_between(p(0), p(3)), Some([5, 4]));
+        assert_eq!(mesh.edge_between(p(0), p(2)), None);
+        assert_eq!(mesh.edge_between(p(0), p(0)), None);
+        assert_eq!(mesh.edge_between(p(10), p(0)), None);
+    }
+
+    #[test]
+    fn point_on_boundary() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.is_point_on_boundary([0.5, 0.0], 0.01));
+        assert!(mesh.is_point_on_boundary([1.5, 1.05], 0.1));
+        assert!(!mesh.is_point_on_boundary([1.5, 1.05], 0.01));
+        // edge between two polygons
+        assert!(!mesh.is_point_on_boundary([0.5, 1.0], 0.01));
+        assert!(!mesh.is_point_on_boundary([0.5, 0.5], 0.1));
+    }
+
+    #[test]
+    fn segment_on_mesh() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.segment_on_mesh([0.1, 0.1], [2.9, 0.9]));
+        assert!(!mesh.segment_on_mesh([0.5, 1.5], [2.5, 0.5]));
+        // through a corner
+        assert!(mesh.segment_on_mesh([0.5, 1.5], [1.5, 0.5]));
+        // along a boundary
+        assert!(mesh.segment_on_mesh([0.0, 0.0], [3.0, 0.0]));
+        assert!(!mesh.segment_on_mesh([0.5, 1.5], [0.5, 3.0]));
+    }
+
+    #[test]
+    fn walk_segments() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.is_segment_on_mesh([0.1, 0.1], [2.9, 0.9]));
+        assert!(!mesh.is_segment_on_mesh([0.5, 1.5], [2.5, 0.5]));
+        assert_eq!(mesh.first_exit([0.5, 1.5], [2.5, 1.5]), Some([1.0, 1.5]));
+        // through a corner
+        assert!(mesh.is_segment_on_mesh([0.5, 1.5], [1.5, 0.5]));
+        assert!(!mesh.is_segment_on_mesh([0.5, 1.5], [0.5, 3.0]));
+        assert_eq!(mesh.first_exit([4.0, 0.5], [0.5, 0.5]), Some([4.0, 0.5]));
+
+        for mesh in test_meshes() {
+            for (i, from) in test_points(200, [0.3, 0.6]).enumerate() {
+                let to = [(i * 11 % 30) as f32 + 0.7, (i * 5 % 30) as f32 + 0.2];
+                if !mesh.point_in_mesh(from) {
+                    continue;
+                }
+                assert_eq!(
+                    mesh.is_segment_on_mesh(from, to),
+                    mesh.segment_on_mesh(from, to),
+                    "{:?} {:?}",
+                    from,
+                    to
+                );
+                if let Some(exit) = mesh.first_exit(from, to) {
+                    assert!(mesh.is_point_on_boundary(exit, 1.0e-3));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn walk_short_segments_and_doors() {
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        // segments of no length
+        assert!(mesh.is_segment_on_mesh([0.5, 0.5], [0.5, 0.5]));
+        assert!(mesh.is_segment_on_mesh([1.0, 0.0], [1.0, 0.0]));
+        assert_eq!(mesh.first_exit([4.0, 0.5], [4.0, 0.5]), Some([4.0, 0.5]));
+        // ending on the boundary, or leaving through it
+        assert!(mesh.is_segment_on_mesh([0.5, 0.5], [3.0, 1.0]));
+        assert_eq!(mesh.first_exit([2.5, 0.5], [2.5, 1.5]), Some([2.5, 1.0]));
+
+        // a closed door stops the segment, and so does a one-way door crossed the wrong way
+        let left = mesh.point_in_polygon([0.5, 0.5]).unwrap();
+        let middle = mesh.point_in_polygon([1.5, 0.5]).unwrap();
+        let door = mesh.edge_between(left, middle).unwrap();
+        mesh.set_edge_open(door, false);
+        assert_eq!(mesh.first_exit([0.5, 0.5], [2.5, 0.5]), Some([1.0, 0.5]));
+        assert_eq!(mesh.first_exit([2.5, 0.5], [0.5, 0.5]), Some([1.0, 0.5]));
+        mesh.set_edge_open(door, true);
+        mesh.set_edge_one_way(door, true);
+        assert_ne!(
+            mesh.is_segment_on_mesh([0.5, 0.5], [2.5, 0.5]),
+            mesh.is_segment_on_mesh([2.5, 0.5], [0.5, 0.5])
+        );
+    }
+    #[test]
+    fn nearest_boundary() {
+        let mesh = mesh_u_grid();
+        assert_eq!(
+            mesh.nearest_boundary([0.5, 0.3]),
+            Some(([0, 1], [0.5, 0.0], 0.3))
+        );
+        assert_eq!(
+            mesh.nearest_boundary([1.5, 0.8]),
+            Some(([6, 5], [1.5, 1.0], 0.19999999))
+        );
+        // from outside the mesh
+        assert_eq!(
+            mesh.nearest_boundary([4.0, 2.0]),
+            Some(([7, 11], [3.0, 2.0], 1.0))
+        );
+        assert_eq!(crate::Mesh::default().nearest_boundary([0.0, 0.0]), None);
+    }
+
+    #[test]
+    fn nearest_boundary_far_from_the_point() {
+        let mesh = random_obstacles(40, 30, 25, 4, 7);
+        let boundary: Vec<_> = mesh
+            .polygons
+            .iter()
+            .flat_map(|polygon| polygon.edges_index())
+            .filter(|edge| mesh.is_boundary_edge(*edge))
+            .collect();
+        for point in [
+            [0.5, 0.5],
+            [20.3, 14.8],
+            [39.9, 29.1],
+            [-10.0, 15.0],
+            [60.0, 45.0],
+        ] {
+            let distance = boundary
+                .iter()
+                .map(|edge| {
+                    let segment = [mesh.vertices[edge[0]].p(), mesh.vertices[edge[1]].p()];
+                    distance_between(point, project_on_segment(point, segment))
+                })
+                .fold(f32::INFINITY, f32::min);
+            assert_eq!(mesh.distance_to_boundary(point), Some(distance));
+        }
+    }
+
+    #[test]
+    fn distance_to_boundary() {
+        let mesh = mesh_u_grid();
+        assert_eq!(mesh.distance_to_boundary([0.5, 0.3]), Some(0.3));
+        assert_eq!(mesh.closest_boundary_point([0.5, 0.3]), Some([0.5, 0.0]));
+        assert_eq!(mesh.closest_boundary_point([4.0, 2.0]), Some([3.0, 2.0]));
+        assert_eq!(
+            crate::Mesh::default().distance_to_boundary([0.0, 0.0]),
+            None
+        );
+        assert_eq!(
+            crate::Mesh::default().closest_boundary_point([0.0, 0.0]),
+            None
+        );
+    }
+}
@@ -0,0 +1,393 @@
+//! Changes to a mesh at runtime that keep the polygons around each vertex, the corners, the dead
+//! ends and the acceleration structures up to date, without building the whole mesh again.
+
+use crate::{
+    builder::{check_polygon, polygons_around},
+    grid::PolygonGrid,
+    merge::convex_parts,
+    validate::fix_winding,
+    Aabb, Mesh, MeshBuildError, Polygon, PolygonId, Vertex,
+};
+
+impl Mesh {
+    /// Add a vertex not used by any polygon yet, and return its index.
+    pub fn add_vertex(&mut self, point: [f32; 2]) -> Result<usize, MeshBuildError> {
+        let vertex = self.vertices.len();
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return Err(MeshBuildError::NonFiniteVertex { vertex });
+        }
+        self.vertices.push(Vertex::new(point[0], point[1], vec![]));
+        Ok(vertex)
+    }
+
+    /// Add a polygon from the indices of its vertices, and return its identifier.
+    ///
+    /// Like with [`MeshBuilder`](crate::MeshBuilder), a polygon in clockwise order is reversed
+    /// and a concave polygon is split into convex parts, added one after the other starting with
+    /// the returned identifier. The polygon is linked to the polygons sharing its edges.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .build()
+    ///     .unwrap();
+    /// let a = mesh.add_vertex([2.0, 0.0]).unwrap();
+    /// let b = mesh.add_vertex([2.0, 1.0]).unwrap();
+    /// mesh.add_polygon([1, a, b, 2]).unwrap();
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    /// ```
+    pub fn add_polygon(
+        &mut self,
+        vertices: impl IntoIterator<Item = usize>,
+    ) -> Result<PolygonId, MeshBuildError> {
+        let first = self.polygons.len();
+        let vertices: Vec<usize> = vertices.into_iter().collect();
+        check_polygon(first, &vertices, self.vertices.len())?;
+
+        // work on the points of the polygon only
+        let points: Vec<[f32; 2]> = vertices.iter().map(|v| self.vertices[*v].p()).collect();
+        let mut local: Vec<usize> = (0..vertices.len()).collect();
+        fix_winding(&points, std::slice::from_mut(&mut local));
+        for part in convex_parts(&points, &local) {
+            let part: Vec<usize> = part.into_iter().map(|i| vertices[i]).collect();
+            let aabb = Aabb::from_points(part.iter().map(|v| self.vertices[*v].p()));
+            let index = self.polygons.len();
+            self.polygons.push(Polygon {
+                vertices: part,
+                is_one_way: true,
+                flags: 0,
+                secondary_cost: 0.0,
+            });
+            self.polygon_aabbs.push(aabb);
+            self.aabb = self.aabb.union(&aabb);
+            if !self.polygon_grid.insert(index, aabb) {
+                self.polygon_grid = PolygonGrid::new(&self.polygon_aabbs, self.aabb);
+            }
+        }
+
+        let added = first..self.polygons.len();
+        for vertex in vertices.iter().copied() {
+            let around = self
+                .around(vertex)
+                .chain(
+                    added
+                        .clone()
+                        .filter(|p| self.polygons[*p].vertices.contains(&vertex)),
+                )
+                .collect();
+            self.relink(vertex, around);
+        }
+        self.update_dead_ends(&vertices);
+        self.update_islands();
+        self.notify_polygons(added.collect(), Aabb::EMPTY, false);
+        Ok(PolygonId::new(first))
+    }
+
+    /// Remove a polygon, and return it. The last polygon takes its identifier.
+    ///
+    /// Its vertices are kept, even when no other polygon uses them. Edges of the polygon that
+    /// were closed or one-way are reset.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .vertex([2.0, 0.0])
+    ///     .vertex([2.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .polygon([1, 4, 5, 2])
+    ///     .build()
+    ///     .unwrap();
+    /// let right = mesh.locate_with_hint([1.5, 0.5], None).unwrap();
+    /// mesh.remove_polygon(right).unwrap();
+    /// assert!(!mesh.point_in_mesh([1.5, 0.5]));
+    /// assert!(mesh.vertices()[4].polygons().is_empty());
+    /// ```
+    pub fn remove_polygon(&mut self, polygon: PolygonId) -> Option<Polygon> {
+        let index = polygon.index();
+        if index >= self.polygons.len() {
+            return None;
+        }
+        let last = self.polygons.len() - 1;
+        let vertices = self.polygons[index].vertices.clone();
+        for edge in self.polygons[index].edges_index() {
+            self.set_edge_open(edge, true);
+            self.set_edge_one_way(edge, false);
+        }
+        for vertex in vertices.iter().copied() {
+            let around = self.around(vertex).filter(|p| *p != index).collect();
+            self.relink(vertex, around);
+        }
+
+        let removed = self.polygons.swap_remove(index);
+        let aabb = self.polygon_aabbs.swap_remove(index);
+        self.polygon_grid.remove(index, aabb);
+        if index != last {
+            let moved = self.polygon_aabbs[index];
+            self.polygon_grid.remove(last, moved);
+            self.polygon_grid.insert(index, moved);
+            for vertex in self.polygons[index].vertices.clone() {
+                for around in &mut self.vertices[vertex].polygons {
+                    if *around == Some(PolygonId::new(last)) {
+                        *around = Some(PolygonId::new(index));
+                    }
+                }
+            }
+        }
+        // the bounds only shrink if the polygon was on them
+        if aabb.min[0] <= self.aabb.min[0]
+            || aabb.min[1] <= self.aabb.min[1]
+            || aabb.max[0] >= self.aabb.max[0]
+            || aabb.max[1] >= self.aabb.max[1]
+        {
+            self.aabb = self
+                .polygon_aabbs
+                .iter()
+                .fold(Aabb::EMPTY, |aabb, polygon| aabb.union(polygon));
+        }
+        self.update_dead_ends(&vertices);
+        self.update_islands();
+        self.notify_polygons(vec![], aabb, index != last);
+        Some(removed)
+    }
+
+    /// Split a polygon in two along the diagonal between two of its vertices, that must not be
+    /// next to each other, and return the identifier of the new polygon.
+    ///
+    /// The polygon keeps the part going counterclockwise from `from` to `to`, and the new polygon,
+    /// added after the last one, gets the other part. Returns `None` if the diagonal is not
+    /// valid.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .polygon([0, 1, 2, 3])
+    ///     .build()
+    ///     .unwrap();
+    /// let new = mesh.split_polygon(0.into(), 0, 2).unwrap();
+    /// assert_eq!(mesh.polygons().len(), 2);
+    /// assert_eq!(mesh.edge_between(0.into(), new), Some([2, 0]));
+    /// ```
+    pub fn split_polygon(
+        &mut self,
+        polygon: PolygonId,
+        from: usize,
+        to: usize,
+    ) -> Option<PolygonId> {
+        let index = polygon.index();
+        let vertices = self.polygons.get(index)?.vertices.clone();
+        let n = vertices.len();
+        let start = vertices.iter().position(|v| *v == from)?;
+        let end = vertices.iter().position(|v| *v == to)?;
+        if start == end || (start + 1) % n == end || (end + 1) % n == start {
+            return None;
+        }
+        let kept: Vec<usize> = (0..n)
+            .map(|k| vertices[(start + k) % n])
+            .take((end + n - start) % n + 1)
+            .collect();
+        let other: Vec<usize> = (0..n)
+            .map(|k| vertices[(end + k) % n])
+            .take((start + n - end) % n + 1)
+            .collect();
+
+        let new = self.polygons.len();
+        let old_aabb = self.polygon_aabbs[index];
+        let kept_aabb = Aabb::from_points(kept.iter().map(|v| self.vertices[*v].p()));
+        let other_aabb = Aabb::from_points(other.iter().map(|v| self.vertices[*v].p()));
+        self.polygon_grid.remove(index, old_aabb);
+        self.polygon_grid.insert(index, kept_aabb);
+        self.polygon_grid.insert(new, other_aabb);
+        self.polygon_aabbs[index] = kept_aabb;
+        self.polygon_aabbs.push(other_aabb);
+        self.polygons[index].vertices = kept;
+        self.polygons.push(Polygon {
+            vertices: other.clone(),
+            is_one_way: true,
+            flags: self.polygons[index].flags,
+            secondary_cost: self.polygons[index].secondary_cost,
+        });
+
+        // vertices only in the new part keep their order around them
+        for vertex in &other[1..other.len() - 1] {
+            for around in &mut self.vertices[*vertex].polygons {
+                if *around == Some(polygon) {
+                    *around = Some(PolygonId::new(new));
+                }
+            }
+        }
+        for vertex in [from, to] {
+            let around = self.around(vertex).chain([new]).collect();
+            self.relink(vertex, around);
+        }
+        self.update_dead_ends(&vertices);
+        self.update_islands();
+        self.notify_polygons(vec![index, new], Aabb::EMPTY, false);
+        Some(PolygonId::new(new))
+    }
+
+    /// Polygons around a vertex.
+    fn around(&self, vertex: usize) -> impl Iterator<Item = usize> + '_ {
+        self.vertices[vertex]
+            .polygons
+            .iter()
+            .flatten()
+            .map(|polygon| polygon.index())
+    }
+
+    /// Set the polygons around a vertex, in counterclockwise order with the gaps between them.
+    fn relink(&mut self, vertex: usize, around: Vec<usize>) {
+        let polygons = polygons_around(
+            vertex,
+            around,
+            |polygon| &self.polygons[polygon].vertices,
+            |v| self.vertices[v].p(),
+        );
+        self.vertices[vertex].polygons = polygons.into_iter().map(PolygonId::from_raw).collect();
+        self.update_corner(vertex);
+    }
+
+    /// Update the dead end flag of the polygons around some vertices.
+    fn update_dead_ends(&mut self, vertices: &[usize]) {
+        let mut polygons: Vec<usize> = vertices.iter().flat_map(|v| self.around(*v)).collect();
+        polygons.sort_unstable();
+        polygons.dedup();
+        for polygon in polygons {
+            let id = PolygonId::new(polygon);
+            let neighbours = self.polygons[polygon]
+                .edges_index()
+                .into_iter()
+                .filter(|edge| self.polygons_of_edge(*edge).any(|other| other != id))
+                .count();
+            self.polygons[polygon].is_one_way = neighbours <= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, MeshBuilder, PolygonId};
+
+    /// Check that the mesh is the same as if it was built again from its vertices and polygons.
+    fn assert_as_built(mesh: &crate::Mesh) {
+        assert!(mesh.validate().is_empty());
+        let rebuilt = MeshBuilder::from_parts(
+            mesh.vertices.iter().map(|v| v.p()).collect(),
+            mesh.polygons.iter().map(|p| p.vertices.clone()).collect(),
+        )
+        .build()
+        .unwrap();
+        for (vertex, other) in mesh.vertices.iter().zip(&rebuilt.vertices) {
+            // the list is circular, so it may start anywhere
+            let n = vertex.polygons.len();
+            assert_eq!(n, other.polygons.len());
+            assert!((0..n.max(1))
+                .any(|k| (0..n).all(|i| vertex.polygons[(i + k) % n] == other.polygons[i])));
+            assert_eq!(vertex.is_corner, other.is_corner);
+        }
+        for (polygon, other) in mesh.polygons.iter().zip(&rebuilt.polygons) {
+            assert_eq!(polygon.is_one_way, other.is_one_way);
+        }
+        assert_eq!(mesh.bounding_box(), rebuilt.bounding_box());
+        for (index, aabb) in rebuilt.polygon_aabbs.iter().enumerate() {
+            assert_eq!(mesh.polygon_aabbs[index], *aabb);
+            let center = mesh.polygons[index]
+                .vertices
+                .iter()
+                .fold([0.0, 0.0], |c, v| {
+                    let p = mesh.vertices[*v].p();
+                    [c[0] + p[0], c[1] + p[1]]
+                });
+            let n = mesh.polygons[index].vertices.len() as f32;
+            let center = [center[0] / n, center[1] / n];
+            assert_eq!(mesh.point_in_polygon(center), Some(PolygonId::new(index)));
+        }
+    }
+
+    #[test]
+    fn add_polygons() {
+        let mut mesh = grid_mesh(2, 1, |_, _| true);
+        // outside of the bounds of the mesh, and concave
+        let points = [
+            [2.0, 0.0],
+            [4.0, 0.0],
+            [4.0, 2.0],
+            [3.0, 2.0],
+            [3.0, 1.0],
+            [2.0, 1.0],
+        ];
+        let ids: Vec<usize> = points
+            .iter()
+            .map(|p| mesh.add_vertex(*p).unwrap())
+            .collect();
+        let right = mesh
+            .vertices
+            .iter()
+            .position(|v| v.p() == [2.0, 1.0])
+            .unwrap();
+        let bottom = mesh
+            .vertices
+            .iter()
+            .position(|v| v.p() == [2.0, 0.0])
+            .unwrap();
+        let mut polygon = ids.clone();
+        polygon[0] = bottom;
+        polygon[5] = right;
+        // clockwise
+        polygon.reverse();
+        let first = mesh.add_polygon(polygon).unwrap();
+        assert_eq!(first, PolygonId::new(2));
+        assert!(mesh.polygons.len() > 3);
+        assert_as_built(&mesh);
+        assert!(mesh.path([0.5, 0.5], [3.5, 1.5]).is_some());
+
+        assert_eq!(
+            mesh.add_polygon([0, 1]),
+            Err(crate::MeshBuildError::TooFewVertices { polygon: 4 })
+        );
+        assert!(mesh.add_vertex([f32::NAN, 0.0]).is_err());
+    }
+
+    #[test]
+    fn remove_and_split() {
+        let mut mesh = grid_mesh(3, 3, |_, _| true);
+        let path = mesh.path([0.5, 0.5], [2.5, 2.5]).unwrap();
+        let center = mesh.point_in_polygon([1.5, 1.5]).unwrap();
+        let removed = mesh.remove_polygon(center).unwrap();
+        assert_eq!(removed.vertices.len(), 4);
+        assert!(mesh.remove_polygon(PolygonId::new(8)).is_none());
+        assert_as_built(&mesh);
+        assert!(mesh.path([0.5, 0.5], [2.5, 2.5]).unwrap().len > path.len);
+        assert!(!mesh.point_in_mesh([1.5, 1.5]));
+
+        // put it back in two triangles
+        let id = mesh.add_polygon(removed.vertices.clone()).unwrap();
+        let new = mesh
+            .split_polygon(id, removed.vertices[0], removed.vertices[2])
+            .unwrap();
+        assert!(mesh
+            .split_polygon(new, removed.vertices[0], removed.vertices[2])
+            .is_none());
+        assert_as_built(&mesh);
+        assert_eq!(mesh.path([0.5, 0.5], [2.5, 2.5]), Some(path));
+
+        // remove everything
+        while !mesh.polygons.is_empty() {
+            mesh.remove_polygon(PolygonId::new(0)).unwrap();
+            assert_as_built(&mesh);
+        }
+        assert!(!mesh.point_in_mesh([0.5, 0.5]));
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::mpsc::{channel, Receiver};
+
+use crate::{Aabb, Mesh, Path, PolygonId};
+
+/// Notification of an edit of a mesh, sent to the receivers returned by [`Mesh::subscribe`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeshChange {
+    /// Polygons changed or added by the edit, by their identifier after it.
+    pub polygons: Vec<PolygonId>,
+    /// Area covering everything that changed, before and after the edit.
+    pub area: Aabb,
+    /// `true` if polygons got other identifiers, so that identifiers from before the edit can't be
+    /// used anymore.
+    pub renumbered: bool,
+}
+
+impl MeshChange {
+    /// Check if a path from `from` may go through the area that changed, in which case it may be
+    /// blocked, or there may be a shorter one.
+    ///
+    /// This is only a check of the bounding boxes of the segments of the path, so it can report
+    /// paths that are still the shortest.
+    pub fn affects_path(&self, from: [f32; 2], path: &Path) -> bool {
+        let mut last = from;
+        path.path.iter().any(|point| {
+            let segment = Aabb::from_points([last, *point]);
+            last = *point;
+            segment.intersects(&self.area)
+        })
+    }
+}
+
+impl Mesh {
+    /// Receive a [`MeshChange`] after each edit of the mesh, for example to invalidate the paths
+    /// going through the area that changed.
+    ///
+    /// Edits are the changes made with the methods of the mesh, like [`Mesh::carve`],
+    /// [`Mesh::set_edge_open`] or [`Mesh::add_polygon`]. Clones of the mesh start without
+    /// subscribers, so their edits are not received. Dropping a receiver ends its subscription.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mut mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// let path = mesh.path([1.0, 1.0], [9.0, 9.0]).unwrap();
+    /// let changes = mesh.subscribe();
+    ///
+    /// mesh.carve(&[[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]])
+    ///     .unwrap();
+    /// let change = changes.try_recv().unwrap();
+    /// assert!(change.affects_path([1.0, 1.0], &path));
+    /// ```
+    pub fn subscribe(&mut self) -> Receiver<MeshChange> {
+        let (sender, receiver) = channel();
+        self.subscribers.push(sender);
+        receiver
+    }
+
+    /// Send a change to the subscribers, forgetting the ones that dropped their receiver.
+    pub(crate) fn notify(&mut self, change: MeshChange) {
+        self.subscribers
+            .retain(|subscriber| subscriber.send(change.clone()).is_ok());
+    }
+
+    /// Notify that some polygons changed, covering them in the area with `area`.
+    pub(crate) fn notify_polygons(&mut self, polygons: Vec<usize>, area: Aabb, renumbered: bool) {
+        if self.subscribers.is_empty() {
+            return;
+        }
+        let area = polygons.iter().fold(area, |area, polygon| {
+            area.union(&self.polygon_aabbs[*polygon])
+        });
+        self.notify(MeshChange {
+            polygons: polygons.into_iter().map(PolygonId::new).collect(),
+            area,
+            renumbered,
+        });
+    }
+
+    /// Replace the mesh with a mesh built again from it, keeping its settings, its subscribers,
+    /// the state of the edges that are still in it and the secondary costs of its polygons.
+    pub(crate) fn replace(&mut self, mesh: Mesh) {
+        let mut old = std::mem::replace(self, mesh);
+        self.output_quantization = old.output_quantization;
+        self.off_mesh_clamp = old.off_mesh_clamp;
+        self.point_snap = old.point_snap;
+        self.subscribers = std::mem::take(&mut old.subscribers);
+        self.keep_edges(&old);
+        self.keep_secondary_costs(&old);
+    }
+
+    /// Replace the mesh with a mesh built again from it, and notify that all polygons changed.
+    pub(crate) fn rebuild(&mut self, mesh: Mesh) {
+        let area = self.aabb;
+        self.replace(mesh);
+        self.notify_polygons((0..self.polygons.len()).collect(), area, true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, PolygonId};
+
+    #[test]
+    fn changes() {
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let path = mesh.path([0.5, 0.5], [2.5, 0.5]).unwrap();
+        let changes = mesh.subscribe();
+        let dropped = mesh.subscribe();
+        drop(dropped);
+
+        let left = mesh.point_in_polygon([0.5, 0.5]).unwrap();
+        let middle = mesh.point_in_polygon([1.5, 0.5]).unwrap();
+        let door = mesh.edge_between(left, middle).unwrap();
+        mesh.set_edge_open(door, false);
+        let change = changes.try_recv().unwrap();
+        assert_eq!(mesh.subscribers.len(), 1);
+        assert!(change.polygons.contains(&left) && change.polygons.contains(&middle));
+        assert!(!change.renumbered);
+        assert!(change.affects_path([0.5, 0.5], &path));
+        // nothing changes
+        mesh.set_edge_open(door, false);
+        assert!(changes.try_recv().is_err());
+
+        // the clone doesn't notify
+        let mut clone = mesh.clone();
+        assert!(clone.subscribers.is_empty());
+        clone.remove_polygon(PolygonId::new(0)).unwrap();
+        assert!(changes.try_recv().is_err());
+        let area = mesh.polygon_bounding_box(PolygonId::new(0)).unwrap();
+        mesh.remove_polygon(PolygonId::new(0)).unwrap();
+        // the door is opened first
+        let change = changes.try_iter().last().unwrap();
+        assert!(change.renumbered);
+        assert_eq!(change.area, area);
+
+        mesh.merge_convex();
+        let change = changes.try_recv().unwrap();
+        assert_eq!(change.polygons.len(), mesh.polygons.len());
+        assert!(changes.try_recv().is_err());
+
+        // a change away from the path
+        let mut mesh = grid_mesh(3, 3, |_, _| true);
+        let changes = mesh.subscribe();
+        mesh.carve(&[[0.2, 2.2], [0.8, 2.2], [0.8, 2.8]]).unwrap();
+        let change = changes.try_recv().unwrap();
+        assert!(!change.affects_path([0.5, 0.5], &path));
+    }
+}
@@ -0,0 +1,156 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use crate::{helpers::distance_between, EdgeId, Mesh, PolygonId};
+
+/// Distances from every polygon of a mesh to a target, computed once and shared by all the
+/// queries toward this target.
+///
+/// It's computed with a Dijkstra search from the target on the graph of polygons, going through
+/// the middle of the edges between them. Distances are an upper bound of the shortest path length,
+/// but they are cheap to compute for the whole mesh and give a route toward the target from any
+/// polygon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TargetField {
+    target: [f32; 2],
+    polygon: PolygonId,
+    distances: Vec<f32>,
+    anchors: Vec<[f32; 2]>,
+    next: Vec<Option<(PolygonId, EdgeId)>>,
+}
+
+#[derive(PartialEq)]
+struct FieldNode {
+    distance: f32,
+    polygon: PolygonId,
+}
+
+impl Eq for FieldNode {}
+
+impl PartialOrd for FieldNode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FieldNode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+impl Mesh {
+    /// Compute the [`TargetField`] toward `target`, or `None` if it's not in the mesh.
+    pub fn target_field(&self, target: [f32; 2]) -> Option<TargetField> {
+        let polygon = self.point_in_polygon(target)?;
+        let mut field = TargetField {
+            target,
+            polygon,
+            distances: vec![f32::INFINITY; self.polygons.len()],
+            anchors: vec![target; self.polygons.len()],
+            next: vec![None; self.polygons.len()],
+        };
+        field.distances[polygon.index()] = 0.0;
+
+        let mut queue = BinaryHeap::new();
+        queue.push(FieldNode {
+            distance: 0.0,
+            polygon,
+        });
+        while let Some(FieldNode { distance, polygon }) = queue.pop() {
+            if distance > field.distances[polygon.index()] {
+                continue;
+            }
+            for edge in self.polygons[polygon.index()].edges_index() {
+                // the route goes from the neighbour to this polygon
+                if !self.can_cross([edge[1], edge[0]]) {
+                    continue;
+                }
+                let start = self.vertices[edge[0]].p();
+                let end = self.vertices[edge[1]].p();
+                let middle = [(start[0] + end[0]) / 2.0, (start[1] + end[1]) / 2.0];
+                let through = distance + distance_between(field.anchors[polygon.index()], middle);
+                for neighbour in self.polygons_of_edge(edge) {
+                    if neighbour == polygon || through >= field.distances[neighbour.index()] {
+                        continue;
+                    }
+                    field.distances[neighbour.index()] = through;
+                    field.anchors[neighbour.index()] = middle;
+                    field.next[neighbour.index()] = Some((polygon, [edge[1], edge[0]]));
+                    queue.push(FieldNode {
+                        distance: through,
+                        polygon: neighbour,
+                    });
+                }
+            }
+        }
+        Some(field)
+    }
+}
+
+impl TargetField {
+    /// The target of this field.
+    pub fn target(&self) -> [f32; 2] {
+        self.target
+    }
+
+    /// The polygon containing the target.
+    pub fn target_polygon(&self) -> PolygonId {
+        self.polygon
+    }
+
+    /// Check if the target can be reached from a polygon.
+    pub fn is_reachable(&self, polygon: PolygonId) -> bool {
+        self.distances
+            .get(polygon.index())
+            .map(|d| d.is_finite())
+            .unwrap_or(false)
+    }
+
+    /// Distance from the point where the route enters a polygon to the target, or `None` if the
+    /// target can't be reached from it.
+    pub fn distance(&self, polygon: PolygonId) -> Option<f32> {
+        self.is_reachable(polygon)
+            .then(|| self.distances[polygon.index()])
+    }
+
+    /// Next polygon toward the target from a polygon, and the edge to cross to reach it.
+    ///
+    /// Returns `None` for the target polygon and for polygons that can't reach the target.
+    pub fn next_portal(&self, polygon: PolygonId) -> Option<(PolygonId, EdgeId)> {
+        self.next.get(polygon.index()).copied().flatten()
+    }
+
+    /// Estimate of the distance from a point to the target, or `None` if the point is not in the
+    /// mesh or can't reach the target.
+    pub fn estimate(&self, mesh: &Mesh, point: [f32; 2]) -> Option<f32> {
+        let polygon = mesh.point_in_polygon(point)?;
+        if polygon == self.polygon {
+            return Some(distance_between(point, self.target));
+        }
+        self.distance(polygon)
+            .map(|distance| distance + distance_between(point, self.anchors[polygon.index()]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::mesh_u_grid, PolygonId};
+
+    #[test]
+    fn field_on_u() {
+        let mesh = mesh_u_grid();
+        let p = PolygonId::new;
+        let field = mesh.target_field([2.5, 1.5]).unwrap();
+        assert_eq!(field.target_polygon(), p(4));
+        assert_eq!(field.distance(p(4)), Some(0.0));
+        assert_eq!(field.distance(p(2)), Some(0.5));
+        assert_eq!(field.next_portal(p(4)), None);
+        assert_eq!(field.next_portal(p(2)), Some((p(4), [7, 6])));
+        assert_eq!(field.next_portal(p(0)), Some((p(1), [1, 5])));
+        assert_eq!(field.next_portal(p(3)), Some((p(0), [4, 5])));
+        assert!((field.distance(p(3)).unwrap() - (1.5 + 2.0 * 0.5_f32.sqrt())).abs() < 1.0e-5);
+        assert!(field.estimate(&mesh, [0.5, 1.5]).unwrap() >= 2.0_f32.sqrt() + 1.0);
+        assert_eq!(field.estimate(&mesh, [1.5, 1.5]), None);
+        assert!(mesh.target_field([1.5, 1.5]).is_none());
+    }
+}
@@ -0,0 +1,286 @@
+use hashbrown::HashSet;
+
+use crate::{Aabb, Mesh, Path, Polygon, PolygonId, SearchOptions};
+
+/// Which polygons paths can go through, and how much going through them costs, from their area
+/// flags, so that the same mesh can be used by different kinds of units.
+///
+/// Each of the 32 bits of the flags of a polygon, set with [`Mesh::set_polygon_flags`], is an area
+/// type, like water or road. Polygons with an excluded flag can't be used, and the cost of the
+/// others is the highest cost of their flags.
+///
+/// Costs apply to each segment of a path between two turns: its length is multiplied by the
+/// highest cost of the polygons it goes through. Paths can turn at any vertex between polygons
+/// of different costs, so they go around expensive areas when it's shorter.
+///
+/// ```
+/// # use polyanya::{MeshBuilder, PathFilter, PolygonId};
+/// const WATER: u32 = 1;
+///
+/// // three squares, with water in the middle one
+/// let mut mesh = MeshBuilder::new()
+///     .vertex([0.0, 0.0])
+///     .vertex([1.0, 0.0])
+///     .vertex([2.0, 0.0])
+///     .vertex([3.0, 0.0])
+///     .vertex([3.0, 1.0])
+///     .vertex([2.0, 1.0])
+///     .vertex([1.0, 1.0])
+///     .vertex([0.0, 1.0])
+///     .polygon([0, 1, 6, 7])
+///     .polygon([1, 2, 5, 6])
+///     .polygon([2, 3, 4, 5])
+///     .build()
+///     .unwrap();
+/// mesh.set_polygon_flags(PolygonId::new(1), WATER);
+///
+/// let boat = PathFilter::new();
+/// let walker = PathFilter::new().exclude(WATER);
+/// let swimmer = PathFilter::new().cost(WATER, 2.0);
+/// assert_eq!(mesh.path_with_filter([0.5, 0.5], [2.5, 0.5], &boat).unwrap().len, 2.0);
+/// assert!(mesh.path_with_filter([0.5, 0.5], [2.5, 0.5], &walker).is_none());
+/// assert_eq!(mesh.path_with_filter([0.5, 0.5], [2.5, 0.5], &swimmer).unwrap().len, 4.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathFilter {
+    exclude: u32,
+    costs: [f32; 32],
+    avoid: HashSet<PolygonId>,
+}
+
+impl Default for PathFilter {
+    fn default() -> Self {
+        PathFilter {
+            exclude: 0,
+            costs: [1.0; 32],
+            avoid: HashSet::new(),
+        }
+    }
+}
+
+impl PathFilter {
+    /// Create a filter accepting all polygons, with a cost of `1.0`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Exclude the polygons with any of these flags.
+    pub fn exclude(mut self, flags: u32) -> Self {
+        self.exclude |= flags;
+        self
+    }
+
+    /// Set the cost of each of these flags.
+    ///
+    /// Costs under `1.0` are raised to `1.0`, as the search needs paths to cost at least their
+    /// length. An infinite cost excludes the flags.
+    pub fn cost(mut self, flags: u32, cost: f32) -> Self {
+        if cost == f32::INFINITY {
+            return self.exclude(flags);
+        }
+        for (flag, current) in self.costs.iter_mut().enumerate() {
+            if flags & (1 << flag) != 0 {
+                *current = cost.max(1.0);
+            }
+        }
+        self
+    }
+
+    /// Exclude these polygons, whatever their flags.
+    ///
+    /// It's meant for areas to avoid for a while, like a building on fire, without changing the
+    /// mesh or the flags of its polygons.
+    ///
+    /// ```
+    /// # use polyanya::{Mesh, PathFilter};
+    /// // a pillar in the middle of a corridor
+    /// let mesh = Mesh::from_grid(10, 3, |x, y| x != 5 || y != 1);
+    /// let (from, to) = ([0.5, 2.5], [9.5, 2.5]);
+    /// let under_fire = mesh.locate_with_hint([5.5, 2.5], None).unwrap();
+    /// let careful = PathFilter::new().avoid([under_fire]);
+    /// // around the pillar from below
+    /// let path = mesh.path_with_filter(from, to, &careful).unwrap();
+    /// assert!(path.path.contains(&[5.0, 1.0]));
+    /// ```
+    pub fn avoid(mut self, polygons: impl IntoIterator<Item = PolygonId>) -> Self {
+        self.avoid.extend(polygons);
+        self
+    }
+
+    /// Cost of going through a polygon with these flags, or `None` if it's excluded.
+    pub fn flags_cost(&self, flags: u32) -> Option<f32> {
+        if flags & self.exclude != 0 {
+            return None;
+        }
+        let mut cost: f32 = 1.0;
+        let mut remaining = flags;
+        while remaining != 0 {
+            cost = cost.max(self.costs[remaining.trailing_zeros() as usize]);
+            remaining &= remaining - 1;
+        }
+        Some(cost)
+    }
+
+    #[inline(always)]
+    pub(crate) fn polygon_cost(&self, id: PolygonId, polygon: &Polygon) -> Option<f32> {
+        if !self.avoid.is_empty() && self.avoid.contains(&id) {
+            return None;
+        }
+        self.flags_cost(polygon.flags)
+    }
+}
+
+impl Mesh {
+    /// Set the area flags of a polygon, used by [`PathFilter`]s. Returns `false` if there is no
+    /// such polygon.
+    ///
+    /// Flags are kept when the mesh is edited: parts of a polygon keep its flags, and only
+    /// polygons with the same flags are merged.
+    pub fn set_polygon_flags(&mut self, polygon: PolygonId, flags: u32) -> bool {
+        let Some(current) = self.polygons.get_mut(polygon.index()) else {
+            return false;
+        };
+        if current.flags != flags {
+            current.flags = flags;
+            self.notify_polygons(vec![polygon.index()], Aabb::EMPTY, false);
+        }
+        true
+    }
+
+    /// Compute the cheapest path from `from` to `to` through the polygons accepted by a filter.
+    ///
+    /// The length of the path is its cost. Returns `None` if one of the points is not in the
+    /// mesh or is in an excluded polygon, or if `to` can't be reached from `from`.
+    pub fn path_with_filter(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        filter: &PathFilter,
+    ) -> Option<Path> {
+        self.path_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions {
+                filter: Some(filter),
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathFilter;
+    use crate::maps::grid_mesh;
+
+    #[test]
+    fn costs() {
+        let filter = PathFilter::new()
+            .cost(0b11, 3.0)
+            .cost(0b100, 0.5)
+            .cost(0b1000, f32::INFINITY);
+        assert_eq!(filter.flags_cost(0), Some(1.0));
+        assert_eq!(filter.flags_cost(0b110), Some(3.0));
+        assert_eq!(filter.flags_cost(0b100), Some(1.0));
+        assert_eq!(filter.flags_cost(0b1001), None);
+    }
+
+    #[test]
+    fn around_expensive_areas() {
+        // a lake in the middle of a field
+        let mut mesh = grid_mesh(5, 5, |_, _| true);
+        let lake = mesh.point_in_polygon([2.5, 2.5]).unwrap();
+        assert!(mesh.set_polygon_flags(lake, 1));
+        assert_eq!(mesh.polygons[lake.index()].flags(), 1);
+        let (from, to) = ([2.5, 0.5], [2.5, 4.5]);
+        let straight = mesh.path(from, to).unwrap();
+        assert_eq!(straight.len, 4.0);
+
+        // slightly more expensive: still straight
+        let path = mesh
+            .path_with_filter(from, to, &PathFilter::new().cost(1, 1.01))
+            .unwrap();
+        assert_eq!(path.path, straight.path);
+        assert!((path.len - 4.0 * 1.01).abs() < 1.0e-5);
+
+        // much more expensive: around the lake, turning at its corners
+        let around = 2.0 * (0.5_f32.hypot(1.5) + 0.5);
+        for filter in [
+            PathFilter::new().cost(1, 10.0),
+            PathFilter::new().exclude(1),
+        ] {
+            let path = mesh.path_with_filter(from, to, &filter).unwrap();
+            assert!((path.len - around).abs() < 1.0e-4, "{:?}", path);
+            assert!(path.path.contains(&[2.0, 2.0]) || path.path.contains(&[3.0, 2.0]));
+        }
+
+        // from inside the lake
+        assert!(mesh
+            .path_with_filter([2.5, 2.5], to, &PathFilter::new().exclude(1))
+            .is_none());
+        let path = mesh
+            .path_with_filter([2.5, 2.1], [2.5, 2.9], &PathFilter::new().cost(1, 2.0))
+            .unwrap();
+        assert!((path.len - 1.6).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn avoided_polygons() {
+        let mesh = grid_mesh(5, 5, |_, _| true);
+        let (from, to) = ([2.5, 0.5], [2.5, 4.5]);
+        let middle = mesh.point_in_polygon([2.5, 2.5]).unwrap();
+        let path = mesh
+            .path_with_filter(from, to, &PathFilter::new().avoid([middle]))
+            .unwrap();
+        let around = 2.0 * 0.5_f32.hypot(1.5) + 1.0;
+        assert!((path.len - around).abs() < 1.0e-5, "{:?}", path);
+
+        // from an avoided polygon, or all around the target
+        assert!(mesh
+            .path_with_filter([2.5, 2.5], to, &PathFilter::new().avoid([middle]))
+            .is_none());
+        let row = (0..5).map(|x| mesh.point_in_polygon([x as f32 + 0.5, 2.5]).unwrap());
+        assert!(mesh
+            .path_with_filter(from, to, &PathFilter::new().avoid(row))
+            .is_none());
+    }
+
+    #[test]
+    fn kept_by_edits() {
+        let mut mesh = grid_mesh(4, 1, |_, _| true);
+        let middle = mesh.point_in_polygon([1.5, 0.5]).unwrap();
+        mesh.set_polygon_flags(middle, 1);
+        let middle = mesh.point_in_polygon([2.5, 0.5]).unwrap();
+        mesh.set_polygon_flags(middle, 1);
+        let flags = |mesh: &crate::Mesh, x: f32| {
+            mesh.polygons[mesh.point_in_polygon([x, 0.5]).unwrap().index()].flags()
+        };
+
+        // areas are merged apart
+        mesh.merge_convex();
+        assert_eq!(mesh.polygons.len(), 3);
+        assert_eq!([0.5, 1.5, 2.5, 3.5].map(|x| flags(&mesh, x)), [0, 1, 1, 0]);
+
+        // carved parts keep the flags of their area
+        mesh.carve(&[[0.8, 0.4], [3.2, 0.4], [3.2, 0.6], [0.8, 0.6]])
+            .unwrap();
+        assert!(mesh.validate().is_empty());
+        for (x, expected) in [(0.5, 0), (0.9, 0), (1.5, 1), (2.9, 1), (3.1, 0)] {
+            for y in [0.2, 0.8] {
+                let polygon = mesh.point_in_polygon([x, y]).unwrap();
+                assert_eq!(mesh.polygons[polygon.index()].flags(), expected);
+            }
+        }
+        let mut bytes = vec![];
+        mesh.save_binary(&mut bytes).unwrap();
+        let loaded = crate::Mesh::load_binary(bytes.as_slice()).unwrap();
+        assert!(loaded
+            .polygons
+            .iter()
+            .zip(&mesh.polygons)
+            .all(|(a, b)| a.flags() == b.flags()));
+    }
+}
@@ -0,0 +1,139 @@
+use std::task::Poll;
+
+use crate::{counted, Mesh, Path, SearchContext, SearchInstance, SearchOptions};
+
+enum FinderState<'m> {
+    Searching(Box<SearchInstance<'m>>),
+    Done(Option<Path>),
+}
+
+/// A search for the shortest path from a point to another that can be spread over several
+/// frames, keeping its progress between them.
+///
+/// Each [`PathFinder::tick`] expands a limited number of nodes, so that a long query doesn't
+/// take more than its share of a frame. The path is the same as the one from [`Mesh::path`].
+///
+/// ```
+/// # use std::task::Poll;
+/// # use polyanya::{Mesh, PathFinder};
+/// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+/// let mut finder = PathFinder::new(&mesh, [1.0, 3.0], [4.0, 2.0]);
+///
+/// let path = loop {
+///     // each frame
+///     if let Poll::Ready(path) = finder.tick(2) {
+///         break path;
+///     }
+/// };
+/// assert_eq!(path, mesh.path([1.0, 3.0], [4.0, 2.0]));
+/// ```
+pub struct PathFinder<'m> {
+    state: FinderState<'m>,
+    expanded: usize,
+}
+
+impl<'m> PathFinder<'m> {
+    /// Start a search for the shortest path from `from` to `to`. Nothing is expanded until the
+    /// first [`PathFinder::tick`].
+    pub fn new(mesh: &'m Mesh, from: [f32; 2], to: [f32; 2]) -> Self {
+        let state = counted(
+            |state| matches!(state, FinderState::Done(None)),
+            || match SearchInstance::start(
+                mesh,
+                &mut SearchContext::new(),
+                from,
+                to,
+                mesh.point_in_polygon(from),
+                mesh.point_in_polygon(to),
+                SearchOptions::default(),
+            ) {
+                Ok(search) => FinderState::Searching(Box::new(search)),
+                Err(result) => FinderState::Done(result.map(|result| result.path)),
+            },
+        );
+        PathFinder { state, expanded: 0 }
+    }
+
+    /// Continue the search, expanding at most `budget` nodes.
+    ///
+    /// Returns `Poll::Pending` if the search needs more ticks, or its result once it's done:
+    /// the path, or `None` if one of the points is not in the mesh or if `to` can't be reached
+    /// from `from`. Ticking a search that's done returns its result again.
+    pub fn tick(&mut self, budget: usize) -> Poll<Option<Path>> {
+        match &mut self.state {
+            FinderState::Searching(search) => {
+                // counted when the search started
+                #[cfg(feature = "counters")]
+                let query = crate::counters::Query::resume();
+                let mut expanded = 0;
+                let result = search.expand(budget, |_| expanded += 1);
+                self.expanded += expanded;
+                let Poll::Ready(result) = result else {
+                    return Poll::Pending;
+                };
+                let path = result.map(|result| result.path);
+                #[cfg(feature = "counters")]
+                if path.is_none() {
+                    query.fail();
+                }
+                self.state = FinderState::Done(path.clone());
+                Poll::Ready(path)
+            }
+            FinderState::Done(path) => Poll::Ready(path.clone()),
+        }
+    }
+
+    /// Check if the search is done, in which case ticking it returns its result.
+    pub fn is_done(&self) -> bool {
+        matches!(self.state, FinderState::Done(_))
+    }
+
+    /// Number of nodes the search went through so far.
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    use super::PathFinder;
+    use crate::maps::maze;
+
+    #[test]
+    fn spread_over_ticks() {
+        let mesh = maze(10, 10, 3);
+        let (from, to) = ([1.5, 1.5], [19.5, 19.5]);
+        let expected = mesh.path(from, to);
+        assert!(expected.is_some());
+
+        for budget in [1, 4, usize::MAX] {
+            let mut finder = PathFinder::new(&mesh, from, to);
+            let mut ticks: usize = 0;
+            let path = loop {
+                ticks += 1;
+                assert!(finder.expanded() <= (ticks - 1).saturating_mul(budget));
+                match finder.tick(budget) {
+                    Poll::Ready(path) => break path,
+                    Poll::Pending => assert!(!finder.is_done()),
+                }
+            };
+            assert_eq!(path, expected);
+            assert!(finder.is_done());
+            assert_eq!(finder.tick(budget), Poll::Ready(expected.clone()));
+            if budget == 1 {
+                assert!(ticks > 1);
+            } else if budget == usize::MAX {
+                assert_eq!(ticks, 1);
+            }
+        }
+
+        // done without ticking
+        let mut finder = PathFinder::new(&mesh, from, [-1.0, 0.0]);
+        assert!(finder.is_done());
+        assert_eq!(finder.tick(0), Poll::Ready(None));
+        let mut finder = PathFinder::new(&mesh, from, [1.2, 1.8]);
+        assert!(matches!(finder.tick(0), Poll::Ready(Some(_))));
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, PolygonId,
+};
+
+/// Directions toward a goal for every polygon of a mesh, as returned by [`Mesh::flow_field`].
+///
+/// It's computed once with a search from the goal to the whole mesh, then units heading to the
+/// goal only need to follow the direction of the polygon they're in instead of each searching
+/// for its own path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowField {
+    goal: [f32; 2],
+    polygon: PolygonId,
+    distances: Vec<f32>,
+    directions: Vec<Option<[f32; 2]>>,
+    /// For each polygon, the corners in sight of its points that paths to the goal go through.
+    views: Vec<Vec<View>>,
+}
+
+/// A corner seeing part of a polygon through an interval, with the length of the path from it to
+/// the goal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct View {
+    root: [f32; 2],
+    interval: [[f32; 2]; 2],
+    cost: f32,
+    weight: f32,
+}
+
+impl View {
+    /// Check if the corner is in sight of a point of the polygon.
+    fn sees(&self, point: [f32; 2]) -> bool {
+        let [start, end] = self.interval;
+        if self.root == start || self.root == end {
+            // a corner of the polygon, in sight of all of it
+            return true;
+        }
+        let cross = |to: [f32; 2]| {
+            (to[0] - self.root[0]) * (point[1] - self.root[1])
+                - (to[1] - self.root[1]) * (point[0] - self.root[0])
+        };
+        cross(start) * cross(end) <= 0.0
+    }
+
+    fn distance(&self, point: [f32; 2]) -> f32 {
+        self.cost + distance_between(self.root, point) * self.weight
+    }
+}
+
+impl Mesh {
+    /// Compute the [`FlowField`] toward `goal`, or `None` if it's not in the mesh.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9);
+    /// let flow = mesh.flow_field([7.0, 1.0]).unwrap();
+    ///
+    /// // units on the other side of the wall go up to go around it
+    /// let direction = flow.direction_at(&mesh, [4.0, 1.0]).unwrap();
+    /// assert!(direction[1] > 0.9);
+    /// ```
+    pub fn flow_field(&self, goal: [f32; 2]) -> Option<FlowField> {
+        let polygon = self.point_in_polygon(goal)?;
+        let mut field = FlowField {
+            goal,
+            polygon,
+            distances: vec![f32::INFINITY; self.polygons.len()],
+            directions: vec![None; self.polygons.len()],
+            views: vec![vec![]; self.polygons.len()],
+        };
+        field.distances[polygon.index()] = 0.0;
+
+        // paths are the same both ways: search from the goal
+        self.search_everywhere(goal, polygon, f32::INFINITY, |to, node| {
+            // points of the polygon the node leaves that don't see the interval it came through
+            // see the corner it turns on
+            if let Some(from) = node.polygon_from.filter(|from| *from != polygon) {
+                let turns_in = self.polygons[from.index()]
+                    .vertices
+                    .iter()
+                    .any(|vertex| self.vertices[*vertex].p() == node.r);
+                if turns_in {
+                    field.views[from.index()].push(View {
+                        root: node.r,
+                        interval: [node.r, node.r],
+                        cost: node.f,
+                        weight: node.weight,
+                    });
+                }
+            }
+            if to == polygon {
+                return;
+            }
+            let view = View {
+                root: node.r,
+                interval: node.i,
+                cost: node.f + node.penalty,
+                weight: node.weight,
+            };
+            let distance = view.distance(project_on_segment(node.r, node.i));
+            let known = &mut field.distances[to.index()];
+            *known = known.min(distance);
+            field.views[to.index()].push(view);
+        });
+
+        for (index, polygon) in self.polygons.iter().enumerate() {
+            // polygons are convex: their center is inside
+            let count = polygon.vertices.len() as f32;
+            let center = polygon.vertices.iter().fold([0.0, 0.0], |center, vertex| {
+                let p = self.vertices[*vertex].p();
+                [center[0] + p[0] / count, center[1] + p[1] / count]
+            });
+            field.directions[index] = field
+                .next_corner(PolygonId::new(index), center)
+                .and_then(|corner| direction(center, corner));
+        }
+        Some(field)
+    }
+}
+
+/// Unit vector from `from` to `to`, or `None` if they are the same point.
+fn direction(from: [f32; 2], to: [f32; 2]) -> Option<[f32; 2]> {
+    let length = distance_between(from, to);
+    (length > 0.0).then(|| [(to[0] - from[0]) / length, (to[1] - from[1]) / length])
+}
+
+impl FlowField {
+    /// The goal of this field.
+    pub fn goal(&self) -> [f32; 2] {
+        self.goal
+    }
+
+    /// The polygon containing the goal.
+    pub fn goal_polygon(&self) -> PolygonId {
+        self.polygon
+    }
+
+    /// Check if the goal can be reached from a polygon.
+    pub fn is_reachable(&self, polygon: PolygonId) -> bool {
+        self.distances
+            .get(polygon.index())
+            .map(|d| d.is_finite())
+            .unwrap_or(false)
+    }
+
+    /// Length of the shortest path from the closest point of a polygon to the goal, or `None` if
+    /// the goal can't be reached from it.
+    pub fn distance(&self, polygon: PolygonId) -> Option<f32> {
+        self.is_reachable(polygon)
+            .then(|| self.distances[polygon.index()])
+    }
+
+    /// Direction to follow in a polygon toward the goal, as a unit vector, or `None` if the goal
+    /// can't be reached from it.
+    ///
+    /// It's the direction of the shortest path from the center of the polygon, a good enough
+    /// heading for any unit in the polygon.
+    pub fn direction(&self, polygon: PolygonId) -> Option<[f32; 2]> {
+        self.directions.get(polygon.index()).copied().flatten()
+    }
+
+    /// Direction to follow from a point toward the goal, as a unit vector, or `None` if the
+    /// point is not in the mesh, can't reach the goal, or is on it.
+    ///
+    /// It's the direction of the shortest path from the point, more precise than the direction
+    /// of its polygon.
+    pub fn direction_at(&self, mesh: &Mesh, point: [f32; 2]) -> Option<[f32; 2]> {
+        let polygon = mesh.point_in_polygon(point)?;
+        direction(point, self.next_corner(polygon, point)?)
+    }
+
+    /// First corner of the shortest path from a point of a polygon to the goal.
+    fn next_corner(&self, polygon: PolygonId, point: [f32; 2]) -> Option<[f32; 2]> {
+        if polygon == self.polygon {
+            return Some(self.goal);
+        }
+        let views = self.views.get(polygon.index())?;
+        let closest = |views: &mut dyn Iterator<Item = &View>| {
+            views
+                .min_by(|a, b| a.distance(point).total_cmp(&b.distance(point)))
+                .map(|view| view.root)
+        };
+        // rounding can leave points of the polygon out of sight of every view by a tiny margin
+        closest(&mut views.iter().filter(|view| view.sees(point)))
+            .or_else(|| closest(&mut views.iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{grid_mesh, maze, random_obstacles, test_points},
+        PolygonId,
+    };
+
+    #[test]
+    fn follows_paths() {
+        for (mesh, goal) in [
+            (maze(8, 8, 5), [15.5, 15.5]),
+            (random_obstacles(30, 30, 25, 5, 2), [0.5, 0.5]),
+        ] {
+            let flow = mesh.flow_field(goal).unwrap();
+            assert_eq!(flow.goal(), goal);
+            for point in test_points(60, [0.5, 0.5]) {
+                let Some(path) = mesh.path(point, goal).filter(|path| path.len > 0.0) else {
+                    assert!(flow.direction_at(&mesh, point).is_none());
+                    continue;
+                };
+                // the direction at a point is toward the first step of its path
+                let direction = flow.direction_at(&mesh, point).unwrap();
+                let step = path.path[0];
+                let length = ((step[0] - point[0]).powi(2) + (step[1] - point[1]).powi(2)).sqrt();
+                let expected = [(step[0] - point[0]) / length, (step[1] - point[1]) / length];
+                assert!((direction[0] - expected[0]).abs() < 1.0e-3);
+                assert!((direction[1] - expected[1]).abs() < 1.0e-3);
+
+                let polygon = mesh.locate_with_hint(point, None).unwrap();
+                let heading = flow.direction(polygon).unwrap();
+                assert!((heading[0].powi(2) + heading[1].powi(2) - 1.0).abs() < 1.0e-3);
+                assert!(flow.distance(polygon).unwrap() <= path.len + 1.0e-3);
+            }
+            assert!(flow.direction_at(&mesh, goal).is_none());
+        }
+        let mesh = maze(8, 8, 5);
+        let flow = mesh.flow_field([15.5, 15.5]).unwrap();
+        assert!(flow.direction(PolygonId::new(1000)).is_none());
+        assert!(mesh.flow_field([-1.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn behind_a_wall() {
+        let walled = grid_mesh(10, 3, |x, _| x != 5);
+        let flow = walled.flow_field([1.5, 1.2]).unwrap();
+        let polygon = |point| walled.point_in_polygon(point).unwrap();
+        let behind = polygon([7.5, 1.5]);
+        assert!(!flow.is_reachable(behind));
+        assert_eq!(flow.direction(behind), None);
+        assert_eq!(flow.distance(behind), None);
+        assert_eq!(flow.direction_at(&walled, [7.5, 1.5]), None);
+        assert_eq!(flow.direction_at(&walled, [-1.0, 1.5]), None);
+
+        // in the polygon of the goal, straight to it
+        assert_eq!(flow.goal_polygon(), polygon([1.5, 1.5]));
+        assert_eq!(flow.direction(flow.goal_polygon()), Some([0.0, -1.0]));
+        assert_eq!(flow.direction_at(&walled, [1.5, 0.5]), Some([0.0, 1.0]));
+        // from the wall and from a corner of the mesh
+        assert_eq!(flow.direction_at(&walled, [5.0, 1.2]), Some([-1.0, 0.0]));
+        let direction = flow.direction_at(&walled, [0.0, 3.0]).unwrap();
+        let length = 1.5_f32.hypot(1.8);
+        assert!((direction[0] - 1.5 / length).abs() < 1.0e-5);
+        assert!((direction[1] + 1.8 / length).abs() < 1.0e-5);
+    }
+}
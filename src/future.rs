@@ -0,0 +1,217 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(feature = "parallel")]
+use std::{
+    sync::{Arc, Mutex, PoisonError},
+    task::Waker,
+};
+
+use crate::{Mesh, Path, PathFinder};
+
+/// A search for the shortest path that runs as a future, as returned by [`Mesh::path_async`].
+///
+/// It expands a few nodes each time it's polled, and yields back to the executor between them,
+/// so that long queries don't block the other tasks. It doesn't depend on a runtime. The search
+/// still runs on the thread polling it: see `MeshSnapshot::spawn_path`, with the `parallel`
+/// feature, to run it on another thread.
+pub struct PathFuture<'m> {
+    finder: PathFinder<'m>,
+    yield_every: usize,
+}
+
+impl Future for PathFuture<'_> {
+    type Output = Option<Path>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Path>> {
+        let this = self.get_mut();
+        let result = this.finder.tick(this.yield_every);
+        if result.is_pending() {
+            // there is more work right away: ask to be polled again after the other tasks
+            cx.waker().wake_by_ref();
+        }
+        result
+    }
+}
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], as a future yielding to
+    /// the executor after expanding `yield_every` nodes.
+    ///
+    /// The search runs on the thread polling the future, in steps between which the other tasks
+    /// of the executor can run: it doesn't take the work off that thread. With the `parallel`
+    /// feature, `MeshSnapshot::spawn_path` runs the search on the rayon thread pool instead.
+    ///
+    /// To run it in a task that must be `'static`, move a [`MeshSnapshot`](crate::MeshSnapshot)
+    /// of a [`SharedMesh`](crate::SharedMesh) into it.
+    ///
+    /// ```
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// # use polyanya::{Mesh, SharedMesh};
+    /// let shared = SharedMesh::new(Mesh::from_file("meshes/arena.mesh").unwrap());
+    /// let snapshot = shared.snapshot();
+    /// let task = async move { snapshot.path_async([1.0, 3.0], [4.0, 2.0], 10).await };
+    ///
+    /// // usually done by the executor
+    /// let mut task = pin!(task);
+    /// let path = loop {
+    ///     if let Poll::Ready(path) = task.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+    ///         break path;
+    ///     }
+    /// };
+    /// assert!(path.is_some());
+    /// ```
+    pub fn path_async(&self, from: [f32; 2], to: [f32; 2], yield_every: usize) -> PathFuture<'_> {
+        PathFuture {
+            finder: PathFinder::new(self, from, to),
+            yield_every: yield_every.max(1),
+        }
+    }
+}
+
+/// A search for the shortest path running on the rayon thread pool, as returned by
+/// [`MeshSnapshot::spawn_path`](crate::MeshSnapshot::spawn_path).
+///
+/// It's ready when the search is done, and wakes its task then.
+#[cfg(feature = "parallel")]
+pub struct SpawnedPath {
+    shared: Arc<Mutex<Spawned>>,
+}
+
+/// State shared between a [`SpawnedPath`] and its search.
+#[cfg(feature = "parallel")]
+#[derive(Default)]
+struct Spawned {
+    /// The result of the search, once it's done.
+    path: Option<Option<Path>>,
+    /// The task waiting for the result.
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "parallel")]
+impl Future for SpawnedPath {
+    type Output = Option<Path>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Path>> {
+        let mut shared = self.shared.lock().unwrap_or_else(PoisonError::into_inner);
+        match shared.path.take() {
+            Some(path) => Poll::Ready(path),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl SpawnedPath {
+    /// Search for the shortest path from `from` to `to` on the rayon thread pool.
+    pub(crate) fn spawn(snapshot: crate::MeshSnapshot, from: [f32; 2], to: [f32; 2]) -> Self {
+        let shared = Arc::new(Mutex::new(Spawned::default()));
+        let search = shared.clone();
+        rayon::spawn(move || {
+            let path = snapshot.path(from, to);
+            let mut search = search.lock().unwrap_or_else(PoisonError::into_inner);
+            search.path = Some(path);
+            if let Some(waker) = search.waker.take() {
+                waker.wake();
+            }
+        });
+        SpawnedPath { shared }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    use crate::maps::maze;
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn yields_to_executor() {
+        let mesh = maze(10, 10, 3);
+        let (from, to) = ([1.5, 1.5], [19.5, 19.5]);
+        let wakes = Arc::new(CountWakes(AtomicUsize::new(0)));
+        let waker = Waker::from(wakes.clone());
+        let mut context = Context::from_waker(&waker);
+
+        let mut future = pin!(mesh.path_async(from, to, 2));
+        let mut polls = 0;
+        let path = loop {
+            polls += 1;
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(path) => break path,
+                // woken each time it's pending, to be polled again
+                Poll::Pending => assert_eq!(wakes.0.load(Ordering::SeqCst), polls),
+            }
+        };
+        assert!(polls > 1);
+        assert_eq!(path, mesh.path(from, to));
+    }
+
+    #[test]
+    fn can_be_spawned() {
+        fn assert_send<T: Send>(_: &T) {}
+        let mesh = maze(2, 2, 0);
+        assert_send(&mesh.path_async([0.5, 0.5], [1.5, 1.5], 10));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn spawned_on_the_pool() {
+        use std::thread::{self, Thread};
+
+        use crate::SharedMesh;
+
+        struct Unpark(Thread);
+
+        impl Wake for Unpark {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        let shared = SharedMesh::new(maze(10, 10, 3));
+        let snapshot = shared.snapshot();
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        for (from, to) in [
+            ([1.5, 1.5], [19.5, 19.5]),
+            ([1.5, 1.5], [1.5, 1.5]),
+            ([1.5, 1.5], [-1.0, 1.5]),
+        ] {
+            let mut future = pin!(snapshot.spawn_path(from, to));
+            let path = loop {
+                match future.as_mut().poll(&mut context) {
+                    Poll::Ready(path) => break path,
+                    // woken when the search is done
+                    Poll::Pending => thread::park(),
+                }
+            };
+            assert_eq!(path, snapshot.path(from, to));
+        }
+    }
+}
@@ -0,0 +1,321 @@
+//! Import of Godot `NavigationMesh` resources.
+//!
+//! A navigation mesh has a list of 3D vertices and a list of convex polygons given by the indices of
+//! their vertices. Vertices are projected on the ground plane, with Godot's `x` and `z` coordinates
+//! becoming the `x` and `y` coordinates of the mesh. Neighbours are computed from the edges shared
+//! by polygons.
+//!
+//! Resources can be read from the text format of `.tres` and `.tscn` files, or from JSON with the
+//! `godot` feature.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{Mesh, MeshBuildError, MeshBuilder};
+
+/// Error returned when a Godot navigation mesh can't be imported.
+#[derive(Debug)]
+pub enum GodotError {
+    /// The file has no `NavigationMesh` resource.
+    MissingResource,
+    /// A property of the resource is missing.
+    MissingProperty { property: &'static str },
+    /// A property of the resource has an unexpected value.
+    InvalidProperty { property: &'static str },
+    /// The file is not valid JSON.
+    #[cfg(feature = "godot")]
+    Json(serde_json::Error),
+    /// The resource doesn't describe a valid mesh.
+    InvalidMesh(MeshBuildError),
+}
+
+impl Display for GodotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GodotError::MissingResource => write!(f, "no NavigationMesh resource"),
+            GodotError::MissingProperty { property } => write!(f, "missing property {}", property),
+            GodotError::InvalidProperty { property } => write!(f, "invalid property {}", property),
+            #[cfg(feature = "godot")]
+            GodotError::Json(error) => write!(f, "invalid navigation mesh: {}", error),
+            GodotError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+impl Error for GodotError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "godot")]
+            GodotError::Json(error) => Some(error),
+            GodotError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "godot")]
+impl From<serde_json::Error> for GodotError {
+    fn from(error: serde_json::Error) -> Self {
+        GodotError::Json(error)
+    }
+}
+
+impl From<MeshBuildError> for GodotError {
+    fn from(error: MeshBuildError) -> Self {
+        GodotError::InvalidMesh(error)
+    }
+}
+
+/// Build a mesh from the `vertices` and `polygons` of a `NavigationMesh`.
+///
+/// Godot doesn't enforce a winding order, so polygons can be in either order.
+pub fn from_navigation_mesh(
+    vertices: &[[f32; 3]],
+    polygons: &[Vec<usize>],
+) -> Result<Mesh, MeshBuildError> {
+    let mut builder = MeshBuilder::new();
+    for vertex in vertices {
+        builder.add_vertex([vertex[0], vertex[2]])?;
+    }
+    for polygon in polygons {
+        builder.add_polygon_any_order(polygon.iter().copied())?;
+    }
+    builder.build()
+}
+
+/// Value of `property` in a section of a resource file, spanning several lines if its brackets are
+/// not closed on the first one.
+fn property<'a>(section: &'a str, property: &'static str) -> Result<&'a str, GodotError> {
+    let start = section
+        .lines()
+        .find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == property).then_some(value)
+        })
+        .ok_or(GodotError::MissingProperty { property })?;
+    // offset of the value in the section, to read past the end of its line
+    let offset = start.as_ptr() as usize - section.as_ptr() as usize;
+    let mut depth = 0;
+    for (index, character) in section[offset..].char_indices() {
+        match character {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '\n' if depth == 0 => return Ok(section[offset..offset + index].trim()),
+            _ => (),
+        }
+    }
+    Ok(section[offset..].trim())
+}
+
+/// Numbers of a packed array such as `PackedVector3Array(0, 1, 2)`.
+fn packed_array<T: std::str::FromStr>(
+    value: &str,
+    property: &'static str,
+) -> Result<Vec<T>, GodotError> {
+    let invalid = || GodotError::InvalidProperty { property };
+    let (_, content) = value.split_once('(').ok_or_else(invalid)?;
+    let content = content.trim_end().strip_suffix(')').ok_or_else(invalid)?;
+    content
+        .split(',')
+        .map(str::trim)
+        .filter(|number| !number.is_empty())
+        .map(|number| number.parse().map_err(|_| invalid()))
+        .collect()
+}
+
+/// Build a mesh from the first `NavigationMesh` resource of a `.tres` or `.tscn` file.
+///
+/// Both the packed arrays of Godot 4 (`PackedVector3Array`, `PackedInt32Array`) and the pool
+/// arrays of Godot 3 (`PoolVector3Array`, `PoolIntArray`) are supported.
+pub fn from_resource(content: &str) -> Result<Mesh, GodotError> {
+    let mut sections = content.split("\n[").skip(1);
+    let section = if content.starts_with("[gd_resource")
+        && content
+            .lines()
+            .next()
+            .is_some_and(|header| header.contains("type=\"NavigationMesh\""))
+    {
+        sections.find(|section| section.starts_with("resource]"))
+    } else {
+        sections.find(|section| {
+            section.starts_with("sub_resource") && section.contains("type=\"NavigationMesh\"")
+        })
+    }
+    .ok_or(GodotError::MissingResource)?;
+
+    let coordinates: Vec<f32> = packed_array(property(section, "vertices")?, "vertices")?;
+    if !coordinates.len().is_multiple_of(3) {
+        return Err(GodotError::InvalidProperty {
+            property: "vertices",
+        });
+    }
+    let vertices: Vec<[f32; 3]> = coordinates
+        .chunks_exact(3)
+        .map(|vertex| [vertex[0], vertex[1], vertex[2]])
+        .collect();
+
+    let polygons = property(section, "polygons")?;
+    let polygons = polygons
+        .strip_prefix('[')
+        .and_then(|polygons| polygons.strip_suffix(']'))
+        .ok_or(GodotError::InvalidProperty {
+            property: "polygons",
+        })?;
+    let polygons = polygons
+        .split_inclusive(')')
+        .map(|polygon| polygon.trim_start_matches([',', ' ', '\n', '\r', '\t']))
+        .filter(|polygon| !polygon.is_empty())
+        .map(|polygon| packed_array(polygon, "polygons"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(from_navigation_mesh(&vertices, &polygons)?)
+}
+
+/// Build a mesh from a navigation mesh saved as JSON, with the `godot` feature.
+///
+/// The JSON object has a `vertices` field, a list of `[x, y, z]` arrays or a flat list of
+/// coordinates like `NavigationMesh.vertices`, and a `polygons` field, a list of lists of indices.
+#[cfg(feature = "godot")]
+pub fn from_json(content: &str) -> Result<Mesh, GodotError> {
+    use serde_json::Value;
+
+    let navigation_mesh: Value = serde_json::from_str(content)?;
+    let invalid = |property| GodotError::InvalidProperty { property };
+    let array = |property| match navigation_mesh.get(property) {
+        Some(Value::Array(array)) => Ok(array),
+        Some(_) => Err(invalid(property)),
+        None => Err(GodotError::MissingProperty { property }),
+    };
+
+    let vertices = array("vertices")?;
+    let coordinates = if vertices.iter().all(Value::is_array) {
+        vertices
+            .iter()
+            .map(|vertex| match vertex.as_array() {
+                Some(coordinates) if coordinates.len() == 3 => Ok(coordinates.iter()),
+                _ => Err(invalid("vertices")),
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+    } else {
+        vertices.iter().collect()
+    };
+    let coordinates = coordinates
+        .into_iter()
+        .map(|coordinate| {
+            coordinate
+                .as_f64()
+                .map(|coordinate| coordinate as f32)
+                .ok_or(invalid("vertices"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if !coordinates.len().is_multiple_of(3) {
+        return Err(invalid("vertices"));
+    }
+    let vertices: Vec<[f32; 3]> = coordinates
+        .chunks_exact(3)
+        .map(|vertex| [vertex[0], vertex[1], vertex[2]])
+        .collect();
+
+    let polygons = array("polygons")?
+        .iter()
+        .map(|polygon| {
+            polygon
+                .as_array()
+                .ok_or(invalid("polygons"))?
+                .iter()
+                .map(|index| {
+                    index
+                        .as_u64()
+                        .map(|index| index as usize)
+                        .ok_or(invalid("polygons"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(from_navigation_mesh(&vertices, &polygons)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_resource, GodotError};
+    use crate::MeshBuildError;
+
+    const SCENE: &str = r#"[gd_scene load_steps=2 format=3 uid="uid://c8kqxvd2ptj1w"]
+
+[sub_resource type="BoxMesh" id="BoxMesh_1"]
+
+[sub_resource type="NavigationMesh" id="NavigationMesh_2kf6m"]
+vertices = PackedVector3Array(0, 0.5, 0, 2, 0.5, 0, 2, 0.5, 1, 0, 0.5, 1, 3, 0.5, 0, 3, 0.5, 1)
+polygons = [PackedInt32Array(3, 2, 1, 0), PackedInt32Array(1, 4, 5, 2)]
+agent_radius = 0.25
+
+[node name="Level" type="Node3D"]
+
+[node name="NavigationRegion3D" type="NavigationRegion3D" parent="."]
+navigation_mesh = SubResource("NavigationMesh_2kf6m")
+"#;
+
+    #[test]
+    fn scene() {
+        let mesh = from_resource(SCENE).unwrap();
+        assert_eq!(mesh.vertices.len(), 6);
+        assert_eq!(mesh.polygons.len(), 2);
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.interior_edges().count(), 1);
+        assert!((mesh.path([0.5, 0.5], [2.5, 0.5]).unwrap().len - 2.0).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn godot_3_resource() {
+        let resource = "[gd_resource type=\"NavigationMesh\" format=2]
+
+[resource]
+vertices = PoolVector3Array( 0, 0, 0, 1, 0, 0, 0, 0, 1 )
+polygons = [ PoolIntArray( 0, 1, 2 ) ]
+";
+        let mesh = from_resource(resource).unwrap();
+        assert_eq!(mesh.polygons.len(), 1);
+        assert!(mesh.point_in_mesh([0.2, 0.2]));
+    }
+
+    #[test]
+    fn invalid_resources() {
+        assert!(matches!(
+            from_resource("[gd_scene format=3]\n\n[node name=\"Level\" type=\"Node3D\"]\n"),
+            Err(GodotError::MissingResource)
+        ));
+        assert!(matches!(
+            from_resource(&SCENE.replace("polygons", "polys")),
+            Err(GodotError::MissingProperty {
+                property: "polygons"
+            })
+        ));
+        assert!(matches!(
+            from_resource(&SCENE.replace("1, 4, 5, 2", "1, 4, 9, 2")),
+            Err(GodotError::InvalidMesh(
+                MeshBuildError::VertexOutOfRange { .. }
+            ))
+        ));
+    }
+
+    #[cfg(feature = "godot")]
+    #[test]
+    fn json() {
+        let mesh = super::from_json(
+            r#"{"vertices": [[0, 0, 0], [1, 0, 0], [0, 0, 1]], "polygons": [[0, 1, 2]]}"#,
+        )
+        .unwrap();
+        assert_eq!(mesh.polygons.len(), 1);
+        let flat = super::from_json(
+            r#"{"vertices": [0, 0, 0, 1, 0, 0, 0, 0, 1], "polygons": [[0, 2, 1]]}"#,
+        )
+        .unwrap();
+        assert!(flat.point_in_mesh([0.2, 0.2]));
+    }
+}
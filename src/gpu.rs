@@ -0,0 +1,349 @@
+//! Point location on the GPU, with the `gpu` feature.
+//!
+//! The polygons of the mesh and the grid of their bounding boxes are uploaded once to a compute
+//! shader, which finds the polygon of each point of a batch. The shader only decides for the
+//! points clearly inside a polygon. It leaves the others to [`Mesh::locate_batch`] on the CPU:
+//! points near an edge or the border of a cell of the grid, points outside of the mesh, and all
+//! the points of a batch when the GPU fails. Results are always the ones of the CPU.
+//!
+//! There may be no GPU to run on, like on CI machines: [`GpuLocator::new`] then returns `None`,
+//! and the tests of this module return without checking anything.
+
+use std::sync::mpsc;
+
+use wgpu::util::DeviceExt;
+
+use crate::{Mesh, PolygonId};
+
+/// Result of the shader for the points it leaves to the CPU.
+const UNDECIDED: u32 = u32::MAX;
+
+/// Number of points located by each workgroup of the shader.
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Locate points in a mesh on the GPU, against the grid of its polygons.
+///
+/// The GPU only decides for points clearly inside a polygon. Points close to an edge or to the
+/// border of a cell of the grid, or outside of the mesh, are located on the CPU, so that the
+/// results are always the ones of [`Mesh::locate_batch`].
+///
+/// The mesh is uploaded once, and stays borrowed so that it can't change while it's on the GPU.
+///
+/// ```
+/// # use polyanya::*;
+/// let mesh = Mesh::new(
+///     vec![
+///         Vertex::new(0.0, 0.0, vec![0, -1]),
+///         Vertex::new(1.0, 0.0, vec![0, -1]),
+///         Vertex::new(1.0, 1.0, vec![0, -1]),
+///         Vertex::new(0.0, 1.0, vec![0, -1]),
+///     ],
+///     vec![Polygon::new(4, vec![0, 1, 2, 3, -1, -1, -1, -1])],
+/// );
+/// let points = [[0.5, 0.5], [2.0, 2.0]];
+/// if let Some(locator) = GpuLocator::new(&mesh) {
+///     assert_eq!(locator.locate_batch(&points), mesh.locate_batch(&points));
+/// }
+/// ```
+pub struct GpuLocator<'m> {
+    mesh: &'m Mesh,
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    grid: wgpu::Buffer,
+    polygons: wgpu::Buffer,
+    /// Number of points located by each dispatch, from the limits of the GPU.
+    max_points: usize,
+}
+
+impl<'m> GpuLocator<'m> {
+    /// Upload a mesh and its grid to the GPU.
+    ///
+    /// Returns `None` when there is no GPU with compute shaders, or when the mesh doesn't fit in
+    /// its buffers.
+    pub fn new(mesh: &'m Mesh) -> Option<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .ok()?;
+        if !adapter
+            .get_downlevel_capabilities()
+            .flags
+            .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+        {
+            return None;
+        }
+        let limits = adapter.limits();
+        let (device, queue) = pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor {
+            label: Some("polyanya"),
+            required_limits: limits.clone(),
+            ..Default::default()
+        }))
+        .ok()?;
+
+        let (grid, polygons) = mesh_words(mesh)?;
+        if polygons.len() as u64 * 4 > limits.max_storage_buffer_binding_size {
+            return None;
+        }
+        let max_points = (limits.max_compute_workgroups_per_dimension as u64
+            * WORKGROUP_SIZE as u64)
+            .min(limits.max_storage_buffer_binding_size / 8) as usize;
+
+        let shader = device.create_shader_module(wgpu::include_wgsl!("gpu.wgsl"));
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("polyanya locate"),
+            layout: None,
+            module: &shader,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        let grid = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("polyanya grid"),
+            contents: &bytes(grid),
+            usage: wgpu::BufferUsages::UNIFORM,
+        });
+        let polygons = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("polyanya polygons"),
+            contents: &bytes(polygons),
+            usage: wgpu::BufferUsages::STORAGE,
+        });
+        Some(GpuLocator {
+            mesh,
+            device,
+            queue,
+            pipeline,
+            grid,
+            polygons,
+            max_points: max_points.max(1),
+        })
+    }
+
+    /// Find the polygon containing each point, `None` for points outside of the mesh.
+    ///
+    /// Same results as [`Mesh::locate_batch`].
+    pub fn locate_batch(&self, points: &[[f32; 2]]) -> Vec<Option<PolygonId>> {
+        let mut located = Vec::with_capacity(points.len());
+        for chunk in points.chunks(self.max_points) {
+            located.extend(
+                self.locate_on_gpu(chunk).into_iter().map(|polygon| {
+                    (polygon != UNDECIDED).then(|| PolygonId::new(polygon as usize))
+                }),
+            );
+        }
+        let undecided: Vec<usize> = located
+            .iter()
+            .enumerate()
+            .filter(|(_, polygon)| polygon.is_none())
+            .map(|(i, _)| i)
+            .collect();
+        let undecided_points: Vec<[f32; 2]> = undecided.iter().map(|i| points[*i]).collect();
+        for (i, polygon) in undecided
+            .into_iter()
+            .zip(self.mesh.locate_batch(&undecided_points))
+        {
+            located[i] = polygon;
+        }
+        located
+    }
+
+    /// Run the shader on points, [`UNDECIDED`] for the points left to the CPU.
+    fn locate_on_gpu(&self, points: &[[f32; 2]]) -> Vec<u32> {
+        if points.is_empty() {
+            return vec![];
+        }
+        let size = points.len() as u64 * 4;
+        let points_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("polyanya points"),
+                contents: &bytes(points.iter().flatten().map(|c| c.to_bits())),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let located = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("polyanya located"),
+            size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let read = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("polyanya read"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("polyanya locate"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                self.grid.as_entire_binding(),
+                self.polygons.as_entire_binding(),
+                points_buffer.as_entire_binding(),
+                located.as_entire_binding(),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(binding, resource)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource,
+            })
+            .collect::<Vec<_>>(),
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups((points.len() as u32).div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&located, 0, &read, 0, size);
+        self.queue.submit([encoder.finish()]);
+
+        let slice = read.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        // if the GPU fails, the CPU locates all the points
+        if self
+            .device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .is_err()
+            || !matches!(receiver.recv(), Ok(Ok(())))
+        {
+            return vec![UNDECIDED; points.len()];
+        }
+        let located = slice
+            .get_mapped_range()
+            .chunks_exact(4)
+            .map(|word| u32::from_ne_bytes([word[0], word[1], word[2], word[3]]))
+            .collect();
+        read.unmap();
+        located
+    }
+}
+
+/// Layout of the grid and the words of the polygons, cells and vertices, as read by the shader.
+///
+/// Returns `None` if the mesh has too many of them to be indexed by `u32`.
+fn mesh_words(mesh: &Mesh) -> Option<([u32; 12], Vec<u32>)> {
+    let (aabb, cell_size, [width, height]) = mesh.polygon_grid.layout();
+    let cells = mesh.polygon_grid.cells();
+    let word = |value: usize| u32::try_from(value).ok();
+
+    let mut words = Vec::new();
+    let mut start = mesh.polygons.len() + 1;
+    for polygon in &mesh.polygons {
+        words.push(word(start)?);
+        start += polygon.vertices.len();
+    }
+    words.push(word(start)?);
+    for polygon in &mesh.polygons {
+        for vertex in &polygon.vertices {
+            words.push(word(*vertex)?);
+        }
+    }
+
+    let cells_start = words.len();
+    let mut start = cells_start + cells.len() + 1;
+    for cell in cells {
+        words.push(word(start)?);
+        start += cell.len();
+    }
+    words.push(word(start)?);
+    for cell in cells {
+        for polygon in cell {
+            words.push(word(*polygon)?);
+        }
+    }
+
+    let vertices_start = words.len();
+    for vertex in &mesh.vertices {
+        words.extend([vertex.x.to_bits(), vertex.y.to_bits()]);
+    }
+    word(words.len())?;
+
+    let grid = [
+        aabb.min[0].to_bits(),
+        aabb.min[1].to_bits(),
+        aabb.max[0].to_bits(),
+        aabb.max[1].to_bits(),
+        cell_size[0].to_bits(),
+        cell_size[1].to_bits(),
+        word(width)?,
+        word(height)?,
+        word(cells_start)?,
+        word(vertices_start)?,
+        0,
+        0,
+    ];
+    Some((grid, words))
+}
+
+fn bytes(words: impl IntoIterator<Item = u32>) -> Vec<u8> {
+    words.into_iter().flat_map(u32::to_ne_bytes).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GpuLocator, UNDECIDED};
+    use crate::{
+        maps::{maze, random_obstacles, rooms_and_corridors},
+        Mesh,
+    };
+
+    fn points_over(mesh: &Mesh, step: f32) -> Vec<[f32; 2]> {
+        let mut points = vec![];
+        let mut y = mesh.aabb.min[1] - 1.0;
+        while y < mesh.aabb.max[1] + 1.0 {
+            let mut x = mesh.aabb.min[0] - 1.0;
+            while x < mesh.aabb.max[0] + 1.0 {
+                points.push([x, y]);
+                x += step;
+            }
+            y += step;
+        }
+        points
+    }
+
+    #[test]
+    fn same_as_the_cpu() {
+        let meshes = [
+            random_obstacles(40, 30, 25, 4, 3),
+            maze(15, 15, 2),
+            rooms_and_corridors(40, 40, 6, 5),
+            Mesh::from_file("meshes/aurora-merged.mesh").unwrap(),
+        ];
+        for mesh in meshes {
+            let Some(locator) = GpuLocator::new(&mesh) else {
+                // no GPU to run on
+                return;
+            };
+            let step = mesh.aabb.size()[0] / 297.3;
+            let mut points = points_over(&mesh, step);
+            // points clearly inside a polygon are located on the GPU
+            let decided = locator
+                .locate_on_gpu(&points)
+                .into_iter()
+                .filter(|polygon| *polygon != UNDECIDED)
+                .count();
+            let inside = mesh.locate_batch(&points).into_iter().flatten().count();
+            assert!(decided > inside * 9 / 10);
+            // the others, like the vertices of the mesh, on the CPU
+            points.extend(mesh.vertices.iter().map(|vertex| vertex.p()));
+            assert_eq!(locator.locate_batch(&points), mesh.locate_batch(&points));
+        }
+    }
+
+    #[test]
+    fn empty() {
+        let mesh = Mesh::default();
+        if let Some(locator) = GpuLocator::new(&mesh) {
+            assert_eq!(locator.locate_batch(&[]), vec![]);
+            assert_eq!(locator.locate_batch(&[[0.0, 0.0]]), vec![None]);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+// Locate points in the polygons of a mesh, against the grid of their bounding boxes.
+//
+// Points are only located here when the result is certain to be the one of the CPU: clearly
+// inside a polygon, far enough from the edges and from the borders between cells. The others are
+// left `UNDECIDED` for the CPU.
+
+struct Grid {
+    min: vec2<f32>,
+    max: vec2<f32>,
+    cell_size: vec2<f32>,
+    width: u32,
+    height: u32,
+    // where the cells start in `mesh`
+    cells: u32,
+    // where the vertices start in `mesh`
+    vertices: u32,
+}
+
+// In order: where the vertices of each polygon start, then the vertices of the polygons, where
+// the polygons of each cell start, the polygons of the cells, and the coordinates of the vertices.
+@group(0) @binding(0) var<uniform> grid: Grid;
+@group(0) @binding(1) var<storage, read> mesh: array<u32>;
+@group(0) @binding(2) var<storage, read> points: array<vec2<f32>>;
+@group(0) @binding(3) var<storage, read_write> located: array<u32>;
+
+const UNDECIDED: u32 = 0xffffffffu;
+// Width of the band around an edge where a point is on the edge, as in `on_side`.
+const EDGE: f32 = 1e-2;
+// Relative error allowed between the computations here and on the CPU.
+const ERROR: f32 = 1e-6;
+
+fn vertex(index: u32) -> vec2<f32> {
+    let at = grid.vertices + 2u * index;
+    return vec2<f32>(bitcast<f32>(mesh[at]), bitcast<f32>(mesh[at + 1u]));
+}
+
+// Cell along an axis, or -1 when the point is too close to the border of a cell to be sure of it.
+fn cell(offset: f32, size: f32, count: u32) -> i32 {
+    let position = max(offset / size, 0.0);
+    let border = round(position);
+    // on either side of the first and the last borders the cell is the same once clamped
+    if border >= 1.0 && border < f32(count) && abs(position - border) <= position * ERROR {
+        return -1;
+    }
+    return i32(min(u32(position), count - 1u));
+}
+
+// 1 if the point is on the left of the edge, -1 on its right, 0 when it's not sure to be out of
+// the band of the edge.
+fn side(point: vec2<f32>, a: vec2<f32>, b: vec2<f32>) -> i32 {
+    let first = (point.y - a.y) * (b.x - a.x);
+    let second = (point.x - a.x) * (b.y - a.y);
+    let cross = first - second;
+    let error = (abs(first) + abs(second)) * ERROR;
+    if cross > EDGE + error {
+        return 1;
+    }
+    if cross < -EDGE - error {
+        return -1;
+    }
+    return 0;
+}
+
+fn locate(point: vec2<f32>) -> u32 {
+    if grid.width == 0u || !(all(point >= grid.min) && all(point <= grid.max)) {
+        return UNDECIDED;
+    }
+    let x = cell(point.x - grid.min.x, grid.cell_size.x, grid.width);
+    let y = cell(point.y - grid.min.y, grid.cell_size.y, grid.height);
+    if x < 0 || y < 0 {
+        return UNDECIDED;
+    }
+    let cell = grid.cells + u32(y) * grid.width + u32(x);
+    for (var candidate = mesh[cell]; candidate < mesh[cell + 1u]; candidate++) {
+        let polygon = mesh[candidate];
+        let start = mesh[polygon];
+        let end = mesh[polygon + 1u];
+        if start == end {
+            return UNDECIDED;
+        }
+        // 1 inside, -1 outside, 0 either
+        var inside = 1;
+        for (var at = start; at < end; at++) {
+            let next = select(at + 1u, start, at + 1u == end);
+            let a = vertex(mesh[at]);
+            let b = vertex(mesh[next]);
+            let side = side(point, a, b);
+            if side < 0 {
+                inside = -1;
+                break;
+            }
+            if side == 0 {
+                // the CPU could find the point on this edge
+                if all(point >= min(a, b)) && all(point <= max(a, b)) {
+                    return UNDECIDED;
+                }
+                // or stop here, or go on to the next edges
+                inside = 0;
+            }
+        }
+        if inside == 0 {
+            return UNDECIDED;
+        }
+        if inside == 1 {
+            return polygon;
+        }
+    }
+    return UNDECIDED;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    if id.x < arrayLength(&points) {
+        located[id.x] = locate(points[id.x]);
+    }
+}
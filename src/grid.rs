@@ -0,0 +1,381 @@
+use std::io::{self, Read, Write};
+
+use crate::{
+    binary::{BinaryReader, BinaryWriter},
+    Aabb, Mesh, MeshBinaryError, PolygonId,
+};
+
+/// Maximum number of cells of a [`PolygonGrid`] on each axis.
+const MAX_CELLS: usize = 1024;
+
+/// Uniform grid over the bounding boxes of the polygons of a mesh, to only check the polygons
+/// close to a point when locating it.
+///
+/// Each cell lists the polygons whose bounding box overlaps it, in increasing order.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PolygonGrid {
+    aabb: Aabb,
+    cell_size: [f32; 2],
+    width: usize,
+    height: usize,
+    /// Polygons of each cell, row by row.
+    cells: Vec<Vec<usize>>,
+}
+
+impl PolygonGrid {
+    /// Build a grid over polygons from their bounding boxes, with about as many cells as polygons.
+    pub(crate) fn new(polygon_aabbs: &[Aabb], aabb: Aabb) -> Self {
+        if polygon_aabbs.is_empty() || aabb.is_empty() {
+            return PolygonGrid::default();
+        }
+        let size = aabb.size();
+        let side = (size[0].max(f32::EPSILON) * size[1].max(f32::EPSILON)
+            / polygon_aabbs.len() as f32)
+            .sqrt();
+        let cells_along = |length: f32| ((length / side).ceil() as usize).clamp(1, MAX_CELLS);
+        let width = cells_along(size[0]);
+        let height = cells_along(size[1]);
+        let mut grid = PolygonGrid {
+            aabb,
+            cell_size: [
+                size[0].max(f32::EPSILON) / width as f32,
+                size[1].max(f32::EPSILON) / height as f32,
+            ],
+            width,
+            height,
+            cells: vec![vec![]; width * height],
+        };
+        for (polygon, polygon_aabb) in polygon_aabbs.iter().enumerate() {
+            for cell in grid.cells_of(*polygon_aabb) {
+                grid.cells[cell].push(polygon);
+            }
+        }
+        grid
+    }
+
+    /// Cells overlapping a bounding box, clamped to the grid.
+    fn cells_of(&self, aabb: Aabb) -> impl Iterator<Item = usize> {
+        let ((min_x, min_y), (max_x, max_y)) = if self.cells.is_empty() || aabb.is_empty() {
+            ((1, 1), (0, 0))
+        } else {
+            (
+                self.cell_coordinates(aabb.min),
+                self.cell_coordinates(aabb.max),
+            )
+        };
+        let width = self.width;
+        (min_y..=max_y).flat_map(move |y| (min_x..=max_x).map(move |x| y * width + x))
+    }
+
+    /// Add a polygon, keeping the polygons of each cell in increasing order.
+    ///
+    /// Returns `false` without adding it if its bounding box is not inside the grid, in which
+    /// case the grid must be built again.
+    pub(crate) fn insert(&mut self, polygon: usize, aabb: Aabb) -> bool {
+        if self.cells.is_empty()
+            || !(aabb.is_empty() || self.aabb.contains(aabb.min) && self.aabb.contains(aabb.max))
+        {
+            return false;
+        }
+        for cell in self.cells_of(aabb).collect::<Vec<_>>() {
+            let polygons = &mut self.cells[cell];
+            if let Err(index) = polygons.binary_search(&polygon) {
+                polygons.insert(index, polygon);
+            }
+        }
+        true
+    }
+
+    /// Remove a polygon, given the bounding box it was added with.
+    pub(crate) fn remove(&mut self, polygon: usize, aabb: Aabb) {
+        for cell in self.cells_of(aabb).collect::<Vec<_>>() {
+            let polygons = &mut self.cells[cell];
+            if let Ok(index) = polygons.binary_search(&polygon) {
+                polygons.remove(index);
+            }
+        }
+    }
+
+    /// Coordinates of the cell containing a point, clamped to the grid.
+    fn cell_coordinates(&self, point: [f32; 2]) -> (usize, usize) {
+        let x = ((point[0] - self.aabb.min[0]) / self.cell_size[0]).max(0.0) as usize;
+        let y = ((point[1] - self.aabb.min[1]) / self.cell_size[1]).max(0.0) as usize;
+        (x.min(self.width - 1), y.min(self.height - 1))
+    }
+
+    /// Polygons whose bounding box may contain the point, in increasing order.
+    pub(crate) fn candidates(&self, point: [f32; 2]) -> &[usize] {
+        if self.cells.is_empty() || !self.aabb.contains(point) {
+            return &[];
+        }
+        let (x, y) = self.cell_coordinates(point);
+        &self.cells[y * self.width + x]
+    }
+
+    /// Polygons whose bounding box may overlap a bounding box, in increasing order.
+    pub(crate) fn candidates_in(&self, aabb: Aabb) -> Vec<usize> {
+        if !self.aabb.intersects(&aabb) {
+            return vec![];
+        }
+        let mut candidates: Vec<usize> = self
+            .cells_of(aabb)
+            .flat_map(|cell| self.cells[cell].iter().copied())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        candidates
+    }
+
+    /// Bounds of the grid, size of its cells, and number of cells on each axis.
+    #[cfg(feature = "gpu")]
+    pub(crate) fn layout(&self) -> (Aabb, [f32; 2], [usize; 2]) {
+        (self.aabb, self.cell_size, [self.width, self.height])
+    }
+
+    /// Polygons of each cell, row by row.
+    #[cfg(feature = "gpu")]
+    pub(crate) fn cells(&self) -> &[Vec<usize>] {
+        &self.cells
+    }
+
+    /// Polygons of the cells around a point, ring of cells after ring of cells from the cell of
+    /// the point, with a distance to the point that the polygons of the ring and of the next ones
+    /// can't be closer than. Polygons can be listed in several rings.
+    pub(crate) fn rings(&self, point: [f32; 2]) -> impl Iterator<Item = (f32, Vec<usize>)> + '_ {
+        let (rings, (x, y)) = if self.cells.is_empty() {
+            (0, (0, 0))
+        } else {
+            (self.width.max(self.height), self.cell_coordinates(point))
+        };
+        let outside = self.aabb.distance_to(point);
+        let cell = self.cell_size[0].min(self.cell_size[1]);
+        (0..rings).map(move |ring| {
+            // the cells of the ring are behind the cells of the previous rings
+            let distance = outside.max(ring.saturating_sub(1) as f32 * cell);
+            let mut polygons = vec![];
+            for row in y.saturating_sub(ring)..=(y + ring).min(self.height - 1) {
+                let columns = x.saturating_sub(ring)..=(x + ring).min(self.width - 1);
+                let side = row + ring == y || row == y + ring;
+                for column in columns {
+                    if side || column + ring == x || column == x + ring {
+                        polygons.extend(&self.cells[row * self.width + column]);
+                    }
+                }
+            }
+            (distance, polygons)
+        })
+    }
+
+    /// Write the grid in the binary mesh format.
+    pub(crate) fn save<W: Write>(&self, out: &mut BinaryWriter<W>) -> io::Result<()> {
+        out.aabb(self.aabb)?;
+        out.len(self.width)?;
+        out.len(self.height)?;
+        // start of the polygons of each cell, with one more entry for the end
+        let nb_offsets = if self.cells.is_empty() {
+            0
+        } else {
+            self.cells.len() + 1
+        };
+        out.len(nb_offsets)?;
+        let mut offset = 0;
+        if nb_offsets > 0 {
+            out.len(offset)?;
+        }
+        for cell in &self.cells {
+            offset += cell.len();
+            out.len(offset)?;
+        }
+        out.len(offset)?;
+        for polygon in self.cells.iter().flatten() {
+            out.len(*polygon)?;
+        }
+        Ok(())
+    }
+
+    /// Read a grid written by [`PolygonGrid::save`], over `nb_polygons` polygons.
+    pub(crate) fn load<R: Read>(
+        input: &mut BinaryReader<R>,
+        nb_polygons: usize,
+    ) -> Result<Self, MeshBinaryError> {
+        let aabb = input.aabb()?;
+        let width = input.len()?;
+        let height = input.len()?;
+        let nb_offsets = input.len()?;
+        let offsets = input.many(nb_offsets, |input| Ok(input.len()?))?;
+        let nb_candidates = input.len()?;
+        let polygons = input.many(nb_candidates, |input| match input.len()? {
+            polygon if polygon < nb_polygons => Ok(polygon),
+            _ => Err(MeshBinaryError::Corrupted("unknown polygon in grid")),
+        })?;
+
+        if offsets.is_empty() {
+            return Ok(PolygonGrid::default());
+        }
+        if width == 0
+            || height == 0
+            || width > MAX_CELLS
+            || height > MAX_CELLS
+            || offsets.len() != width * height + 1
+            || offsets[0] != 0
+            || offsets.windows(2).any(|w| w[0] > w[1])
+            || offsets[offsets.len() - 1] != polygons.len()
+        {
+            return Err(MeshBinaryError::Corrupted("invalid grid"));
+        }
+        let size = aabb.size();
+        Ok(PolygonGrid {
+            aabb,
+            cell_size: [
+                size[0].max(f32::EPSILON) / width as f32,
+                size[1].max(f32::EPSILON) / height as f32,
+            ],
+            width,
+            height,
+            cells: offsets
+                .windows(2)
+                .map(|w| polygons[w[0]..w[1]].to_vec())
+                .collect(),
+        })
+    }
+}
+
+impl Mesh {
+    /// Find the polygons overlapping the rectangle from `min` to `max`, borders included, in
+    /// increasing order.
+    ///
+    /// Useful to go over the walkable area in a camera view or a trigger zone.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let tiles = ["..#", "..#", "..."];
+    /// let mesh = Mesh::from_grid(3, 3, |x, y| tiles[y].as_bytes()[x] == b'.');
+    /// assert_eq!(mesh.polygons_in_aabb([2.2, 0.2], [2.8, 1.8]), vec![]);
+    /// assert_eq!(mesh.polygons_in_aabb([0.0, 0.0], [3.0, 3.0]).len(), 2);
+    /// ```
+    pub fn polygons_in_aabb(&self, min: [f32; 2], max: [f32; 2]) -> Vec<PolygonId> {
+        let aabb = Aabb::new(min, max);
+        self.polygon_grid
+            .candidates_in(aabb)
+            .into_iter()
+            .filter(|polygon| self.polygon_overlaps(*polygon, aabb))
+            .map(PolygonId::new)
+            .collect()
+    }
+
+    /// Check if a polygon overlaps a bounding box, by looking for one of its edges with all the
+    /// corners of the box on the outer side.
+    fn polygon_overlaps(&self, polygon_index: usize, aabb: Aabb) -> bool {
+        if let Some(polygon_aabb) = self.polygon_aabbs.get(polygon_index) {
+            if !polygon_aabb.intersects(&aabb) {
+                return false;
+            }
+        }
+        let corners = [
+            aabb.min,
+            [aabb.max[0], aabb.min[1]],
+            aabb.max,
+            [aabb.min[0], aabb.max[1]],
+        ];
+        !self.polygons[polygon_index]
+            .edges_index()
+            .into_iter()
+            .any(|edge| {
+                let a = self.vertices[edge[0]].p();
+                let b = self.vertices[edge[1]].p();
+                corners.iter().all(|corner| {
+                    (b[0] - a[0]) * (corner[1] - a[1]) - (b[1] - a[1]) * (corner[0] - a[0]) < 0.0
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{random_obstacles, test_points},
+        tests::mesh_u_grid,
+        Aabb, Mesh, PolygonId,
+    };
+
+    #[test]
+    fn candidates() {
+        let mesh = mesh_u_grid();
+        assert!(mesh.polygon_grid.candidates([0.5, 0.5]).contains(&0));
+        assert!(mesh.polygon_grid.candidates([2.5, 1.5]).contains(&4));
+        assert!(!mesh.polygon_grid.candidates([0.5, 0.5]).contains(&4));
+        assert!(mesh.polygon_grid.candidates([3.0, 2.0]).contains(&4));
+        assert!(mesh.polygon_grid.candidates([-1.0, 0.5]).is_empty());
+        assert!(Mesh::default()
+            .polygon_grid
+            .candidates([0.0, 0.0])
+            .is_empty());
+    }
+
+    #[test]
+    fn same_as_linear_scan() {
+        let mesh = Mesh::from_file("meshes/arena-merged.mesh").unwrap();
+        let aabb = mesh.bounding_box();
+        for i in 0..=50 {
+            for j in 0..=50 {
+                let point = [
+                    aabb.min[0] + aabb.size()[0] * i as f32 / 50.0,
+                    aabb.min[1] + aabb.size()[1] * j as f32 / 50.0,
+                ];
+                let linear = (0..mesh.polygons.len())
+                    .find(|p| mesh.polygon_contains(*p, point))
+                    .map(PolygonId::new);
+                assert_eq!(mesh.point_in_polygon_unit(point), linear);
+            }
+        }
+    }
+
+    #[test]
+    fn polygons_in_aabb() {
+        let mesh = mesh_u_grid();
+        let ids = |ids: &[usize]| ids.iter().copied().map(PolygonId::new).collect::<Vec<_>>();
+        assert_eq!(mesh.polygons_in_aabb([0.2, 0.2], [0.4, 0.4]), ids(&[0]));
+        assert_eq!(
+            mesh.polygons_in_aabb([0.5, 0.5], [1.5, 1.5]),
+            ids(&[0, 1, 3])
+        );
+        assert_eq!(mesh.polygons_in_aabb([1.2, 1.2], [1.8, 1.8]), ids(&[]));
+        // touching a border
+        assert_eq!(mesh.polygons_in_aabb([2.0, 1.5], [2.5, 3.0]), ids(&[4]));
+        assert_eq!(mesh.polygons_in_aabb([5.0, 5.0], [6.0, 6.0]), ids(&[]));
+        assert_eq!(
+            mesh.polygons_in_aabb([-1.0, -1.0], [4.0, 4.0]),
+            ids(&[0, 1, 2, 3, 4])
+        );
+
+        // same as checking every polygon
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        for (i, min) in test_points(50, [0.3, 0.6]).enumerate() {
+            let aabb = Aabb::new(min, [min[0] + (i % 5) as f32, min[1] + (i % 3) as f32]);
+            let linear = (0..mesh.polygons.len())
+                .filter(|p| mesh.polygon_overlaps(*p, aabb))
+                .map(PolygonId::new)
+                .collect::<Vec<_>>();
+            assert_eq!(mesh.polygons_in_aabb(aabb.min, aabb.max), linear);
+        }
+    }
+
+    #[test]
+    fn degenerate_aabbs() {
+        let mesh = mesh_u_grid();
+        let ids = |ids: &[usize]| ids.iter().copied().map(PolygonId::new).collect::<Vec<_>>();
+        // a point, inside a polygon or on a vertex
+        assert_eq!(mesh.polygons_in_aabb([0.5, 0.5], [0.5, 0.5]), ids(&[0]));
+        assert_eq!(
+            mesh.polygons_in_aabb([1.0, 1.0], [1.0, 1.0]),
+            ids(&[0, 1, 3])
+        );
+        // a line along an edge
+        assert_eq!(mesh.polygons_in_aabb([0.2, 1.0], [0.8, 1.0]), ids(&[0, 3]));
+        // inverted
+        assert_eq!(mesh.polygons_in_aabb([0.8, 0.8], [0.2, 0.2]), ids(&[]));
+
+        let empty = Mesh::default();
+        assert_eq!(empty.polygons_in_aabb([0.0, 0.0], [1.0, 1.0]), ids(&[]));
+    }
+}
@@ -86,6 +86,19 @@ pub(crate) fn distance_between(from: [f32; 2], to: [f32; 2]) -> f32 {
     ((to[0] - from[0]).powi(2) + (to[1] - from[1]).powi(2)).sqrt()
 }
 
+#[cfg_attr(feature = "tracing", instrument(skip_all))]
+#[inline(always)]
+pub(crate) fn project_on_segment(point: [f32; 2], segment: [[f32; 2]; 2]) -> [f32; 2] {
+    let d = [segment[1][0] - segment[0][0], segment[1][1] - segment[0][1]];
+    let len2 = d[0] * d[0] + d[1] * d[1];
+    if len2 == 0.0 {
+        return segment[0];
+    }
+    let t = (((point[0] - segment[0][0]) * d[0] + (point[1] - segment[0][1]) * d[1]) / len2)
+        .clamp(0.0, 1.0);
+    [segment[0][0] + t * d[0], segment[0][1] + t * d[1]]
+}
+
 #[cfg_attr(feature = "tracing", instrument(skip_all))]
 #[inline(always)]
 pub(crate) fn line_intersect_segment(
@@ -107,11 +120,34 @@ pub(crate) fn line_intersect_segment(
     }
 }
 
+/// Check if a point is inside a simple polygon, that may be non convex and in any winding order.
+pub(crate) fn point_in_simple_polygon(point: [f32; 2], polygon: &[[f32; 2]]) -> bool {
+    let mut inside = false;
+    let mut last = match polygon.last() {
+        Some(last) => *last,
+        None => return false,
+    };
+    for vertex in polygon {
+        if (vertex[1] > point[1]) != (last[1] > point[1]) {
+            let x =
+                vertex[0] + (point[1] - vertex[1]) * (last[0] - vertex[0]) / (last[1] - vertex[1]);
+            if point[0] < x {
+                inside = !inside;
+            }
+        }
+        last = *vertex;
+    }
+    inside
+}
+
 #[cfg(test)]
 mod tests {
     use crate::EdgeSide;
 
-    use super::{heuristic, line_intersect_segment, mirror, on_side};
+    use super::{
+        heuristic, line_intersect_segment, mirror, on_side, point_in_simple_polygon,
+        project_on_segment,
+    };
 
     #[test]
     fn test_on_side() {
@@ -163,6 +199,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_project_on_segment() {
+        let segment = [[0.0, 0.0], [2.0, 0.0]];
+        assert_eq!(project_on_segment([1.0, 1.0], segment), [1.0, 0.0]);
+        assert_eq!(project_on_segment([-1.0, 1.0], segment), [0.0, 0.0]);
+        assert_eq!(project_on_segment([3.0, -1.0], segment), [2.0, 0.0]);
+        assert_eq!(
+            project_on_segment([3.0, -1.0], [[1.0, 1.0], [1.0, 1.0]]),
+            [1.0, 1.0]
+        );
+    }
+
     #[test]
     fn test_line_intersect() {
         assert_eq!(
@@ -186,4 +234,21 @@ mod tests {
             None
         );
     }
+
+    #[test]
+    fn test_point_in_simple_polygon() {
+        let l_shape = [
+            [0.0, 0.0],
+            [2.0, 0.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+        ];
+        assert!(point_in_simple_polygon([0.5, 1.5], &l_shape));
+        assert!(point_in_simple_polygon([1.5, 0.5], &l_shape));
+        assert!(!point_in_simple_polygon([1.5, 1.5], &l_shape));
+        assert!(!point_in_simple_polygon([-0.5, 0.5], &l_shape));
+        assert!(!point_in_simple_polygon([0.5, 0.5], &[]));
+    }
 }
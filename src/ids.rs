@@ -0,0 +1,86 @@
+use std::fmt::{self, Display};
+
+/// Identifier of a polygon of a [`Mesh`](crate::Mesh), its index in the list of polygons.
+///
+/// Places where there may be no polygon, like outside of the mesh, use `Option<PolygonId>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct PolygonId(usize);
+
+/// Identifier of a vertex of a [`Mesh`](crate::Mesh), its index in the list of vertices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct VertexId(usize);
+
+impl PolygonId {
+    /// Identifier of the polygon at `index`.
+    pub const fn new(index: usize) -> Self {
+        PolygonId(index)
+    }
+
+    /// Index of the polygon in the list of polygons of the mesh.
+    pub const fn index(self) -> usize {
+        self.0
+    }
+
+    /// Identifier from a polygon reference of the `.mesh` format, where `-1` means no polygon.
+    pub(crate) fn from_raw(raw: isize) -> Option<Self> {
+        (raw >= 0).then_some(PolygonId(raw as usize))
+    }
+}
+
+impl VertexId {
+    /// Identifier of the vertex at `index`.
+    pub const fn new(index: usize) -> Self {
+        VertexId(index)
+    }
+
+    /// Index of the vertex in the list of vertices of the mesh.
+    pub const fn index(self) -> usize {
+        self.0
+    }
+}
+
+impl From<usize> for PolygonId {
+    fn from(index: usize) -> Self {
+        PolygonId(index)
+    }
+}
+
+impl From<PolygonId> for usize {
+    fn from(id: PolygonId) -> Self {
+        id.0
+    }
+}
+
+impl From<usize> for VertexId {
+    fn from(index: usize) -> Self {
+        VertexId(index)
+    }
+}
+
+impl From<VertexId> for usize {
+    fn from(id: VertexId) -> Self {
+        id.0
+    }
+}
+
+impl Display for PolygonId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "polygon {}", self.0)
+    }
+}
+
+impl Display for VertexId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "vertex {}", self.0)
+    }
+}
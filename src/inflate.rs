@@ -0,0 +1,251 @@
+//! Shrinking of the walkable area of a mesh by the radius of an agent.
+//!
+//! The boundary of the mesh is followed into loops, with the walkable area on their left. Each
+//! boundary edge is covered by a rectangle reaching `radius` into the walkable area, and each
+//! reflex corner by a regular polygon around a circle of `radius`. Together they cover every
+//! walkable point closer to the boundary than `radius`. They are inserted with the boundary in a
+//! constrained Delaunay triangulation, and the parts inside the mesh and outside all of them are
+//! kept.
+
+use std::f32::consts::PI;
+
+use hashbrown::{HashMap, HashSet};
+
+use crate::{
+    cdt::Triangulation, helpers::point_in_simple_polygon, Aabb, Mesh, MeshBuildError, MeshBuilder,
+};
+
+/// Number of sides of the polygons around the boundary vertices.
+const SIDES: usize = 8;
+
+fn orient(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f64 {
+    let [a, b, c] = [a, b, c].map(|p| [p[0] as f64, p[1] as f64]);
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Follow boundary edges, given by the vertices each one leaves, into closed loops of points
+/// without repeated or collinear points.
+fn boundary_loops(
+    points: &[[f32; 2]],
+    mut outgoing: HashMap<usize, Vec<usize>>,
+) -> Vec<Vec<[f32; 2]>> {
+    let mut starts: Vec<usize> = outgoing.keys().copied().collect();
+    starts.sort_unstable();
+    let mut loops = vec![];
+    for start in starts {
+        while let Some(mut current) = outgoing.get_mut(&start).and_then(|next| next.pop()) {
+            let mut outline = vec![points[start]];
+            while current != start {
+                outline.push(points[current]);
+                let Some(next) = outgoing.get_mut(&current).and_then(|next| next.pop()) else {
+                    break;
+                };
+                current = next;
+            }
+            outline.dedup();
+            while outline.len() > 1 && outline.first() == outline.last() {
+                outline.pop();
+            }
+            // remove points in the middle of straight parts
+            let mut i = 0;
+            while outline.len() >= 3 && i < outline.len() {
+                let n = outline.len();
+                let (previous, point, next) =
+                    (outline[(i + n - 1) % n], outline[i], outline[(i + 1) % n]);
+                let forward = (point[0] - previous[0]) * (next[0] - point[0])
+                    + (point[1] - previous[1]) * (next[1] - point[1]);
+                if orient(previous, point, next) == 0.0 && forward > 0.0 {
+                    outline.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+            if outline.len() >= 3 {
+                loops.push(outline);
+            }
+        }
+    }
+    loops
+}
+
+impl Mesh {
+    /// Build a mesh where every point is at least `radius` away from the boundary of this mesh,
+    /// so that the paths found on it can be followed by agents of this radius without going
+    /// through walls or obstacles.
+    ///
+    /// Corners are rounded with a few segments, so the walkable area is slightly smaller than the
+    /// exact offset. Passages narrower than `2 * radius` are closed. A `radius` that is not
+    /// positive returns a copy of the mesh.
+    ///
+    /// The shapes covering the boundary are intersected with each other, so this gets slower as
+    /// the radius grows compared to the length of the boundary edges.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let outer = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+    /// let hole = vec![[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]];
+    /// let mesh = Mesh::from_polygons(&outer, &[hole]).unwrap();
+    /// let inflated = mesh.inflate_obstacles(1.0).unwrap();
+    /// assert!(mesh.point_in_mesh([3.5, 5.0]));
+    /// assert!(!inflated.point_in_mesh([3.5, 5.0]));
+    /// assert!(inflated.path([5.0, 2.0], [5.0, 8.0]).is_some());
+    /// ```
+    pub fn inflate_obstacles(&self, radius: f32) -> Result<Mesh, MeshBuildError> {
+        if radius.is_nan() || radius <= 0.0 {
+            return Ok(self.clone());
+        }
+        if radius.is_infinite() {
+            return MeshBuilder::new().build();
+        }
+
+        // boundary edges have the walkable area on their left, as polygons are counterclockwise
+        let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+        let edges: HashSet<(usize, usize)> = self
+            .polygons
+            .iter()
+            .flat_map(|polygon| polygon.edges_index())
+            .map(|edge| (edge[0], edge[1]))
+            .collect();
+        for (from, to) in &edges {
+            if !edges.contains(&(*to, *from)) {
+                outgoing.entry(*from).or_default().push(*to);
+            }
+        }
+        if outgoing.is_empty() {
+            return Ok(self.clone());
+        }
+        let loops = boundary_loops(
+            &self.vertices.iter().map(|v| v.p()).collect::<Vec<_>>(),
+            outgoing,
+        );
+
+        let mut shapes: Vec<Vec<[f32; 2]>> = vec![];
+        for outline in &loops {
+            let n = outline.len();
+            for i in 0..n {
+                let (previous, a, b) = (outline[(i + n - 1) % n], outline[i], outline[(i + 1) % n]);
+                if orient(previous, a, b) < 0.0 {
+                    // the polygon is around the circle, touching it in the middle of its sides
+                    let distance = radius / (PI / SIDES as f32).cos();
+                    shapes.push(
+                        (0..SIDES)
+                            .map(|k| {
+                                let angle = 2.0 * PI * k as f32 / SIDES as f32;
+                                [a[0] + distance * angle.cos(), a[1] + distance * angle.sin()]
+                            })
+                            .collect(),
+                    );
+                }
+                let length = (b[0] - a[0]).hypot(b[1] - a[1]);
+                let normal = [
+                    (a[1] - b[1]) / length * radius,
+                    (b[0] - a[0]) / length * radius,
+                ];
+                shapes.push(vec![
+                    a,
+                    b,
+                    [b[0] + normal[0], b[1] + normal[1]],
+                    [a[0] + normal[0], a[1] + normal[1]],
+                ]);
+            }
+        }
+        let bounds: Vec<Aabb> = shapes
+            .iter()
+            .map(|shape| Aabb::from_points(shape.iter().copied()))
+            .collect();
+
+        let mut triangulation = Triangulation::new(
+            bounds
+                .iter()
+                .fold(self.aabb, |aabb, bounds| aabb.union(bounds)),
+        );
+        for outline in &loops {
+            triangulation.insert_outline(outline);
+        }
+        for shape in &shapes {
+            triangulation.insert_outline(shape);
+        }
+
+        // shapes by the cells of a grid they overlap, to check points against the nearby ones
+        let cell_size = radius * 4.0;
+        let cell = |point: [f32; 2]| {
+            (
+                (point[0] / cell_size).floor() as i64,
+                (point[1] / cell_size).floor() as i64,
+            )
+        };
+        let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+        for (index, bounds) in bounds.iter().enumerate() {
+            let (min, max) = (cell(bounds.min), cell(bounds.max));
+            for x in min.0..=max.0 {
+                for y in min.1..=max.1 {
+                    grid.entry((x, y)).or_default().push(index);
+                }
+            }
+        }
+        let mut mesh = triangulation.mesh(|point| {
+            self.point_in_polygon_unit(point).is_some()
+                && grid.get(&cell(point)).is_none_or(|candidates| {
+                    !candidates.iter().any(|index| {
+                        bounds[*index].contains(point)
+                            && point_in_simple_polygon(point, &shapes[*index])
+                    })
+                })
+        })?;
+        mesh.merge_convex();
+        mesh.output_quantization = self.output_quantization;
+        mesh.off_mesh_clamp = self.off_mesh_clamp;
+        mesh.point_snap = self.point_snap;
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{helpers::distance_between, Mesh};
+
+    /// Distance from a point to the outline of the square from `min` to `max`.
+    fn to_square(point: [f32; 2], min: f32, max: f32) -> f32 {
+        let inside = [
+            point[0] - min,
+            max - point[0],
+            point[1] - min,
+            max - point[1],
+        ];
+        if inside.iter().all(|distance| *distance >= 0.0) {
+            inside.into_iter().fold(f32::INFINITY, f32::min)
+        } else {
+            let closest = point.map(|coordinate| coordinate.clamp(min, max));
+            distance_between(point, closest)
+        }
+    }
+
+    #[test]
+    fn around_hole() {
+        let outer = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+        let hole = vec![[4.0, 4.0], [6.0, 4.0], [6.0, 6.0], [4.0, 6.0]];
+        let mesh = Mesh::from_polygons(&outer, &[hole]).unwrap();
+        let inflated = mesh.inflate_obstacles(1.0).unwrap();
+        assert!(inflated.validate().is_empty());
+        for point in [[0.5, 5.0], [5.0, 9.5], [3.5, 5.0], [6.5, 6.5]] {
+            assert!(!inflated.point_in_mesh(point));
+        }
+        for point in [[1.2, 5.0], [2.8, 5.0], [7.0, 7.0]] {
+            assert!(inflated.point_in_mesh(point));
+        }
+
+        let original = mesh.path([5.0, 2.0], [5.0, 8.0]).unwrap();
+        let path = inflated.path([5.0, 2.0], [5.0, 8.0]).unwrap();
+        assert!(path.len > original.len);
+        for point in &path.path {
+            assert!(to_square(*point, 4.0, 6.0) > 0.999);
+            assert!(to_square(*point, 0.0, 10.0) > 0.999);
+        }
+
+        assert_eq!(
+            mesh.inflate_obstacles(0.0).unwrap().polygons.len(),
+            mesh.polygons.len()
+        );
+        assert!(mesh.inflate_obstacles(3.0).unwrap().polygons.is_empty());
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead, Read},
+    str::{FromStr, SplitWhitespace},
+};
+
+use crate::{builder::check_polygon, Mesh, MeshBuildError, Polygon, Vertex};
+
+/// Shortest line of a vertex or a polygon, `0 0 0` and a line break.
+const MIN_LINE_LEN: usize = 6;
+
+/// Most vertices or polygons allocated before reading them when the length of the input is not
+/// known, so that a wrong count in the header doesn't allocate more than the mesh needs.
+const MAX_RESERVED: usize = 1 << 16;
+
+/// Error returned when a mesh file can't be read.
+///
+/// Line numbers start at 1.
+#[derive(Debug)]
+pub enum MeshParseError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// A line ended before a token that was expected.
+    MissingToken { line: usize, expected: &'static str },
+    /// A token couldn't be parsed as what was expected.
+    InvalidToken {
+        line: usize,
+        expected: &'static str,
+        found: String,
+    },
+    /// A line has more tokens than expected.
+    UnexpectedToken { line: usize, found: String },
+    /// A line was found after all the vertices and polygons were read.
+    UnexpectedLine { line: usize },
+    /// The file ended before all the vertices and polygons were read.
+    UnexpectedEof { expected: &'static str },
+    /// The file was read, but the mesh it describes is invalid.
+    InvalidMesh(MeshBuildError),
+}
+
+impl Display for MeshParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshParseError::Io(error) => write!(f, "error reading mesh: {}", error),
+            MeshParseError::MissingToken { line, expected } => {
+                write!(f, "line {}: missing {}", line, expected)
+            }
+            MeshParseError::InvalidToken {
+                line,
+                expected,
+                found,
+            } => write!(f, "line {}: expected {}, found {:?}", line, expected, found),
+            MeshParseError::UnexpectedToken { line, found } => {
+                write!(f, "line {}: unexpected token {:?}", line, found)
+            }
+            MeshParseError::UnexpectedLine { line } => write!(f, "line {}: unexpected line", line),
+            MeshParseError::UnexpectedEof { expected } => {
+                write!(f, "unexpected end of file, expected {}", expected)
+            }
+            MeshParseError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+impl Error for MeshParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MeshParseError::Io(error) => Some(error),
+            MeshParseError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for MeshParseError {
+    fn from(error: io::Error) -> Self {
+        MeshParseError::Io(error)
+    }
+}
+
+impl From<MeshBuildError> for MeshParseError {
+    fn from(error: MeshBuildError) -> Self {
+        MeshParseError::InvalidMesh(error)
+    }
+}
+
+/// Tokens of a line, with its number for error reporting.
+pub(crate) struct Tokens<'a> {
+    line: usize,
+    tokens: SplitWhitespace<'a>,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(line: usize, content: &'a str) -> Self {
+        Tokens {
+            line,
+            tokens: content.split_whitespace(),
+        }
+    }
+
+    pub(crate) fn next<T: FromStr>(&mut self, expected: &'static str) -> Result<T, MeshParseError> {
+        let token = self.tokens.next().ok_or(MeshParseError::MissingToken {
+            line: self.line,
+            expected,
+        })?;
+        token.parse().map_err(|_| MeshParseError::InvalidToken {
+            line: self.line,
+            expected,
+            found: token.to_string(),
+        })
+    }
+
+    pub(crate) fn end(mut self) -> Result<(), MeshParseError> {
+        match self.tokens.next() {
+            Some(token) => Err(MeshParseError::UnexpectedToken {
+                line: self.line,
+                found: token.to_string(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Mesh {
+    /// Read a mesh from a file in the `.mesh` format of the original Polyanya implementation.
+    pub fn from_file(path: &str) -> Result<Mesh, MeshParseError> {
+        let file = std::fs::File::open(path)?;
+        let len = file.metadata().ok().map(|metadata| metadata.len() as usize);
+        Mesh::parse(io::BufReader::new(file), len)
+    }
+
+    /// Read a mesh in the `.mesh` format from any reader.
+    ///
+    /// The reader is buffered internally, there is no need to wrap it in a [`io::BufReader`].
+    pub fn from_reader(reader: impl Read) -> Result<Mesh, MeshParseError> {
+        Mesh::parse(io::BufReader::new(reader), None)
+    }
+
+    /// Read a mesh in the `.mesh` format from bytes, for example from [`include_bytes!`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Mesh, MeshParseError> {
+        Mesh::parse(bytes, Some(bytes.len()))
+    }
+
+    /// Parse a mesh, `len` being the length of the input if it's known.
+    fn parse(reader: impl BufRead, len: Option<usize>) -> Result<Mesh, MeshParseError> {
+        // the counts of the header are not trusted until the lines are read
+        let reserved = len.map_or(MAX_RESERVED, |len| len / MIN_LINE_LEN);
+        let mut vertices = vec![];
+        let mut polygons = vec![];
+        let mut counts = None;
+        for (index, line) in reader.lines().enumerate() {
+            let line = line?;
+            let mut tokens = Tokens::new(index + 1, &line);
+            let (nb_vertices, nb_polygons) = match counts {
+                None => {
+                    if line == "mesh" || line == "2" || line.trim().is_empty() {
+                        continue;
+                    }
+                    let nb_vertices = tokens.next("number of vertices")?;
+                    let nb_polygons = tokens.next("number of polygons")?;
+                    tokens.end()?;
+                    vertices.reserve(reserved.min(nb_vertices));
+                    polygons.reserve(reserved.min(nb_polygons));
+                    counts = Some((nb_vertices, nb_polygons));
+                    continue;
+                }
+                Some(counts) => counts,
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            if vertices.len() < nb_vertices {
+                let x = tokens.next("vertex x coordinate")?;
+                let y = tokens.next("vertex y coordinate")?;
+                let nb: usize = tokens.next("number of polygons around vertex")?;
+                let around: Vec<isize> = (0..nb)
+                    .map(|_| tokens.next("polygon around vertex"))
+                    .collect::<Result<_, _>>()?;
+                tokens.end()?;
+                if let Some(polygon) = around
+                    .iter()
+                    .find(|polygon| !(-1..nb_polygons as isize).contains(polygon))
+                {
+                    return Err(MeshBuildError::PolygonOutOfRange {
+                        vertex: vertices.len(),
+                        polygon: *polygon,
+                    }
+                    .into());
+                }
+                vertices.push(Vertex::new(x, y, around));
+            } else if polygons.len() < nb_polygons {
+                let nb: usize = tokens.next("number of vertices of polygon")?;
+                let mut data = Vec::with_capacity(nb.min(line.len()) * 2);
+                for _ in 0..nb {
+                    let vertex: usize = tokens.next("polygon vertex")?;
+                    data.push(vertex as isize);
+                }
+                for _ in 0..nb {
+                    data.push(tokens.next("polygon neighbour")?);
+                }
+                tokens.end()?;
+                polygons.push(Polygon::new(nb, data));
+            } else {
+                return Err(MeshParseError::UnexpectedLine { line: index + 1 });
+            }
+        }
+        match counts {
+            None => Err(MeshParseError::UnexpectedEof {
+                expected: "number of vertices and polygons",
+            }),
+            Some((nb_vertices, _)) if vertices.len() < nb_vertices => {
+                Err(MeshParseError::UnexpectedEof { expected: "vertex" })
+            }
+            Some((_, nb_polygons)) if polygons.len() < nb_polygons => {
+                Err(MeshParseError::UnexpectedEof {
+                    expected: "polygon",
+                })
+            }
+            Some(_) => {
+                check_mesh(&vertices, &polygons)?;
+                let mut mesh = Mesh::new(vertices, polygons);
+                mesh.split_concave_polygons();
+                Ok(mesh)
+            }
+        }
+    }
+}
+
+/// Check that the vertices and polygons read can make a mesh.
+fn check_mesh(vertices: &[Vertex], polygons: &[Polygon]) -> Result<(), MeshBuildError> {
+    for (index, vertex) in vertices.iter().enumerate() {
+        if !vertex.x.is_finite() || !vertex.y.is_finite() {
+            return Err(MeshBuildError::NonFiniteVertex { vertex: index });
+        }
+    }
+    for (index, polygon) in polygons.iter().enumerate() {
+        check_polygon(index, &polygon.vertices, vertices.len())?;
+    }
+    Ok(())
+}
+
+impl FromStr for Mesh {
+    type Err = MeshParseError;
+
+    /// Read a mesh in the `.mesh` format from a string.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Mesh::from_bytes(s.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeshParseError;
+    use crate::{Mesh, MeshBuildError, PolygonId};
+
+    fn parse(content: &str) -> Result<Mesh, MeshParseError> {
+        content.parse()
+    }
+
+    #[test]
+    fn parse_valid() {
+        let mesh = parse("mesh\n2\n3 1\n0 0 1 0\n1 0 1 0\n0 1 1 0\n3 0 1 2 -1 -1 -1\n").unwrap();
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.polygons.len(), 1);
+        assert_eq!(mesh.polygons[0].vertices, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn parse_float_coordinates() {
+        let mesh =
+            parse("mesh\n2\n3 1\n-0.5 -1 1 0\n1.25 -1 1 0\n0 2.5e-1 1 0\n3 0 1 2 -1 -1 -1\n")
+                .unwrap();
+        assert_eq!(mesh.vertices[0].x, -0.5);
+        assert_eq!(mesh.vertices[1].x, 1.25);
+        assert_eq!(mesh.vertices[2].y, 0.25);
+        assert_eq!(mesh.point_in_polygon([0.0, -0.5]), Some(PolygonId::new(0)));
+    }
+
+    #[test]
+    fn parse_concave() {
+        // an L shaped polygon is split in two
+        let mesh = parse(
+            "mesh\n2\n6 1\n0 0 1 0\n2 0 1 0\n2 1 1 0\n1 1 1 0\n1 2 1 0\n0 2 1 0\n\
+             6 0 1 2 3 4 5 -1 -1 -1 -1 -1 -1\n",
+        )
+        .unwrap();
+        assert_eq!(mesh.polygons.len(), 2);
+        assert!(mesh.validate().is_empty());
+        assert_eq!(
+            mesh.path([1.8, 0.5], [0.5, 1.8]).unwrap().path,
+            vec![[1.0, 1.0], [0.5, 1.8]]
+        );
+    }
+
+    #[test]
+    fn from_bytes_and_reader() {
+        let bytes = include_bytes!("../meshes/arena-merged.mesh");
+        let from_bytes = Mesh::from_bytes(bytes).unwrap();
+        let from_reader = Mesh::from_reader(std::io::Cursor::new(bytes)).unwrap();
+        let from_file = Mesh::from_file("meshes/arena-merged.mesh").unwrap();
+        assert_eq!(from_bytes.polygons.len(), from_file.polygons.len());
+        assert_eq!(from_reader.vertices.len(), from_file.vertices.len());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(matches!(
+            parse("mesh\n2\n3 1\n0 0 1 0\n1 x 1 0\n"),
+            Err(MeshParseError::InvalidToken {
+                line: 5,
+                expected: "vertex y coordinate",
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse("mesh\n2\n1 1\n0 0 1 0\n3 0 1 2 -1 -1\n"),
+            Err(MeshParseError::MissingToken {
+                line: 5,
+                expected: "polygon neighbour",
+            })
+        ));
+        assert!(matches!(
+            parse("mesh\n2\n1 0\n0 0 1 0 4\n"),
+            Err(MeshParseError::UnexpectedToken { line: 4, .. })
+        ));
+        assert!(matches!(
+            parse("mesh\n2\n1 0\n0 0 0\n0 0 0\n"),
+            Err(MeshParseError::UnexpectedLine { line: 5 })
+        ));
+        assert!(matches!(
+            parse("mesh\n2\n2 0\n0 0 0\n"),
+            Err(MeshParseError::UnexpectedEof { expected: "vertex" })
+        ));
+        assert!(matches!(
+            parse("mesh\n2\n3 1\n0 0 1 0\n1 0 1 0\n0 1 1 0\n3 0 1 -2 -1 -1 -1\n"),
+            Err(MeshParseError::InvalidToken {
+                line: 7,
+                expected: "polygon vertex",
+                ..
+            })
+        ));
+        assert!(matches!(
+            Mesh::from_file("meshes/missing.mesh"),
+            Err(MeshParseError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn parse_invalid_meshes() {
+        let invalid = |content: &str| match parse(content) {
+            Err(MeshParseError::InvalidMesh(error)) => error,
+            result => panic!("{:?}", result.map(|mesh| mesh.polygons.len())),
+        };
+        let vertices = "mesh\n2\n3 1\n0 0 1 0\n1 0 1 0\n0 1 1 0\n";
+        assert_eq!(
+            invalid(&format!("{}3 0 1 99 -1 -1 -1\n", vertices)),
+            MeshBuildError::VertexOutOfRange {
+                polygon: 0,
+                vertex: 99
+            }
+        );
+        assert_eq!(
+            invalid(&format!("{}0\n", vertices)),
+            MeshBuildError::TooFewVertices { polygon: 0 }
+        );
+        assert_eq!(
+            invalid(&format!("{}2 0 1 -1 -1\n", vertices)),
+            MeshBuildError::TooFewVertices { polygon: 0 }
+        );
+        assert_eq!(
+            invalid(&format!("{}3 0 1 1 -1 -1 -1\n", vertices)),
+            MeshBuildError::DuplicateVertex {
+                polygon: 0,
+                vertex: 1
+            }
+        );
+        assert_eq!(
+            invalid("mesh\n2\n3 1\n0 0 1 0\n1 0 1 7\n0 1 1 0\n3 0 1 2 -1 -1 -1\n"),
+            MeshBuildError::PolygonOutOfRange {
+                vertex: 1,
+                polygon: 7
+            }
+        );
+        assert_eq!(
+            invalid("mesh\n2\n3 1\n0 0 1 0\n1 NaN 1 0\n0 1 1 0\n3 0 1 2 -1 -1 -1\n"),
+            MeshBuildError::NonFiniteVertex { vertex: 1 }
+        );
+        // counts larger than the file are not allocated
+        assert!(matches!(
+            parse("mesh\n2\n1000000000000 1000000000000\n0 0 0\n"),
+            Err(MeshParseError::UnexpectedEof { expected: "vertex" })
+        ));
+    }
+}
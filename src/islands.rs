@@ -0,0 +1,262 @@
+use crate::{counted, BudgetedPath, Mesh, PolygonId};
+
+/// A connected part of a mesh, as listed by [`Mesh::islands`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Island {
+    /// Identifier of the island, as returned by [`Mesh::island_of`].
+    pub id: usize,
+    /// Polygons of the island.
+    pub polygons: Vec<PolygonId>,
+    /// Total area of its polygons.
+    pub area: f32,
+}
+
+impl Mesh {
+    /// Check if there may be a path from `from` to `to`, without searching for it: both points
+    /// are in the mesh, in parts of it connected by edges that are not closed.
+    ///
+    /// A `false` means there is no path, and is known in constant time once the points are
+    /// located, while a search would go through the whole part of the mesh around `from` before
+    /// giving up. One-way edges connect both their sides, so a `true` doesn't guarantee a path
+    /// when some edges are one-way.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // two rooms separated by a wall
+    /// let mesh = Mesh::from_grid(10, 10, |x, _| x != 5);
+    /// assert!(mesh.is_reachable([1.0, 1.0], [4.0, 8.0]));
+    /// assert!(!mesh.is_reachable([1.0, 1.0], [8.0, 8.0]));
+    /// ```
+    pub fn is_reachable(&self, from: [f32; 2], to: [f32; 2]) -> bool {
+        match (self.point_in_polygon(from), self.point_in_polygon(to)) {
+            (Some(from), Some(to)) => self.same_island(from, to),
+            _ => false,
+        }
+    }
+
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], or if `to` can't be
+    /// reached, the shortest path to the point closest to it that can.
+    ///
+    /// The path is not complete when it stops short of `to`, which can be in another part of the
+    /// mesh, or outside of it. Returns `None` if `from` is not in the mesh, or if no point closer
+    /// to `to` can be reached.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // two rooms separated by a wall
+    /// let mesh = Mesh::from_grid(10, 10, |x, _| x != 5);
+    /// let result = mesh.path_or_closest([1.0, 1.0], [8.0, 3.0]).unwrap();
+    /// assert!(!result.complete);
+    /// assert_eq!(result.path.path.last(), Some(&[5.0, 3.0]));
+    /// ```
+    pub fn path_or_closest(&self, from: [f32; 2], to: [f32; 2]) -> Option<BudgetedPath> {
+        counted(Option::is_none, || {
+            let start = self.point_in_polygon(from)?;
+            if let Some(path) = self.path(from, to) {
+                return Some(BudgetedPath {
+                    path,
+                    complete: true,
+                });
+            }
+            let island = self.polygon_island(start)?;
+            if self.island_of(to) == Some(island) {
+                // in the same part of the mesh, but behind one-way edges
+                return None;
+            }
+            // the island doesn't contain `to`: its closest point is on an edge
+            let (closest, _) =
+                self.closest_on_edges(to, |polygon| self.islands[polygon] == island)?;
+            Some(BudgetedPath {
+                path: self.path(from, closest)?,
+                complete: false,
+            })
+        })
+    }
+
+    /// Identifier of the connected part of the mesh a point is in, or `None` if it's not in the
+    /// mesh.
+    ///
+    /// Points with the same identifier are connected by edges that are not closed. Identifiers
+    /// go from 0 to the number of islands, and change when the mesh is edited.
+    pub fn island_of(&self, point: [f32; 2]) -> Option<usize> {
+        self.polygon_island(self.point_in_polygon(point)?)
+    }
+
+    /// Identifier of the connected part of the mesh a polygon is in, or `None` if it's not in the
+    /// mesh.
+    pub fn polygon_island(&self, polygon: PolygonId) -> Option<usize> {
+        self.islands.get(polygon.index()).copied()
+    }
+
+    /// Iterate over the connected parts of the mesh, by identifier.
+    ///
+    /// A mesh should usually have a single island, or one per area that agents are not meant to
+    /// leave: other small islands are often regions disconnected by mistake.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // two small rooms closed off by walls
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 8 && !(x == 9 && y == 2));
+    /// let islands: Vec<_> = mesh.islands().collect();
+    /// assert_eq!(islands.len(), 3);
+    ///
+    /// let small = islands.iter().min_by(|a, b| a.area.total_cmp(&b.area)).unwrap();
+    /// assert_eq!(small.area, 2.0);
+    /// assert_eq!(mesh.island_of([9.5, 0.5]), Some(small.id));
+    /// ```
+    pub fn islands(&self) -> impl Iterator<Item = Island> {
+        let count = self.islands.iter().max().map_or(0, |last| last + 1);
+        let mut islands: Vec<Island> = (0..count)
+            .map(|id| Island {
+                id,
+                polygons: vec![],
+                area: 0.0,
+            })
+            .collect();
+        for index in 0..self.polygons.len() {
+            let island = &mut islands[self.islands[index]];
+            island.polygons.push(PolygonId::new(index));
+            island.area += self.polygon_area(index);
+        }
+        islands.into_iter()
+    }
+
+    /// Check if two polygons are in the same connected part of the mesh.
+    pub(crate) fn same_island(&self, from: PolygonId, to: PolygonId) -> bool {
+        self.islands.get(from.index()) == self.islands.get(to.index())
+    }
+
+    /// Label the connected parts of the mesh again, after polygons or closed edges changed.
+    pub(crate) fn update_islands(&mut self) {
+        let mut islands = vec![usize::MAX; self.polygons.len()];
+        let mut next = 0;
+        let mut stack = vec![];
+        for first in 0..self.polygons.len() {
+            if islands[first] != usize::MAX {
+                continue;
+            }
+            islands[first] = next;
+            stack.push(first);
+            while let Some(polygon) = stack.pop() {
+                for edge in self.polygons[polygon].edges_index() {
+                    if self.is_edge_closed(edge) {
+                        continue;
+                    }
+                    for neighbour in self.polygons_of_edge(edge) {
+                        if islands[neighbour.index()] == usize::MAX {
+                            islands[neighbour.index()] = next;
+                            stack.push(neighbour.index());
+                        }
+                    }
+                }
+            }
+            next += 1;
+        }
+        self.islands = islands;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{grid_mesh, maze, random_obstacles, test_points},
+        Mesh, PolygonId,
+    };
+
+    #[test]
+    fn reachable() {
+        let mut mesh = grid_mesh(3, 3, |x, y| !(x == 1 && y < 2));
+        assert!(mesh.is_reachable([0.5, 0.5], [2.5, 0.5]));
+        assert!(!mesh.is_reachable([0.5, 0.5], [-1.0, 0.5]));
+
+        // closing the door at the top of the wall splits the mesh in two
+        let door = mesh
+            .edge_between(
+                mesh.point_in_polygon([1.5, 2.5]).unwrap(),
+                mesh.point_in_polygon([0.5, 2.5]).unwrap(),
+            )
+            .unwrap();
+        mesh.set_edge_open(door, false);
+        assert!(!mesh.is_reachable([0.5, 0.5], [2.5, 0.5]));
+        assert!(mesh.path([0.5, 0.5], [2.5, 0.5]).is_none());
+        mesh.set_edge_open(door, true);
+        assert!(mesh.is_reachable([0.5, 0.5], [2.5, 0.5]));
+
+        for mesh in [maze(8, 8, 5), random_obstacles(30, 30, 40, 5, 2)] {
+            for (i, from) in test_points(60, [0.5, 0.5]).enumerate() {
+                let to = [(i * 11 % 30) as f32 + 0.5, (i * 5 % 30) as f32 + 0.5];
+                assert_eq!(mesh.is_reachable(from, to), mesh.path(from, to).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn islands() {
+        let mut mesh = grid_mesh(3, 3, |x, y| !(x == 1 && y < 2));
+        assert_eq!(mesh.islands().count(), 1);
+        let island = mesh.islands().next().unwrap();
+        assert_eq!(island.polygons.len(), mesh.polygons.len());
+        assert!((island.area - 7.0).abs() < 1.0e-5);
+
+        let left = mesh.point_in_polygon([0.5, 2.5]).unwrap();
+        let door = mesh
+            .edge_between(mesh.point_in_polygon([1.5, 2.5]).unwrap(), left)
+            .unwrap();
+        mesh.set_edge_open(door, false);
+        let islands: Vec<_> = mesh.islands().collect();
+        assert_eq!(islands.len(), 2);
+        assert!((islands.iter().map(|island| island.area).sum::<f32>() - 7.0).abs() < 1.0e-5);
+        for island in &islands {
+            for polygon in &island.polygons {
+                assert_eq!(mesh.polygon_island(*polygon), Some(island.id));
+            }
+        }
+        assert_ne!(mesh.island_of([0.5, 0.5]), mesh.island_of([2.5, 0.5]));
+        assert_eq!(mesh.island_of([0.5, 0.5]), mesh.polygon_island(left));
+        assert_eq!(mesh.island_of([-1.0, 0.5]), None);
+        assert_eq!(mesh.polygon_island(PolygonId::new(1000)), None);
+    }
+
+    #[test]
+    fn closest_when_unreachable() {
+        let mesh = grid_mesh(5, 3, |x, y| x != 2 && !(x == 0 && y == 1));
+        let (from, to) = ([0.5, 0.5], [1.5, 2.5]);
+        let result = mesh.path_or_closest(from, to).unwrap();
+        assert!(result.complete);
+        assert_eq!(Some(result.path), mesh.path(from, to));
+
+        // on the other side of the wall, or outside of the mesh
+        for (to, closest) in [([4.5, 1.5], [2.0, 1.5]), ([-1.0, 5.0], [0.0, 3.0])] {
+            let result = mesh.path_or_closest(from, to).unwrap();
+            assert!(!result.complete);
+            assert_eq!(Some(result.path), mesh.path(from, closest));
+        }
+        assert!(mesh.path_or_closest([-1.0, 0.5], to).is_none());
+    }
+
+    #[test]
+    fn one_way_and_empty() {
+        let mut mesh = grid_mesh(3, 3, |x, y| !(x == 1 && y < 2));
+        let door = mesh
+            .edge_between(
+                mesh.point_in_polygon([1.5, 2.5]).unwrap(),
+                mesh.point_in_polygon([0.5, 2.5]).unwrap(),
+            )
+            .unwrap();
+        // from the top of the wall to the left side only
+        assert!(mesh.set_edge_one_way(door, true));
+        let (from, to) = ([0.5, 0.5], [2.5, 0.5]);
+        assert!(mesh.path(from, to).is_none());
+        // one-way edges still connect their sides
+        assert_eq!(mesh.islands().count(), 1);
+        assert!(mesh.is_reachable(from, to));
+        assert!(mesh.path_or_closest(from, to).is_none());
+        assert!(mesh.path_or_closest(to, from).unwrap().complete);
+
+        let empty = Mesh::default();
+        assert_eq!(empty.islands().count(), 0);
+        assert!(!empty.is_reachable([0.0, 0.0], [0.0, 0.0]));
+        assert_eq!(empty.island_of([0.0, 0.0]), None);
+        assert!(empty.path_or_closest([0.0, 0.0], [1.0, 0.0]).is_none());
+    }
+}
@@ -0,0 +1,211 @@
+//! Import of [LDtk](https://ldtk.io) projects, enabled with the `ldtk` feature.
+//!
+//! The walkable cells of an IntGrid layer become square polygons, building a mesh for each level.
+//! Meshes use the pixel coordinates of their level, with `y` going down like in LDtk.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{maps::scaled_grid_mesh, Mesh};
+
+/// Error returned when an LDtk project can't be imported.
+#[derive(Debug)]
+pub enum LdtkError {
+    /// The project is not valid JSON.
+    Json(serde_json::Error),
+    /// A field is missing or has an unexpected type.
+    InvalidField { field: &'static str },
+    /// The layers of a level are not in the project, but in a separate file.
+    ExternalLevel { level: String },
+    /// A level has no IntGrid layer with the requested name.
+    UnknownLayer { level: String, layer: String },
+}
+
+impl Display for LdtkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LdtkError::Json(error) => write!(f, "invalid ldtk project: {}", error),
+            LdtkError::InvalidField { field } => write!(f, "missing or invalid field {}", field),
+            LdtkError::ExternalLevel { level } => {
+                write!(f, "level {} is saved in a separate file", level)
+            }
+            LdtkError::UnknownLayer { level, layer } => {
+                write!(f, "level {} has no IntGrid layer named {}", level, layer)
+            }
+        }
+    }
+}
+
+impl Error for LdtkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            LdtkError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for LdtkError {
+    fn from(error: serde_json::Error) -> Self {
+        LdtkError::Json(error)
+    }
+}
+
+/// The mesh of a level of an LDtk project.
+#[derive(Debug, Clone)]
+pub struct LdtkLevel {
+    /// Identifier of the level in the project.
+    pub identifier: String,
+    /// Position of the level in the world, in pixels.
+    pub world_position: [f32; 2],
+    /// Mesh of the walkable cells of the level, in pixels relative to the level.
+    pub mesh: Mesh,
+}
+
+fn field<'a>(value: &'a Value, field: &'static str) -> Result<&'a Value, LdtkError> {
+    value.get(field).ok_or(LdtkError::InvalidField { field })
+}
+
+fn number(value: &Value, name: &'static str) -> Result<i64, LdtkError> {
+    field(value, name)?
+        .as_i64()
+        .ok_or(LdtkError::InvalidField { field: name })
+}
+
+/// Build a mesh for each level of a project, from the IntGrid layer named `layer`.
+///
+/// A cell is walkable when `walkable` returns `true` for its value, `0` being an empty cell.
+pub fn levels(
+    project: &str,
+    layer: &str,
+    walkable: impl Fn(i64) -> bool,
+) -> Result<Vec<LdtkLevel>, LdtkError> {
+    let project: Value = serde_json::from_str(project)?;
+    let levels = field(&project, "levels")?
+        .as_array()
+        .ok_or(LdtkError::InvalidField { field: "levels" })?;
+
+    levels
+        .iter()
+        .map(|level| {
+            let identifier = field(level, "identifier")?
+                .as_str()
+                .ok_or(LdtkError::InvalidField {
+                    field: "identifier",
+                })?
+                .to_string();
+            let layers = match field(level, "layerInstances")? {
+                Value::Array(layers) => layers,
+                Value::Null => return Err(LdtkError::ExternalLevel { level: identifier }),
+                _ => {
+                    return Err(LdtkError::InvalidField {
+                        field: "layerInstances",
+                    })
+                }
+            };
+            let instance = layers
+                .iter()
+                .find(|instance| {
+                    instance["__type"].as_str() == Some("IntGrid")
+                        && instance["__identifier"].as_str() == Some(layer)
+                })
+                .ok_or_else(|| LdtkError::UnknownLayer {
+                    level: identifier.clone(),
+                    layer: layer.to_string(),
+                })?;
+
+            let width = number(instance, "__cWid")?.max(0) as usize;
+            let height = number(instance, "__cHei")?.max(0) as usize;
+            let cell = number(instance, "__gridSize")? as f32;
+            let origin = [
+                number(instance, "__pxTotalOffsetX")? as f32,
+                number(instance, "__pxTotalOffsetY")? as f32,
+            ];
+            let values = field(instance, "intGridCsv")?
+                .as_array()
+                .ok_or(LdtkError::InvalidField {
+                    field: "intGridCsv",
+                })?
+                .iter()
+                .map(|value| {
+                    value.as_i64().ok_or(LdtkError::InvalidField {
+                        field: "intGridCsv",
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.len() != width * height {
+                return Err(LdtkError::InvalidField {
+                    field: "intGridCsv",
+                });
+            }
+
+            Ok(LdtkLevel {
+                world_position: [
+                    number(level, "worldX")? as f32,
+                    number(level, "worldY")? as f32,
+                ],
+                mesh: scaled_grid_mesh(width, height, origin, [cell, cell], |x, y| {
+                    walkable(values[y * width + x])
+                }),
+                identifier,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{levels, LdtkError};
+
+    const PROJECT: &str = r#"{
+ "jsonVersion": "1.5.3",
+ "levels": [
+  {"identifier": "Level_0", "worldX": 0, "worldY": 0, "layerInstances": [
+   {"__identifier": "Entities", "__type": "Entities", "entityInstances": []},
+   {"__identifier": "Walls", "__type": "IntGrid", "__cWid": 3, "__cHei": 2, "__gridSize": 8,
+    "__pxTotalOffsetX": 0, "__pxTotalOffsetY": 0, "intGridCsv": [0,1,0, 0,0,0]}
+  ]},
+  {"identifier": "Level_1", "worldX": 24, "worldY": 0, "layerInstances": [
+   {"__identifier": "Walls", "__type": "IntGrid", "__cWid": 2, "__cHei": 1, "__gridSize": 8,
+    "__pxTotalOffsetX": 4, "__pxTotalOffsetY": 0, "intGridCsv": [2,0]}
+  ]}
+ ]
+}"#;
+
+    #[test]
+    fn project() {
+        let levels = levels(PROJECT, "Walls", |value| value != 1).unwrap();
+        assert_eq!(levels.len(), 2);
+        assert_eq!(levels[0].identifier, "Level_0");
+        assert_eq!(levels[0].mesh.polygons.len(), 5);
+        assert!(!levels[0].mesh.point_in_mesh([12.0, 4.0]));
+        assert!(levels[0].mesh.path([4.0, 4.0], [20.0, 4.0]).unwrap().len > 19.0);
+        assert_eq!(levels[1].world_position, [24.0, 0.0]);
+        assert_eq!(levels[1].mesh.bounding_box().min, [4.0, 0.0]);
+        assert_eq!(levels[1].mesh.polygons.len(), 2);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            levels(PROJECT, "Ground", |value| value == 0),
+            Err(LdtkError::UnknownLayer { .. })
+        ));
+        assert!(matches!(
+            levels(
+                r#"{"levels": [{"identifier": "A", "layerInstances": null}]}"#,
+                "Walls",
+                |value| value == 0
+            ),
+            Err(LdtkError::ExternalLevel { .. })
+        ));
+        assert!(matches!(
+            levels("{}", "Walls", |value| value == 0),
+            Err(LdtkError::InvalidField { field: "levels" })
+        ));
+    }
+}
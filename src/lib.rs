@@ -3,34 +3,164 @@ use std::{
     collections::BinaryHeap,
     fmt::{self, Display},
     hash::Hash,
-    io,
-    io::BufRead,
+    sync::mpsc::Sender,
+    task::Poll,
 };
 
-use hashbrown::{hash_map::Entry, HashMap};
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+use hashbrown::{hash_map::Entry, HashMap, HashSet};
 use helpers::{distance_between, heuristic, on_side};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 #[cfg(feature = "tracing")]
 use tracing::instrument;
 
-use crate::helpers::{line_intersect_segment, on_segment, turning_on};
+use crate::{
+    grid::PolygonGrid,
+    helpers::{line_intersect_segment, on_segment, project_on_segment, turning_on},
+};
 
+mod aabb;
+mod alternatives;
+mod anchor;
+mod anytime;
+pub mod bake;
+mod binary;
+pub mod bitmap;
+mod budget;
+mod builder;
+mod carve;
+mod cdt;
+mod cleanup;
+mod clearance;
+mod closest;
+mod context;
+mod corridor;
+mod cost;
+#[cfg(feature = "counters")]
+pub mod counters;
+mod diff;
+mod distance;
+mod doors;
+mod edges;
+mod edit;
+mod events;
+mod field;
+mod filter;
+mod finder;
+mod flow;
+mod future;
+pub mod godot;
+#[cfg(feature = "gpu")]
+mod gpu;
+mod grid;
 mod helpers;
-
-#[derive(Debug)]
+mod ids;
+mod inflate;
+mod input;
+mod islands;
+#[cfg(feature = "ldtk")]
+pub mod ldtk;
+mod loader;
+pub mod maps;
+mod margin;
+mod matrix;
+mod medial;
+mod merge;
+mod moving;
+pub mod movingai;
+mod nearest;
+mod obstacles;
+mod output;
+mod path;
+mod polyline;
+mod progress;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+mod range;
+mod replan;
+mod sample;
+mod secondary;
+#[cfg(feature = "serde")]
+mod serde;
+mod sizes;
+mod slide;
+mod smooth;
+mod snap;
+mod snapshot;
+mod stitch;
+pub mod svg;
+#[cfg(feature = "tiled")]
+pub mod tiled;
+mod tiles;
+mod triangle;
+mod turns;
+pub mod unity;
+mod validate;
+mod visibility;
+mod waypoints;
+mod within;
+
+pub use aabb::Aabb;
+pub use anchor::AgentAnchor;
+pub use anytime::AnytimePath;
+pub use binary::MeshBinaryError;
+pub use budget::BudgetedPath;
+pub use builder::{MeshBuildError, MeshBuilder};
+pub use carve::CarveError;
+pub use context::SearchContext;
+pub use corridor::{CorridorPath, PathCorridor, Portal};
+pub use cost::CostModel;
+pub use diff::MeshDiff;
+pub use distance::DistanceField;
+pub use edges::EdgeId;
+pub use events::MeshChange;
+pub use field::TargetField;
+pub use filter::PathFilter;
+pub use finder::PathFinder;
+pub use flow::FlowField;
+pub use future::PathFuture;
+#[cfg(feature = "parallel")]
+pub use future::SpawnedPath;
+#[cfg(feature = "gpu")]
+pub use gpu::GpuLocator;
+pub use ids::{PolygonId, VertexId};
+pub use input::MeshParseError;
+pub use islands::Island;
+pub use loader::{register_loader, MeshLoadError, MeshLoader, MeshLoaders};
+pub use medial::MedialAxis;
+pub use moving::MovingTargetSearch;
+pub use obstacles::{ObstacleId, ObstacleLayer};
+pub use progress::{PathProgress, Progress};
+pub use range::ReachableArea;
+pub use replan::{PlanId, Replanner};
+pub use sizes::AgentSizes;
+pub use snap::{PointSnap, SnapPattern};
+pub use snapshot::{MeshSnapshot, SharedMesh};
+pub use tiles::TiledMesh;
+pub use validate::MeshIssue;
+pub use waypoints::WaypointPath;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Vertex {
     x: f32,
     y: f32,
-    polygons: Vec<isize>,
+    polygons: Vec<Option<PolygonId>>,
     is_corner: bool,
 }
 
 impl Vertex {
-    pub fn new(x: u32, y: u32, poly: Vec<isize>) -> Self {
+    /// Create a vertex from its position and the polygons around it, as in the `.mesh` format:
+    /// in counterclockwise order, with `-1` where there is no polygon.
+    pub fn new(x: f32, y: f32, poly: Vec<isize>) -> Self {
+        let polygons: Vec<_> = poly.into_iter().map(PolygonId::from_raw).collect();
         Vertex {
-            x: x as f32,
-            y: y as f32,
-            is_corner: poly.contains(&-1),
-            polygons: poly,
+            x,
+            y,
+            is_corner: polygons.contains(&None),
+            polygons,
         }
     }
 
@@ -38,6 +168,32 @@ impl Vertex {
     fn p(&self) -> [f32; 2] {
         [self.x, self.y]
     }
+
+    /// Horizontal coordinate of the vertex.
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+
+    /// Vertical coordinate of the vertex.
+    pub fn y(&self) -> f32 {
+        self.y
+    }
+
+    /// Position of the vertex.
+    pub fn position(&self) -> [f32; 2] {
+        self.p()
+    }
+
+    /// Polygons around the vertex in counterclockwise order, with `None` where there is no
+    /// polygon.
+    pub fn polygons(&self) -> &[Option<PolygonId>] {
+        &self.polygons
+    }
+
+    /// `true` if the vertex is on the boundary of the mesh.
+    pub fn is_corner(&self) -> bool {
+        self.is_corner
+    }
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -54,17 +210,23 @@ struct Successor {
     ty: SuccessorType,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Path {
     pub len: f32,
     pub path: Vec<[f32; 2]>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Polygon {
     vertices: Vec<usize>,
     // neighbours: Vec<isize>,
     is_one_way: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    flags: u32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    secondary_cost: f32,
 }
 
 impl Polygon {
@@ -89,9 +251,37 @@ impl Polygon {
             vertices,
             // neighbours,
             is_one_way,
+            flags: 0,
+            secondary_cost: 0.0,
         }
     }
 
+    /// Vertices of the polygon in counterclockwise order.
+    pub fn vertices(&self) -> impl ExactSizeIterator<Item = VertexId> + '_ {
+        self.vertices.iter().copied().map(VertexId::new)
+    }
+
+    /// Edges of the polygon in counterclockwise order.
+    pub fn edges(&self) -> impl ExactSizeIterator<Item = EdgeId> {
+        self.edges_index().into_iter()
+    }
+
+    /// `true` if the polygon has only one neighbour, so it can only be a dead end.
+    pub fn is_one_way(&self) -> bool {
+        self.is_one_way
+    }
+
+    /// Area flags of the polygon, set with [`Mesh::set_polygon_flags`].
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
+
+    /// Secondary cost of going through the polygon, set with
+    /// [`Mesh::set_polygon_secondary_cost`].
+    pub fn secondary_cost(&self) -> f32 {
+        self.secondary_cost
+    }
+
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     #[inline(always)]
     fn edges_index(&self) -> Vec<[usize; 2]> {
@@ -127,8 +317,49 @@ impl Polygon {
 
 #[derive(Debug, Default)]
 pub struct Mesh {
-    pub vertices: Vec<Vertex>,
-    pub polygons: Vec<Polygon>,
+    /// Vertices of the mesh. Call [`Mesh::rebuild_caches`] after changing them.
+    vertices: Vec<Vertex>,
+    /// Polygons of the mesh. Call [`Mesh::rebuild_caches`] after changing them.
+    polygons: Vec<Polygon>,
+    polygon_aabbs: Vec<Aabb>,
+    aabb: Aabb,
+    polygon_grid: PolygonGrid,
+    output_quantization: Option<f32>,
+    off_mesh_clamp: Option<f32>,
+    point_snap: Option<PointSnap>,
+    /// Shared edges closed with [`Mesh::set_edge_open`], with their smallest vertex first.
+    closed_edges: HashSet<EdgeId>,
+    /// Shared edges set with [`Mesh::set_edge_one_way`], in the direction they can be crossed.
+    one_way_edges: HashSet<EdgeId>,
+    /// Secondary costs set with [`Mesh::set_edge_secondary_cost`], with their smallest vertex
+    /// first.
+    secondary_edge_costs: HashMap<EdgeId, f32>,
+    /// Senders of the receivers returned by [`Mesh::subscribe`].
+    subscribers: Vec<Sender<MeshChange>>,
+    /// Connected part of the mesh each polygon is in, see [`Mesh::is_reachable`].
+    islands: Vec<usize>,
+}
+
+impl Clone for Mesh {
+    /// Clone the mesh without its subscribers: the receivers returned by [`Mesh::subscribe`] are
+    /// only notified of the changes of the mesh they subscribed to.
+    fn clone(&self) -> Self {
+        Mesh {
+            vertices: self.vertices.clone(),
+            polygons: self.polygons.clone(),
+            polygon_aabbs: self.polygon_aabbs.clone(),
+            aabb: self.aabb,
+            polygon_grid: self.polygon_grid.clone(),
+            output_quantization: self.output_quantization,
+            off_mesh_clamp: self.off_mesh_clamp,
+            point_snap: self.point_snap,
+            closed_edges: self.closed_edges.clone(),
+            one_way_edges: self.one_way_edges.clone(),
+            secondary_edge_costs: self.secondary_edge_costs.clone(),
+            subscribers: vec![],
+            islands: self.islands.clone(),
+        }
+    }
 }
 
 struct Root([f32; 2]);
@@ -144,67 +375,189 @@ impl Hash for Root {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
         ((self.0[0] * 10000.0) as i32).hash(state);
         ((self.0[1] * 10000.0) as i32).hash(state);
-        state.finish();
     }
 }
 
 impl Mesh {
-    pub fn from_file(path: &str) -> Mesh {
-        let file = std::fs::File::open(path).unwrap();
-        let mut mesh = Mesh::default();
-        let mut nb_vertices = 0;
-        let mut nb_polygons = 0;
-        let mut phase = 0;
-        for line in io::BufReader::new(file).lines() {
-            let line: String = line.unwrap();
-            if phase == 0 {
-                if line == "mesh" || line == "2" {
-                    continue;
-                } else {
-                    (nb_vertices, nb_polygons) = line
-                        .split_once(' ')
-                        .map(|(a, b)| (a.parse().unwrap(), b.parse().unwrap()))
-                        .unwrap();
-                    phase = 1;
-                    continue;
-                }
-            }
-            if phase == 1 {
-                if nb_vertices > 0 {
-                    nb_vertices -= 1;
-                    let mut values = line.split(' ');
-                    let x = values.next().unwrap().parse().unwrap();
-                    let y = values.next().unwrap().parse().unwrap();
-                    let _ = values.next();
-                    let vertex = Vertex::new(x, y, values.map(|v| v.parse().unwrap()).collect());
-                    mesh.vertices.push(vertex);
-                } else {
-                    phase = 2;
-                }
-            }
-            if phase == 2 {
-                if nb_polygons > 0 {
-                    nb_polygons -= 1;
-                    let mut values = line.split(' ');
-                    let n = values.next().unwrap().parse().unwrap();
-                    let polygon = Polygon::new(n, values.map(|v| v.parse().unwrap()).collect());
-                    mesh.polygons.push(polygon)
-                } else {
-                    panic!("unexpected line");
-                }
-            }
-        }
+    /// Create a mesh from its vertices and polygons, computing the bounding boxes and the grid
+    /// used to speed up point location, and the connected parts of the mesh.
+    pub fn new(vertices: Vec<Vertex>, polygons: Vec<Polygon>) -> Mesh {
+        let mut mesh = Mesh::without_caches(vertices, polygons);
+        mesh.rebuild_caches();
         mesh
     }
+
+    /// Create a mesh from its vertices and polygons, with the default settings and without its
+    /// bounding boxes, point location grid and connected parts.
+    pub(crate) fn without_caches(vertices: Vec<Vertex>, polygons: Vec<Polygon>) -> Mesh {
+        Mesh {
+            vertices,
+            polygons,
+            ..Mesh::default()
+        }
+    }
+
+    /// Compute again the bounding boxes, the grid used to speed up point location and the
+    /// connected parts of the mesh, after changing its vertices or polygons directly.
+    pub(crate) fn rebuild_caches(&mut self) {
+        self.polygon_aabbs = self
+            .polygons
+            .iter()
+            .map(|polygon| {
+                Aabb::from_points(polygon.vertices.iter().map(|v| self.vertices[*v].p()))
+            })
+            .collect();
+        self.aabb = self
+            .polygon_aabbs
+            .iter()
+            .fold(Aabb::EMPTY, |aabb, polygon| aabb.union(polygon));
+        self.polygon_grid = PolygonGrid::new(&self.polygon_aabbs, self.aabb);
+        self.update_islands();
+    }
+
+    /// Bounding box of all the polygons of the mesh.
+    pub fn bounding_box(&self) -> Aabb {
+        self.aabb
+    }
+
+    /// Vertices of the mesh, indexed by [`VertexId`].
+    ///
+    /// They can only be changed by the methods editing the mesh, like [`Mesh::add_polygon`] or
+    /// [`Mesh::carve`], which keep the bounding boxes and point location up to date.
+    pub fn vertices(&self) -> &[Vertex] {
+        &self.vertices
+    }
+
+    /// Polygons of the mesh, indexed by [`PolygonId`].
+    pub fn polygons(&self) -> &[Polygon] {
+        &self.polygons
+    }
+
+    /// Round the turning points of the paths found to a grid of size `grid`, or stop rounding them
+    /// with `None`.
+    ///
+    /// The length of the paths is computed from the rounded points. The start and the goal of a
+    /// path are never rounded.
+    pub fn set_output_quantization(&mut self, grid: Option<f32>) {
+        self.output_quantization = grid.filter(|grid| *grid > 0.0);
+    }
+
+    /// Size of the grid turning points are rounded to, if any.
+    pub fn output_quantization(&self) -> Option<f32> {
+        self.output_quantization
+    }
+
+    /// Bounding box of a polygon, or `None` if there is no polygon with this index.
+    pub fn polygon_bounding_box(&self, polygon: PolygonId) -> Option<Aabb> {
+        self.polygon_aabbs.get(polygon.index()).copied()
+    }
+
+    /// The vertex with this identifier, or `None` if there is none.
+    pub fn vertex(&self, vertex: VertexId) -> Option<&Vertex> {
+        self.vertices.get(vertex.index())
+    }
+
+    /// The polygon with this identifier, or `None` if there is none.
+    pub fn polygon(&self, polygon: PolygonId) -> Option<&Polygon> {
+        self.polygons.get(polygon.index())
+    }
+
+    /// Iterate over the vertices of the mesh with their identifier.
+    pub fn iter_vertices(&self) -> impl ExactSizeIterator<Item = (VertexId, &Vertex)> + '_ {
+        self.vertices
+            .iter()
+            .enumerate()
+            .map(|(i, vertex)| (VertexId::new(i), vertex))
+    }
+
+    /// Iterate over the polygons of the mesh with their identifier.
+    pub fn iter_polygons(&self) -> impl ExactSizeIterator<Item = (PolygonId, &Polygon)> + '_ {
+        self.polygons
+            .iter()
+            .enumerate()
+            .map(|(i, polygon)| (PolygonId::new(i), polygon))
+    }
+}
+
+/// Options of a search, on top of its endpoints.
+#[derive(Clone, Copy)]
+struct SearchOptions<'o> {
+    filter: Option<&'o PathFilter>,
+    model: Option<&'o (dyn CostModel + Sync)>,
+    /// Weight of the heuristic outside of the target polygon. Over `1.0`, the search expands
+    /// fewer nodes but finds paths up to this many times longer than the shortest one.
+    heuristic_weight: f32,
+    /// Number of nodes the search can expand before returning a partial path.
+    max_expanded: usize,
+    /// Goals of a search for the nearest of several points, with their polygon. The search ends
+    /// at the first one reached instead of its target.
+    goals: &'o [([f32; 2], PolygonId)],
+    /// Search the whole part of the mesh reachable from the start, without a target.
+    exhaustive: bool,
+    /// Longest path the search looks for: nodes that can't lead to a shorter one are pruned.
+    max_len: f32,
+    /// Keep the polygons each node went through since the start of the search.
+    corridor: bool,
+    /// Cost added to the length of the paths each time they turn.
+    turn_penalty: f32,
+    /// Weight of the secondary costs of the polygons and edges, added to the length of the paths.
+    secondary_weight: f32,
+}
+
+impl Default for SearchOptions<'_> {
+    fn default() -> Self {
+        SearchOptions {
+            filter: None,
+            model: None,
+            heuristic_weight: 1.0,
+            max_expanded: usize::MAX,
+            goals: &[],
+            exhaustive: false,
+            max_len: f32::INFINITY,
+            corridor: false,
+            turn_penalty: 0.0,
+            secondary_weight: 0.0,
+        }
+    }
+}
+
+impl SearchOptions<'_> {
+    /// Check if going through some polygons can cost more than their length.
+    #[inline(always)]
+    fn has_costs(&self) -> bool {
+        self.filter.is_some() || self.secondary_weight > 0.0
+    }
+
+    /// Cost of going through a polygon for each unit of length, or `None` if it's excluded by the
+    /// filter.
+    #[inline(always)]
+    fn polygon_cost(&self, id: PolygonId, polygon: &Polygon) -> Option<f32> {
+        let cost = match self.filter {
+            Some(filter) => filter.polygon_cost(id, polygon)?,
+            None => 1.0,
+        };
+        Some(cost + self.secondary_weight * polygon.secondary_cost)
+    }
 }
 
+/// Number of successors of a search node from which their nodes are built in parallel, when
+/// there is more than one thread.
+#[cfg(feature = "parallel")]
+const PARALLEL_SUCCESSORS: usize = 64;
+
+/// Number of successors whose nodes are built by each task, when they are built in parallel.
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK: usize = 16;
+
 struct SearchInstance<'m> {
     queue: BinaryHeap<SearchNode>,
     node_buffer: Vec<SearchNode>,
     root_history: HashMap<Root, f32>,
+    from: [f32; 2],
     to: [f32; 2],
-    polygon_to: isize,
+    polygon_to: Option<PolygonId>,
     mesh: &'m Mesh,
+    options: SearchOptions<'m>,
     #[cfg(feature = "stats")]
     pushed: usize,
     #[cfg(feature = "stats")]
@@ -219,27 +572,235 @@ struct SearchInstance<'m> {
     fail_fast: i32,
 }
 
+/// Run a query of the API, counted once with the `counters` feature even if it searches several
+/// times, and as a failure if `failed` is true for its result.
+#[inline(always)]
+pub(crate) fn counted<T>(failed: impl FnOnce(&T) -> bool, query: impl FnOnce() -> T) -> T {
+    #[cfg(feature = "counters")]
+    let counter = counters::Query::start();
+    let result = query();
+    #[cfg(feature = "counters")]
+    if failed(&result) {
+        counter.fail();
+    }
+    #[cfg(not(feature = "counters"))]
+    let _ = failed;
+    result
+}
+
 impl Mesh {
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    pub fn path(&self, from: [f32; 2], to: [f32; 2]) -> Path {
-        let starting_polygon_index = self.point_in_polygon(from);
-        let starting_polygon = self.polygons.get(starting_polygon_index).unwrap();
-        let ending_polygon = self.point_in_polygon(to);
-
-        if starting_polygon_index == ending_polygon {
-            return Path {
-                len: distance_between(from, to),
-                path: vec![to],
+    /// Compute the shortest path from `from` to `to`.
+    ///
+    /// Returns `None` if one of the points is not in the mesh, or if `to` can't be reached from
+    /// `from`. Points outside of the mesh can be moved onto it with [`Mesh::set_off_mesh_clamp`].
+    pub fn path(&self, from: [f32; 2], to: [f32; 2]) -> Option<Path> {
+        let (from, from_polygon) = self.locate_or_clamp(from);
+        let (to, to_polygon) = self.locate_or_clamp(to);
+        self.path_between_polygons(
+            from,
+            to,
+            from_polygon,
+            to_polygon,
+            SearchOptions::default(),
+            |_| (),
+        )
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    /// Compute the shortest path from `from` to `to`, starting point location from the polygons
+    /// they were last known to be in.
+    ///
+    /// Hints are checked with [`Mesh::locate_with_hint`], so they can be stale or `None`.
+    pub fn path_with_hint(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        from_hint: Option<PolygonId>,
+        to_hint: Option<PolygonId>,
+    ) -> Option<Path> {
+        self.path_between_polygons(
+            from,
+            to,
+            self.locate_with_hint(from, from_hint),
+            self.locate_with_hint(to, to_hint),
+            SearchOptions::default(),
+            |_| (),
+        )
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn path_between_polygons(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        starting_polygon_index: Option<PolygonId>,
+        ending_polygon: Option<PolygonId>,
+        options: SearchOptions,
+        on_expand: impl FnMut(&SearchNode),
+    ) -> Option<Path> {
+        self.search_between_polygons(
+            from,
+            to,
+            starting_polygon_index,
+            ending_polygon,
+            options,
+            on_expand,
+        )
+        .map(|result| result.path)
+    }
+
+    /// Search a path like [`Mesh::path_between_polygons`], returning a partial path if the
+    /// search expands more nodes than allowed by its options.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn search_between_polygons(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        starting_polygon_index: Option<PolygonId>,
+        ending_polygon: Option<PolygonId>,
+        options: SearchOptions,
+        mut on_expand: impl FnMut(&SearchNode),
+    ) -> Option<BudgetedPath> {
+        counted(Option::is_none, || {
+            let mut search = match SearchInstance::start(
+                self,
+                &mut SearchContext::new(),
+                from,
+                to,
+                starting_polygon_index,
+                ending_polygon,
+                options,
+            ) {
+                Ok(search) => search,
+                Err(result) => return result,
             };
-        }
+            match search.expand(options.max_expanded, &mut on_expand) {
+                Poll::Ready(result) => result,
+                Poll::Pending => search
+                    .queue
+                    .peek()
+                    .map(|best| search.path_to_node(best, false)),
+            }
+        })
+    }
 
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[cfg(test)]
+    fn successors(&self, node: SearchNode, to: [f32; 2]) -> Vec<SearchNode> {
         let mut search_instance = SearchInstance {
-            queue: BinaryHeap::with_capacity(15),
-            node_buffer: Vec::with_capacity(10),
-            root_history: HashMap::with_capacity(10),
+            queue: BinaryHeap::new(),
+            node_buffer: Vec::new(),
+            root_history: HashMap::new(),
+            from: node.r,
             to,
-            polygon_to: ending_polygon as isize,
+            polygon_to: self.point_in_polygon(to),
             mesh: self,
+            options: SearchOptions::default(),
+            #[cfg(feature = "stats")]
+            pushed: 0,
+            #[cfg(feature = "stats")]
+            popped: 0,
+            #[cfg(feature = "stats")]
+            successors_called: 0,
+            #[cfg(feature = "stats")]
+            nodes_generated: 0,
+            #[cfg(debug_assertions)]
+            debug: false,
+            #[cfg(debug_assertions)]
+            fail_fast: -1,
+        };
+        search_instance.successors(node);
+        search_instance.queue.drain().collect()
+    }
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[cfg(test)]
+    fn edges_between(&self, node: &SearchNode) -> Vec<Successor> {
+        let search_instance = SearchInstance {
+            queue: BinaryHeap::new(),
+            node_buffer: Vec::new(),
+            root_history: HashMap::new(),
+            from: node.r,
+            to: [0.0, 0.0],
+            polygon_to: self.point_in_polygon([0.0, 0.0]),
+            mesh: self,
+            options: SearchOptions::default(),
+            #[cfg(feature = "stats")]
+            pushed: 0,
+            #[cfg(feature = "stats")]
+            popped: 0,
+            #[cfg(feature = "stats")]
+            successors_called: 0,
+            #[cfg(feature = "stats")]
+            nodes_generated: 0,
+            #[cfg(debug_assertions)]
+            debug: false,
+            #[cfg(debug_assertions)]
+            fail_fast: -1,
+        };
+        search_instance.edges_between(node)
+    }
+}
+
+impl<'m> SearchInstance<'m> {
+    /// Start a search from `from` to `to`, with the nodes of the starting polygon in its queue.
+    ///
+    /// Returns the result of the search instead if it's known without expanding nodes: there is
+    /// no path if a point is not in the mesh, and a straight one if they're in the same polygon.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn start(
+        mesh: &'m Mesh,
+        context: &mut SearchContext,
+        from: [f32; 2],
+        to: [f32; 2],
+        starting_polygon_index: Option<PolygonId>,
+        ending_polygon: Option<PolygonId>,
+        options: SearchOptions<'m>,
+    ) -> Result<Self, Option<BudgetedPath>> {
+        let starting_polygon_index = match starting_polygon_index {
+            // a target in another part of the mesh can't be reached: don't search all this part
+            Some(start) if ending_polygon.is_some_and(|end| !mesh.same_island(start, end)) => {
+                return Err(None);
+            }
+            Some(start)
+                if ending_polygon.is_some() || !options.goals.is_empty() || options.exhaustive =>
+            {
+                start
+            }
+            _ => {
+                return Err(None);
+            }
+        };
+
+        let starting_polygon = &mesh.polygons[starting_polygon_index.index()];
+        let Some(starting_cost) = options.polygon_cost(starting_polygon_index, starting_polygon)
+        else {
+            return Err(None);
+        };
+
+        if Some(starting_polygon_index) == ending_polygon {
+            let len = distance_between(from, to) * starting_cost;
+            if len > options.max_len {
+                return Err(None);
+            }
+            return Err(Some(BudgetedPath {
+                path: Path {
+                    len,
+                    path: vec![to],
+                },
+                complete: true,
+            }));
+        }
+
+        let mut search_instance = SearchInstance {
+            queue: std::mem::take(&mut context.queue),
+            node_buffer: std::mem::take(&mut context.node_buffer),
+            root_history: std::mem::take(&mut context.root_history),
+            from,
+            to,
+            polygon_to: ending_polygon,
+            mesh,
+            options,
             #[cfg(feature = "stats")]
             pushed: 0,
             #[cfg(feature = "stats")]
@@ -260,19 +821,27 @@ impl Mesh {
             r: from,
             i: [[0.0, 0.0], [0.0, 0.0]],
             i_index: [0, 0],
-            polygon_from: -1,
-            polygon_to: starting_polygon_index as isize,
+            polygon_from: None,
+            polygon_to: Some(starting_polygon_index),
             f: 0.0,
             g: 0.0,
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: if options.corridor {
+                vec![starting_polygon_index]
+            } else {
+                vec![]
+            },
         };
 
         for edge in starting_polygon.edges_index() {
-            let start = self.vertices.get(edge[0]).unwrap();
-            let end = self.vertices.get(edge[1]).unwrap();
+            let start = mesh.vertices.get(edge[0]).unwrap();
+            let end = mesh.vertices.get(edge[1]).unwrap();
 
-            let mut other_side = isize::MAX;
+            let mut other_side = None;
             for i in &start.polygons {
-                if *i != -1 && *i != starting_polygon_index as isize && end.polygons.contains(i) {
+                if i.is_some() && *i != Some(starting_polygon_index) && end.polygons.contains(i) {
                     other_side = *i;
                 }
             }
@@ -286,109 +855,117 @@ impl Mesh {
             );
         }
         search_instance.flush_nodes();
+        // goals in the starting polygon are reached in a straight line
+        for (goal, (point, polygon)) in options.goals.iter().enumerate() {
+            let g = distance_between(from, *point) * starting_cost;
+            if *polygon == starting_polygon_index && g <= options.max_len {
+                search_instance.queue.push(SearchNode {
+                    i: [from, from],
+                    g,
+                    goal: Some(goal),
+                    ..empty_node.clone()
+                });
+            }
+        }
+        Ok(search_instance)
+    }
+
+    /// Give the collections of the search back to a context, cleared for the next search.
+    fn finish(self, context: &mut SearchContext) {
+        let SearchInstance {
+            mut queue,
+            mut node_buffer,
+            mut root_history,
+            ..
+        } = self;
+        queue.clear();
+        node_buffer.clear();
+        root_history.clear();
+        *context = SearchContext {
+            queue,
+            node_buffer,
+            root_history,
+        };
+    }
 
-        while let Some(next) = search_instance.queue.pop() {
+    /// Expand up to `budget` nodes, stopping early when the target is reached or there is no
+    /// path. Returns `Poll::Pending` if the search isn't done after them, and can be called again
+    /// to continue it.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn expand(
+        &mut self,
+        budget: usize,
+        mut on_expand: impl FnMut(&SearchNode),
+    ) -> Poll<Option<BudgetedPath>> {
+        let mut expanded = 0;
+        while let Some(next) = self.queue.peek() {
+            // reaching the target doesn't need an expansion
+            let complete = next.goal.is_some()
+                || (self.polygon_to.is_some() && next.polygon_to == self.polygon_to);
+            if !complete && expanded == budget {
+                return Poll::Pending;
+            }
+            let next = self.queue.pop().unwrap();
             #[cfg(feature = "verbose")]
             println!("popped off: {}", next);
             #[cfg(feature = "stats")]
             {
-                search_instance.popped += 1;
+                self.popped += 1;
             }
-            if next.polygon_to == ending_polygon as isize {
+            on_expand(&next);
+            if complete {
                 #[cfg(feature = "stats")]
                 eprintln!(
                     "{:?} / {:?} / {:?} / {:?}",
-                    search_instance.successors_called,
-                    search_instance.nodes_generated,
-                    search_instance.pushed,
-                    search_instance.popped
+                    self.successors_called, self.nodes_generated, self.pushed, self.popped
                 );
-                let mut path = next
-                    .path
-                    .split_first()
-                    .map(|(_, p)| p)
-                    .unwrap_or(&[])
-                    .to_vec();
-                if next.r != from {
-                    path.push(next.r);
-                }
-                if let Some(turn) = turning_on(next.r, to, next.i) {
-                    path.push(turn);
-                }
-                path.push(to);
-                return Path {
-                    path,
-                    len: next.f + next.g,
-                };
+                return Poll::Ready(Some(self.path_to_node(&next, true)));
             }
-            search_instance.successors(next);
-        }
-        Path {
-            path: vec![],
-            len: -1.0,
+            expanded += 1;
+            #[cfg(feature = "counters")]
+            counters::node_expanded();
+            self.successors(next);
         }
+        Poll::Ready(None)
     }
 
-    #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    #[cfg(test)]
-    fn successors(&self, node: SearchNode, to: [f32; 2]) -> Vec<SearchNode> {
-        let mut search_instance = SearchInstance {
-            queue: BinaryHeap::new(),
-            node_buffer: Vec::new(),
-            root_history: HashMap::new(),
-            to,
-            polygon_to: self.point_in_polygon(to) as isize,
-            mesh: self,
-            #[cfg(feature = "stats")]
-            pushed: 0,
-            #[cfg(feature = "stats")]
-            popped: 0,
-            #[cfg(feature = "stats")]
-            successors_called: 0,
-            #[cfg(feature = "stats")]
-            nodes_generated: 0,
-            #[cfg(debug_assertions)]
-            debug: false,
-            #[cfg(debug_assertions)]
-            fail_fast: -1,
+    /// Path from the start of the search through a node: to the target if it's `complete`, or
+    /// else to the closest point to the target seen from the root of the node.
+    fn path_to_node(&self, node: &SearchNode, complete: bool) -> BudgetedPath {
+        let to = node.goal.map_or(self.to, |goal| self.options.goals[goal].0);
+        let mut path = node
+            .path
+            .split_first()
+            .map(|(_, p)| p)
+            .unwrap_or(&[])
+            .to_vec();
+        if node.r != self.from {
+            path.push(node.r);
+        }
+        let len = if complete {
+            if let Some(turn) = turning_on(node.r, to, node.i) {
+                path.push(turn);
+            }
+            path.push(to);
+            node.f + node.penalty + node.g
+        } else {
+            let closest = project_on_segment(to, node.i);
+            path.push(closest);
+            node.f + node.penalty + distance_between(node.r, closest) * node.weight
         };
-        search_instance.successors(node);
-        search_instance.queue.drain().collect()
-    }
-    #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    #[cfg(test)]
-    fn edges_between(&self, node: &SearchNode) -> Vec<Successor> {
-        let search_instance = SearchInstance {
-            queue: BinaryHeap::new(),
-            node_buffer: Vec::new(),
-            root_history: HashMap::new(),
-            to: [0.0, 0.0],
-            polygon_to: self.point_in_polygon([0.0, 0.0]) as isize,
-            mesh: self,
-            #[cfg(feature = "stats")]
-            pushed: 0,
-            #[cfg(feature = "stats")]
-            popped: 0,
-            #[cfg(feature = "stats")]
-            successors_called: 0,
-            #[cfg(feature = "stats")]
-            nodes_generated: 0,
-            #[cfg(debug_assertions)]
-            debug: false,
-            #[cfg(debug_assertions)]
-            fail_fast: -1,
+        let path = match self.mesh.output_quantization {
+            Some(grid) => Path::quantized(self.from, path, grid),
+            None => Path { path, len },
         };
-        search_instance.edges_between(node)
+        BudgetedPath { path, complete }
     }
-}
 
-impl<'m> SearchInstance<'m> {
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     #[inline(always)]
     fn edges_between(&self, node: &SearchNode) -> Vec<Successor> {
         let mut successors = vec![];
 
-        let polygon = self.mesh.polygons.get(node.polygon_to as usize).unwrap();
+        let polygon = &self.mesh.polygons[node.polygon_to.unwrap().index()];
 
         if distance_between(node.i[0], node.r) < 1.0e-5
             || distance_between(node.i[1], node.r) < 1.0e-5
@@ -543,7 +1120,7 @@ impl<'m> SearchInstance<'m> {
     fn add_node(
         &mut self,
         root: [f32; 2],
-        other_side: isize,
+        other_side: Option<PolygonId>,
         start: ([f32; 2], usize),
         end: ([f32; 2], usize),
         node: &SearchNode,
@@ -552,48 +1129,165 @@ impl<'m> SearchInstance<'m> {
         {
             self.nodes_generated += 1;
         }
+        if let Some((new_node, cost)) = self.new_node(root, other_side, start, end, node) {
+            self.insert_node(new_node, cost, node);
+        }
+    }
+
+    /// Build the node going from `node` through an edge, or `None` if it's pruned, with the cost
+    /// of the polygon it goes to. This doesn't change the search, so nodes can be built in
+    /// parallel and then inserted in order with [`SearchInstance::insert_node`].
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[inline(always)]
+    fn new_node(
+        &self,
+        root: [f32; 2],
+        other_side: Option<PolygonId>,
+        start: ([f32; 2], usize),
+        end: ([f32; 2], usize),
+        node: &SearchNode,
+    ) -> Option<(SearchNode, f32)> {
         // prune edges that don't have a polygon on the other side: cul de sac pruning
-        if other_side == isize::MAX {
-            #[cfg(debug_assertions)]
-            if self.debug {
-                println!("x cul de sac");
+        let other_side = match other_side.filter(|_| self.mesh.can_cross([start.1, end.1])) {
+            Some(other_side) => other_side,
+            None => {
+                #[cfg(debug_assertions)]
+                if self.debug {
+                    println!("x cul de sac");
+                }
+
+                return None;
             }
+        };
 
-            return;
-        }
+        // prune polygons excluded by the filter, and find the cost of the segment from the root
+        let (weight, cost) = if self.options.has_costs() {
+            let Some(cost) = self
+                .options
+                .polygon_cost(other_side, &self.mesh.polygons[other_side.index()])
+            else {
+                #[cfg(debug_assertions)]
+                if self.debug {
+                    println!("x excluded");
+                }
+
+                return None;
+            };
+            let current = node.polygon_to.unwrap();
+            let current = self
+                .options
+                .polygon_cost(current, &self.mesh.polygons[current.index()])
+                .unwrap_or(1.0);
+            // paths can turn inside the mesh where costs change: skip the intervals along
+            // their root, that would go around it forever without covering anything
+            if node.polygon_from.is_some()
+                && (root == start.0 || root == end.0)
+                && on_side(root, [start.0, end.0]) == EdgeSide::Edge
+            {
+                #[cfg(debug_assertions)]
+                if self.debug {
+                    println!("x along the root");
+                }
+
+                return None;
+            }
+            if root == node.r {
+                (node.weight.max(current), cost)
+            } else {
+                // turning at a corner of the polygon the node goes to
+                (current, cost)
+            }
+        } else {
+            (1.0, 1.0)
+        };
 
         // prune edges that only lead to one other polygon, and not the target: dead end pruning
-        if self.polygon_to != other_side
-            && self
-                .mesh
-                .polygons
-                .get(other_side as usize)
-                .unwrap()
-                .is_one_way
+        if !self.options.exhaustive
+            && self.polygon_to != Some(other_side)
+            && self.mesh.polygons[other_side.index()].is_one_way
+            && !self
+                .options
+                .goals
+                .iter()
+                .any(|(_, polygon)| *polygon == other_side)
         {
             #[cfg(debug_assertions)]
             if self.debug {
                 println!("x dead end");
             }
 
-            return;
+            return None;
         }
 
+        // prune edges the cost model refuses, and add the cost of the others
+        let crossing = match self.options.model {
+            Some(model) => {
+                let interval = [start.0, end.0];
+                match model.crossing_cost(node.polygon_to.unwrap(), other_side, interval) {
+                    Some(crossing) => crossing.max(0.0),
+                    None => {
+                        #[cfg(debug_assertions)]
+                        if self.debug {
+                            println!("x refused by the cost model");
+                        }
+
+                        return None;
+                    }
+                }
+            }
+            None => 0.0,
+        };
+        let crossing = if self.options.secondary_weight > 0.0 {
+            crossing
+                + self.options.secondary_weight * self.mesh.edge_secondary_cost([start.1, end.1])
+        } else {
+            crossing
+        };
+
         let mut path = node.path.clone();
         if root != node.r {
             path.push(node.r);
         }
 
-        let heuristic = heuristic(root, self.to, [start.0, end.0]);
+        let interval = [start.0, end.0];
+        let heuristic = if self.options.goals.is_empty() {
+            heuristic(root, self.to, interval)
+        } else {
+            self.options
+                .goals
+                .iter()
+                .map(|(goal, _)| heuristic(root, *goal, interval))
+                .fold(f32::INFINITY, f32::min)
+        };
         let new_node = SearchNode {
             path,
             r: root,
-            i: [start.0, end.0],
+            i: interval,
             i_index: [start.1, end.1],
-            polygon_from: node.polygon_to as isize,
-            polygon_to: other_side,
-            f: node.f + distance_between(node.r, root),
-            g: heuristic,
+            polygon_from: node.polygon_to,
+            polygon_to: Some(other_side),
+            f: if root == node.r {
+                node.f
+            } else {
+                node.f
+                    + node.penalty
+                    + distance_between(node.r, root) * node.weight
+                    + self.options.turn_penalty
+            },
+            // in the target polygon, the heuristic is the rest of the path
+            g: if self.polygon_to == Some(other_side) {
+                heuristic * weight.max(cost) + self.last_turn_penalty(root, self.to, interval)
+            } else {
+                heuristic * self.options.heuristic_weight
+            },
+            weight,
+            penalty: if root == node.r {
+                node.penalty + crossing
+            } else {
+                crossing
+            },
+            goal: None,
+            corridor: vec![],
         };
         if new_node.f.is_nan() || new_node.g.is_nan() {
             #[cfg(debug_assertions)]
@@ -601,23 +1295,54 @@ impl<'m> SearchInstance<'m> {
                 println!("x one of the distance is NaN");
             }
 
-            return;
+            return None;
+        }
+
+        // prune nodes that can't lead to a path short enough, even in a straight line
+        let rest = if self.options.exhaustive {
+            // no target: the node must at least reach its interval
+            distance_between(root, project_on_segment(root, interval)) * weight
+        } else if self.polygon_to == Some(other_side) {
+            new_node.g
+        } else {
+            heuristic
+        };
+        if new_node.f + new_node.penalty + rest > self.options.max_len {
+            #[cfg(debug_assertions)]
+            if self.debug {
+                println!("x longer than the maximum length");
+            }
+
+            return None;
         }
 
+        Some((new_node, cost))
+    }
+
+    /// Add a node built by [`SearchInstance::new_node`], unless a node with the same root is
+    /// already cheaper.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[inline(always)]
+    fn insert_node(&mut self, mut new_node: SearchNode, cost: f32, node: &SearchNode) {
+        let (root, interval, weight) = (new_node.r, new_node.i, new_node.weight);
+        let other_side = new_node.polygon_to.unwrap();
+
         match self.root_history.entry(Root(root)) {
             Entry::Occupied(mut o) => {
+                #[cfg(feature = "counters")]
+                counters::cache_hit();
                 if o.get() < &new_node.f {
                     #[cfg(debug_assertions)]
                     if self.debug {
                         println!("x already got a better path");
                     }
+                    return;
                 } else {
                     #[cfg(debug_assertions)]
                     if self.debug {
                         println!("o added!");
                     }
                     o.insert(new_node.f);
-                    self.node_buffer.push(new_node);
                 }
             }
             Entry::Vacant(v) => {
@@ -626,9 +1351,65 @@ impl<'m> SearchInstance<'m> {
                     println!("o added!");
                 }
                 v.insert(new_node.f);
-                self.node_buffer.push(new_node);
             }
         }
+
+        if self.options.corridor {
+            new_node.corridor = node.corridor.clone();
+            new_node.corridor.push(other_side);
+        }
+
+        // with several goals, the node also reaches the ones in its polygon
+        for (goal, (point, polygon)) in self.options.goals.iter().enumerate() {
+            let g = helpers::heuristic(root, *point, interval) * weight.max(cost)
+                + self.last_turn_penalty(root, *point, interval);
+            if *polygon == other_side && new_node.f + new_node.penalty + g <= self.options.max_len {
+                self.node_buffer.push(SearchNode {
+                    g,
+                    goal: Some(goal),
+                    ..new_node.clone()
+                });
+            }
+        }
+        self.node_buffer.push(new_node);
+    }
+
+    /// Turn penalty of the path from `root` to `to` through `interval`, if it turns on the way.
+    #[inline(always)]
+    fn last_turn_penalty(&self, root: [f32; 2], to: [f32; 2], interval: [[f32; 2]; 2]) -> f32 {
+        if self.options.turn_penalty > 0.0 && turning_on(root, to, interval).is_some() {
+            self.options.turn_penalty
+        } else {
+            0.0
+        }
+    }
+
+    /// Continue the search towards another target, computing the heuristic of the nodes in the
+    /// queue again.
+    fn retarget(&mut self, to: [f32; 2], polygon_to: Option<PolygonId>) {
+        self.to = to;
+        self.polygon_to = polygon_to;
+        let mut nodes = std::mem::take(&mut self.queue).into_vec();
+        for node in &mut nodes {
+            node.g = self.target_heuristic(node);
+        }
+        self.queue = nodes.into();
+    }
+
+    /// Heuristic of a node towards the target: the rest of the path if it's in the target
+    /// polygon.
+    fn target_heuristic(&self, node: &SearchNode) -> f32 {
+        let heuristic = heuristic(node.r, self.to, node.i);
+        match node.polygon_to {
+            Some(polygon) if self.polygon_to == Some(polygon) => {
+                let cost = self
+                    .options
+                    .polygon_cost(polygon, &self.mesh.polygons[polygon.index()])
+                    .unwrap_or(1.0);
+                heuristic * node.weight.max(cost) + self.last_turn_penalty(node.r, self.to, node.i)
+            }
+            _ => heuristic * self.options.heuristic_weight,
+        }
     }
 
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
@@ -645,6 +1426,136 @@ impl<'m> SearchInstance<'m> {
         self.queue.extend(self.node_buffer.drain(..));
     }
 
+    /// Check if paths can turn at a vertex: it's a corner of the mesh, or the polygons around it
+    /// have different costs.
+    #[inline(always)]
+    fn is_corner(&self, vertex: &Vertex) -> bool {
+        vertex.is_corner
+            || self.options.has_costs() && {
+                let mut costs = vertex.polygons.iter().flatten().map(|polygon| {
+                    self.options
+                        .polygon_cost(*polygon, &self.mesh.polygons[polygon.index()])
+                });
+                let first = costs.next().flatten();
+                first.is_none() || costs.any(|cost| cost != first)
+            }
+    }
+
+    /// Find the root and the polygon on the other side of a successor, or `None` if it should be
+    /// pruned.
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[inline(always)]
+    fn successor_target(
+        &self,
+        node: &SearchNode,
+        successor: &Successor,
+    ) -> Option<([f32; 2], Option<PolygonId>)> {
+        let start = self.mesh.vertices.get(successor.edge[0]).unwrap();
+        let end = self.mesh.vertices.get(successor.edge[1]).unwrap();
+
+        #[cfg(debug_assertions)]
+        if self.debug {
+            println!("v {:?}", successor);
+        }
+
+        let mut other_side = None;
+        // find the polygon at the other side of this edge
+        for i in &start.polygons {
+            if i.is_some() && *i != node.polygon_to && end.polygons.contains(i) {
+                other_side = *i;
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        if self.debug {
+            println!("| going to {:?}", other_side);
+        }
+
+        let root = match successor.ty {
+            SuccessorType::RightNonObservable => {
+                if distance_between(successor.interval[0], start.p()) > 1.0e-5 {
+                    #[cfg(debug_assertions)]
+                    if self.debug {
+                        println!("x non observable on an intersection");
+                    }
+                    return None;
+                }
+                let vertex = self.mesh.vertices.get(node.i_index[0]).unwrap();
+                if self.is_corner(vertex) && distance_between(vertex.p(), node.i[0]) < 1.0e-5 {
+                    node.i[0]
+                } else {
+                    #[cfg(debug_assertions)]
+                    if self.debug {
+                        println!("x non observable on an non corner");
+                    }
+                    return None;
+                }
+            }
+            SuccessorType::Observable => node.r,
+            SuccessorType::LeftNonObservable => {
+                if distance_between(successor.interval[1], end.p()) > 1.0e-5 {
+                    #[cfg(debug_assertions)]
+                    if self.debug {
+                        println!("x non observable on an intersection");
+                    }
+                    return None;
+                }
+                let vertex = self.mesh.vertices.get(node.i_index[1]).unwrap();
+                if self.is_corner(vertex) && distance_between(vertex.p(), node.i[1]) < 1.0e-5 {
+                    node.i[1]
+                } else {
+                    #[cfg(debug_assertions)]
+                    if self.debug {
+                        println!("x non observable on an non corner");
+                    }
+                    return None;
+                }
+            }
+        };
+
+        #[cfg(debug_assertions)]
+        if self.debug {
+            println!("| through root {:?}", root);
+        }
+
+        Some((root, other_side))
+    }
+
+    /// Build the nodes of the successors of a node in parallel, by chunks, then insert them in
+    /// the order of the successors so that the search is the same as when adding them one by one.
+    #[cfg(feature = "parallel")]
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn add_nodes_in_parallel(&mut self, node: &SearchNode, successors: &[Successor]) {
+        let search = &*self;
+        let built: Vec<Vec<_>> = successors
+            .par_chunks(PARALLEL_CHUNK)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .map(|successor| {
+                        let (root, other_side) = search.successor_target(node, successor)?;
+                        Some(search.new_node(
+                            root,
+                            other_side,
+                            (successor.interval[0], successor.edge[0]),
+                            (successor.interval[1], successor.edge[1]),
+                            node,
+                        ))
+                    })
+                    .collect()
+            })
+            .collect();
+        for new_node in built.into_iter().flatten().flatten() {
+            #[cfg(feature = "stats")]
+            {
+                self.nodes_generated += 1;
+            }
+            if let Some((new_node, cost)) = new_node {
+                self.insert_node(new_node, cost, node);
+            }
+        }
+    }
+
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
     #[inline(always)]
     fn successors(&mut self, mut node: SearchNode) {
@@ -659,85 +1570,34 @@ impl<'m> SearchInstance<'m> {
                 self.debug = true;
                 self.fail_fast = 3;
             }
-            for successor in self.edges_between(&node) {
-                let start = self.mesh.vertices.get(successor.edge[0]).unwrap();
-                let end = self.mesh.vertices.get(successor.edge[1]).unwrap();
-
-                #[cfg(debug_assertions)]
-                if self.debug {
-                    println!("v {:?}", successor);
-                }
-
-                let mut other_side = isize::MAX;
-                // find the polygon at the other side of this edge
-                for i in &start.polygons {
-                    if *i != -1 && *i != node.polygon_to as isize && end.polygons.contains(i) {
-                        other_side = *i;
-                    }
-                }
-
-                #[cfg(debug_assertions)]
-                if self.debug {
-                    println!("| going to {:?}", other_side);
-                }
-
-                let root = match successor.ty {
-                    SuccessorType::RightNonObservable => {
-                        if distance_between(successor.interval[0], start.p()) > 1.0e-5 {
-                            #[cfg(debug_assertions)]
-                            if self.debug {
-                                println!("x non observable on an intersection");
-                            }
-                            continue;
-                        }
-                        let vertex = self.mesh.vertices.get(node.i_index[0]).unwrap();
-                        if vertex.is_corner && distance_between(vertex.p(), node.i[0]) < 1.0e-5 {
-                            node.i[0]
-                        } else {
-                            #[cfg(debug_assertions)]
-                            if self.debug {
-                                println!("x non observable on an non corner");
-                            }
-                            continue;
-                        }
-                    }
-                    SuccessorType::Observable => node.r,
-                    SuccessorType::LeftNonObservable => {
-                        if distance_between(successor.interval[1], end.p()) > 1.0e-5 {
-                            #[cfg(debug_assertions)]
-                            if self.debug {
-                                println!("x non observable on an intersection");
-                            }
-                            continue;
-                        }
-                        let vertex = self.mesh.vertices.get(node.i_index[1]).unwrap();
-                        if vertex.is_corner && distance_between(vertex.p(), node.i[1]) < 1.0e-5 {
-                            node.i[1]
-                        } else {
-                            #[cfg(debug_assertions)]
-                            if self.debug {
-                                println!("x non observable on an non corner");
-                            }
-                            continue;
-                        }
+            let successors = self.edges_between(&node);
+            #[cfg(feature = "parallel")]
+            let parallel =
+                successors.len() >= PARALLEL_SUCCESSORS && rayon::current_num_threads() > 1;
+            #[cfg(not(feature = "parallel"))]
+            let parallel = false;
+            if parallel {
+                #[cfg(feature = "parallel")]
+                self.add_nodes_in_parallel(&node, &successors);
+            } else {
+                for successor in &successors {
+                    if let Some((root, other_side)) = self.successor_target(&node, successor) {
+                        self.add_node(
+                            root,
+                            other_side,
+                            (successor.interval[0], successor.edge[0]),
+                            (successor.interval[1], successor.edge[1]),
+                            &node,
+                        )
                     }
-                };
-
-                #[cfg(debug_assertions)]
-                if self.debug {
-                    println!("| through root {:?}", root);
                 }
-
-                self.add_node(
-                    root,
-                    other_side,
-                    (successor.interval[0], successor.edge[0]),
-                    (successor.interval[1], successor.edge[1]),
-                    &node,
-                )
             }
 
-            if self.node_buffer.len() == 1 && self.node_buffer[0].polygon_to != self.polygon_to {
+            // exhaustive searches report each polygon they go through, so they don't skip the queue
+            if !self.options.exhaustive
+                && self.node_buffer.len() == 1
+                && self.node_buffer[0].polygon_to != self.polygon_to
+            {
                 #[cfg(feature = "verbose")]
                 for new_node in &self.node_buffer {
                     println!("        intermediate: {}", new_node);
@@ -774,59 +1634,84 @@ enum EdgeSide {
 
 impl Mesh {
     pub fn point_in_mesh(&self, point: [f32; 2]) -> bool {
-        self.point_in_polygon(point) != usize::MAX
+        self.point_in_polygon(point).is_some()
     }
 
+    /// Find the polygon containing each point, `None` for points outside of the mesh.
+    ///
+    /// With the `parallel` feature, points are located in parallel. With the `gpu` feature,
+    /// `GpuLocator` locates them on the GPU with the same results.
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    fn point_in_polygon(&self, point: [f32; 2]) -> usize {
-        let delta = 0.1;
-        [
-            [0.0, 0.0],
-            [delta, 0.0],
-            [delta, delta],
-            [0.0, delta],
-            [-delta, delta],
-            [-delta, 0.0],
-            [-delta, -delta],
-            [0.0, -delta],
-            [delta, -delta],
-        ]
-        .iter()
-        .map(|delta| self.point_in_polygon_unit([point[0] + delta[0], point[1] + delta[1]]))
-        .find(|poly| *poly != usize::MAX)
-        .unwrap_or(usize::MAX)
+    pub fn locate_batch(&self, points: &[[f32; 2]]) -> Vec<Option<PolygonId>> {
+        let locate = |point: &[f32; 2]| self.point_in_polygon(*point);
+        #[cfg(feature = "parallel")]
+        let located = points.par_iter().map(locate).collect();
+        #[cfg(not(feature = "parallel"))]
+        let located = points.iter().map(locate).collect();
+        located
     }
 
     #[cfg_attr(feature = "tracing", instrument(skip_all))]
-    fn point_in_polygon_unit(&self, point: [f32; 2]) -> usize {
-        'polygons: for (i, polygon) in self.polygons.iter().enumerate() {
-            for edge in polygon.edges_index() {
-                let last = self.vertices.get(edge[0]).unwrap();
-                let next = self.vertices.get(edge[1]).unwrap();
-                let current_side = on_side(point, [[last.x, last.y], [next.x, next.y]]);
-                if on_segment(point, [[last.x, last.y], [next.x, next.y]]) {
-                    return i;
-                }
-                if current_side != EdgeSide::Left {
-                    continue 'polygons;
-                }
+    fn point_in_polygon(&self, point: [f32; 2]) -> Option<PolygonId> {
+        self.locate_with_snap(point, self.point_snap())
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    fn point_in_polygon_unit(&self, point: [f32; 2]) -> Option<PolygonId> {
+        self.polygon_grid
+            .candidates(point)
+            .iter()
+            .copied()
+            .find(|i| self.polygon_contains(*i, point))
+            .map(PolygonId::new)
+    }
+
+    #[cfg_attr(feature = "tracing", instrument(skip_all))]
+    #[inline(always)]
+    fn polygon_contains(&self, polygon_index: usize, point: [f32; 2]) -> bool {
+        if let Some(aabb) = self.polygon_aabbs.get(polygon_index) {
+            if !aabb.contains(point) {
+                return false;
             }
-            return i;
         }
-        usize::MAX
+        let polygon = &self.polygons[polygon_index];
+        for edge in polygon.edges_index() {
+            let last = self.vertices.get(edge[0]).unwrap();
+            let next = self.vertices.get(edge[1]).unwrap();
+            let current_side = on_side(point, [[last.x, last.y], [next.x, next.y]]);
+            if on_segment(point, [[last.x, last.y], [next.x, next.y]]) {
+                return true;
+            }
+            // points on the line of an edge are in the polygon if they're inside the others
+            if current_side == EdgeSide::Right {
+                return false;
+            }
+        }
+        true
     }
 }
 
-#[derive(PartialEq, Debug)]
+#[derive(PartialEq, Debug, Clone)]
 struct SearchNode {
     path: Vec<[f32; 2]>,
     r: [f32; 2],
     i: [[f32; 2]; 2],
     i_index: [usize; 2],
-    polygon_from: isize,
-    polygon_to: isize,
+    polygon_from: Option<PolygonId>,
+    polygon_to: Option<PolygonId>,
     f: f32,
     g: f32,
+    /// Cost of the distance from the root to the interval, the highest cost of the polygons
+    /// between them.
+    weight: f32,
+    /// Cost of the edges crossed from the root to the interval, from the cost model. It's not in
+    /// `f` so that nodes are compared by the cost of their root.
+    penalty: f32,
+    /// For searches with several goals, the goal this node reaches in its polygon, with `g` the
+    /// distance to it.
+    goal: Option<usize>,
+    /// Polygons from the start of the search to `polygon_to`, for searches keeping them.
+    corridor: Vec<PolygonId>,
 }
 
 impl Display for SearchNode {
@@ -849,7 +1734,7 @@ impl Eq for SearchNode {}
 
 impl Ord for SearchNode {
     fn cmp(&self, other: &Self) -> Ordering {
-        match (self.f + self.g).total_cmp(&(other.f + other.g)) {
+        match (self.f + self.penalty + self.g).total_cmp(&(other.f + other.penalty + other.g)) {
             Ordering::Less => Ordering::Greater,
             Ordering::Equal => Ordering::Equal,
             Ordering::Greater => Ordering::Less,
@@ -863,6 +1748,7 @@ mod tests {
         ($x:expr, $y:expr) => {
             let val = $x;
             let expected = $y;
+            #[allow(clippy::neg_cmp_op_on_partial_ord)]
             if !((val - expected).abs() < 0.01) {
                 assert_eq!(val, expected);
             }
@@ -871,43 +1757,138 @@ mod tests {
 
     use crate::{
         helpers::{distance_between, mirror},
-        Mesh, Path, Polygon, SearchNode, Vertex,
+        Aabb, Mesh, Path, Polygon, PolygonId, SearchNode, Vertex, VertexId,
     };
 
-    fn mesh_u_grid() -> Mesh {
-        Mesh {
-            vertices: vec![
-                Vertex::new(0, 0, vec![0, -1]),
-                Vertex::new(1, 0, vec![0, 1, -1]),
-                Vertex::new(2, 0, vec![1, 2, -1]),
-                Vertex::new(3, 0, vec![2, -1]),
-                Vertex::new(0, 1, vec![3, 0, -1]),
-                Vertex::new(1, 1, vec![3, 1, 0, -1]),
-                Vertex::new(2, 1, vec![4, 2, 1, -1]),
-                Vertex::new(3, 1, vec![4, 2, -1]),
-                Vertex::new(0, 2, vec![3, -1]),
-                Vertex::new(1, 2, vec![3, -1]),
-                Vertex::new(2, 2, vec![4, -1]),
-                Vertex::new(3, 2, vec![4, -1]),
+    pub(crate) fn mesh_u_grid() -> Mesh {
+        Mesh::new(
+            vec![
+                Vertex::new(0.0, 0.0, vec![0, -1]),
+                Vertex::new(1.0, 0.0, vec![0, 1, -1]),
+                Vertex::new(2.0, 0.0, vec![1, 2, -1]),
+                Vertex::new(3.0, 0.0, vec![2, -1]),
+                Vertex::new(0.0, 1.0, vec![3, 0, -1]),
+                Vertex::new(1.0, 1.0, vec![3, 1, 0, -1]),
+                Vertex::new(2.0, 1.0, vec![4, 2, 1, -1]),
+                Vertex::new(3.0, 1.0, vec![4, 2, -1]),
+                Vertex::new(0.0, 2.0, vec![3, -1]),
+                Vertex::new(1.0, 2.0, vec![3, -1]),
+                Vertex::new(2.0, 2.0, vec![4, -1]),
+                Vertex::new(3.0, 2.0, vec![4, -1]),
             ],
-            polygons: vec![
+            vec![
                 Polygon::new(4, vec![0, 1, 5, 4, -1, 1, 3, -1]),
                 Polygon::new(4, vec![1, 2, 6, 5, -1, 2, -1, 0]),
                 Polygon::new(4, vec![2, 3, 7, 6, -1, -1, 4, 1]),
                 Polygon::new(4, vec![4, 5, 9, 8, 0, -1, -1, -1]),
                 Polygon::new(4, vec![6, 7, 11, 10, 2, -1, -1, -1]),
             ],
-        }
+        )
     }
 
     #[test]
     fn point_in_polygon() {
         let mesh = mesh_u_grid();
-        assert_eq!(mesh.point_in_polygon([0.5, 0.5]), 0);
-        assert_eq!(mesh.point_in_polygon([1.5, 0.5]), 1);
-        assert_eq!(mesh.point_in_polygon([0.5, 1.5]), 3);
-        assert_eq!(mesh.point_in_polygon([1.5, 1.5]), usize::MAX);
-        assert_eq!(mesh.point_in_polygon([2.5, 1.5]), 4);
+        assert_eq!(mesh.point_in_polygon([0.5, 0.5]), Some(PolygonId::new(0)));
+        assert_eq!(mesh.point_in_polygon([1.5, 0.5]), Some(PolygonId::new(1)));
+        assert_eq!(mesh.point_in_polygon([0.5, 1.5]), Some(PolygonId::new(3)));
+        assert_eq!(mesh.point_in_polygon([1.5, 1.5]), None);
+        assert_eq!(mesh.point_in_polygon([2.5, 1.5]), Some(PolygonId::new(4)));
+        assert_eq!(mesh.point_in_polygon([5.0, 5.0]), None);
+    }
+
+    #[test]
+    fn point_on_collinear_edges() {
+        // merged polygons can have consecutive edges on the same line: a point on the second one
+        // is on the line of the first, but out of its segment
+        let mesh = Mesh::new(
+            vec![
+                Vertex::new(0.0, 0.0, vec![0, -1]),
+                Vertex::new(1.0, 0.0, vec![0, -1]),
+                Vertex::new(2.0, 0.0, vec![0, -1]),
+                Vertex::new(2.0, 1.0, vec![0, -1]),
+                Vertex::new(0.0, 1.0, vec![0, -1]),
+            ],
+            vec![Polygon::new(5, vec![0, 1, 2, 3, 4, -1, -1, -1, -1, -1])],
+        );
+        assert_eq!(mesh.point_in_polygon([1.5, 0.0]), Some(PolygonId::new(0)));
+        assert_eq!(mesh.point_in_polygon([0.5, 0.0]), Some(PolygonId::new(0)));
+        assert_eq!(mesh.point_in_polygon([2.5, 0.0]), None);
+    }
+
+    #[test]
+    fn no_path() {
+        let mesh = mesh_u_grid();
+        assert_eq!(mesh.path([1.5, 1.5], [2.5, 1.5]), None);
+        assert_eq!(mesh.path([0.5, 1.5], [1.5, 1.5]), None);
+
+        let split = crate::maps::grid_mesh(3, 1, |x, _| x != 1);
+        assert_eq!(split.path([0.5, 0.5], [2.5, 0.5]), None);
+    }
+
+    #[test]
+    fn locate_batch() {
+        let mesh = mesh_u_grid();
+        assert_eq!(
+            mesh.locate_batch(&[[0.5, 0.5], [1.5, 1.5], [2.5, 1.5]]),
+            vec![Some(PolygonId::new(0)), None, Some(PolygonId::new(4))]
+        );
+    }
+
+    #[test]
+    fn output_quantization() {
+        let mut mesh = mesh_from_paper();
+        mesh.set_output_quantization(Some(2.0));
+        let path = mesh.path([12.0, 0.0], [3.0, 1.0]).unwrap();
+        assert_eq!(path.path, vec![[8.0, 4.0], [4.0, 2.0], [3.0, 1.0]]);
+        assert_delta!(
+            path.len,
+            distance_between([12.0, 0.0], [8.0, 4.0])
+                + distance_between([8.0, 4.0], [4.0, 2.0])
+                + distance_between([4.0, 2.0], [3.0, 1.0])
+        );
+        mesh.set_output_quantization(None);
+        assert_eq!(
+            mesh.path([12.0, 0.0], [3.0, 1.0]).unwrap().path,
+            vec![[7.0, 4.0], [4.0, 2.0], [3.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn bounding_box() {
+        let mesh = mesh_u_grid();
+        assert_eq!(mesh.bounding_box(), Aabb::new([0.0, 0.0], [3.0, 2.0]));
+        assert_eq!(
+            mesh.polygon_bounding_box(PolygonId::new(4)),
+            Some(Aabb::new([2.0, 1.0], [3.0, 2.0]))
+        );
+        assert_eq!(mesh.polygon_bounding_box(PolygonId::new(5)), None);
+    }
+
+    #[test]
+    fn accessors() {
+        let mesh = mesh_u_grid();
+        let vertex = mesh.vertex(VertexId::new(5)).unwrap();
+        assert_eq!(vertex.position(), [1.0, 1.0]);
+        assert_eq!((vertex.x(), vertex.y()), (1.0, 1.0));
+        assert!(vertex.is_corner());
+        assert_eq!(vertex.polygons().iter().flatten().count(), 3);
+        assert!(mesh.vertex(VertexId::new(12)).is_none());
+
+        let polygon = mesh.polygon(PolygonId::new(1)).unwrap();
+        assert_eq!(
+            polygon.vertices().map(VertexId::index).collect::<Vec<_>>(),
+            vec![1, 2, 6, 5]
+        );
+        assert_eq!(polygon.edges().next(), Some([1, 2]));
+        assert!(!polygon.is_one_way());
+        assert!(mesh.polygon(PolygonId::new(5)).is_none());
+
+        assert_eq!(mesh.iter_vertices().len(), 12);
+        assert_eq!(
+            mesh.iter_polygons().last().map(|(id, _)| id),
+            Some(PolygonId::new(4))
+        );
     }
 
     #[test]
@@ -921,25 +1902,29 @@ mod tests {
             r: from,
             i: [[1.0, 0.0], [1.0, 1.0]],
             i_index: [1, 5],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 1,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(1)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 1);
         assert_eq!(successors[0].r, from);
         assert_eq!(successors[0].f, 0.0);
         assert_eq!(successors[0].g, distance_between(from, to));
-        assert_eq!(successors[0].polygon_from, 1);
-        assert_eq!(successors[0].polygon_to, 2);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(1)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[0].i, [[2.0, 0.0], [2.0, 1.0]]);
         assert_eq!(successors[0].i_index, [2, 6]);
 
         assert_eq!(successors[0].path, Vec::<[f32; 2]>::new());
 
         assert_eq!(
-            mesh.path(from, to),
+            mesh.path(from, to).unwrap(),
             Path {
                 path: vec![to],
                 len: distance_between(from, to)
@@ -958,24 +1943,28 @@ mod tests {
             r: from,
             i: [[2.0, 1.0], [2.0, 0.0]],
             i_index: [6, 2],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 1,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(1)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = mesh.successors(search_node, to);
         assert_eq!(successors.len(), 1);
         assert_eq!(successors[0].r, from);
         assert_eq!(successors[0].f, 0.0);
         assert_eq!(successors[0].g, distance_between(to, from));
-        assert_eq!(successors[0].polygon_from, 1);
-        assert_eq!(successors[0].polygon_to, 0);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(1)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(0)));
         assert_eq!(successors[0].i, [[1.0, 1.0], [1.0, 0.0]]);
         assert_eq!(successors[0].i_index, [5, 1]);
         assert_eq!(successors[0].path, Vec::<[f32; 2]>::new());
 
         assert_eq!(
-            mesh.path(from, to),
+            mesh.path(from, to).unwrap(),
             Path {
                 path: vec![to],
                 len: distance_between(from, to)
@@ -994,10 +1983,14 @@ mod tests {
             r: from,
             i: [[0.0, 1.0], [1.0, 1.0]],
             i_index: [4, 5],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 0,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(0)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 1);
@@ -1007,14 +2000,14 @@ mod tests {
             distance_between(from, [1.0, 1.0]) + distance_between([1.0, 1.0], [2.0, 1.0])
         );
         assert_eq!(successors[0].g, distance_between([2.0, 1.0], to));
-        assert_eq!(successors[0].polygon_from, 2);
-        assert_eq!(successors[0].polygon_to, 4);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(2)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(4)));
         assert_eq!(successors[0].i, [[3.0, 1.0], [2.0, 1.0]]);
         assert_eq!(successors[0].i_index, [7, 6]);
         assert_eq!(successors[0].path, vec![from, [1.0, 1.0]]);
 
         assert_eq!(
-            mesh.path(from, to),
+            mesh.path(from, to).unwrap(),
             Path {
                 path: vec![[1.0, 1.0], [2.0, 1.0], to],
                 len: distance_between(from, [1.0, 1.0])
@@ -1036,10 +2029,14 @@ mod tests {
             i: [[1.0, 0.0], [1.0, 1.0]],
             i_index: [1, 5],
 
-            polygon_from: 0,
-            polygon_to: 1,
+            polygon_from: Some(PolygonId::new(0)),
+            polygon_to: Some(PolygonId::new(1)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 1);
@@ -1049,14 +2046,14 @@ mod tests {
             distance_between(from, [1.0, 1.0]) + distance_between([1.0, 1.0], [2.0, 1.0])
         );
         assert_eq!(successors[0].g, distance_between([2.0, 1.0], to));
-        assert_eq!(successors[0].polygon_from, 2);
-        assert_eq!(successors[0].polygon_to, 4);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(2)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(4)));
         assert_eq!(successors[0].i, [[3.0, 1.0], [2.0, 1.0]]);
         assert_eq!(successors[0].i_index, [7, 6]);
         assert_eq!(successors[0].path, vec![from, [1.0, 1.0]]);
 
         assert_eq!(
-            mesh.path(from, to),
+            mesh.path(from, to).unwrap(),
             Path {
                 path: vec![[1.0, 1.0], [2.0, 1.0], to],
                 len: distance_between(from, [1.0, 1.0])
@@ -1066,34 +2063,34 @@ mod tests {
         );
     }
 
-    fn mesh_from_paper() -> Mesh {
-        Mesh {
-            vertices: vec![
-                Vertex::new(0, 6, vec![0, -1]),           // 0
-                Vertex::new(2, 5, vec![0, -1, 2]),        // 1
-                Vertex::new(5, 7, vec![0, 2, -1]),        // 2
-                Vertex::new(5, 8, vec![0, -1]),           // 3
-                Vertex::new(0, 8, vec![0, -1]),           // 4
-                Vertex::new(1, 4, vec![1, -1]),           // 5
-                Vertex::new(2, 1, vec![1, -1]),           // 6
-                Vertex::new(4, 1, vec![1, -1]),           // 7
-                Vertex::new(4, 2, vec![1, -1, 2]),        // 8
-                Vertex::new(2, 4, vec![1, 2, -1]),        // 9
-                Vertex::new(7, 4, vec![2, -1, 4]),        // 10
-                Vertex::new(10, 7, vec![2, 4, 6, -1, 3]), // 11
-                Vertex::new(7, 7, vec![2, 3, -1]),        // 12
-                Vertex::new(11, 8, vec![3, -1]),          // 13
-                Vertex::new(7, 8, vec![3, -1]),           // 14
-                Vertex::new(7, 0, vec![5, 4, -1]),        // 15
-                Vertex::new(11, 3, vec![4, 5, -1]),       // 16
-                Vertex::new(11, 5, vec![4, -1, 6]),       // 17
-                Vertex::new(12, 0, vec![5, -1]),          // 18
-                Vertex::new(12, 3, vec![5, -1]),          // 19
-                Vertex::new(13, 5, vec![6, -1]),          // 20
-                Vertex::new(13, 7, vec![6, -1]),          // 21
-                Vertex::new(1, 3, vec![1, -1]),           // 22
+    pub(crate) fn mesh_from_paper() -> Mesh {
+        Mesh::new(
+            vec![
+                Vertex::new(0.0, 6.0, vec![0, -1]),           // 0
+                Vertex::new(2.0, 5.0, vec![0, -1, 2]),        // 1
+                Vertex::new(5.0, 7.0, vec![0, 2, -1]),        // 2
+                Vertex::new(5.0, 8.0, vec![0, -1]),           // 3
+                Vertex::new(0.0, 8.0, vec![0, -1]),           // 4
+                Vertex::new(1.0, 4.0, vec![1, -1]),           // 5
+                Vertex::new(2.0, 1.0, vec![1, -1]),           // 6
+                Vertex::new(4.0, 1.0, vec![1, -1]),           // 7
+                Vertex::new(4.0, 2.0, vec![1, -1, 2]),        // 8
+                Vertex::new(2.0, 4.0, vec![1, 2, -1]),        // 9
+                Vertex::new(7.0, 4.0, vec![2, -1, 4]),        // 10
+                Vertex::new(10.0, 7.0, vec![2, 4, 6, -1, 3]), // 11
+                Vertex::new(7.0, 7.0, vec![2, 3, -1]),        // 12
+                Vertex::new(11.0, 8.0, vec![3, -1]),          // 13
+                Vertex::new(7.0, 8.0, vec![3, -1]),           // 14
+                Vertex::new(7.0, 0.0, vec![5, 4, -1]),        // 15
+                Vertex::new(11.0, 3.0, vec![4, 5, -1]),       // 16
+                Vertex::new(11.0, 5.0, vec![4, -1, 6]),       // 17
+                Vertex::new(12.0, 0.0, vec![5, -1]),          // 18
+                Vertex::new(12.0, 3.0, vec![5, -1]),          // 19
+                Vertex::new(13.0, 5.0, vec![6, -1]),          // 20
+                Vertex::new(13.0, 7.0, vec![6, -1]),          // 21
+                Vertex::new(1.0, 3.0, vec![1, -1]),           // 22
             ],
-            polygons: vec![
+            vec![
                 Polygon::new(5, vec![0, 1, 2, 3, 4, -1, -1, 2, -1, -1]),
                 Polygon::new(6, vec![5, 22, 6, 7, 8, 9, -1, -1, -1, -1, 2, -1]),
                 Polygon::new(7, vec![1, 9, 8, 10, 11, 12, 2, -1, 1, -1, 4, 3, -1, 0]),
@@ -1102,7 +2099,7 @@ mod tests {
                 Polygon::new(4, vec![15, 18, 19, 16, -1, -1, -1, 4]),
                 Polygon::new(4, vec![11, 17, 20, 21, 4, -1, -1, -1]),
             ],
-        }
+        )
     }
 
     #[test]
@@ -1116,10 +2113,14 @@ mod tests {
             r: from,
             i: [[11.0, 3.0], [7.0, 0.0]],
             i_index: [16, 15],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 4,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(4)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 2);
@@ -1130,8 +2131,8 @@ mod tests {
             successors[1].g,
             distance_between([11.0, 3.0], [9.75, 6.75]) + distance_between([9.75, 6.75], to)
         );
-        assert_eq!(successors[1].polygon_from, 4);
-        assert_eq!(successors[1].polygon_to, 2);
+        assert_eq!(successors[1].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[1].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[1].i, [[10.0, 7.0], [9.75, 6.75]]);
         assert_eq!(successors[1].i_index, [11, 10]);
         assert_eq!(successors[1].path, vec![from]);
@@ -1139,14 +2140,14 @@ mod tests {
         assert_eq!(successors[0].r, from);
         assert_eq!(successors[0].f, 0.0);
         assert_eq!(successors[0].g, distance_between(from, to));
-        assert_eq!(successors[0].polygon_from, 4);
-        assert_eq!(successors[0].polygon_to, 2);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[0].i, [[9.75, 6.75], [7.0, 4.0]]);
         assert_eq!(successors[0].i_index, [11, 10]);
         assert_eq!(successors[0].path, Vec::<[f32; 2]>::new());
 
-        assert_eq!(mesh.path(from, to).len, distance_between(from, to));
-        assert_eq!(mesh.path(from, to).path, vec![to]);
+        assert_eq!(mesh.path(from, to).unwrap().len, distance_between(from, to));
+        assert_eq!(mesh.path(from, to).unwrap().path, vec![to]);
     }
 
     #[test]
@@ -1160,10 +2161,14 @@ mod tests {
             r: from,
             i: [[11.0, 3.0], [7.0, 0.0]],
             i_index: [16, 15],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 4,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(4)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 3);
@@ -1174,8 +2179,8 @@ mod tests {
             successors[0].g,
             distance_between([11.0, 3.0], [11.0, 5.0]) + distance_between([11.0, 5.0], to)
         );
-        assert_eq!(successors[0].polygon_from, 4);
-        assert_eq!(successors[0].polygon_to, 6);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(6)));
         assert_eq!(successors[0].i, [[11.0, 5.0], [10.0, 7.0]]);
         assert_eq!(successors[0].i_index, [17, 11]);
         assert_eq!(successors[0].path, vec![from]);
@@ -1186,8 +2191,8 @@ mod tests {
             successors[1].g,
             distance_between([11.0, 3.0], mirror(to, [[10.0, 7.0], [9.75, 6.75]]))
         );
-        assert_eq!(successors[1].polygon_from, 4);
-        assert_eq!(successors[1].polygon_to, 2);
+        assert_eq!(successors[1].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[1].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[1].i, [[10.0, 7.0], [9.75, 6.75]]);
         assert_eq!(successors[1].i_index, [11, 10]);
         assert_eq!(successors[1].path, vec![from]);
@@ -1199,19 +2204,22 @@ mod tests {
             distance_between(from, [9.75, 6.75])
                 + distance_between([9.75, 6.75], mirror(to, [[9.75, 6.75], [7.0, 4.0]]))
         );
-        assert_eq!(successors[2].polygon_from, 4);
-        assert_eq!(successors[2].polygon_to, 2);
+        assert_eq!(successors[2].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[2].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[2].i, [[9.75, 6.75], [7.0, 4.0]]);
         assert_eq!(successors[2].i_index, [11, 10]);
         assert_eq!(successors[2].path, Vec::<[f32; 2]>::new());
 
         assert_delta!(
-            mesh.path(from, to).len,
+            mesh.path(from, to).unwrap().len,
             distance_between(from, [11.0, 3.0])
                 + distance_between([11.0, 3.0], [11.0, 5.0])
                 + distance_between([11.0, 5.0], to)
         );
-        assert_eq!(mesh.path(from, to).path, vec![[11.0, 3.0], [11.0, 5.0], to]);
+        assert_eq!(
+            mesh.path(from, to).unwrap().path,
+            vec![[11.0, 3.0], [11.0, 5.0], to]
+        );
     }
 
     #[test]
@@ -1225,10 +2233,14 @@ mod tests {
             r: from,
             i: [[11.0, 3.0], [7.0, 0.0]],
             i_index: [16, 15],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 4,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(4)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 2);
@@ -1239,8 +2251,8 @@ mod tests {
             successors[1].g,
             distance_between([11.0, 3.0], [9.75, 6.75]) + distance_between([9.75, 6.75], to)
         );
-        assert_eq!(successors[1].polygon_from, 4);
-        assert_eq!(successors[1].polygon_to, 2);
+        assert_eq!(successors[1].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[1].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[1].i, [[10.0, 7.0], [9.75, 6.75]]);
         assert_eq!(successors[1].i_index, [11, 10]);
         assert_eq!(successors[1].path, vec![from]);
@@ -1251,17 +2263,17 @@ mod tests {
             successors[0].g,
             distance_between(from, [7.0, 4.0]) + distance_between([7.0, 4.0], to)
         );
-        assert_eq!(successors[0].polygon_from, 4);
-        assert_eq!(successors[0].polygon_to, 2);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[0].i, [[9.75, 6.75], [7.0, 4.0]]);
         assert_eq!(successors[0].i_index, [11, 10]);
         assert_eq!(successors[0].path, Vec::<[f32; 2]>::new());
 
         assert_delta!(
-            mesh.path(from, to).len,
+            mesh.path(from, to).unwrap().len,
             distance_between(from, [7.0, 4.0]) + distance_between([7.0, 4.0], to)
         );
-        assert_eq!(mesh.path(from, to).path, vec![[7.0, 4.0], to]);
+        assert_eq!(mesh.path(from, to).unwrap().path, vec![[7.0, 4.0], to]);
     }
 
     #[test]
@@ -1275,10 +2287,14 @@ mod tests {
             r: from,
             i: [[11.0, 3.0], [7.0, 0.0]],
             i_index: [16, 15],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 4,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(4)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
         let successors = dbg!(mesh.successors(search_node, to));
         assert_eq!(successors.len(), 2);
@@ -1289,8 +2305,8 @@ mod tests {
             successors[1].g,
             distance_between([11.0, 3.0], [9.75, 6.75]) + distance_between([9.75, 6.75], to)
         );
-        assert_eq!(successors[1].polygon_from, 4);
-        assert_eq!(successors[1].polygon_to, 2);
+        assert_eq!(successors[1].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[1].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[1].i, [[10.0, 7.0], [9.75, 6.75]]);
         assert_eq!(successors[1].i_index, [11, 10]);
         assert_eq!(successors[1].path, vec![from]);
@@ -1301,8 +2317,8 @@ mod tests {
             successors[0].g,
             distance_between(from, [7.0, 4.0]) + distance_between([7.0, 4.0], to)
         );
-        assert_eq!(successors[0].polygon_from, 4);
-        assert_eq!(successors[0].polygon_to, 2);
+        assert_eq!(successors[0].polygon_from, Some(PolygonId::new(4)));
+        assert_eq!(successors[0].polygon_to, Some(PolygonId::new(2)));
         assert_eq!(successors[0].i, [[9.75, 6.75], [7.0, 4.0]]);
         assert_eq!(successors[0].i_index, [11, 10]);
         assert_eq!(successors[0].path, Vec::<[f32; 2]>::new());
@@ -1313,13 +2329,16 @@ mod tests {
         assert_eq!(successors.len(), 1);
 
         assert_delta!(
-            mesh.path(from, to).len,
+            mesh.path(from, to).unwrap().len,
             distance_between(from, [7.0, 4.0])
                 + distance_between([7.0, 4.0], [4.0, 2.0])
                 + distance_between([4.0, 2.0], to)
         );
 
-        assert_eq!(mesh.path(from, to).path, vec![[7.0, 4.0], [4.0, 2.0], to]);
+        assert_eq!(
+            mesh.path(from, to).unwrap().path,
+            vec![[7.0, 4.0], [4.0, 2.0], to]
+        );
     }
 
     #[test]
@@ -1333,10 +2352,14 @@ mod tests {
             r: from,
             i: [[11.0, 3.0], [7.0, 0.0]],
             i_index: [16, 15],
-            polygon_from: mesh.point_in_polygon(from) as isize,
-            polygon_to: 4,
+            polygon_from: mesh.point_in_polygon(from),
+            polygon_to: Some(PolygonId::new(4)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
 
         let successors = mesh.edges_between(&search_node);
@@ -1352,10 +2375,14 @@ mod tests {
             r: from,
             i: [[9.75, 6.75], [7.0, 4.0]],
             i_index: [11, 10],
-            polygon_from: 4,
-            polygon_to: 2,
+            polygon_from: Some(PolygonId::new(4)),
+            polygon_to: Some(PolygonId::new(2)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
 
         let successors = mesh.edges_between(&search_node);
@@ -1371,10 +2398,14 @@ mod tests {
             r: [11.0, 3.0],
             i: [[10.0, 7.0], [7.0, 4.0]],
             i_index: [11, 10],
-            polygon_from: 4,
-            polygon_to: 2,
+            polygon_from: Some(PolygonId::new(4)),
+            polygon_to: Some(PolygonId::new(2)),
             f: 0.0,
             g: distance_between(from, to),
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
 
         let successors = mesh.edges_between(&search_node);
@@ -1395,10 +2426,14 @@ mod tests {
             r: [0.0, 0.0],
             i: [[1.0, 0.0], [1.0, 1.0]],
             i_index: [1, 5],
-            polygon_from: 0,
-            polygon_to: 1,
+            polygon_from: Some(PolygonId::new(0)),
+            polygon_to: Some(PolygonId::new(1)),
             f: 0.0,
             g: 1.0,
+            weight: 1.0,
+            penalty: 0.0,
+            goal: None,
+            corridor: vec![],
         };
 
         let successors = mesh.edges_between(&search_node);
@@ -1408,4 +2443,25 @@ mod tests {
         }
         // assert!(false);
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_successors() {
+        // crossing the open area of the comb builds its 801 nodes in parallel
+        let mesh = crate::maps::comb(200, 30);
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        for _ in 0..10 {
+            let path = pool
+                .install(|| mesh.path([0.5, 0.5], [398.5, 31.5]))
+                .unwrap();
+            assert_eq!(path.path, vec![[1.0, 1.0], [398.0, 31.0], [398.5, 31.5]]);
+            let path = pool
+                .install(|| mesh.path([200.5, 31.5], [0.5, 0.5]))
+                .unwrap();
+            assert_eq!(path.path, vec![[200.0, 31.0], [1.0, 1.0], [0.5, 0.5]]);
+        }
+    }
 }
@@ -0,0 +1,363 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+use crate::{binary::MAGIC, Mesh};
+
+/// A format meshes can be loaded from, to be registered in [`MeshLoaders`].
+pub trait MeshLoader: Send + Sync {
+    /// Name of the format, used in error messages.
+    fn name(&self) -> &str;
+
+    /// Extensions of files in this format, in lowercase and without the leading dot.
+    fn extensions(&self) -> &[&str];
+
+    /// Check if `bytes`, the content of a file, are in this format, usually by looking for magic
+    /// bytes at its beginning.
+    ///
+    /// Formats without a recognizable header are only detected by their extension.
+    fn detect(&self, _bytes: &[u8]) -> bool {
+        false
+    }
+
+    /// Load a mesh from the content of a file.
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>>;
+}
+
+/// Error returned when a mesh can't be loaded with [`Mesh::load`] or [`MeshLoaders`].
+#[derive(Debug)]
+pub enum MeshLoadError {
+    /// The file couldn't be read.
+    Io(io::Error),
+    /// No loader recognizes the content or the extension of the file.
+    UnknownFormat,
+    /// The loader of the format failed.
+    Format {
+        format: String,
+        error: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl Display for MeshLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshLoadError::Io(error) => write!(f, "error reading mesh: {}", error),
+            MeshLoadError::UnknownFormat => write!(f, "unknown mesh format"),
+            MeshLoadError::Format { format, error } => {
+                write!(f, "error loading {} mesh: {}", format, error)
+            }
+        }
+    }
+}
+
+impl Error for MeshLoadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MeshLoadError::Io(error) => Some(error),
+            MeshLoadError::UnknownFormat => None,
+            MeshLoadError::Format { error, .. } => Some(error.as_ref()),
+        }
+    }
+}
+
+impl From<io::Error> for MeshLoadError {
+    fn from(error: io::Error) -> Self {
+        MeshLoadError::Io(error)
+    }
+}
+
+/// A set of [`MeshLoader`], choosing one for each file from its content or its extension.
+///
+/// The default set has the formats of this crate: `.mesh` files, the binary format of
+/// [`Mesh::save_binary`], MovingAI `.map` files, Godot resources, and Tiled `.tmx` maps with the
+/// `tiled` feature.
+#[derive(Clone)]
+pub struct MeshLoaders {
+    loaders: Vec<Arc<dyn MeshLoader>>,
+}
+
+impl fmt::Debug for MeshLoaders {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.loaders.iter().map(|loader| loader.name()))
+            .finish()
+    }
+}
+
+impl Default for MeshLoaders {
+    fn default() -> Self {
+        let mut loaders = MeshLoaders::new();
+        loaders.register(TextFormat);
+        loaders.register(BinaryFormat);
+        loaders.register(MovingAiFormat);
+        loaders.register(GodotFormat);
+        #[cfg(feature = "tiled")]
+        loaders.register(TiledFormat);
+        loaders
+    }
+}
+
+impl MeshLoaders {
+    /// An empty set of loaders.
+    pub fn new() -> Self {
+        MeshLoaders { loaders: vec![] }
+    }
+
+    /// Add a loader. It takes precedence over the loaders registered before it.
+    pub fn register(&mut self, loader: impl MeshLoader + 'static) {
+        self.loaders.push(Arc::new(loader));
+    }
+
+    /// Find the loader for a file, first from its content then from its extension.
+    pub fn find(&self, bytes: &[u8], extension: Option<&str>) -> Option<&dyn MeshLoader> {
+        let extension = extension.map(str::to_ascii_lowercase);
+        self.loaders
+            .iter()
+            .rev()
+            .find(|loader| loader.detect(bytes))
+            .or_else(|| {
+                let extension = extension.as_deref()?;
+                self.loaders
+                    .iter()
+                    .rev()
+                    .find(|loader| loader.extensions().contains(&extension))
+            })
+            .map(|loader| loader.as_ref())
+    }
+
+    /// Load a mesh from the content of a file with the given extension.
+    pub fn load_bytes(&self, bytes: &[u8], extension: Option<&str>) -> Result<Mesh, MeshLoadError> {
+        let loader = self
+            .find(bytes, extension)
+            .ok_or(MeshLoadError::UnknownFormat)?;
+        loader.load(bytes).map_err(|error| MeshLoadError::Format {
+            format: loader.name().to_string(),
+            error,
+        })
+    }
+
+    /// Load a mesh from a file.
+    pub fn load(&self, path: impl AsRef<std::path::Path>) -> Result<Mesh, MeshLoadError> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        self.load_bytes(
+            &bytes,
+            path.extension().and_then(|extension| extension.to_str()),
+        )
+    }
+}
+
+/// Loaders used by [`Mesh::load`].
+fn global_loaders() -> &'static RwLock<MeshLoaders> {
+    static LOADERS: OnceLock<RwLock<MeshLoaders>> = OnceLock::new();
+    LOADERS.get_or_init(|| RwLock::new(MeshLoaders::default()))
+}
+
+/// Register a loader used by [`Mesh::load`], taking precedence over the loaders registered before
+/// it and the formats of this crate.
+pub fn register_loader(loader: impl MeshLoader + 'static) {
+    global_loaders()
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .register(loader);
+}
+
+impl Mesh {
+    /// Load a mesh from a file, detecting its format from its content or its extension.
+    ///
+    /// Formats added with [`register_loader`] are supported too.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Mesh, MeshLoadError> {
+        let loaders = global_loaders()
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
+        loaders.load(path)
+    }
+}
+
+struct TextFormat;
+
+impl MeshLoader for TextFormat {
+    fn name(&self) -> &str {
+        "polyanya"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mesh"]
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(b"mesh\n") || bytes.starts_with(b"mesh\r\n")
+    }
+
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+        Ok(Mesh::from_bytes(bytes)?)
+    }
+}
+
+struct BinaryFormat;
+
+impl MeshLoader for BinaryFormat {
+    fn name(&self) -> &str {
+        "binary"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["plya"]
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(MAGIC)
+    }
+
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+        Ok(Mesh::load_binary(bytes)?)
+    }
+}
+
+/// Content of a text file, for loaders of text formats.
+fn text(bytes: &[u8]) -> Result<&str, Box<dyn Error + Send + Sync>> {
+    Ok(std::str::from_utf8(bytes)?)
+}
+
+struct MovingAiFormat;
+
+impl MeshLoader for MovingAiFormat {
+    fn name(&self) -> &str {
+        "MovingAI"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["map"]
+    }
+
+    fn detect(&self, bytes: &[u8]) -> bool {
+        bytes.starts_with(b"type octile")
+    }
+
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+        Ok(crate::movingai::load_map(text(bytes)?)?)
+    }
+}
+
+struct GodotFormat;
+
+impl MeshLoader for GodotFormat {
+    fn name(&self) -> &str {
+        "Godot"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tres", "tscn"]
+    }
+
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+        Ok(crate::godot::from_resource(text(bytes)?)?)
+    }
+}
+
+#[cfg(feature = "tiled")]
+struct TiledFormat;
+
+#[cfg(feature = "tiled")]
+impl MeshLoader for TiledFormat {
+    fn name(&self) -> &str {
+        "Tiled"
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tmx"]
+    }
+
+    fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+        Ok(crate::tiled::from_tmx(text(bytes)?, None)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use super::{register_loader, MeshLoadError, MeshLoader, MeshLoaders};
+    use crate::{maps::grid_mesh, Mesh};
+
+    /// Loads a mesh of `width` by `height` cells from a file with its size, like `3x2`.
+    struct SizeFormat;
+
+    impl MeshLoader for SizeFormat {
+        fn name(&self) -> &str {
+            "size"
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["size"]
+        }
+
+        fn load(&self, bytes: &[u8]) -> Result<Mesh, Box<dyn Error + Send + Sync>> {
+            let (width, height) = std::str::from_utf8(bytes)?
+                .trim()
+                .split_once('x')
+                .ok_or("missing size")?;
+            Ok(grid_mesh(width.parse()?, height.parse()?, |_, _| true))
+        }
+    }
+
+    #[test]
+    fn detection() {
+        let mut loaders = MeshLoaders::default();
+        let text = std::fs::read("meshes/arena.mesh").unwrap();
+        let mut binary = vec![];
+        Mesh::from_bytes(&text)
+            .unwrap()
+            .save_binary(&mut binary)
+            .unwrap();
+
+        assert_eq!(loaders.find(&text, None).unwrap().name(), "polyanya");
+        assert_eq!(
+            loaders.find(&binary, Some("mesh")).unwrap().name(),
+            "binary"
+        );
+        assert_eq!(loaders.find(b"", Some("TSCN")).unwrap().name(), "Godot");
+        assert!(loaders.find(b"3x2", Some("size")).is_none());
+        assert!(matches!(
+            loaders.load_bytes(b"3x2", Some("size")),
+            Err(MeshLoadError::UnknownFormat)
+        ));
+
+        loaders.register(SizeFormat);
+        let mesh = loaders.load_bytes(b"3x2", Some("size")).unwrap();
+        assert_eq!(mesh.polygons.len(), 6);
+        assert!(matches!(
+            loaders.load_bytes(b"3", Some("size")),
+            Err(MeshLoadError::Format { format, .. }) if format == "size"
+        ));
+        assert_eq!(
+            loaders.load_bytes(&binary, None).unwrap().polygons.len(),
+            Mesh::from_bytes(&text).unwrap().polygons.len()
+        );
+    }
+
+    #[test]
+    fn load() {
+        let mesh = Mesh::load("meshes/arena.mesh").unwrap();
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(matches!(
+            Mesh::load("meshes/missing.mesh"),
+            Err(MeshLoadError::Io(_))
+        ));
+
+        let path = std::env::temp_dir().join(format!("polyanya-{}.size", std::process::id()));
+        std::fs::write(&path, "2x2").unwrap();
+        assert!(matches!(
+            Mesh::load(&path),
+            Err(MeshLoadError::UnknownFormat)
+        ));
+        register_loader(SizeFormat);
+        let mesh = Mesh::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(mesh.unwrap().polygons.len(), 4);
+    }
+}
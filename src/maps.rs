@@ -0,0 +1,452 @@
+//! Generators of synthetic maps, to try the crate or benchmark it without a mesh file.
+//!
+//! Maps are built on a grid of unit cells, with the walkable cells becoming square polygons. The
+//! same seed always gives the same map.
+
+use hashbrown::HashMap;
+
+use crate::{Mesh, MeshBuilder, Polygon, Vertex};
+
+/// Small deterministic random number generator (xorshift64*), so that maps don't depend on an
+/// external crate or platform.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        // splitmix64 step to spread the seed bits
+        let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        Rng((z ^ (z >> 31)) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// Random number in `0..max`.
+    fn below(&mut self, max: usize) -> usize {
+        (self.next_u64() % max.max(1) as u64) as usize
+    }
+}
+
+/// Build a mesh with a square polygon for each walkable cell of a `width` x `height` grid.
+pub(crate) fn grid_mesh(
+    width: usize,
+    height: usize,
+    walkable: impl Fn(usize, usize) -> bool,
+) -> Mesh {
+    let is_walkable = |x: isize, y: isize| {
+        x >= 0
+            && y >= 0
+            && (x as usize) < width
+            && (y as usize) < height
+            && walkable(x as usize, y as usize)
+    };
+    let mut polygon_ids = vec![-1_isize; width * height];
+    let mut nb_polygons = 0;
+    for y in 0..height {
+        for x in 0..width {
+            if walkable(x, y) {
+                polygon_ids[y * width + x] = nb_polygons;
+                nb_polygons += 1;
+            }
+        }
+    }
+    let polygon_at = |x: isize, y: isize| {
+        if is_walkable(x, y) {
+            polygon_ids[y as usize * width + x as usize]
+        } else {
+            -1
+        }
+    };
+
+    let mut vertex_ids = vec![usize::MAX; (width + 1) * (height + 1)];
+    let mut vertices = vec![];
+    for y in 0..=height as isize {
+        for x in 0..=width as isize {
+            // cells around the vertex, counterclockwise
+            let mut around = vec![];
+            for (dx, dy) in [(-1, -1), (0, -1), (0, 0), (-1, 0)] {
+                let polygon = polygon_at(x + dx, y + dy);
+                if polygon != -1 || around.last() != Some(&-1) {
+                    around.push(polygon);
+                }
+            }
+            if around.len() > 1 && around.first() == Some(&-1) && around.last() == Some(&-1) {
+                around.pop();
+            }
+            if around.iter().all(|p| *p == -1) {
+                continue;
+            }
+            vertex_ids[y as usize * (width + 1) + x as usize] = vertices.len();
+            vertices.push(Vertex::new(x as f32, y as f32, around));
+        }
+    }
+    let vertex_at = |x: usize, y: usize| vertex_ids[y * (width + 1) + x] as isize;
+
+    let mut polygons = Vec::with_capacity(nb_polygons as usize);
+    for y in 0..height {
+        for x in 0..width {
+            if !walkable(x, y) {
+                continue;
+            }
+            let (ix, iy) = (x as isize, y as isize);
+            polygons.push(Polygon::new(
+                4,
+                vec![
+                    vertex_at(x, y),
+                    vertex_at(x + 1, y),
+                    vertex_at(x + 1, y + 1),
+                    vertex_at(x, y + 1),
+                    polygon_at(ix, iy - 1),
+                    polygon_at(ix + 1, iy),
+                    polygon_at(ix, iy + 1),
+                    polygon_at(ix - 1, iy),
+                ],
+            ));
+        }
+    }
+    Mesh::new(vertices, polygons)
+}
+
+/// Build a mesh from the walkable cells of a `width` x `height` grid, merging them into rectangles.
+///
+/// Rectangles are grown greedily, first along rows then down the following rows. Where the corner
+/// of a rectangle touches the side of another one, a vertex is added on that side so that
+/// neighbours share whole edges.
+pub(crate) fn merged_grid_mesh(
+    width: usize,
+    height: usize,
+    walkable: impl Fn(usize, usize) -> bool,
+) -> Mesh {
+    let mut covered = vec![false; width * height];
+    let is_free = |covered: &[bool], x: usize, y: usize| !covered[y * width + x] && walkable(x, y);
+    let mut rectangles = vec![];
+    for y in 0..height {
+        for x in 0..width {
+            if !is_free(&covered, x, y) {
+                continue;
+            }
+            let mut x1 = x + 1;
+            while x1 < width && is_free(&covered, x1, y) {
+                x1 += 1;
+            }
+            let mut y1 = y + 1;
+            while y1 < height && (x..x1).all(|cx| is_free(&covered, cx, y1)) {
+                y1 += 1;
+            }
+            for cy in y..y1 {
+                for cx in x..x1 {
+                    covered[cy * width + cx] = true;
+                }
+            }
+            rectangles.push((x, y, x1, y1));
+        }
+    }
+
+    let mut builder = MeshBuilder::new();
+    let mut vertex_ids = HashMap::new();
+    for (x0, y0, x1, y1) in &rectangles {
+        for corner in [(*x0, *y0), (*x1, *y0), (*x1, *y1), (*x0, *y1)] {
+            vertex_ids.entry(corner).or_insert_with(|| {
+                builder
+                    .add_vertex([corner.0 as f32, corner.1 as f32])
+                    .unwrap()
+            });
+        }
+    }
+    for (x0, y0, x1, y1) in rectangles {
+        // walk around the rectangle counterclockwise, keeping the corners of other rectangles
+        let boundary = (x0..x1)
+            .map(|x| (x, y0))
+            .chain((y0..y1).map(|y| (x1, y)))
+            .chain((x0 + 1..=x1).rev().map(|x| (x, y1)))
+            .chain((y0 + 1..=y1).rev().map(|y| (x0, y)));
+        let polygon = boundary.filter_map(|point| vertex_ids.get(&point).copied());
+        builder.add_polygon(polygon).unwrap();
+    }
+    builder.build().unwrap()
+}
+
+impl Mesh {
+    /// Build a mesh from the walkable cells of a `width` x `height` tilemap, decomposed into
+    /// rectangles.
+    ///
+    /// Cell `(x, y)` covers the unit square from `[x, y]` to `[x + 1, y + 1]`. Rectangles are grown
+    /// greedily along rows then down the following rows, and vertices are added where the corner
+    /// of a rectangle touches the side of another one, so the mesh needs no triangulation.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let tiles = ["..#", "..#", "..."];
+    /// let mesh = Mesh::from_grid(3, 3, |x, y| tiles[y].as_bytes()[x] == b'.');
+    /// assert_eq!(mesh.polygons().len(), 2);
+    /// assert!(!mesh.point_in_mesh([2.5, 0.5]));
+    /// assert!(mesh.path([0.5, 0.5], [2.5, 2.5]).is_some());
+    /// ```
+    pub fn from_grid(width: usize, height: usize, walkable: impl Fn(usize, usize) -> bool) -> Mesh {
+        merged_grid_mesh(width, height, walkable)
+    }
+}
+
+/// Build a mesh like [`grid_mesh`], with cells of size `cell` instead of unit cells and the grid
+/// starting at `origin`.
+#[cfg_attr(not(any(feature = "tiled", feature = "ldtk")), allow(dead_code))]
+pub(crate) fn scaled_grid_mesh(
+    width: usize,
+    height: usize,
+    origin: [f32; 2],
+    cell: [f32; 2],
+    walkable: impl Fn(usize, usize) -> bool,
+) -> Mesh {
+    let mut mesh = grid_mesh(width, height, walkable);
+    for vertex in &mut mesh.vertices {
+        vertex.x = origin[0] + vertex.x * cell[0];
+        vertex.y = origin[1] + vertex.y * cell[1];
+    }
+    mesh.rebuild_caches();
+    mesh
+}
+
+/// Rooms of random sizes connected by corridors, on a `width` x `height` grid.
+///
+/// Each room is connected to the previous one by an L-shaped corridor, so the whole map is
+/// connected.
+pub fn rooms_and_corridors(width: usize, height: usize, rooms: usize, seed: u64) -> Mesh {
+    let mut rng = Rng::new(seed);
+    let mut walkable = vec![false; width * height];
+    let mut centers: Vec<(usize, usize)> = vec![];
+    let max_size = (width.min(height) / 4).max(2);
+    for _ in 0..rooms {
+        let room_width = 2 + rng.below(max_size - 1);
+        let room_height = 2 + rng.below(max_size - 1);
+        if room_width >= width || room_height >= height {
+            continue;
+        }
+        let x = rng.below(width - room_width);
+        let y = rng.below(height - room_height);
+        for cy in y..y + room_height {
+            for cx in x..x + room_width {
+                walkable[cy * width + cx] = true;
+            }
+        }
+        let center = (x + room_width / 2, y + room_height / 2);
+        if let Some(previous) = centers.last() {
+            let (x0, x1) = (previous.0.min(center.0), previous.0.max(center.0));
+            for cx in x0..=x1 {
+                walkable[previous.1 * width + cx] = true;
+            }
+            let (y0, y1) = (previous.1.min(center.1), previous.1.max(center.1));
+            for cy in y0..=y1 {
+                walkable[cy * width + center.0] = true;
+            }
+        }
+        centers.push(center);
+    }
+    grid_mesh(width, height, |x, y| walkable[y * width + x])
+}
+
+/// A perfect maze of `width` x `height` rooms, with corridors and walls one cell wide.
+///
+/// The grid is `2 * width + 1` x `2 * height + 1` cells. Room `(x, y)` is the cell
+/// `(2 * x + 1, 2 * y + 1)`, and there is exactly one route between any two rooms.
+pub fn maze(width: usize, height: usize, seed: u64) -> Mesh {
+    let mut rng = Rng::new(seed);
+    let grid_width = 2 * width + 1;
+    let grid_height = 2 * height + 1;
+    let mut walkable = vec![false; grid_width * grid_height];
+    let mut visited = vec![false; width * height];
+    if width == 0 || height == 0 {
+        return Mesh::default();
+    }
+
+    // iterative recursive backtracker
+    let mut stack = vec![(0, 0)];
+    visited[0] = true;
+    walkable[grid_width + 1] = true;
+    while let Some(&(x, y)) = stack.last() {
+        let neighbours: Vec<(usize, usize)> = [(0, -1), (1, 0), (0, 1), (-1, 0)]
+            .iter()
+            .map(|(dx, dy)| (x as isize + dx, y as isize + dy))
+            .filter(|(nx, ny)| {
+                *nx >= 0 && *ny >= 0 && (*nx as usize) < width && (*ny as usize) < height
+            })
+            .map(|(nx, ny)| (nx as usize, ny as usize))
+            .filter(|(nx, ny)| !visited[ny * width + nx])
+            .collect();
+        if neighbours.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let (nx, ny) = neighbours[rng.below(neighbours.len())];
+        visited[ny * width + nx] = true;
+        walkable[(ny * 2 + 1) * grid_width + nx * 2 + 1] = true;
+        walkable[(y + ny + 1) * grid_width + x + nx + 1] = true;
+        stack.push((nx, ny));
+    }
+    grid_mesh(grid_width, grid_height, |x, y| walkable[y * grid_width + x])
+}
+
+/// An open `width` x `height` area with random rectangular obstacles.
+///
+/// Obstacles are up to `max_size` cells wide and high. They can overlap, and can split the area
+/// into several disconnected parts.
+pub fn random_obstacles(
+    width: usize,
+    height: usize,
+    obstacles: usize,
+    max_size: usize,
+    seed: u64,
+) -> Mesh {
+    let mut rng = Rng::new(seed);
+    let mut walkable = vec![true; width * height];
+    for _ in 0..obstacles {
+        let obstacle_width = 1 + rng.below(max_size.min(width));
+        let obstacle_height = 1 + rng.below(max_size.min(height));
+        let x = rng.below(width + 1 - obstacle_width);
+        let y = rng.below(height + 1 - obstacle_height);
+        for cy in y..y + obstacle_height {
+            for cx in x..x + obstacle_width {
+                walkable[cy * width + cx] = false;
+            }
+        }
+    }
+    grid_mesh(width, height, |x, y| walkable[y * width + x])
+}
+
+/// An open area `height` cells high between two rows of `teeth` alcoves one cell wide.
+///
+/// The open area is a single polygon with a vertex at each side of each alcove, so searches
+/// crossing it have hundreds of successors at once. Alcove `i` of the bottom row is the cell
+/// `(2 * i, 0)`, and of the top row the cell `(2 * i, height + 1)`.
+pub fn comb(teeth: usize, height: usize) -> Mesh {
+    if teeth == 0 || height == 0 {
+        return Mesh::default();
+    }
+    let width = 2 * teeth;
+    let top = height + 1;
+    let mut builder = MeshBuilder::new();
+    let mut vertex = |x: usize, y: usize| builder.add_vertex([x as f32, y as f32]).unwrap();
+    let bottom_side: Vec<usize> = (0..=width).map(|x| vertex(x, 1)).collect();
+    let top_side: Vec<usize> = (0..=width).map(|x| vertex(x, top)).collect();
+    let alcoves: Vec<[usize; 4]> = (0..teeth)
+        .flat_map(|i| {
+            let x = 2 * i;
+            [
+                [
+                    vertex(x, 0),
+                    vertex(x + 1, 0),
+                    bottom_side[x + 1],
+                    bottom_side[x],
+                ],
+                [
+                    top_side[x],
+                    top_side[x + 1],
+                    vertex(x + 1, top + 1),
+                    vertex(x, top + 1),
+                ],
+            ]
+        })
+        .collect();
+    builder
+        .add_polygon(bottom_side.iter().chain(top_side.iter().rev()).copied())
+        .unwrap();
+    for alcove in alcoves {
+        builder.add_polygon(alcove).unwrap();
+    }
+    builder.build().unwrap()
+}
+
+/// The meshes the searches are tested on: a maze, and an open area split by obstacles.
+#[cfg(test)]
+pub(crate) fn test_meshes() -> [Mesh; 2] {
+    [maze(8, 8, 5), random_obstacles(30, 30, 25, 5, 2)]
+}
+
+/// `count` points spread over the 30 x 30 area of the [`test_meshes`], at `offset` from the
+/// corner of their cell. Some are outside of the meshes or in their obstacles.
+#[cfg(test)]
+pub(crate) fn test_points(count: usize, offset: [f32; 2]) -> impl Iterator<Item = [f32; 2]> {
+    (0..count).map(move |i| {
+        [
+            (i * 7 % 30) as f32 + offset[0],
+            (i * 13 % 30) as f32 + offset[1],
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{comb, grid_mesh, maze, merged_grid_mesh, random_obstacles, rooms_and_corridors};
+    use crate::{Aabb, Mesh};
+
+    #[test]
+    fn grid() {
+        // a U shape, like the mesh used in other tests
+        let mesh = grid_mesh(3, 2, |x, y| y == 0 || x != 1);
+        assert_eq!(mesh.polygons.len(), 5);
+        assert_eq!(mesh.vertices.len(), 12);
+        assert!(
+            (mesh.path([0.5, 1.5], [2.5, 1.5]).unwrap().len - (1.0 + 2.0_f32.sqrt())).abs()
+                < 1.0e-5
+        );
+    }
+
+    #[test]
+    fn merged_grid() {
+        let mesh = merged_grid_mesh(3, 2, |x, y| y == 0 || x != 1);
+        assert_eq!(mesh.polygons.len(), 3);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(
+            (mesh.path([0.5, 1.5], [2.5, 1.5]).unwrap().len - (1.0 + 2.0_f32.sqrt())).abs()
+                < 1.0e-5
+        );
+        // the bottom rectangle has a vertex where each column touches it
+        assert_eq!(mesh.polygons[0].vertices.len(), 6);
+
+        let open = merged_grid_mesh(4, 4, |_, _| true);
+        assert_eq!(open.polygons.len(), 1);
+        assert_eq!(open.vertices.len(), 4);
+
+        let blocked = Mesh::from_grid(4, 4, |_, _| false);
+        assert!(blocked.polygons.is_empty());
+        assert!(blocked.vertices.is_empty());
+    }
+
+    #[test]
+    fn maze_is_connected() {
+        let mesh = maze(5, 4, 42);
+        assert_eq!(mesh.bounding_box(), Aabb::new([1.0, 1.0], [10.0, 8.0]));
+        assert_eq!(mesh.polygons.len(), 5 * 4 * 2 - 1);
+        assert!(mesh.path([1.5, 1.5], [9.5, 7.5]).unwrap().len > 0.0);
+    }
+
+    #[test]
+    fn comb_is_one_open_area() {
+        let mesh = comb(10, 4);
+        assert_eq!(mesh.polygons.len(), 21);
+        assert_eq!(mesh.polygons[0].vertices.len(), 42);
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.bounding_box(), Aabb::new([0.0, 0.0], [20.0, 6.0]));
+        assert_eq!(
+            mesh.path([0.5, 0.5], [18.5, 5.5]).unwrap().path,
+            vec![[1.0, 1.0], [18.0, 5.0], [18.5, 5.5]]
+        );
+        assert!(comb(0, 4).polygons.is_empty());
+    }
+
+    #[test]
+    fn deterministic() {
+        let a = rooms_and_corridors(30, 30, 5, 7);
+        let b = rooms_and_corridors(30, 30, 5, 7);
+        assert_eq!(a.polygons.len(), b.polygons.len());
+        assert_eq!(
+            random_obstacles(20, 20, 10, 3, 1).polygons.len(),
+            random_obstacles(20, 20, 10, 3, 1).polygons.len()
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Aabb, Mesh, Path, PolygonId,
+};
+
+impl Mesh {
+    /// Compute a path from `from` to `to` that keeps away from the walls, preferring to go
+    /// through passages at least `margin` away from them.
+    ///
+    /// Crossing an edge closer than `margin` to an edge that can't be crossed costs more the
+    /// closer it is, inversely to the distance. Paths still turn on the corners of the mesh, but
+    /// go around narrow passages when a wider one isn't much longer, which suits vehicles and
+    /// large agents. The length of the path is its actual length, without the costs. Returns
+    /// `None` if one of the points is not in the mesh, or if `to` can't be reached from `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall with a narrow gap, and a wide opening a bit farther
+    /// let mesh = Mesh::from_grid(20, 20, |x, y| y != 10 || x == 9 || (12..16).contains(&x));
+    /// let (from, to) = ([9.5, 2.0], [9.5, 18.0]);
+    /// assert!(mesh.path(from, to).unwrap().len < 17.0);
+    /// let path = mesh.path_with_margin(from, to, 2.0).unwrap();
+    /// assert!(path.path.contains(&[12.0, 10.0]) || path.path.contains(&[12.0, 11.0]));
+    /// ```
+    pub fn path_with_margin(&self, from: [f32; 2], to: [f32; 2], margin: f32) -> Option<Path> {
+        if margin <= 0.0 {
+            return self.path(from, to);
+        }
+        let boundaries: Vec<([[f32; 2]; 2], Aabb)> = self
+            .boundary_edges()
+            .map(|edge| {
+                let segment = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                (segment, Aabb::from_points(segment).inflate(margin))
+            })
+            .collect();
+        let model = |_: PolygonId, _: PolygonId, interval: [[f32; 2]; 2]| {
+            let middle = [
+                (interval[0][0] + interval[1][0]) / 2.0,
+                (interval[0][1] + interval[1][1]) / 2.0,
+            ];
+            let clearance = boundaries
+                .iter()
+                .filter(|(_, aabb)| aabb.contains(middle))
+                .map(|(edge, _)| distance_between(middle, project_on_segment(middle, *edge)))
+                .fold(margin, f32::min);
+            // from nothing at the margin, to 100 times the margin along a wall
+            let clearance = clearance.max(margin / 100.0);
+            Some(margin * (margin / clearance - 1.0))
+        };
+        let path = self.path_with_cost_model(from, to, &model)?;
+        let mut len = 0.0;
+        let mut last = from;
+        for point in &path.path {
+            len += distance_between(last, *point);
+            last = *point;
+        }
+        Some(Path {
+            path: path.path,
+            len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{random_obstacles, test_points},
+        Mesh,
+    };
+
+    #[test]
+    fn away_from_walls() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let from = [1.5, 1.5];
+        for to in test_points(30, [0.5, 0.5]) {
+            let shortest = mesh.path(from, to);
+            let away = mesh.path_with_margin(from, to, 1.0);
+            assert_eq!(shortest.is_some(), away.is_some());
+            let (Some(shortest), Some(away)) = (shortest, away) else {
+                continue;
+            };
+            // a real path, not shorter than the shortest one
+            assert!(away.len >= shortest.len - 1.0e-3);
+            assert_eq!(away.path.last(), Some(&to));
+            for point in &away.path {
+                assert!(mesh.point_in_mesh(*point));
+            }
+        }
+
+        // without margin, it's the shortest path
+        let to = [28.5, 28.5];
+        assert_eq!(mesh.path_with_margin(from, to, 0.0), mesh.path(from, to));
+    }
+
+    #[test]
+    fn narrow_and_unreachable() {
+        // the only way is a gap narrower than the margin
+        let mesh = Mesh::from_grid(10, 10, |x, y| y != 5 || x == 2);
+        let (from, to) = ([2.5, 1.0], [8.5, 9.0]);
+        let path = mesh.path_with_margin(from, to, 3.0).unwrap();
+        assert!((path.len - mesh.path(from, to).unwrap().len).abs() < 1.0e-3);
+        assert_eq!(path.path.last(), Some(&to));
+        for margin in [-1.0, f32::NAN] {
+            assert_eq!(mesh.path_with_margin(from, to, margin), mesh.path(from, to));
+        }
+        assert_eq!(mesh.path_with_margin(from, from, 3.0).unwrap().len, 0.0);
+
+        let walled = Mesh::from_grid(10, 10, |_, y| y != 5);
+        assert!(walled.path_with_margin(from, to, 1.0).is_none());
+        assert!(walled.path_with_margin([-1.0, 1.0], from, 1.0).is_none());
+        assert!(walled.path_with_margin(from, [20.0, 1.0], 1.0).is_none());
+    }
+}
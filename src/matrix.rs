@@ -0,0 +1,152 @@
+use std::task::Poll;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{Mesh, PolygonId, SearchContext, SearchInstance, SearchOptions};
+
+impl Mesh {
+    /// Compute the length of the shortest paths from each source to each target, `None` when
+    /// there is no path.
+    ///
+    /// `matrix[i][j]` is the distance from `sources[i]` to `targets[j]`. Points are located once,
+    /// and there is a single search from each source, going on until it reached all the targets.
+    /// With the `parallel` feature, sources are searched from in parallel.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+    /// let squad = [[1.0, 3.0], [2.0, 5.0]];
+    /// let objectives = [[4.0, 2.0], [6.0, 1.0], [100.0, 100.0]];
+    ///
+    /// let matrix = mesh.distance_matrix(&squad, &objectives);
+    /// assert_eq!(matrix[0][0], mesh.path([1.0, 3.0], [4.0, 2.0]).map(|path| path.len));
+    /// assert_eq!(matrix[1][2], None);
+    /// ```
+    pub fn distance_matrix(
+        &self,
+        sources: &[[f32; 2]],
+        targets: &[[f32; 2]],
+    ) -> Vec<Vec<Option<f32>>> {
+        #[cfg(feature = "counters")]
+        let _query = crate::counters::Query::start();
+        let located = self.locate_batch(targets);
+        let (goals, indices): (Vec<([f32; 2], PolygonId)>, Vec<usize>) = targets
+            .iter()
+            .zip(located)
+            .enumerate()
+            .filter_map(|(index, (target, polygon))| Some(((*target, polygon?), index)))
+            .unzip();
+        let row = |(source, polygon): (&[f32; 2], Option<PolygonId>)| {
+            let mut row = vec![None; targets.len()];
+            for (distance, index) in self
+                .distances_to_goals(*source, polygon, &goals)
+                .into_iter()
+                .zip(&indices)
+            {
+                row[*index] = distance;
+            }
+            row
+        };
+
+        let sources_located = self.locate_batch(sources);
+        #[cfg(feature = "parallel")]
+        let matrix = sources.par_iter().zip(sources_located).map(row).collect();
+        #[cfg(not(feature = "parallel"))]
+        let matrix = sources.iter().zip(sources_located).map(row).collect();
+        matrix
+    }
+
+    /// Length of the shortest path from `from` to each goal, with a search going on until it
+    /// reached all of them.
+    fn distances_to_goals(
+        &self,
+        from: [f32; 2],
+        polygon: Option<PolygonId>,
+        goals: &[([f32; 2], PolygonId)],
+    ) -> Vec<Option<f32>> {
+        let mut distances = vec![None; goals.len()];
+        if goals.is_empty() {
+            return distances;
+        }
+        let Ok(mut search) = SearchInstance::start(
+            self,
+            &mut SearchContext::new(),
+            from,
+            from,
+            polygon,
+            None,
+            SearchOptions {
+                goals,
+                ..SearchOptions::default()
+            },
+        ) else {
+            return distances;
+        };
+
+        let mut remaining = goals.len();
+        while remaining > 0 {
+            let mut reached = None;
+            let result = search.expand(usize::MAX, |node| {
+                if let Some(goal) = node.goal {
+                    reached = Some((goal, node.f + node.penalty + node.g));
+                }
+            });
+            // the search stops at each goal reached, and can be continued for the others
+            let (Poll::Ready(Some(_)), Some((goal, distance))) = (result, reached) else {
+                break;
+            };
+            if distances[goal].is_none() {
+                distances[goal] = Some(distance);
+                remaining -= 1;
+            }
+        }
+        distances
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::{grid_mesh, maze, test_meshes, test_points};
+
+    #[test]
+    fn same_as_paths() {
+        for mesh in test_meshes() {
+            let points: Vec<[f32; 2]> = test_points(30, [0.5, 0.5]).collect();
+            let (sources, targets) = points.split_at(8);
+            let matrix = mesh.distance_matrix(sources, targets);
+            assert_eq!(matrix.len(), sources.len());
+            for (source, row) in sources.iter().zip(&matrix) {
+                assert_eq!(row.len(), targets.len());
+                for (target, distance) in targets.iter().zip(row) {
+                    match (mesh.path(*source, *target), distance) {
+                        (Some(path), Some(distance)) => {
+                            assert!((path.len - distance).abs() < 1.0e-3)
+                        }
+                        (None, None) => (),
+                        (path, distance) => panic!("{:?} / {:?}", path, distance),
+                    }
+                }
+            }
+        }
+        assert!(maze(2, 2, 0).distance_matrix(&[], &[[0.5, 0.5]]).is_empty());
+        assert_eq!(
+            maze(2, 2, 0).distance_matrix(&[[1.5, 1.5]], &[]),
+            vec![Vec::<Option<f32>>::new()]
+        );
+    }
+
+    #[test]
+    fn unreachable_and_repeated_points() {
+        let walled = grid_mesh(10, 3, |x, _| x != 5);
+        let sources = [[1.5, 1.5], [-1.0, 1.5], [8.5, 1.5]];
+        let targets = [[8.5, 0.5], [1.5, 1.5], [20.0, 1.5], [8.5, 0.5], [0.0, 3.0]];
+        let matrix = walled.distance_matrix(&sources, &targets);
+        let diagonal = 1.5_f32.hypot(1.5);
+        // behind the wall, at the source, outside of the mesh, twice the same, on a corner
+        assert_eq!(matrix[0], vec![None, Some(0.0), None, None, Some(diagonal)]);
+        // from outside of the mesh
+        assert_eq!(matrix[1], vec![None; targets.len()]);
+        assert_eq!(matrix[2], vec![Some(1.0), None, None, Some(1.0), None]);
+    }
+}
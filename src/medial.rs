@@ -0,0 +1,174 @@
+use crate::{helpers::distance_between, Mesh, PolygonId};
+
+/// Number of points checked along an edge for the one furthest from the walls.
+const EDGE_SAMPLES: usize = 16;
+
+/// Approximation of the medial axis of the walkable area of a mesh, as returned by
+/// [`Mesh::medial_axis`]: a graph of points as far as possible from the walls, with their
+/// clearance.
+///
+/// There is a node inside each polygon, with the same index as the polygon, followed by a node on
+/// each edge shared by two polygons, linked to the nodes of these polygons. Following the links
+/// stays away from the walls, which is what routing agents as wide as possible needs, and the
+/// clearance of the nodes along the way tells how wide the corridors are.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MedialAxis {
+    nodes: Vec<([f32; 2], f32)>,
+    links: Vec<[usize; 2]>,
+    neighbours: Vec<Vec<usize>>,
+}
+
+impl Mesh {
+    /// Compute the [`MedialAxis`] of the mesh.
+    ///
+    /// The node of a polygon is the point furthest from the walls among its center and the points
+    /// halfway from it to each vertex, and the node of an edge the point furthest from the walls
+    /// along it. It's a preprocessing pass, to run again after the mesh changes.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a room 6 wide, and a corridor 2 wide leaving it
+    /// let mesh = Mesh::from_grid(16, 6, |x, y| x < 6 || (2..4).contains(&y));
+    /// let axis = mesh.medial_axis();
+    /// let in_corridor = axis.nearest([12.0, 3.0]).unwrap();
+    /// assert_eq!(axis.clearance(in_corridor), 1.0);
+    /// let in_room = axis.nearest([3.0, 3.0]).unwrap();
+    /// assert!(axis.clearance(in_room) > 2.0);
+    /// ```
+    pub fn medial_axis(&self) -> MedialAxis {
+        let clearance = |point: [f32; 2]| {
+            let clearance = self.distance_to_boundary(point).unwrap_or(f32::INFINITY);
+            (point, clearance)
+        };
+        let furthest = |candidates: &mut dyn Iterator<Item = [f32; 2]>| {
+            candidates
+                .map(clearance)
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .unwrap_or(([0.0, 0.0], 0.0))
+        };
+
+        let mut nodes: Vec<([f32; 2], f32)> = self
+            .polygons
+            .iter()
+            .map(|polygon| {
+                let points: Vec<[f32; 2]> = polygon
+                    .vertices
+                    .iter()
+                    .map(|vertex| self.vertices[*vertex].p())
+                    .collect();
+                let n = points.len() as f32;
+                let center = points
+                    .iter()
+                    .fold([0.0, 0.0], |sum, p| [sum[0] + p[0] / n, sum[1] + p[1] / n]);
+                furthest(
+                    &mut std::iter::once(center).chain(
+                        points
+                            .iter()
+                            .map(|p| [(center[0] + p[0]) / 2.0, (center[1] + p[1]) / 2.0]),
+                    ),
+                )
+            })
+            .collect();
+        let mut links = vec![];
+        for (edge, a, b) in self.interior_edges() {
+            let [start, end] = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+            nodes.push(furthest(&mut (1..EDGE_SAMPLES).map(|i| {
+                let t = i as f32 / EDGE_SAMPLES as f32;
+                [
+                    start[0] + (end[0] - start[0]) * t,
+                    start[1] + (end[1] - start[1]) * t,
+                ]
+            })));
+            let node = nodes.len() - 1;
+            links.extend([[a.index(), node], [node, b.index()]]);
+        }
+
+        let mut neighbours = vec![vec![]; nodes.len()];
+        for [a, b] in &links {
+            neighbours[*a].push(*b);
+            neighbours[*b].push(*a);
+        }
+        MedialAxis {
+            nodes,
+            links,
+            neighbours,
+        }
+    }
+}
+
+impl MedialAxis {
+    /// Number of nodes in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Check if the graph has no node.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Position of a node.
+    pub fn point(&self, node: usize) -> [f32; 2] {
+        self.nodes[node].0
+    }
+
+    /// Distance from a node to the closest wall, the radius of the widest agent that can stand
+    /// on it.
+    pub fn clearance(&self, node: usize) -> f32 {
+        self.nodes[node].1
+    }
+
+    /// Node inside a polygon.
+    pub fn polygon_node(&self, polygon: PolygonId) -> usize {
+        polygon.index()
+    }
+
+    /// Nodes linked to a node.
+    pub fn neighbours(&self, node: usize) -> &[usize] {
+        &self.neighbours[node]
+    }
+
+    /// Iterate over the links between nodes.
+    pub fn links(&self) -> impl Iterator<Item = [usize; 2]> + '_ {
+        self.links.iter().copied()
+    }
+
+    /// Node closest to a point, or `None` if the graph is empty.
+    pub fn nearest(&self, point: [f32; 2]) -> Option<usize> {
+        (0..self.nodes.len()).min_by(|a, b| {
+            distance_between(point, self.nodes[*a].0)
+                .total_cmp(&distance_between(point, self.nodes[*b].0))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::random_obstacles, tests::mesh_u_grid, PolygonId};
+
+    #[test]
+    fn medial_axis() {
+        let mesh = mesh_u_grid();
+        let axis = mesh.medial_axis();
+        // a node in each polygon, and on each of the 4 edges between them
+        assert_eq!(axis.len(), 9);
+        assert_eq!(axis.links().count(), 8);
+        assert_eq!(axis.point(axis.polygon_node(PolygonId::new(0))), [0.5, 0.5]);
+        assert_eq!(axis.clearance(axis.polygon_node(PolygonId::new(0))), 0.5);
+        let between = axis.neighbours(0)[0];
+        assert_eq!(axis.point(between), [1.0, 0.5]);
+        assert_eq!(axis.clearance(between), 0.5);
+
+        let mesh = random_obstacles(12, 12, 6, 3, 2);
+        let axis = mesh.medial_axis();
+        for node in 0..axis.len() {
+            let point = axis.point(node);
+            assert!(mesh.point_in_mesh(point));
+            assert_eq!(Some(axis.clearance(node)), mesh.distance_to_boundary(point));
+        }
+        for [a, b] in axis.links() {
+            assert!(axis.neighbours(a).contains(&b));
+            assert!(axis.neighbours(b).contains(&a));
+        }
+    }
+}
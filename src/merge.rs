@@ -0,0 +1,454 @@
+//! Merging of neighbouring polygons into larger convex polygons, with the Hertel–Mehlhorn
+//! algorithm.
+//!
+//! Edges shared by two polygons are removed, longest first, when the polygon they leave is still
+//! convex. Fewer polygons means fewer intervals to expand during a search.
+//!
+//! The same algorithm splits concave polygons into convex parts: they are triangulated by ear
+//! clipping, then the triangles are merged back.
+
+use hashbrown::HashMap;
+
+use crate::{validate::EPSILON, Mesh, MeshBuilder};
+
+fn orient(a: [f32; 2], b: [f32; 2], c: [f32; 2]) -> f64 {
+    let [a, b, c] = [a, b, c].map(|p| [p[0] as f64, p[1] as f64]);
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+/// Polygon left by removing the edge from `u` to `v` of `polygon`, which `other` has from `v` to
+/// `u`, or `None` if it would not be convex.
+fn merged(
+    points: &[[f32; 2]],
+    polygon: &[usize],
+    other: &[usize],
+    [u, v]: [usize; 2],
+) -> Option<Vec<usize>> {
+    let i = polygon.iter().position(|p| *p == u)?;
+    let j = other.iter().position(|p| *p == v)?;
+    let (n, m) = (polygon.len(), other.len());
+    if polygon[(i + 1) % n] != v || other[(j + 1) % m] != u {
+        return None;
+    }
+
+    // from `v` around `polygon` to `u`, then around `other` back to the vertex before `v`
+    let mut vertices = Vec::with_capacity(n + m - 2);
+    vertices.extend((1..=n).map(|k| polygon[(i + k) % n]));
+    vertices.extend((2..m).map(|k| other[(j + k) % m]));
+    if (1..vertices.len()).any(|k| vertices[..k].contains(&vertices[k])) {
+        return None;
+    }
+
+    let convex_at = |k: usize| {
+        let len = vertices.len();
+        orient(
+            points[vertices[(k + len - 1) % len]],
+            points[vertices[k]],
+            points[vertices[(k + 1) % len]],
+        ) >= 0.0
+    };
+    // only the angles at the ends of the removed edge change
+    (convex_at(0) && convex_at(n - 1)).then_some(vertices)
+}
+
+/// Check if a counterclockwise polygon turns clockwise at one of its vertices.
+fn is_concave(points: &[[f32; 2]], polygon: &[usize]) -> bool {
+    let n = polygon.len();
+    (0..n).any(|i| {
+        orient(
+            points[polygon[(i + n - 1) % n]],
+            points[polygon[i]],
+            points[polygon[(i + 1) % n]],
+        ) < -EPSILON as f64
+    })
+}
+
+/// Triangulate a simple counterclockwise polygon by ear clipping. Ears with another vertex of the
+/// polygon on their boundary are skipped, so that no vertex is left in the middle of an edge.
+fn ear_clipping(points: &[[f32; 2]], polygon: &[usize]) -> Vec<Vec<usize>> {
+    let mut remaining = polygon.to_vec();
+    let mut triangles = vec![];
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let ear = (0..n).find(|i| {
+            let [a, b, c] = [(i + n - 1) % n, *i, (i + 1) % n].map(|k| remaining[k]);
+            let [pa, pb, pc] = [a, b, c].map(|v| points[v]);
+            orient(pa, pb, pc) > 0.0
+                && !remaining.iter().any(|v| {
+                    let p = points[*v];
+                    ![a, b, c].contains(v)
+                        && orient(pa, pb, p) >= 0.0
+                        && orient(pb, pc, p) >= 0.0
+                        && orient(pc, pa, p) >= 0.0
+                })
+        });
+        let Some(i) = ear else {
+            // not a simple polygon, keep what is left as is
+            triangles.push(remaining);
+            return triangles;
+        };
+        triangles.push(vec![
+            remaining[(i + n - 1) % n],
+            remaining[i],
+            remaining[(i + 1) % n],
+        ]);
+        remaining.remove(i);
+    }
+    if orient(
+        points[remaining[0]],
+        points[remaining[1]],
+        points[remaining[2]],
+    ) > 0.0
+    {
+        triangles.push(remaining);
+    }
+    triangles
+}
+
+/// Split a concave counterclockwise polygon into convex parts. Other polygons are returned as
+/// they are.
+pub(crate) fn convex_parts(points: &[[f32; 2]], polygon: &[usize]) -> Vec<Vec<usize>> {
+    let n = polygon.len();
+    let area: f64 = (0..n)
+        .map(|i| {
+            let (a, b) = (points[polygon[i]], points[polygon[(i + 1) % n]]);
+            a[0] as f64 * b[1] as f64 - b[0] as f64 * a[1] as f64
+        })
+        .sum();
+    if area <= 0.0 || !is_concave(points, polygon) {
+        return vec![polygon.to_vec()];
+    }
+    let mut parts: Vec<Option<Vec<usize>>> = ear_clipping(points, polygon)
+        .into_iter()
+        .map(Some)
+        .collect();
+    if parts.is_empty() {
+        return vec![polygon.to_vec()];
+    }
+
+    let mut edges = HashMap::new();
+    for (index, part) in parts.iter().flatten().enumerate() {
+        for k in 0..part.len() {
+            edges.insert((part[k], part[(k + 1) % part.len()]), index);
+        }
+    }
+    let mut diagonals: Vec<[usize; 2]> = edges
+        .keys()
+        .filter(|(from, to)| from < to && edges.contains_key(&(*to, *from)))
+        .map(|(from, to)| [*from, *to])
+        .collect();
+    let length = |[from, to]: [usize; 2]| {
+        let (a, b) = (points[from], points[to]);
+        (a[0] - b[0]).hypot(a[1] - b[1])
+    };
+    diagonals.sort_unstable_by(|a, b| length(*b).total_cmp(&length(*a)).then(a.cmp(b)));
+    for [u, v] in diagonals {
+        let (Some(&kept), Some(&removed)) = (edges.get(&(u, v)), edges.get(&(v, u))) else {
+            continue;
+        };
+        let (Some(part), Some(other)) = (&parts[kept], &parts[removed]) else {
+            continue;
+        };
+        let Some(vertices) = merged(points, part, other, [u, v]) else {
+            continue;
+        };
+        edges.remove(&(u, v));
+        edges.remove(&(v, u));
+        for k in 0..vertices.len() {
+            edges.insert((vertices[k], vertices[(k + 1) % vertices.len()]), kept);
+        }
+        parts[kept] = Some(vertices);
+        parts[removed] = None;
+    }
+    parts.into_iter().flatten().collect()
+}
+
+/// Split the concave polygons into convex parts, returning the polygons and the number of polygons
+/// split. The first part of a polygon keeps its index, the other parts are added at the end.
+pub(crate) fn split_concave(
+    points: &[[f32; 2]],
+    polygons: Vec<Vec<usize>>,
+) -> (Vec<Vec<usize>>, Vec<usize>, usize) {
+    let mut split = 0;
+    let mut extra = vec![];
+    let mut origins: Vec<usize> = (0..polygons.len()).collect();
+    let mut result: Vec<Vec<usize>> = polygons
+        .into_iter()
+        .enumerate()
+        .map(|(origin, polygon)| {
+            let mut parts = convex_parts(points, &polygon).into_iter();
+            let first = parts.next().unwrap_or(polygon);
+            let before = extra.len();
+            extra.extend(parts);
+            if extra.len() > before {
+                split += 1;
+                origins.extend(std::iter::repeat_n(origin, extra.len() - before));
+            }
+            first
+        })
+        .collect();
+    result.extend(extra);
+    (result, origins, split)
+}
+
+impl Mesh {
+    /// Merge neighbouring polygons into larger convex polygons, then rebuild the adjacency of
+    /// polygons and vertices.
+    ///
+    /// This is useful for triangulated meshes, like the ones built by [`Mesh::from_polygons`] or
+    /// imported from game engines, as paths are found faster with fewer polygons. Vertices keep
+    /// their index, polygons are renumbered.
+    ///
+    /// ```
+    /// # use polyanya::MeshBuilder;
+    /// let mut mesh = MeshBuilder::new()
+    ///     .vertex([0.0, 0.0])
+    ///     .vertex([1.0, 0.0])
+    ///     .vertex([1.0, 1.0])
+    ///     .vertex([0.0, 1.0])
+    ///     .polygon([0, 1, 2])
+    ///     .polygon([0, 2, 3])
+    ///     .build()
+    ///     .unwrap();
+    /// mesh.merge_convex();
+    /// assert_eq!(mesh.polygons().len(), 1);
+    /// ```
+    pub fn merge_convex(&mut self) {
+        let points: Vec<[f32; 2]> = self.vertices.iter().map(|vertex| vertex.p()).collect();
+        let mut polygons: Vec<Option<Vec<usize>>> = self
+            .polygons
+            .iter()
+            .map(|polygon| Some(polygon.vertices.clone()))
+            .collect();
+
+        // polygon having each directed edge
+        let mut edges = HashMap::new();
+        for (index, polygon) in self.polygons.iter().enumerate() {
+            for [from, to] in polygon.edges_index() {
+                edges.insert((from, to), index);
+            }
+        }
+
+        let mut candidates: Vec<[usize; 2]> = edges
+            .keys()
+            .filter(|(from, to)| from < to && edges.contains_key(&(*to, *from)))
+            .map(|(from, to)| [*from, *to])
+            .collect();
+        let length = |[from, to]: [usize; 2]| {
+            let (a, b) = (points[from], points[to]);
+            (a[0] - b[0]).hypot(a[1] - b[1])
+        };
+        candidates.sort_unstable_by(|a, b| length(*b).total_cmp(&length(*a)).then(a.cmp(b)));
+
+        for [u, v] in candidates {
+            let (Some(&kept), Some(&removed)) = (edges.get(&(u, v)), edges.get(&(v, u))) else {
+                continue;
+            };
+            if kept == removed {
+                continue;
+            }
+            let (Some(polygon), Some(other)) = (&polygons[kept], &polygons[removed]) else {
+                continue;
+            };
+            // keep the areas, the costs and the doors apart
+            if self.polygons[kept].flags != self.polygons[removed].flags
+                || self.polygons[kept].secondary_cost != self.polygons[removed].secondary_cost
+                || self.is_edge_gated([u, v])
+                || self.edge_secondary_cost([u, v]) != 0.0
+            {
+                continue;
+            }
+            let Some(vertices) = merged(&points, polygon, other, [u, v]) else {
+                continue;
+            };
+            edges.remove(&(u, v));
+            edges.remove(&(v, u));
+            let len = vertices.len();
+            for k in 0..len {
+                edges.insert((vertices[k], vertices[(k + 1) % len]), kept);
+            }
+            polygons[kept] = Some(vertices);
+            polygons[removed] = None;
+        }
+
+        let flags = polygons
+            .iter()
+            .zip(&self.polygons)
+            .filter(|(polygon, _)| polygon.is_some())
+            .map(|(_, polygon)| polygon.flags)
+            .collect();
+        self.rebuild(
+            MeshBuilder::from_parts(points, polygons.into_iter().flatten().collect())
+                .with_flags(flags)
+                .build()
+                .expect("building from valid parts can't fail"),
+        );
+    }
+
+    /// Split the concave polygons into convex parts, then rebuild the adjacency of polygons and
+    /// vertices. Returns the number of polygons split.
+    ///
+    /// Paths are only correct on meshes of convex polygons. Meshes built with a [`MeshBuilder`] or
+    /// loaded from a file are already split. The first part of a polygon keeps its index, the
+    /// other parts are added after the last polygon.
+    pub fn split_concave_polygons(&mut self) -> usize {
+        let points: Vec<[f32; 2]> = self.vertices.iter().map(|vertex| vertex.p()).collect();
+        let (polygons, origins, split) = split_concave(
+            &points,
+            self.polygons
+                .iter()
+                .map(|polygon| polygon.vertices.clone())
+                .collect(),
+        );
+        if split > 0 {
+            let flags = origins
+                .iter()
+                .map(|origin| self.polygons[*origin].flags)
+                .collect();
+            self.rebuild(
+                MeshBuilder::from_parts(points, polygons)
+                    .with_flags(flags)
+                    .build()
+                    .expect("building from valid parts can't fail"),
+            );
+        }
+        split
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mesh, MeshBuilder};
+
+    #[test]
+    fn triangulated_square() {
+        let mut builder = MeshBuilder::new();
+        for y in 0..4 {
+            for x in 0..4 {
+                builder = builder.vertex([x as f32, y as f32]);
+            }
+        }
+        for y in 0..3 {
+            for x in 0..3 {
+                let v = y * 4 + x;
+                builder = builder
+                    .polygon([v, v + 1, v + 5])
+                    .polygon([v, v + 5, v + 4]);
+            }
+        }
+        let mut mesh = builder.build().unwrap();
+        let before = mesh.path([0.5, 0.2], [2.7, 2.9]).unwrap();
+        mesh.merge_convex();
+        assert!(mesh.polygons.len() < 18);
+        assert_eq!(mesh.vertices.len(), 16);
+        assert!(mesh.validate().is_empty());
+        assert_eq!(mesh.path([0.5, 0.2], [2.7, 2.9]), Some(before));
+    }
+
+    #[test]
+    fn keeps_holes_and_reflex_corners() {
+        let outer = [
+            [0.0, 0.0],
+            [10.0, 0.0],
+            [10.0, 10.0],
+            [5.0, 5.0],
+            [0.0, 10.0],
+        ];
+        let hole = vec![[2.0, 2.0], [4.0, 2.0], [4.0, 4.0], [2.0, 4.0]];
+        let mut mesh = Mesh::from_polygons(&outer, &[hole]).unwrap();
+        let triangles = mesh.polygons.len();
+        let queries = [
+            ([1.0, 1.0], [9.0, 8.0]),
+            ([1.0, 8.0], [9.0, 8.0]),
+            ([3.0, 1.0], [3.0, 5.0]),
+        ];
+        let paths: Vec<_> = queries
+            .iter()
+            .map(|(from, to)| mesh.path(*from, *to))
+            .collect();
+
+        mesh.set_output_quantization(Some(0.5));
+        mesh.merge_convex();
+        assert!(mesh.polygons.len() < triangles);
+        assert_eq!(mesh.output_quantization(), Some(0.5));
+        mesh.set_output_quantization(None);
+        assert!(mesh.validate().is_empty());
+        assert!(!mesh.point_in_mesh([3.0, 3.0]));
+        assert!(!mesh.point_in_mesh([5.0, 8.0]));
+        for (id, polygon) in mesh.iter_polygons() {
+            let points: Vec<_> = polygon
+                .vertices()
+                .map(|v| mesh.vertex(v).unwrap().position())
+                .collect();
+            let n = points.len();
+            for i in 0..n {
+                let [a, b, c] = [points[i], points[(i + 1) % n], points[(i + 2) % n]];
+                let cross = (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0]);
+                assert!(cross >= 0.0, "polygon {} is not convex", id);
+            }
+        }
+        for ((from, to), path) in queries.iter().zip(paths) {
+            let merged = mesh.path(*from, *to);
+            assert_eq!(merged.is_some(), path.is_some());
+            if let (Some(merged), Some(path)) = (merged, path) {
+                assert!((merged.len - path.len).abs() < 1.0e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn concave_polygons() {
+        // an L, with a vertex in the middle of its bottom edge
+        let mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([2.0, 0.0])
+            .vertex([2.0, 1.0])
+            .vertex([1.0, 1.0])
+            .vertex([1.0, 2.0])
+            .vertex([0.0, 2.0])
+            .polygon([0, 1, 2, 3, 4, 5, 6])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.polygons.len(), 2);
+        assert!(mesh.validate().is_empty());
+        assert!(mesh
+            .polygons
+            .iter()
+            .any(|polygon| polygon.vertices.contains(&1)));
+        let path = mesh.path([1.8, 0.5], [0.5, 1.8]).unwrap();
+        assert_eq!(path.path, vec![[1.0, 1.0], [0.5, 1.8]]);
+
+        // a comb with three teeth, with the other polygons keeping their index
+        let mut builder = MeshBuilder::new();
+        for point in [
+            [0.0, 0.0],
+            [5.0, 0.0],
+            [5.0, 2.0],
+            [4.0, 2.0],
+            [4.0, 1.0],
+            [3.0, 1.0],
+            [3.0, 2.0],
+            [2.0, 2.0],
+            [2.0, 1.0],
+            [1.0, 1.0],
+            [1.0, 2.0],
+            [0.0, 2.0],
+            [0.0, -1.0],
+            [5.0, -1.0],
+        ] {
+            builder = builder.vertex(point);
+        }
+        let mut mesh = builder
+            .polygon(0..12)
+            .polygon([12, 13, 1, 0])
+            .build()
+            .unwrap();
+        assert_eq!(mesh.polygons[1].vertices, vec![12, 13, 1, 0]);
+        assert!(mesh.polygons.len() >= 4);
+        assert!(mesh.validate().is_empty());
+        assert_eq!(mesh.split_concave_polygons(), 0);
+        assert!(mesh.path([0.5, 1.5], [4.5, 1.5]).is_some());
+        assert!(!mesh.point_in_mesh([1.5, 1.5]));
+    }
+}
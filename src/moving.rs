@@ -0,0 +1,225 @@
+use hashbrown::HashMap;
+
+use crate::{
+    counted, Mesh, Path, PolygonId, SearchContext, SearchInstance, SearchNode, SearchOptions,
+};
+
+/// Searches for the shortest paths from a point to a target that moves, as returned by
+/// [`Mesh::moving_target_search`].
+///
+/// Each [`MovingTargetSearch::path_to`] continues the previous search towards the new position of
+/// the target instead of starting again: the nodes left in its queue, the nodes it expanded and
+/// the best paths found to each root are kept. When the target only moved a little, like a player
+/// chased by an agent stepping sideways, the path is found expanding few new nodes.
+pub struct MovingTargetSearch<'m> {
+    mesh: &'m Mesh,
+    from: [f32; 2],
+    polygon_from: Option<PolygonId>,
+    search: Option<SearchInstance<'m>>,
+    /// Nodes expanded so far, by the polygon they enter.
+    closed: HashMap<PolygonId, Vec<SearchNode>>,
+    /// Nodes that reached a previous target, and were not expanded.
+    reached: Vec<SearchNode>,
+    expanded: usize,
+}
+
+impl Mesh {
+    /// Start searching for the shortest paths from `from` to a target that moves, keeping the
+    /// search between them. Nothing is searched until the first [`MovingTargetSearch::path_to`].
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(20, 20, |x, y| x != 10 || y > 15);
+    /// let mut chase = mesh.moving_target_search([2.0, 2.0]);
+    /// let path = chase.path_to([18.0, 2.0]).unwrap();
+    /// assert_eq!(path, mesh.path([2.0, 2.0], [18.0, 2.0]).unwrap());
+    /// let expanded = chase.expanded();
+    ///
+    /// // the target steps sideways
+    /// let path = chase.path_to([18.0, 3.0]).unwrap();
+    /// assert_eq!(path, mesh.path([2.0, 2.0], [18.0, 3.0]).unwrap());
+    /// assert!(chase.expanded() - expanded < expanded);
+    /// ```
+    pub fn moving_target_search(&self, from: [f32; 2]) -> MovingTargetSearch<'_> {
+        MovingTargetSearch {
+            mesh: self,
+            from,
+            polygon_from: self.point_in_polygon(from),
+            search: None,
+            closed: HashMap::new(),
+            reached: vec![],
+            expanded: 0,
+        }
+    }
+}
+
+impl MovingTargetSearch<'_> {
+    /// Start of the paths.
+    pub fn from(&self) -> [f32; 2] {
+        self.from
+    }
+
+    /// Number of nodes expanded since the search started, for all the targets.
+    pub fn expanded(&self) -> usize {
+        self.expanded
+    }
+
+    /// Compute the shortest path from the start to the current position of the target, continuing
+    /// the search for its previous positions.
+    ///
+    /// Returns `None` if one of the points is not in the mesh, or if `to` can't be reached from
+    /// the start.
+    pub fn path_to(&mut self, to: [f32; 2]) -> Option<Path> {
+        counted(Option::is_none, || self.search_to(to))
+    }
+
+    fn search_to(&mut self, to: [f32; 2]) -> Option<Path> {
+        let polygon_to = self.mesh.point_in_polygon(to);
+        let (Some(start), Some(end)) = (self.polygon_from, polygon_to) else {
+            return None;
+        };
+        if start == end || !self.mesh.same_island(start, end) {
+            return self.mesh.path_between_polygons(
+                self.from,
+                to,
+                Some(start),
+                Some(end),
+                SearchOptions::default(),
+                |_| (),
+            );
+        }
+
+        let search = match &mut self.search {
+            Some(search) => {
+                // nodes that reached a previous target must still be expanded for the others
+                search.queue.extend(self.reached.drain(..));
+                search.retarget(to, polygon_to);
+                search
+            }
+            None => self.search.insert(
+                SearchInstance::start(
+                    self.mesh,
+                    &mut SearchContext::new(),
+                    self.from,
+                    to,
+                    Some(start),
+                    Some(end),
+                    SearchOptions {
+                        // keep every polygon in the queue, even those the target is not in
+                        exhaustive: true,
+                        ..SearchOptions::default()
+                    },
+                )
+                .ok()?,
+            ),
+        };
+
+        // nodes already expanded into the target polygon reach it without searching further
+        let best = self.closed.get(&end).and_then(|nodes| {
+            nodes
+                .iter()
+                .map(|node| (node.f + node.penalty + search.target_heuristic(node), node))
+                .min_by(|a, b| a.0.total_cmp(&b.0))
+                .map(|(cost, node)| {
+                    let g = search.target_heuristic(node);
+                    (cost, SearchNode { g, ..node.clone() })
+                })
+        });
+        while let Some(next) = search.queue.peek() {
+            if best
+                .as_ref()
+                .is_some_and(|(cost, _)| *cost <= next.f + next.penalty + next.g)
+            {
+                break;
+            }
+            let next = search.queue.pop().unwrap();
+            if next.polygon_to == polygon_to {
+                let path = search.path_to_node(&next, true).path;
+                self.reached.push(next);
+                return Some(path);
+            }
+            self.expanded += 1;
+            #[cfg(feature = "counters")]
+            crate::counters::node_expanded();
+            self.closed
+                .entry(next.polygon_to.unwrap())
+                .or_default()
+                .push(next.clone());
+            search.successors(next);
+        }
+        best.map(|(_, node)| search.path_to_node(&node, true).path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::{grid_mesh, random_obstacles, test_points};
+
+    #[test]
+    fn chasing() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let from = [1.5, 1.5];
+        let mut chase = mesh.moving_target_search(from);
+        assert_eq!(chase.from(), from);
+        // the target wanders around the mesh, with a few jumps
+        let mut to = [28.5, 28.5];
+        for (i, jump) in test_points(60, [0.5, 0.5]).enumerate() {
+            to = if i % 20 == 19 {
+                jump
+            } else {
+                let step = [[0.7, 0.0], [0.0, -0.7], [-0.7, 0.0], [0.0, 0.7]][i / 3 % 4];
+                [to[0] + step[0], to[1] + step[1]]
+            };
+            let expected = mesh.path(from, to);
+            let path = chase.path_to(to);
+            assert_eq!(path.is_some(), expected.is_some(), "{:?}", to);
+            if let (Some(path), Some(expected)) = (path, expected) {
+                assert!((path.len - expected.len).abs() < 1.0e-3, "{:?}", to);
+                assert_eq!(path.path.last(), Some(&to));
+            }
+        }
+        assert!(chase.path_to([-1.0, 1.0]).is_none());
+        assert_eq!(chase.path_to([1.8, 1.2]).unwrap().path, vec![[1.8, 1.2]]);
+
+        // following the target doesn't search the mesh again
+        let to = [28.5, 28.5];
+        let mut fresh = mesh.moving_target_search(from);
+        assert!(fresh.path_to(to).is_some());
+        let mut chase = mesh.moving_target_search(from);
+        assert!(chase.path_to([28.5, 26.4]).is_some());
+        let expanded = chase.expanded();
+        assert!(chase.path_to([28.5, 27.1]).is_some());
+        assert!(chase.path_to(to).is_some());
+        assert!(chase.expanded() - expanded < fresh.expanded());
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        // a wall, and a one-way door from the right to the left
+        let mut mesh = grid_mesh(10, 3, |x, y| x != 5 || y == 2);
+        let left = mesh.point_in_polygon([4.5, 2.5]).unwrap();
+        let door = mesh.point_in_polygon([5.5, 2.5]).unwrap();
+        let edge = mesh.edge_between(door, left).unwrap();
+        assert!(mesh.set_edge_one_way(edge, true));
+
+        let from = [1.5, 1.5];
+        assert!(mesh.path(from, [8.5, 0.5]).is_none());
+        assert!(mesh.path([8.5, 0.5], from).is_some());
+        let mut chase = mesh.moving_target_search(from);
+        for to in [
+            [8.5, 0.5],
+            [3.5, 1.5],
+            [-1.0, 1.5],
+            [9.5, 2.5],
+            [4.5, 0.5],
+            from,
+        ] {
+            assert_eq!(chase.path_to(to), mesh.path(from, to), "{:?}", to);
+        }
+        assert_eq!(chase.path_to(from).unwrap().len, 0.0);
+
+        let mut outside = mesh.moving_target_search([-1.0, 1.5]);
+        assert!(outside.path_to([3.5, 1.5]).is_none());
+        assert_eq!(outside.expanded(), 0);
+    }
+}
@@ -0,0 +1,257 @@
+//! Import of the grid maps and scenarios of the
+//! [MovingAI benchmarks](https://movingai.com/benchmarks/grids.html).
+//!
+//! Passable cells are merged into rectangles, which become the polygons of the mesh. Cell `(x, y)`
+//! covers the square from `[x, y]` to `[x + 1, y + 1]`, with `y` going down like the rows of the
+//! map. The start and goal of scenario queries are at the center of their cell.
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+use crate::{Mesh, Path};
+
+/// Error returned when a MovingAI file can't be read.
+///
+/// Line numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MovingAiError {
+    /// A header line is missing or invalid.
+    InvalidHeader { line: usize, expected: &'static str },
+    /// A row of the map doesn't have the width given in the header.
+    InvalidRow { line: usize },
+    /// The file ended before all the rows were read.
+    UnexpectedEof,
+    /// A query of a scenario couldn't be parsed.
+    InvalidQuery { line: usize },
+}
+
+impl Display for MovingAiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MovingAiError::InvalidHeader { line, expected } => {
+                write!(f, "line {}: expected {}", line, expected)
+            }
+            MovingAiError::InvalidRow { line } => write!(f, "line {}: invalid row length", line),
+            MovingAiError::UnexpectedEof => write!(f, "unexpected end of file"),
+            MovingAiError::InvalidQuery { line } => write!(f, "line {}: invalid query", line),
+        }
+    }
+}
+
+impl Error for MovingAiError {}
+
+/// Check if a cell of a MovingAI map can be walked on: ground (`.` and `G`) and swamp (`S`).
+fn is_passable(cell: u8) -> bool {
+    matches!(cell, b'.' | b'G' | b'S')
+}
+
+/// Build a mesh from a map in the `.map` format.
+pub fn load_map(content: &str) -> Result<Mesh, MovingAiError> {
+    let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+    let mut header = |key: &'static str| {
+        let (line, content) = lines.next().ok_or(MovingAiError::UnexpectedEof)?;
+        match content.split_once(' ') {
+            Some((found, value)) if found == key => Ok((line, value.trim())),
+            _ => Err(MovingAiError::InvalidHeader {
+                line,
+                expected: key,
+            }),
+        }
+    };
+    header("type")?;
+    let mut size = |key| {
+        let (line, value) = header(key)?;
+        value
+            .parse::<usize>()
+            .map_err(|_| MovingAiError::InvalidHeader {
+                line,
+                expected: key,
+            })
+    };
+    let height = size("height")?;
+    let width = size("width")?;
+    match lines.next() {
+        Some((_, "map")) => (),
+        Some((line, _)) => {
+            return Err(MovingAiError::InvalidHeader {
+                line,
+                expected: "map",
+            })
+        }
+        None => return Err(MovingAiError::UnexpectedEof),
+    }
+
+    let mut cells = Vec::with_capacity(width * height);
+    for _ in 0..height {
+        let (line, row) = lines.next().ok_or(MovingAiError::UnexpectedEof)?;
+        let row = row.trim_end().as_bytes();
+        if row.len() != width {
+            return Err(MovingAiError::InvalidRow { line });
+        }
+        cells.extend(row.iter().map(|cell| is_passable(*cell)));
+    }
+    Ok(Mesh::from_grid(width, height, |x, y| cells[y * width + x]))
+}
+
+/// A query of a [`Scenario`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScenarioQuery {
+    /// Bucket of the query, grouping queries of similar lengths.
+    pub bucket: usize,
+    /// Name of the map file.
+    pub map: String,
+    /// Width and height of the map.
+    pub map_size: [usize; 2],
+    /// Center of the start cell.
+    pub start: [f32; 2],
+    /// Center of the goal cell.
+    pub goal: [f32; 2],
+    /// Length of the optimal path on the grid, moving between the centers of neighbouring cells.
+    pub expected_length: f32,
+}
+
+/// Result of running a [`ScenarioQuery`] on a mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryReport<'s> {
+    /// The query.
+    pub query: &'s ScenarioQuery,
+    /// The path found, or `None` if there was none.
+    pub path: Option<Path>,
+}
+
+impl QueryReport<'_> {
+    /// Difference between the length of the path found and the reference length, or `None` if
+    /// no path was found.
+    ///
+    /// Paths on a mesh can take any angle, so they are expected to be shorter than the reference
+    /// paths moving between cells, and the error is usually negative.
+    pub fn error(&self) -> Option<f32> {
+        self.path
+            .as_ref()
+            .map(|path| path.len - self.query.expected_length)
+    }
+}
+
+/// Queries of a `.scen` file.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Scenario {
+    queries: Vec<ScenarioQuery>,
+}
+
+impl Scenario {
+    /// Parse a scenario in the `.scen` format.
+    pub fn parse(content: &str) -> Result<Scenario, MovingAiError> {
+        let mut queries = vec![];
+        for (index, content) in content.lines().enumerate() {
+            let line = index + 1;
+            if content.trim().is_empty() || (line == 1 && content.starts_with("version")) {
+                continue;
+            }
+            let invalid = || MovingAiError::InvalidQuery { line };
+            let fields: Vec<&str> = content.split('\t').collect();
+            if fields.len() != 9 {
+                return Err(invalid());
+            }
+            let integer = |i: usize| fields[i].trim().parse::<usize>().map_err(|_| invalid());
+            let center = |i: usize| integer(i).map(|cell| cell as f32 + 0.5);
+            queries.push(ScenarioQuery {
+                bucket: integer(0)?,
+                map: fields[1].to_string(),
+                map_size: [integer(2)?, integer(3)?],
+                start: [center(4)?, center(5)?],
+                goal: [center(6)?, center(7)?],
+                expected_length: fields[8].trim().parse().map_err(|_| invalid())?,
+            });
+        }
+        Ok(Scenario { queries })
+    }
+
+    /// The queries of the scenario.
+    pub fn queries(&self) -> &[ScenarioQuery] {
+        &self.queries
+    }
+
+    /// Iterate over the start, goal and expected length of each query.
+    pub fn iter(&self) -> impl Iterator<Item = ([f32; 2], [f32; 2], f32)> + '_ {
+        self.queries
+            .iter()
+            .map(|query| (query.start, query.goal, query.expected_length))
+    }
+
+    /// Run each query on `mesh`, lazily.
+    pub fn run<'s>(&'s self, mesh: &'s Mesh) -> impl Iterator<Item = QueryReport<'s>> + 's {
+        self.queries.iter().map(move |query| QueryReport {
+            query,
+            path: mesh.path(query.start, query.goal),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_map, MovingAiError, Scenario};
+
+    const MAP: &str = "type octile
+height 4
+width 5
+map
+..@..
+..@..
+..T..
+.....
+";
+
+    #[test]
+    fn map() {
+        let mesh = load_map(MAP).unwrap();
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.polygons.len(), 3);
+        assert!(!mesh.point_in_mesh([2.5, 0.5]));
+        let len = mesh.path([1.5, 0.5], [3.5, 0.5]).unwrap().len;
+        assert!((len - (1.0 + 2.0 * 6.5_f32.sqrt())).abs() < 1.0e-4);
+    }
+
+    #[test]
+    fn invalid_map() {
+        assert_eq!(
+            load_map("type octile\nheight 1\nwidth 2\nmap\n...\n").err(),
+            Some(MovingAiError::InvalidRow { line: 5 })
+        );
+        assert_eq!(
+            load_map("type octile\nheight x\n").err(),
+            Some(MovingAiError::InvalidHeader {
+                line: 2,
+                expected: "height"
+            })
+        );
+        assert_eq!(
+            load_map("type octile\nheight 2\nwidth 2\nmap\n..\n").err(),
+            Some(MovingAiError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn scenario() {
+        let scenario = Scenario::parse(
+            "version 1\n\
+             0\ttest.map\t5\t4\t1\t0\t3\t0\t6.82842712\n\
+             1\ttest.map\t5\t4\t0\t3\t4\t3\t4\n",
+        )
+        .unwrap();
+        assert_eq!(scenario.queries().len(), 2);
+        assert_eq!(scenario.queries()[0].start, [1.5, 0.5]);
+        assert_eq!(scenario.iter().nth(1), Some(([0.5, 3.5], [4.5, 3.5], 4.0)));
+
+        let mesh = load_map(MAP).unwrap();
+        let reports: Vec<_> = scenario.run(&mesh).collect();
+        assert!(reports[0].error().unwrap() < 0.0);
+        assert!(reports[1].error().unwrap().abs() < 1.0e-5);
+
+        assert_eq!(
+            Scenario::parse("version 1\n0\ttest.map\t5\t4\t1\t0\n"),
+            Err(MovingAiError::InvalidQuery { line: 2 })
+        );
+    }
+}
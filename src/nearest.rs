@@ -0,0 +1,110 @@
+use crate::{Mesh, Path, SearchOptions};
+
+impl Mesh {
+    /// Compute the shortest path from `from` to the nearest of several goals, with a single
+    /// search instead of one per goal.
+    ///
+    /// Returns the index of the goal reached with the path to it, or `None` if `from` is not in
+    /// the mesh or no goal can be reached. Goals that are not in the mesh are ignored.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9);
+    /// // the closest resource as the crow flies is behind the wall
+    /// let resources = [[6.5, 1.0], [1.0, 8.0], [20.0, 20.0]];
+    ///
+    /// let (nearest, path) = mesh.path_to_nearest([4.0, 1.0], &resources).unwrap();
+    /// assert_eq!(nearest, 1);
+    /// assert_eq!(Some(path), mesh.path([4.0, 1.0], resources[1]));
+    /// ```
+    pub fn path_to_nearest(&self, from: [f32; 2], goals: &[[f32; 2]]) -> Option<(usize, Path)> {
+        let located: Vec<([f32; 2], _)> = goals
+            .iter()
+            .filter_map(|goal| Some((*goal, self.point_in_polygon(*goal)?)))
+            .collect();
+        if located.is_empty() {
+            return None;
+        }
+        let path = self.path_between_polygons(
+            from,
+            from,
+            self.point_in_polygon(from),
+            None,
+            SearchOptions {
+                goals: &located,
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )?;
+        // paths end exactly at their goal, even with quantization
+        let end = *path.path.last()?;
+        let nearest = goals.iter().position(|goal| *goal == end)?;
+        Some((nearest, path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::{grid_mesh, test_meshes, test_points};
+
+    #[test]
+    fn same_as_closest_path() {
+        for mesh in test_meshes() {
+            let points: Vec<[f32; 2]> = test_points(40, [0.5, 0.5])
+                .filter(|point| mesh.point_in_mesh(*point))
+                .collect();
+            let (from, goals) = points.split_first().unwrap();
+            for count in [1, 2, 5, goals.len()] {
+                let goals = &goals[..count];
+                let expected = goals
+                    .iter()
+                    .filter_map(|goal| mesh.path(*from, *goal))
+                    .map(|path| path.len)
+                    .fold(f32::INFINITY, f32::min);
+                match mesh.path_to_nearest(*from, goals) {
+                    Some((nearest, path)) => {
+                        assert!((path.len - expected).abs() < 1.0e-3);
+                        assert_eq!(path.path.last(), Some(&goals[nearest]));
+                    }
+                    None => assert_eq!(expected, f32::INFINITY),
+                }
+            }
+        }
+
+        // a goal in the starting polygon, farther than one in another polygon
+        let mesh = grid_mesh(10, 1, |_, _| true);
+        let (nearest, path) = mesh
+            .path_to_nearest([4.9, 0.5], &[[4.1, 0.5], [5.1, 0.5]])
+            .unwrap();
+        assert_eq!(nearest, 1);
+        assert!((path.len - 0.2).abs() < 1.0e-5);
+        assert!(mesh.path_to_nearest([0.5, 0.5], &[]).is_none());
+        assert!(mesh.path_to_nearest([0.5, 0.5], &[[-1.0, 0.0]]).is_none());
+    }
+
+    #[test]
+    fn unreachable_goals() {
+        let walled = grid_mesh(10, 3, |x, _| x != 5);
+        let behind = [[8.5, 0.5], [9.5, 2.5]];
+        assert!(walled.path_to_nearest([1.5, 1.5], &behind).is_none());
+        // goals outside of the mesh are skipped
+        let (nearest, _) = walled
+            .path_to_nearest([1.5, 1.5], &[[-1.0, 1.5], [8.5, 0.5], [3.5, 1.5]])
+            .unwrap();
+        assert_eq!(nearest, 2);
+        assert!(walled.path_to_nearest([-1.0, 1.5], &[[3.5, 1.5]]).is_none());
+
+        // a goal at the start, given twice
+        let (nearest, path) = walled
+            .path_to_nearest([1.5, 1.5], &[[3.5, 1.5], [1.5, 1.5], [1.5, 1.5]])
+            .unwrap();
+        assert_eq!(nearest, 1);
+        assert_eq!(path.len, 0.0);
+        // on the boundary of the mesh
+        let (nearest, path) = walled
+            .path_to_nearest([1.5, 1.5], &[[5.0, 1.5], [0.0, 0.0]])
+            .unwrap();
+        assert_eq!(nearest, 1);
+        assert_eq!(path.path, vec![[0.0, 0.0]]);
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    collections::BTreeMap,
+    f32::consts::PI,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
+use crate::{
+    carve::{check_polygon, CarveError},
+    Mesh, Path,
+};
+
+/// Number of sides of the polygons around discs.
+const DISC_SIDES: usize = 16;
+
+/// Identifier of an obstacle of an [`ObstacleLayer`], valid until it is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ObstacleId(u64);
+
+/// Temporary obstacles, like units or destructible walls, over a mesh that is not modified.
+///
+/// Obstacles are carved out of a copy of the mesh with [`Mesh::carve`] when a path is needed
+/// after they changed, so that adding or removing many obstacles at once costs a single update.
+/// Only the obstacles added since the last update are carved, and the holes of the removed ones are
+/// filled again from the mesh: moving a unit only changes the polygons around it. Discs are
+/// replaced by polygons around them.
+///
+/// ```
+/// # use polyanya::{Mesh, ObstacleLayer};
+/// let mut layer = ObstacleLayer::new(Mesh::from_grid(10, 10, |_, _| true));
+/// layer
+///     .add_polygon(&[[0.0, 4.0], [9.0, 4.0], [9.0, 5.0], [0.0, 5.0]])
+///     .unwrap();
+/// let unit = layer.add_disc([9.5, 4.5], 0.5).unwrap();
+/// assert!(layer.path([5.0, 1.0], [5.0, 9.0]).is_none());
+///
+/// layer.remove(unit);
+/// assert!(layer.path([5.0, 1.0], [5.0, 9.0]).is_some());
+/// // the mesh itself never changes
+/// assert!(layer.base().point_in_mesh([5.0, 4.5]));
+/// ```
+#[derive(Debug)]
+pub struct ObstacleLayer {
+    base: Mesh,
+    obstacles: BTreeMap<ObstacleId, Vec<[f32; 2]>>,
+    next: u64,
+    /// The mesh with the obstacles carved out, updated when first needed.
+    carved: OnceLock<Mesh>,
+    /// The mesh with the obstacles carved out before they changed, while `carved` is not updated.
+    previous: Mutex<Option<Mesh>>,
+    /// Obstacles added since `previous`.
+    added: Vec<ObstacleId>,
+    /// Outlines of the obstacles removed since `previous`.
+    removed: Vec<Vec<[f32; 2]>>,
+}
+
+impl Clone for ObstacleLayer {
+    fn clone(&self) -> Self {
+        ObstacleLayer {
+            base: self.base.clone(),
+            obstacles: self.obstacles.clone(),
+            next: self.next,
+            carved: self.carved.clone(),
+            previous: Mutex::new(
+                self.previous
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .clone(),
+            ),
+            added: self.added.clone(),
+            removed: self.removed.clone(),
+        }
+    }
+}
+
+impl ObstacleLayer {
+    /// Create a layer without obstacles over a mesh.
+    pub fn new(mesh: Mesh) -> Self {
+        ObstacleLayer {
+            base: mesh,
+            obstacles: BTreeMap::new(),
+            next: 0,
+            carved: OnceLock::new(),
+            previous: Mutex::new(None),
+            added: vec![],
+            removed: vec![],
+        }
+    }
+
+    /// The mesh without obstacles.
+    pub fn base(&self) -> &Mesh {
+        &self.base
+    }
+
+    /// Add an obstacle covering a simple polygon, in any winding order.
+    pub fn add_polygon(&mut self, polygon: &[[f32; 2]]) -> Result<ObstacleId, CarveError> {
+        check_polygon(polygon)?;
+        Ok(self.insert(polygon.to_vec()))
+    }
+
+    /// Add an obstacle covering a disc.
+    pub fn add_disc(&mut self, center: [f32; 2], radius: f32) -> Result<ObstacleId, CarveError> {
+        if !radius.is_finite() || radius <= 0.0 {
+            return Err(CarveError::InvalidRadius);
+        }
+        if !center[0].is_finite() || !center[1].is_finite() {
+            return Err(CarveError::NonFinitePoint { point: 0 });
+        }
+        // the polygon is around the disc, touching it in the middle of its sides
+        let distance = radius / (PI / DISC_SIDES as f32).cos();
+        let polygon = (0..DISC_SIDES)
+            .map(|k| {
+                let angle = 2.0 * PI * k as f32 / DISC_SIDES as f32;
+                [
+                    center[0] + distance * angle.cos(),
+                    center[1] + distance * angle.sin(),
+                ]
+            })
+            .collect();
+        Ok(self.insert(polygon))
+    }
+
+    fn insert(&mut self, polygon: Vec<[f32; 2]>) -> ObstacleId {
+        let id = ObstacleId(self.next);
+        self.next += 1;
+        self.obstacles.insert(id, polygon);
+        self.changing();
+        self.added.push(id);
+        id
+    }
+
+    /// Remove an obstacle, returning `false` if there was none with this identifier.
+    pub fn remove(&mut self, obstacle: ObstacleId) -> bool {
+        let Some(outline) = self.obstacles.remove(&obstacle) else {
+            return false;
+        };
+        self.changing();
+        match self.added.iter().position(|added| *added == obstacle) {
+            // not carved yet
+            Some(index) => {
+                self.added.remove(index);
+            }
+            None => self.removed.push(outline),
+        }
+        true
+    }
+
+    /// Remove all the obstacles.
+    pub fn clear(&mut self) {
+        if !self.obstacles.is_empty() {
+            self.obstacles.clear();
+            self.carved = OnceLock::new();
+            *self
+                .previous
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner) = None;
+            self.added.clear();
+            self.removed.clear();
+        }
+    }
+
+    /// Keep the mesh with the obstacles carved out to update it, before they change.
+    fn changing(&mut self) {
+        if let Some(carved) = self.carved.take() {
+            *self
+                .previous
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner) = Some(carved);
+            self.added.clear();
+            self.removed.clear();
+        }
+    }
+
+    /// Number of obstacles.
+    pub fn len(&self) -> usize {
+        self.obstacles.len()
+    }
+
+    /// `true` if there is no obstacle.
+    pub fn is_empty(&self) -> bool {
+        self.obstacles.is_empty()
+    }
+
+    /// The outline of an obstacle, with discs replaced by polygons.
+    pub fn outline(&self, obstacle: ObstacleId) -> Option<&[[f32; 2]]> {
+        self.obstacles
+            .get(&obstacle)
+            .map(|polygon| polygon.as_slice())
+    }
+
+    /// The mesh with the obstacles carved out.
+    pub fn mesh(&self) -> &Mesh {
+        self.carved.get_or_init(|| {
+            let previous = self
+                .previous
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .take();
+            previous
+                .and_then(|previous| self.update(previous))
+                .unwrap_or_else(|| self.carve_base())
+        })
+    }
+
+    /// Carve the obstacles added and fill the holes of the ones removed since `mesh` was up to
+    /// date, or `None` to carve all the obstacles out of the base mesh again.
+    fn update(&self, mut mesh: Mesh) -> Option<Mesh> {
+        let carved: Vec<&[[f32; 2]]> = self
+            .obstacles
+            .iter()
+            .filter(|(obstacle, _)| !self.added.contains(obstacle))
+            .map(|(_, polygon)| polygon.as_slice())
+            .collect();
+        let removed: Vec<&[[f32; 2]]> = self.removed.iter().map(Vec::as_slice).collect();
+        mesh.uncarve(&self.base, &removed, &carved).ok()?;
+        let added: Vec<&[[f32; 2]]> = self
+            .added
+            .iter()
+            .map(|obstacle| self.obstacles[obstacle].as_slice())
+            .collect();
+        mesh.carve_all(&added).ok()?;
+        // the vertices of the filled holes are left unused
+        let unused = mesh
+            .vertices
+            .iter()
+            .filter(|vertex| vertex.polygons().iter().all(Option::is_none))
+            .count();
+        (unused <= mesh.vertices.len() / 2).then_some(mesh)
+    }
+
+    /// Carve all the obstacles out of the base mesh.
+    fn carve_base(&self) -> Mesh {
+        let mut mesh = self.base.clone();
+        let obstacles: Vec<&[[f32; 2]]> = self
+            .obstacles
+            .values()
+            .map(|polygon| polygon.as_slice())
+            .collect();
+        mesh.carve_all(&obstacles)
+            .expect("obstacles are checked when added");
+        mesh
+    }
+
+    /// Compute the shortest path from `from` to `to` around the obstacles.
+    ///
+    /// Returns `None` if one of the points is not in the mesh or is covered by an obstacle, or if
+    /// `to` can't be reached from `from`.
+    pub fn path(&self, from: [f32; 2], to: [f32; 2]) -> Option<Path> {
+        self.mesh().path(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ObstacleLayer;
+    use crate::{
+        maps::{random_obstacles, test_points},
+        CarveError, Mesh,
+    };
+
+    #[test]
+    fn add_and_remove() {
+        let mut layer = ObstacleLayer::new(Mesh::from_grid(10, 10, |_, _| true));
+        let free = layer.path([1.0, 5.0], [9.0, 5.0]).unwrap();
+        assert_eq!(free.path.len(), 1);
+
+        let unit = layer.add_disc([5.0, 5.0], 1.0).unwrap();
+        assert_eq!(layer.len(), 1);
+        assert!(!layer.mesh().point_in_mesh([5.0, 5.0]));
+        let around = layer.path([1.0, 5.0], [9.0, 5.0]).unwrap();
+        assert!(around.len > free.len);
+        for point in &around.path {
+            assert!((point[0] - 5.0).hypot(point[1] - 5.0) >= 1.0 - 1.0e-4);
+        }
+        assert!(layer.path([5.0, 5.0], [9.0, 5.0]).is_none());
+
+        // two overlapping obstacles
+        let wall = layer
+            .add_polygon(&[[5.0, -1.0], [5.5, -1.0], [5.5, 5.0], [5.0, 5.0]])
+            .unwrap();
+        assert!(layer.mesh().validate().is_empty());
+        let longer = layer.path([1.0, 5.0], [9.0, 5.0]).unwrap();
+        assert!(longer.len >= around.len);
+        assert!(longer.path.iter().all(|point| point[1] >= 5.0));
+
+        assert!(layer.remove(unit));
+        assert!(!layer.remove(unit));
+        assert_eq!(layer.outline(wall).map(|outline| outline.len()), Some(4));
+        layer.clear();
+        assert!(layer.is_empty());
+        assert_eq!(layer.path([1.0, 5.0], [9.0, 5.0]), Some(free));
+
+        assert_eq!(
+            layer.add_disc([0.0, 0.0], -1.0),
+            Err(CarveError::InvalidRadius)
+        );
+        assert_eq!(
+            layer.add_polygon(&[[0.0, 0.0], [1.0, 0.0]]),
+            Err(CarveError::TooFewPoints)
+        );
+    }
+
+    #[test]
+    fn moving_units() {
+        let mut layer = ObstacleLayer::new(random_obstacles(30, 30, 20, 4, 5));
+        let mut units: Vec<_> = (0..6)
+            .map(|i| {
+                let center = [(i * 5) as f32 + 2.5, 15.0];
+                (layer.add_disc(center, 0.8).unwrap(), center)
+            })
+            .collect();
+        for step in 0..12 {
+            // units move, overlapping each other and the walls of the mesh
+            for (i, (unit, center)) in units.iter_mut().enumerate() {
+                assert!(layer.remove(*unit));
+                let angle = (step * 3 + i) as f32;
+                *center = [center[0] + angle.cos(), center[1] + angle.sin()];
+                *unit = layer.add_disc(*center, 0.8).unwrap();
+            }
+            if step % 4 == 0 {
+                layer
+                    .add_polygon(&[[12.0, 10.0], [14.0, 10.0], [14.0, 20.0], [12.0, 20.0]])
+                    .unwrap();
+            }
+            let mesh = layer.mesh();
+            assert!(mesh.validate().is_empty());
+
+            let mut carved = ObstacleLayer::new(layer.base().clone());
+            for outline in layer.obstacles.values() {
+                carved.add_polygon(outline).unwrap();
+            }
+            let carved = carved.mesh();
+            for (i, point) in test_points(100, [0.37, 0.61]).enumerate() {
+                assert_eq!(mesh.point_in_mesh(point), carved.point_in_mesh(point));
+                let from = [(i * 11 % 30) as f32 + 0.37, (i * 3 % 30) as f32 + 0.61];
+                match (mesh.path(from, point), carved.path(from, point)) {
+                    (Some(path), Some(expected)) => {
+                        assert!((path.len - expected.len).abs() < 1.0e-3)
+                    }
+                    (path, expected) => assert_eq!(path, expected),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::{self, Write};
+
+use crate::{Mesh, PolygonId};
+
+/// Raw index of a polygon in the `.mesh` format, `-1` when there is none.
+fn raw(polygon: Option<PolygonId>) -> isize {
+    polygon.map_or(-1, |polygon| polygon.index() as isize)
+}
+
+impl Mesh {
+    /// Write the mesh in the `.mesh` format of the original Polyanya implementation, as read by
+    /// [`Mesh::from_reader`].
+    ///
+    /// Neighbours of polygons follow the convention of the format: the `i`-th neighbour is on the
+    /// other side of the edge from the `i - 1`-th vertex to the `i`-th vertex.
+    pub fn write_mesh_format(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "mesh")?;
+        writeln!(writer, "2")?;
+        writeln!(writer, "{} {}", self.vertices.len(), self.polygons.len())?;
+        for vertex in &self.vertices {
+            write!(
+                writer,
+                "{} {} {}",
+                vertex.x(),
+                vertex.y(),
+                vertex.polygons().len()
+            )?;
+            for polygon in vertex.polygons() {
+                write!(writer, " {}", raw(*polygon))?;
+            }
+            writeln!(writer)?;
+        }
+        for (id, polygon) in self.iter_polygons() {
+            write!(writer, "{}", polygon.vertices.len())?;
+            for vertex in &polygon.vertices {
+                write!(writer, " {}", vertex)?;
+            }
+            let mut previous = *polygon.vertices.last().unwrap();
+            for vertex in &polygon.vertices {
+                let neighbour = self
+                    .polygons_of_edge([previous, *vertex])
+                    .find(|other| *other != id);
+                write!(writer, " {}", raw(neighbour))?;
+                previous = *vertex;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mesh, MeshBuilder};
+
+    #[test]
+    fn arena_round_trip() {
+        let content = std::fs::read_to_string("meshes/arena.mesh").unwrap();
+        let mesh: Mesh = content.parse().unwrap();
+        let mut written = vec![];
+        mesh.write_mesh_format(&mut written).unwrap();
+        let lines = |content: &str| {
+            content
+                .lines()
+                .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|line| !line.is_empty())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            lines(std::str::from_utf8(&written).unwrap()),
+            lines(&content)
+        );
+    }
+
+    #[test]
+    fn built_mesh() {
+        let mesh = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([1.0, 1.0])
+            .vertex([0.5, 1.5])
+            .polygon([0, 1, 2])
+            .polygon([0, 2, 3])
+            .build()
+            .unwrap();
+        let mut written = vec![];
+        mesh.write_mesh_format(&mut written).unwrap();
+        let read = Mesh::from_bytes(&written).unwrap();
+        assert_eq!(
+            read.vertices
+                .iter()
+                .map(|vertex| (vertex.position(), vertex.polygons().to_vec()))
+                .collect::<Vec<_>>(),
+            mesh.vertices
+                .iter()
+                .map(|vertex| (vertex.position(), vertex.polygons().to_vec()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            read.iter_polygons()
+                .map(|(_, polygon)| polygon.vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            mesh.iter_polygons()
+                .map(|(_, polygon)| polygon.vertices().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            std::str::from_utf8(&written).unwrap().lines().nth(8),
+            Some("3 0 2 3 -1 0 -1")
+        );
+    }
+}
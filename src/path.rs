@@ -0,0 +1,379 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, Path,
+};
+
+/// Grid size used to quantize points before hashing them in [`Path::fingerprint`].
+const FINGERPRINT_RESOLUTION: f64 = 1.0e-3;
+
+/// Distance from the segment between its neighbours under which a point of a path is considered
+/// on it, and removed by [`Path::simplify`].
+const COLLINEAR_TOLERANCE: f32 = 1.0e-5;
+
+impl Path {
+    /// Build a path from `from` going through `path`, rounding all its points but the last one
+    /// to a grid of size `grid`.
+    pub(crate) fn quantized(from: [f32; 2], mut path: Vec<[f32; 2]>, grid: f32) -> Path {
+        let turning_points = path.len().saturating_sub(1);
+        for point in path.iter_mut().take(turning_points) {
+            *point = [
+                (point[0] / grid).round() * grid,
+                (point[1] / grid).round() * grid,
+            ];
+        }
+        let mut len = 0.0;
+        let mut last = from;
+        for point in &path {
+            len += distance_between(last, *point);
+            last = *point;
+        }
+        Path { len, path }
+    }
+
+    /// Points every `spacing` along the path from `from`, the point it was computed from.
+    ///
+    /// The points are measured along the path, going round its corners: they start with `from`,
+    /// are all `spacing` apart, and end with the end of the path, that can be closer to the point
+    /// before it. If `spacing` is not positive, the points of the path are kept as they are.
+    ///
+    /// ```
+    /// # use polyanya::Path;
+    /// let path = Path {
+    ///     len: 3.0,
+    ///     path: vec![[1.0, 0.0], [1.0, 2.0]],
+    /// };
+    /// assert_eq!(
+    ///     path.resample([0.0, 0.0], 1.25),
+    ///     vec![[0.0, 0.0], [1.0, 0.25], [1.0, 1.5], [1.0, 2.0]]
+    /// );
+    /// ```
+    pub fn resample(&self, from: [f32; 2], spacing: f32) -> Vec<[f32; 2]> {
+        let mut points = vec![from];
+        if spacing <= 0.0 || spacing.is_nan() {
+            points.extend(self.path.iter().copied());
+            return points;
+        }
+        // distance along the current segment of the next point
+        let mut next = spacing;
+        let mut last = from;
+        for point in &self.path {
+            let length = distance_between(last, *point);
+            while next < length {
+                let t = next / length;
+                points.push([
+                    last[0] + (point[0] - last[0]) * t,
+                    last[1] + (point[1] - last[1]) * t,
+                ]);
+                next += spacing;
+            }
+            next -= length;
+            last = *point;
+        }
+        if !self.path.is_empty() {
+            points.push(last);
+        }
+        points
+    }
+
+    /// Simplify a path starting at `from`, keeping fewer points but never straying more than
+    /// `tolerance` away from it, with the Douglas-Peucker algorithm.
+    ///
+    /// Points on the segment between their neighbours are always removed, as well as repeated
+    /// points. The end of the path is kept. It's meant for drawing a path with fewer segments,
+    /// like on a minimap: the simplified path can cut corners of the mesh.
+    ///
+    /// ```
+    /// # use polyanya::Path;
+    /// let path = Path {
+    ///     len: 4.0,
+    ///     path: vec![[1.0, 0.0], [2.0, 0.1], [3.0, 0.0], [4.0, 0.0]],
+    /// };
+    /// // a small bump on a straight line
+    /// assert_eq!(path.simplify([0.0, 0.0], 0.0).path.len(), 4);
+    /// assert_eq!(path.simplify([0.0, 0.0], 0.2).path, vec![[4.0, 0.0]]);
+    /// ```
+    pub fn simplify(&self, from: [f32; 2], tolerance: f32) -> Path {
+        let mut points: Vec<[f32; 2]> = vec![from];
+        for point in &self.path {
+            // drop the last point kept if it's on the way to this one
+            if points.len() >= 2 {
+                let previous = [points[points.len() - 2], *point];
+                let last = points[points.len() - 1];
+                if distance_between(last, project_on_segment(last, previous)) <= COLLINEAR_TOLERANCE
+                {
+                    points.pop();
+                }
+            }
+            if points.last() != Some(point) {
+                points.push(*point);
+            }
+        }
+        // a path to where it starts
+        if let (1, Some(end)) = (points.len(), self.path.last()) {
+            points.push(*end);
+        }
+        let mut kept = vec![false; points.len()];
+        kept[0] = true;
+        kept[points.len() - 1] = true;
+        douglas_peucker(&points, tolerance, &mut kept);
+
+        let mut len = 0.0;
+        let mut last = from;
+        let path: Vec<[f32; 2]> = points
+            .into_iter()
+            .zip(kept)
+            .skip(1)
+            .filter(|(_, kept)| *kept)
+            .map(|(point, _)| {
+                len += distance_between(last, point);
+                last = point;
+                point
+            })
+            .collect();
+        Path { len, path }
+    }
+
+    /// Change the end of the path from `from` to `to`, for a target that moved a little.
+    ///
+    /// The path is kept up to its last turning point that has a straight line to `to` on the mesh,
+    /// going from there to `to`. If there is none, a path is searched only from the last turning
+    /// point. It's much cheaper than searching the whole path again, but the result is not always
+    /// the shortest path. Returns `None` if `to` is not in the mesh, or if it can't be reached.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall to go around
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y > 5);
+    /// let path = mesh.path([2.0, 2.0], [8.0, 2.0]).unwrap();
+    /// assert_eq!(path.path, vec![[5.0, 6.0], [6.0, 6.0], [8.0, 2.0]]);
+    /// let spliced = path.splice_to(&mesh, [2.0, 2.0], [8.0, 3.0]).unwrap();
+    /// assert_eq!(spliced.path, vec![[5.0, 6.0], [6.0, 6.0], [8.0, 3.0]]);
+    /// ```
+    pub fn splice_to(&self, mesh: &Mesh, from: [f32; 2], to: [f32; 2]) -> Option<Path> {
+        if !mesh.point_in_mesh(to) {
+            return None;
+        }
+        let mut points: Vec<[f32; 2]> = vec![from];
+        points.extend(self.path.iter().take(self.path.len().saturating_sub(1)));
+        let kept = (0..points.len())
+            .rev()
+            .find(|i| mesh.is_segment_on_mesh(points[*i], to));
+        let rest = match kept {
+            Some(kept) => {
+                points.truncate(kept + 1);
+                vec![to]
+            }
+            None => mesh.path(*points.last().unwrap(), to)?.path,
+        };
+
+        let mut len = 0.0;
+        let mut last = from;
+        let path: Vec<[f32; 2]> = points
+            .into_iter()
+            .skip(1)
+            .chain(rest)
+            .inspect(|point| {
+                len += distance_between(last, *point);
+                last = *point;
+            })
+            .collect();
+        Some(Path { len, path })
+    }
+
+    /// Hash of the points of the path, quantized to a millimeter grid (if units are meters).
+    ///
+    /// Two paths with the same points up to the quantization have the same fingerprint. The hash
+    /// (64 bits FNV-1a) doesn't depend on the platform or the build, so it can be stored or sent
+    /// over the network to refer to a path.
+    pub fn fingerprint(&self) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut hash = OFFSET_BASIS;
+        let mut write = |bytes: [u8; 8]| {
+            for byte in bytes {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(PRIME);
+            }
+        };
+        write((self.path.len() as u64).to_le_bytes());
+        for point in &self.path {
+            for coordinate in point {
+                let quantized = (*coordinate as f64 / FINGERPRINT_RESOLUTION).round() as i64;
+                write(quantized.to_le_bytes());
+            }
+        }
+        hash
+    }
+}
+
+/// Mark the points to keep between the first and the last one, that are further than
+/// `tolerance` from the segment between the points kept around them.
+fn douglas_peucker(points: &[[f32; 2]], tolerance: f32, kept: &mut [bool]) {
+    if points.len() < 3 {
+        return;
+    }
+    let segment = [points[0], points[points.len() - 1]];
+    let (furthest, distance) = points[1..points.len() - 1]
+        .iter()
+        .map(|point| distance_between(*point, project_on_segment(*point, segment)))
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .unwrap();
+    if distance > tolerance {
+        let furthest = furthest + 1;
+        kept[furthest] = true;
+        douglas_peucker(&points[..=furthest], tolerance, &mut kept[..=furthest]);
+        douglas_peucker(&points[furthest..], tolerance, &mut kept[furthest..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{helpers::distance_between, maps::maze, Mesh, Path};
+
+    #[test]
+    fn quantized() {
+        let path = Path::quantized([0.0, 0.0], vec![[1.04, 0.02], [1.23, 1.27]], 0.1);
+        assert_eq!(path.path, vec![[1.0, 0.0], [1.23, 1.27]]);
+        assert!((path.len - (1.0 + 0.23_f32.hypot(1.27))).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn resample() {
+        let path = Path {
+            len: 4.0,
+            path: vec![[2.0, 0.0], [2.0, 2.0]],
+        };
+        let points = path.resample([0.0, 0.0], 0.5);
+        assert_eq!(points.len(), 9);
+        assert_eq!(points[4], [2.0, 0.0]);
+        assert_eq!(points[8], [2.0, 2.0]);
+        // even spacing along the path, going round the corner
+        let points = path.resample([0.0, 0.0], 1.5);
+        assert_eq!(points, vec![[0.0, 0.0], [1.5, 0.0], [2.0, 1.0], [2.0, 2.0]]);
+
+        assert_eq!(
+            path.resample([0.0, 0.0], 10.0),
+            vec![[0.0, 0.0], [2.0, 2.0]]
+        );
+        assert_eq!(
+            path.resample([0.0, 0.0], 0.0),
+            vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]]
+        );
+        let empty = Path {
+            len: 0.0,
+            path: vec![],
+        };
+        assert_eq!(empty.resample([1.0, 1.0], 1.0), vec![[1.0, 1.0]]);
+    }
+
+    #[test]
+    fn simplify() {
+        let from = [0.0, 0.0];
+        let path = Path {
+            len: 5.0,
+            path: vec![
+                [1.0, 0.0],
+                [2.0, 0.0],
+                [2.0, 0.0],
+                [3.0, 0.0],
+                [3.0, 1.0],
+                [3.5, 1.5],
+                [4.0, 2.0],
+            ],
+        };
+        // collinear and repeated points
+        let simplified = path.simplify(from, 0.0);
+        assert_eq!(simplified.path, vec![[3.0, 0.0], [3.0, 1.0], [4.0, 2.0]]);
+        assert!((simplified.len - (4.0 + 2.0_f32.sqrt())).abs() < 1.0e-5);
+        // the corner is closer than the tolerance to the direct line
+        assert_eq!(path.simplify(from, 1.0).path, vec![[3.0, 0.0], [4.0, 2.0]]);
+        assert_eq!(path.simplify(from, 2.0).path, vec![[4.0, 2.0]]);
+
+        let empty = Path {
+            len: 0.0,
+            path: vec![],
+        };
+        assert_eq!(empty.simplify(from, 1.0), empty);
+        // going back and forth is not a straight line
+        let back = Path {
+            len: 2.0,
+            path: vec![[1.0, 0.0], [0.0, 0.0]],
+        };
+        assert_eq!(back.simplify(from, 0.0), back);
+        let in_place = Path {
+            len: 0.0,
+            path: vec![from],
+        };
+        assert_eq!(in_place.simplify(from, 1.0), in_place);
+    }
+
+    #[test]
+    fn splice_to() {
+        let mesh = maze(8, 8, 5);
+        let from = [1.5, 1.5];
+        let mut path = mesh.path(from, [13.5, 13.5]).unwrap();
+        // the target wanders around its cell
+        for i in 0..20 {
+            let to = [13.9 - (i % 5) as f32 * 0.2, 13.9 - (i / 5) as f32 * 0.2];
+            let Some(spliced) = path.splice_to(&mesh, from, to) else {
+                assert!(mesh.path(from, to).is_none());
+                continue;
+            };
+            assert_eq!(spliced.path.last(), Some(&to));
+            let mut last = from;
+            let mut len = 0.0;
+            for point in &spliced.path {
+                assert!(mesh.is_segment_on_mesh(last, *point));
+                len += distance_between(last, *point);
+                last = *point;
+            }
+            assert!((spliced.len - len).abs() < 1.0e-3);
+            assert!(spliced.len >= mesh.path(from, to).unwrap().len - 1.0e-3);
+            path = spliced;
+        }
+        assert!(path.splice_to(&mesh, from, [-1.0, 0.0]).is_none());
+
+        // a second wall hides the new target from all the turning points
+        let mesh = Mesh::from_grid(10, 10, |x, y| (x != 5 || y > 5) && (x != 7 || y < 3));
+        let path = mesh.path([2.0, 2.0], [8.0, 2.0]).unwrap();
+        assert_eq!(path.path.len(), 4);
+        let spliced = path.splice_to(&mesh, [2.0, 2.0], [9.5, 9.5]).unwrap();
+        assert_eq!(spliced.path[..3], path.path[..3]);
+        assert_eq!(spliced.path[3..], vec![[8.0, 3.0], [9.5, 9.5]]);
+        // without turning points, only the start is kept
+        let path = mesh.path([2.0, 2.0], [2.5, 3.0]).unwrap();
+        let spliced = path.splice_to(&mesh, [2.0, 2.0], [3.0, 8.0]).unwrap();
+        assert_eq!(spliced.path, vec![[3.0, 8.0]]);
+        assert!((spliced.len - 37.0_f32.sqrt()).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn fingerprint() {
+        let path = Path {
+            len: 2.0,
+            path: vec![[1.0, 0.0], [1.0, 1.0]],
+        };
+        let close = Path {
+            len: 2.0,
+            path: vec![[1.0001, 0.0], [1.0, 0.9999]],
+        };
+        let other = Path {
+            len: 2.0,
+            path: vec![[1.0, 0.0], [1.0, 1.01]],
+        };
+        assert_eq!(path.fingerprint(), close.fingerprint());
+        assert_ne!(path.fingerprint(), other.fingerprint());
+        assert_ne!(
+            path.fingerprint(),
+            Path {
+                len: 0.0,
+                path: vec![]
+            }
+            .fingerprint()
+        );
+        // stable across versions and platforms
+        assert_eq!(path.fingerprint(), 0xeefc_ca55_dcfb_0a4a);
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{helpers::distance_between, Mesh, Path};
+
+impl Mesh {
+    /// Move a polyline onto the mesh.
+    ///
+    /// Points outside of the mesh are projected on the nearest boundary edge, and segments leaving
+    /// the mesh are replaced by the shortest path between their ends. Points that can't be reached
+    /// from the previous one are skipped.
+    ///
+    /// Unlike [`Mesh::path`], the returned path starts with the first point of the polyline.
+    pub fn clamp_polyline(&self, points: &[[f32; 2]]) -> Path {
+        let mut clamped = Path {
+            len: 0.0,
+            path: vec![],
+        };
+        let mut projected = points.iter().filter_map(|point| {
+            if self.point_in_mesh(*point) {
+                Some(*point)
+            } else {
+                self.nearest_boundary(*point)
+                    .map(|(_, projected, _)| projected)
+            }
+        });
+        let mut last = match projected.next() {
+            Some(first) => first,
+            None => return clamped,
+        };
+        clamped.path.push(last);
+
+        for next in projected {
+            if self.segment_on_mesh(last, next) {
+                clamped.len += distance_between(last, next);
+                clamped.path.push(next);
+            } else {
+                let detour = match self.path(last, next) {
+                    Some(detour) => detour,
+                    None => continue,
+                };
+                clamped.len += detour.len;
+                clamped.path.extend(detour.path);
+            }
+            last = next;
+        }
+        clamped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::mesh_u_grid, Path};
+
+    #[test]
+    fn clamp_inside() {
+        let mesh = mesh_u_grid();
+        assert_eq!(
+            mesh.clamp_polyline(&[[0.5, 0.5], [2.5, 0.5], [2.5, 1.5]]),
+            Path {
+                len: 3.0,
+                path: vec![[0.5, 0.5], [2.5, 0.5], [2.5, 1.5]],
+            }
+        );
+        assert_eq!(mesh.clamp_polyline(&[]).path, Vec::<[f32; 2]>::new());
+    }
+
+    #[test]
+    fn clamp_outside() {
+        let mesh = mesh_u_grid();
+        let clamped = mesh.clamp_polyline(&[[0.5, -1.0], [0.5, 1.5], [2.5, 1.5]]);
+        assert_eq!(
+            clamped.path,
+            vec![[0.5, 0.0], [0.5, 1.5], [1.0, 1.0], [2.0, 1.0], [2.5, 1.5]]
+        );
+        assert!((clamped.len - (2.5 + 2.0 * 0.5_f32.sqrt())).abs() < 1.0e-5);
+    }
+}
@@ -0,0 +1,282 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, Path,
+};
+
+/// Progress of an agent along a path, as returned by [`PathProgress::update`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Distance travelled along the path since its start.
+    pub travelled: f32,
+    /// Distance remaining until the end of the path.
+    pub remaining: f32,
+    /// Index of the segment the agent is on. Segment `i` leads to the `i`-th point of the path.
+    pub segment: usize,
+    /// Next point of the path the agent should go to, `None` if the path is empty.
+    pub next_corner: Option<[f32; 2]>,
+}
+
+/// Follows an agent along a [`Path`].
+///
+/// The agent doesn't have to be exactly on the path: its position is projected on the closest
+/// segment, and it can only go forward from one segment to the next.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathProgress {
+    points: Vec<[f32; 2]>,
+    distances: Vec<f32>,
+    segment: usize,
+}
+
+impl PathProgress {
+    /// Start following a path from `from`, the point the path was computed from.
+    pub fn new(from: [f32; 2], path: &Path) -> Self {
+        let mut points = Vec::with_capacity(path.path.len() + 1);
+        points.push(from);
+        points.extend(path.path.iter().copied());
+        let mut distances = Vec::with_capacity(points.len());
+        let mut distance = 0.0;
+        distances.push(distance);
+        for segment in points.windows(2) {
+            distance += distance_between(segment[0], segment[1]);
+            distances.push(distance);
+        }
+        PathProgress {
+            points,
+            distances,
+            segment: 0,
+        }
+    }
+
+    /// Length of the path being followed.
+    pub fn length(&self) -> f32 {
+        *self.distances.last().unwrap()
+    }
+
+    /// Index of the current segment. Segment `i` leads to the `i`-th point of the path.
+    pub fn segment(&self) -> usize {
+        self.segment
+    }
+
+    /// Points of the path, starting with the point it was computed from.
+    pub fn points(&self) -> &[[f32; 2]] {
+        &self.points
+    }
+
+    fn segment_count(&self) -> usize {
+        self.points.len() - 1
+    }
+
+    fn projection(&self, segment: usize, position: [f32; 2]) -> [f32; 2] {
+        project_on_segment(position, [self.points[segment], self.points[segment + 1]])
+    }
+
+    /// Update with the current position of the agent.
+    pub fn update(&mut self, position: [f32; 2]) -> Progress {
+        if self.segment_count() == 0 {
+            return Progress {
+                travelled: 0.0,
+                remaining: 0.0,
+                segment: 0,
+                next_corner: None,
+            };
+        }
+        while self.segment + 1 < self.segment_count() {
+            let current = distance_between(position, self.projection(self.segment, position));
+            let next = distance_between(position, self.projection(self.segment + 1, position));
+            if next <= current {
+                self.segment += 1;
+            } else {
+                break;
+            }
+        }
+        let projected = self.projection(self.segment, position);
+        let travelled =
+            self.distances[self.segment] + distance_between(self.points[self.segment], projected);
+        Progress {
+            travelled,
+            remaining: self.length() - travelled,
+            segment: self.segment,
+            next_corner: Some(self.points[self.segment + 1]),
+        }
+    }
+
+    /// Skip the corners of the path that are no longer needed from the current position of the
+    /// agent, and return how many were removed.
+    ///
+    /// An agent that has been pushed away from its path may be able to go straight to a later
+    /// point. This checks the following points in order while they are in line of sight, so it's
+    /// meant to be called periodically rather than on every update.
+    pub fn shortcut(&mut self, mesh: &Mesh, position: [f32; 2]) -> usize {
+        self.update(position);
+        let mut visible = None;
+        for (i, point) in self.points.iter().enumerate().skip(self.segment + 2) {
+            if mesh.segment_on_mesh(position, *point) {
+                visible = Some(i);
+            } else {
+                break;
+            }
+        }
+        let visible = match visible {
+            Some(visible) => visible,
+            None => return 0,
+        };
+        let removed = visible - self.segment - 1;
+        self.points
+            .splice(self.segment + 1..visible, std::iter::once(position));
+        self.segment += 1;
+        self.distances.truncate(self.segment);
+        let mut distance = *self.distances.last().unwrap();
+        for segment in self.points[self.segment - 1..].windows(2) {
+            distance += distance_between(segment[0], segment[1]);
+            self.distances.push(distance);
+        }
+        removed
+    }
+
+    /// Point at `distance` along the path from its start, clamped to the ends of the path.
+    pub fn point_at(&self, distance: f32) -> [f32; 2] {
+        if self.points.len() < 2 {
+            return self.points[0];
+        }
+        let segment = self
+            .distances
+            .partition_point(|d| *d <= distance)
+            .clamp(1, self.points.len() - 1);
+        let start = self.points[segment - 1];
+        let end = self.points[segment];
+        let length = self.distances[segment] - self.distances[segment - 1];
+        if length == 0.0 {
+            return end;
+        }
+        let t = ((distance - self.distances[segment - 1]) / length).clamp(0.0, 1.0);
+        [
+            start[0] + t * (end[0] - start[0]),
+            start[1] + t * (end[1] - start[1]),
+        ]
+    }
+
+    /// Update with the current position of the agent, and return the unit direction it should
+    /// move in, or `None` if it reached the end of the path.
+    ///
+    /// The agent aims at the point `lookahead` further along the path, cutting corners when the
+    /// straight line to that point stays on the mesh. Otherwise, it aims at the next corner.
+    pub fn desired_direction(
+        &mut self,
+        mesh: &Mesh,
+        position: [f32; 2],
+        lookahead: f32,
+    ) -> Option<[f32; 2]> {
+        let progress = self.update(position);
+        let next_corner = progress.next_corner?;
+        let ahead = self.point_at(progress.travelled + lookahead);
+        let target = if mesh.segment_on_mesh(position, ahead) {
+            ahead
+        } else {
+            next_corner
+        };
+        let direction = [target[0] - position[0], target[1] - position[1]];
+        let length = distance_between(position, target);
+        (length > 1.0e-5).then(|| [direction[0] / length, direction[1] / length])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathProgress;
+    use crate::{tests::mesh_u_grid, Path};
+
+    #[test]
+    fn follow_path() {
+        let path = Path {
+            len: 4.0,
+            path: vec![[2.0, 0.0], [2.0, 2.0]],
+        };
+        let mut progress = PathProgress::new([0.0, 0.0], &path);
+        assert_eq!(progress.length(), 4.0);
+
+        let p = progress.update([1.0, 0.1]);
+        assert_eq!(p.travelled, 1.0);
+        assert_eq!(p.remaining, 3.0);
+        assert_eq!(p.segment, 0);
+        assert_eq!(p.next_corner, Some([2.0, 0.0]));
+
+        let p = progress.update([2.1, 0.5]);
+        assert_eq!(p.travelled, 2.5);
+        assert_eq!(p.segment, 1);
+        assert_eq!(p.next_corner, Some([2.0, 2.0]));
+
+        // can't go back to a previous segment
+        let p = progress.update([1.0, 0.0]);
+        assert_eq!(p.segment, 1);
+
+        let p = progress.update([2.0, 2.5]);
+        assert_eq!(p.remaining, 0.0);
+    }
+
+    #[test]
+    fn empty_path() {
+        let path = Path {
+            len: -1.0,
+            path: vec![],
+        };
+        let mut progress = PathProgress::new([0.0, 0.0], &path);
+        assert_eq!(progress.update([1.0, 0.0]).next_corner, None);
+    }
+
+    #[test]
+    fn point_at() {
+        let path = Path {
+            len: 4.0,
+            path: vec![[2.0, 0.0], [2.0, 2.0]],
+        };
+        let progress = PathProgress::new([0.0, 0.0], &path);
+        assert_eq!(progress.point_at(-1.0), [0.0, 0.0]);
+        assert_eq!(progress.point_at(1.0), [1.0, 0.0]);
+        assert_eq!(progress.point_at(2.0), [2.0, 0.0]);
+        assert_eq!(progress.point_at(3.5), [2.0, 1.5]);
+        assert_eq!(progress.point_at(10.0), [2.0, 2.0]);
+    }
+
+    #[test]
+    fn desired_direction() {
+        let mesh = mesh_u_grid();
+        let path = mesh.path([0.5, 1.5], [2.5, 1.5]).unwrap();
+        let mut progress = PathProgress::new([0.5, 1.5], &path);
+
+        // can't cut the corner, aim at it
+        let direction = progress.desired_direction(&mesh, [0.5, 1.5], 1.0).unwrap();
+        assert!((direction[0] - 0.5_f32.sqrt()).abs() < 1.0e-5);
+        assert!((direction[1] + 0.5_f32.sqrt()).abs() < 1.0e-5);
+
+        // along the bottom corridor, look ahead on the straight line
+        assert_eq!(
+            progress.desired_direction(&mesh, [1.2, 1.0], 0.5),
+            Some([1.0, 0.0])
+        );
+
+        assert_eq!(progress.desired_direction(&mesh, [2.5, 1.5], 1.0), None);
+    }
+
+    #[test]
+    fn shortcut() {
+        let mesh = mesh_u_grid();
+        let from = [0.5, 0.5];
+        let path = Path {
+            len: 0.0,
+            path: vec![[0.5, 0.2], [1.5, 0.2], [2.5, 0.8], [2.5, 1.5]],
+        };
+        let mut progress = PathProgress::new(from, &path);
+
+        // pushed to the middle of the corridor, the next corner is not needed
+        assert_eq!(progress.shortcut(&mesh, [1.0, 0.5]), 1);
+        assert_eq!(
+            progress.points(),
+            &[from, [0.5, 0.2], [1.0, 0.5], [2.5, 0.8], [2.5, 1.5]]
+        );
+        let p = progress.update([1.0, 0.5]);
+        assert_eq!(p.segment, 2);
+        assert_eq!(p.next_corner, Some([2.5, 0.8]));
+        assert!((p.travelled - (0.3 + 0.5_f32.hypot(0.3))).abs() < 1.0e-5);
+        assert!((p.remaining - (1.5_f32.hypot(0.3) + 0.7)).abs() < 1.0e-5);
+    }
+}
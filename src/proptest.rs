@@ -0,0 +1,76 @@
+//! [`proptest`](::proptest) strategies generating meshes and queries, enabled with the `proptest`
+//! feature.
+//!
+//! Generated meshes are valid grid meshes, like the ones from [`crate::maps`], and queries are
+//! points inside their polygons, so they can be used to property test code built on top of this
+//! crate.
+
+use ::proptest::{collection::vec, prelude::*};
+
+use crate::{maps::grid_mesh, Mesh, PolygonId};
+
+/// Strategy for meshes on a grid of at most `max_size` x `max_size` cells, with at least one
+/// polygon.
+pub fn mesh(max_size: usize) -> impl Strategy<Value = Mesh> {
+    (1..=max_size.max(1), 1..=max_size.max(1))
+        .prop_flat_map(|(width, height)| {
+            (
+                Just(width),
+                Just(height),
+                vec(any::<bool>(), width * height),
+                0..width * height,
+            )
+        })
+        .prop_map(|(width, height, mut walkable, always_walkable)| {
+            walkable[always_walkable] = true;
+            grid_mesh(width, height, |x, y| walkable[y * width + x])
+        })
+}
+
+/// Strategy for a point inside one of the polygons of `mesh`.
+///
+/// The mesh must have at least one polygon.
+pub fn point_in(mesh: &Mesh) -> impl Strategy<Value = [f32; 2]> {
+    let aabbs: Vec<_> = (0..mesh.polygons.len())
+        .filter_map(|polygon| mesh.polygon_bounding_box(PolygonId::new(polygon)))
+        .collect();
+    (0..aabbs.len(), 0.05_f32..0.95, 0.05_f32..0.95).prop_map(move |(polygon, x, y)| {
+        let aabb = aabbs[polygon];
+        let size = aabb.size();
+        [aabb.min[0] + x * size[0], aabb.min[1] + y * size[1]]
+    })
+}
+
+/// Strategy for a mesh with the start and goal of a query, both inside the mesh.
+///
+/// The goal is not always reachable from the start.
+pub fn mesh_and_query(max_size: usize) -> impl Strategy<Value = (Mesh, [f32; 2], [f32; 2])> {
+    mesh(max_size).prop_flat_map(|mesh| {
+        let from = point_in(&mesh);
+        let to = point_in(&mesh);
+        (Just(mesh), from, to)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ::proptest::prelude::*;
+
+    use super::mesh_and_query;
+    use crate::helpers::distance_between;
+
+    proptest! {
+        #[test]
+        fn query_points_are_in_mesh((mesh, from, to) in mesh_and_query(6)) {
+            prop_assert!(mesh.point_in_mesh(from));
+            prop_assert!(mesh.point_in_mesh(to));
+        }
+
+        #[test]
+        fn path_not_shorter_than_straight_line((mesh, from, to) in mesh_and_query(6)) {
+            if let Some(path) = mesh.path(from, to) {
+                prop_assert!(path.len >= distance_between(from, to) - 1.0e-3);
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, PolygonId,
+};
+
+/// Part of a mesh that can be reached from a point within a path length, as returned by
+/// [`Mesh::reachable_within`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReachableArea {
+    /// Polygons with at least a point in range, with the length of the shortest path to their
+    /// closest point.
+    pub polygons: Vec<(PolygonId, f32)>,
+    /// Where the range stops partway along an edge: the part of the edge in range, with the
+    /// polygon it leads to.
+    pub frontier: Vec<(PolygonId, [[f32; 2]; 2])>,
+}
+
+impl Mesh {
+    /// Find the part of the mesh reachable from `from` with paths at most `radius` long, or
+    /// `None` if `from` is not in the mesh.
+    ///
+    /// It's a single search from `from` with no target, that stops going further once paths
+    /// are longer than the radius. The polygons listed can be highlighted to show how far an
+    /// agent can move in a turn, and the frontier segments to draw where the range ends across
+    /// them.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall between the two points
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9);
+    /// let area = mesh.reachable_within([4.0, 1.0], 4.0).unwrap();
+    ///
+    /// let behind_wall = mesh.locate_with_hint([7.0, 1.0], None).unwrap();
+    /// assert!(!area.polygons.iter().any(|(polygon, _)| *polygon == behind_wall));
+    /// ```
+    pub fn reachable_within(&self, from: [f32; 2], radius: f32) -> Option<ReachableArea> {
+        let polygon = self.point_in_polygon(from)?;
+        let mut distances = vec![f32::INFINITY; self.polygons.len()];
+        distances[polygon.index()] = 0.0;
+        let mut frontier = vec![];
+
+        self.search_everywhere(from, polygon, radius, |polygon, node| {
+            let cost = node.f + node.penalty;
+            let closest = project_on_segment(node.r, node.i);
+            let distance = cost + distance_between(node.r, closest) * node.weight;
+            if distance > radius {
+                return;
+            }
+            let known = &mut distances[polygon.index()];
+            *known = known.min(distance);
+
+            // the interval is only partly in range from the root
+            let reach = (radius - cost) / node.weight;
+            if node
+                .i
+                .iter()
+                .any(|end| distance_between(node.r, *end) > reach)
+            {
+                if let Some(clipped) = clip_to_disc(node.i, node.r, reach) {
+                    frontier.push((polygon, clipped));
+                }
+            }
+        });
+
+        Some(ReachableArea {
+            polygons: distances
+                .into_iter()
+                .enumerate()
+                .filter(|(_, distance)| *distance <= radius)
+                .map(|(index, distance)| (PolygonId::new(index), distance))
+                .collect(),
+            frontier,
+        })
+    }
+}
+
+/// Part of a segment inside a disc, if any.
+fn clip_to_disc(segment: [[f32; 2]; 2], center: [f32; 2], radius: f32) -> Option<[[f32; 2]; 2]> {
+    let [a, b] = segment;
+    let d = [b[0] - a[0], b[1] - a[1]];
+    let m = [a[0] - center[0], a[1] - center[1]];
+    let qa = d[0] * d[0] + d[1] * d[1];
+    if qa == 0.0 {
+        return (distance_between(a, center) <= radius).then_some(segment);
+    }
+    let qb = m[0] * d[0] + m[1] * d[1];
+    let qc = m[0] * m[0] + m[1] * m[1] - radius * radius;
+    let discriminant = qb * qb - qa * qc;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    let t0 = ((-qb - root) / qa).max(0.0);
+    let t1 = ((-qb + root) / qa).min(1.0);
+    if t0 > t1 {
+        return None;
+    }
+    let at = |t: f32| [a[0] + d[0] * t, a[1] + d[1] * t];
+    Some([at(t0), at(t1)])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::distance_between,
+        maps::{grid_mesh, maze},
+    };
+
+    #[test]
+    fn movement_range() {
+        let mesh = maze(8, 8, 5);
+        let (from, radius) = ([1.5, 1.5], 7.6);
+        let area = mesh.reachable_within(from, radius).unwrap();
+        let field = mesh.distance_field(from).unwrap();
+        // the polygons closer than the radius, at the same distance
+        let mut expected: Vec<_> = field
+            .reachable()
+            .filter(|(_, distance)| *distance <= radius)
+            .collect();
+        expected.sort_by_key(|(polygon, _)| polygon.index());
+        assert_eq!(area.polygons.len(), expected.len());
+        for ((polygon, distance), (expected, expected_distance)) in
+            area.polygons.iter().zip(&expected)
+        {
+            assert_eq!(polygon, expected);
+            assert!((distance - expected_distance).abs() < 1.0e-3);
+        }
+        assert!(area.polygons.len() < mesh.polygons.len());
+
+        // the ends of the frontier segments are in range
+        assert!(!area.frontier.is_empty());
+        for (polygon, segment) in &area.frontier {
+            assert!(area.polygons.iter().any(|(p, _)| p == polygon));
+            for end in segment {
+                let path = mesh.path(from, *end).unwrap();
+                assert!(path.len <= radius + 1.0e-3);
+            }
+        }
+        assert!(mesh.reachable_within([-1.0, 0.0], radius).is_none());
+    }
+
+    #[test]
+    fn range_in_a_corridor() {
+        let mesh = grid_mesh(10, 1, |_, _| true);
+        let area = mesh.reachable_within([0.5, 0.5], 3.52).unwrap();
+        assert_eq!(area.polygons.len(), 5);
+        // the range stops across the edge of the fifth cell
+        assert_eq!(area.frontier.len(), 1);
+        let (polygon, [a, b]) = area.frontier[0];
+        assert_eq!(mesh.point_in_polygon([4.5, 0.5]), Some(polygon));
+        assert_eq!([a[0], b[0]], [4.0, 4.0]);
+        assert!((a[1] - b[1]).abs() < 1.0);
+        for end in [a, b] {
+            assert!(distance_between([0.5, 0.5], end) <= 3.52 + 1.0e-5);
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::BTreeMap;
+
+use crate::{helpers::distance_between, Aabb, Mesh, MeshChange, Path};
+
+/// Identifier of a path kept up to date by a [`Replanner`], valid until it is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PlanId(u64);
+
+/// A planned path, from its start to its target.
+#[derive(Debug, Clone)]
+struct Plan {
+    from: [f32; 2],
+    to: [f32; 2],
+    path: Option<Path>,
+}
+
+/// Shortest paths of many agents, kept up to date when the mesh changes.
+///
+/// After each [`MeshChange`], only the paths that may be different are searched again: the ones
+/// going through the area that changed, where they may be blocked, and the ones that would be
+/// longer than going through this area, where a shorter path may have opened. The other paths
+/// stay the shortest ones, so closing a door or carving an obstacle in a corner of the map doesn't
+/// search again the paths of every agent.
+///
+/// Paths going through the area are repaired rather than searched again from their start: they are
+/// kept up to their last turn before the area, and only searched again from there. Like an agent
+/// already on its way going around a new obstacle, a repaired path may not be the shortest one
+/// from its start anymore. Paths that may be shorter, and paths that can't be repaired, are
+/// searched again from their start.
+///
+/// ```
+/// # use polyanya::{Mesh, Replanner};
+/// let mut mesh = Mesh::from_grid(20, 10, |_, _| true);
+/// let changes = mesh.subscribe();
+/// let mut replanner = Replanner::new();
+/// let near = replanner.add(&mesh, [1.0, 5.0], [9.0, 5.0]);
+/// let far = replanner.add(&mesh, [11.0, 1.0], [19.0, 1.0]);
+///
+/// mesh.carve(&[[4.0, 3.0], [6.0, 3.0], [6.0, 7.0], [4.0, 7.0]])
+///     .unwrap();
+/// for change in changes.try_iter() {
+///     assert_eq!(replanner.apply(&mesh, &change), vec![near]);
+/// }
+/// assert_eq!(replanner.path(near), mesh.path([1.0, 5.0], [9.0, 5.0]).as_ref());
+/// assert_eq!(replanner.path(far), mesh.path([11.0, 1.0], [19.0, 1.0]).as_ref());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Replanner {
+    plans: BTreeMap<PlanId, Plan>,
+    next: u64,
+}
+
+impl Replanner {
+    /// Create a replanner without paths.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Compute the shortest path from `from` to `to`, and keep it up to date.
+    pub fn add(&mut self, mesh: &Mesh, from: [f32; 2], to: [f32; 2]) -> PlanId {
+        let id = PlanId(self.next);
+        self.next += 1;
+        self.plans.insert(
+            id,
+            Plan {
+                from,
+                to,
+                path: mesh.path(from, to),
+            },
+        );
+        id
+    }
+
+    /// Stop keeping a path up to date, returning `false` if there was none with this identifier.
+    pub fn remove(&mut self, plan: PlanId) -> bool {
+        self.plans.remove(&plan).is_some()
+    }
+
+    /// Compute again the path of an agent that moved or changed its target.
+    ///
+    /// Returns `false` if there is no path with this identifier.
+    pub fn set_endpoints(
+        &mut self,
+        mesh: &Mesh,
+        plan: PlanId,
+        from: [f32; 2],
+        to: [f32; 2],
+    ) -> bool {
+        let Some(plan) = self.plans.get_mut(&plan) else {
+            return false;
+        };
+        *plan = Plan {
+            from,
+            to,
+            path: mesh.path(from, to),
+        };
+        true
+    }
+
+    /// The current path, or `None` if there is no path with this identifier or if its target
+    /// can't be reached.
+    pub fn path(&self, plan: PlanId) -> Option<&Path> {
+        self.plans.get(&plan)?.path.as_ref()
+    }
+
+    /// Number of paths.
+    pub fn len(&self) -> usize {
+        self.plans.len()
+    }
+
+    /// `true` if there is no path.
+    pub fn is_empty(&self) -> bool {
+        self.plans.is_empty()
+    }
+
+    /// Update the paths after a change of the mesh, received from [`Mesh::subscribe`]. Returns the
+    /// paths that changed.
+    ///
+    /// Changes must all be applied, in any order, with the mesh after them.
+    pub fn apply(&mut self, mesh: &Mesh, change: &MeshChange) -> Vec<PlanId> {
+        if change.area.is_empty() {
+            return vec![];
+        }
+        #[cfg(feature = "counters")]
+        let _query = crate::counters::Query::start();
+        let mut changed = vec![];
+        for (id, plan) in &mut self.plans {
+            let path = match &plan.path {
+                Some(path) if change.affects_path(plan.from, path) => {
+                    repair(mesh, [plan.from, plan.to], path, &change.area)
+                        .or_else(|| mesh.path(plan.from, plan.to))
+                }
+                // any path going through the area is at least as long as going to it in a
+                // straight line
+                Some(path)
+                    if change.area.distance_to(plan.from) + change.area.distance_to(plan.to)
+                        >= path.len =>
+                {
+                    continue;
+                }
+                _ => mesh.path(plan.from, plan.to),
+            };
+            if path != plan.path {
+                plan.path = path;
+                changed.push(*id);
+            }
+        }
+        changed
+    }
+}
+
+/// Keep a path up to its last turn before `area`, and search again from there.
+///
+/// Returns `None` if the path goes through `area` from its start, or if its target can't be reached
+/// from this turn.
+fn repair(mesh: &Mesh, [from, to]: [[f32; 2]; 2], path: &Path, area: &Aabb) -> Option<Path> {
+    let mut last = from;
+    let mut len = 0.0;
+    for (kept, point) in path.path.iter().enumerate() {
+        if Aabb::from_points([last, *point]).intersects(area) {
+            if kept == 0 {
+                return None;
+            }
+            let rest = mesh.path(last, to)?;
+            let mut repaired = path.path[..kept].to_vec();
+            repaired.extend(rest.path);
+            return Some(Path {
+                len: len + rest.len,
+                path: repaired,
+            });
+        }
+        len += distance_between(last, *point);
+        last = *point;
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Replanner;
+    use crate::{
+        maps::{random_obstacles, test_points},
+        Mesh,
+    };
+
+    #[test]
+    fn kept_up_to_date() {
+        let mut mesh = random_obstacles(30, 30, 20, 4, 5);
+        let changes = mesh.subscribe();
+        let mut replanner = Replanner::new();
+        let points: Vec<[f32; 2]> = test_points(24, [0.5, 0.5]).collect();
+        let plans: Vec<_> = points
+            .chunks(2)
+            .map(|pair| (replanner.add(&mesh, pair[0], pair[1]), pair[0], pair[1]))
+            .collect();
+        assert_eq!(replanner.len(), plans.len());
+
+        // obstacles appear, and doors close and open
+        let mut replanned = 0;
+        for i in 0..6 {
+            let [x, y] = [(i * 11 % 26) as f32 + 2.0, (i * 17 % 26) as f32 + 2.0];
+            let _ = mesh.carve(&[[x, y], [x + 1.5, y], [x + 1.5, y + 1.5], [x, y + 1.5]]);
+            let door = mesh.interior_edges().nth(i * 40).map(|(edge, _, _)| edge);
+            if let Some(door) = door {
+                mesh.set_edge_open(door, i % 2 == 1);
+            }
+            let mut changed = vec![];
+            for change in changes.try_iter() {
+                changed.extend(replanner.apply(&mesh, &change));
+            }
+            for (plan, from, to) in &plans {
+                match (replanner.path(*plan), mesh.path(*from, *to)) {
+                    // repaired paths go around what changed, and may be longer
+                    (Some(kept), Some(path)) => {
+                        assert!(kept.len > path.len - 1.0e-3);
+                        assert_eq!(kept.path.last(), Some(to));
+                        let mut last = *from;
+                        for point in &kept.path {
+                            assert!(mesh.segment_on_mesh(last, *point));
+                            last = *point;
+                        }
+                    }
+                    (kept, path) => assert_eq!(kept, path.as_ref()),
+                }
+            }
+            assert!(changed.len() < plans.len());
+            replanned += changed.len();
+        }
+        assert!(replanned > 0);
+
+        let (plan, from, _) = plans[0];
+        assert!(replanner.set_endpoints(&mesh, plan, from, from));
+        assert_eq!(replanner.path(plan).unwrap().path, vec![from]);
+        assert!(replanner.remove(plan));
+        assert!(!replanner.remove(plan));
+        assert_eq!(replanner.path(plan), None);
+    }
+
+    #[test]
+    fn repaired_from_the_last_turn() {
+        let mut mesh = Mesh::from_grid(20, 20, |x, y| x != 10 || y > 15);
+        let changes = mesh.subscribe();
+        let mut replanner = Replanner::new();
+        let plan = replanner.add(&mesh, [2.0, 2.0], [18.0, 2.0]);
+        let path = replanner.path(plan).unwrap().clone();
+        let turns = path.path.len() - 1;
+
+        // an obstacle after the turns over the wall
+        mesh.carve(&[[14.0, 7.0], [16.0, 7.0], [16.0, 9.0], [14.0, 9.0]])
+            .unwrap();
+        for change in changes.try_iter() {
+            assert_eq!(replanner.apply(&mesh, &change), vec![plan]);
+        }
+        let repaired = replanner.path(plan).unwrap();
+        assert_eq!(repaired.path[..turns], path.path[..turns]);
+        let shortest = mesh.path([2.0, 2.0], [18.0, 2.0]).unwrap();
+        assert!((repaired.len - shortest.len).abs() < 1.0e-3);
+        assert!(repaired.len > path.len);
+    }
+
+    #[test]
+    fn unreachable_targets() {
+        // a wall with a door
+        let mut mesh = Mesh::from_grid(10, 3, |x, y| x != 5 || y == 1);
+        let changes = mesh.subscribe();
+        let mut replanner = Replanner::new();
+        let (from, to) = ([1.5, 0.5], [8.5, 0.5]);
+        let across = replanner.add(&mesh, from, to);
+        let outside = replanner.add(&mesh, [-1.0, 0.5], to);
+        assert!(replanner.path(across).is_some());
+        assert!(replanner.path(outside).is_none());
+
+        let door = |mesh: &Mesh| {
+            mesh.edge_between(
+                mesh.point_in_polygon([4.5, 1.5]).unwrap(),
+                mesh.point_in_polygon([5.5, 1.5]).unwrap(),
+            )
+            .unwrap()
+        };
+        for open in [false, true] {
+            mesh.set_edge_open(door(&mesh), open);
+            let change = changes.try_recv().unwrap();
+            assert_eq!(replanner.apply(&mesh, &change), vec![across]);
+            assert_eq!(replanner.path(across), mesh.path(from, to).as_ref());
+            assert!(replanner.path(outside).is_none());
+        }
+
+        // an obstacle on the start
+        mesh.carve(&[[1.0, 0.0], [2.0, 0.0], [2.0, 1.0], [1.0, 1.0]])
+            .unwrap();
+        for change in changes.try_iter() {
+            replanner.apply(&mesh, &change);
+        }
+        assert!(replanner.path(across).is_none());
+
+        let mut empty = Replanner::new();
+        mesh.set_edge_open(door(&mesh), false);
+        assert!(empty.apply(&mesh, &changes.try_recv().unwrap()).is_empty());
+        assert!(empty.is_empty());
+    }
+}
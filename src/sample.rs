@@ -0,0 +1,206 @@
+use crate::{Mesh, PolygonId};
+
+/// Number of points drawn around a point by [`Mesh::random_point_around`] before giving up.
+const MAX_ATTEMPTS: usize = 64;
+
+impl Mesh {
+    /// Pick a random point of the mesh, with the polygon it's in, or `None` if the mesh is empty.
+    ///
+    /// Points are uniformly distributed by area: bigger polygons get more of them. `random` must
+    /// return numbers uniformly distributed in `0.0..1.0`, from any random number generator, for
+    /// example `|| rng.gen()` with the `rand` crate.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |x, _| x != 5);
+    /// // a simple generator, for the example
+    /// let mut seed = 7_u32;
+    /// let mut random = || {
+    ///     seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+    ///     (seed >> 8) as f32 / (1 << 24) as f32
+    /// };
+    /// let (point, _) = mesh.random_point(&mut random).unwrap();
+    /// assert!(mesh.point_in_mesh(point));
+    /// ```
+    pub fn random_point(&self, random: impl FnMut() -> f32) -> Option<([f32; 2], PolygonId)> {
+        self.random_point_in((0..self.polygons.len()).map(PolygonId::new), random)
+    }
+
+    /// Pick a random point in some polygons, uniformly distributed by area, or `None` if there
+    /// are none.
+    ///
+    /// Polygons that are not in the mesh are ignored. See [`Mesh::random_point`] for `random`.
+    pub fn random_point_in(
+        &self,
+        polygons: impl IntoIterator<Item = PolygonId>,
+        mut random: impl FnMut() -> f32,
+    ) -> Option<([f32; 2], PolygonId)> {
+        let mut total = 0.0;
+        let cumulated: Vec<(f32, PolygonId)> = polygons
+            .into_iter()
+            .filter(|polygon| polygon.index() < self.polygons.len())
+            .map(|polygon| {
+                total += self.polygon_area(polygon.index());
+                (total, polygon)
+            })
+            .collect();
+        let target = random() * total;
+        let index = cumulated
+            .partition_point(|(area, _)| *area <= target)
+            .min(cumulated.len().checked_sub(1)?);
+        let polygon = cumulated[index].1;
+        Some((self.random_point_in_polygon(polygon, random), polygon))
+    }
+
+    /// Pick a random point of the mesh at most `radius` from `center` as the crow flies, or
+    /// `None` if none was found.
+    ///
+    /// Points are uniformly distributed on the part of the disc in the mesh. They are drawn in
+    /// the disc until one is in the mesh, so this can fail when only a small part of it is. The
+    /// point can be on the other side of a wall: check that it can be reached if it matters.
+    /// See [`Mesh::random_point`] for `random`.
+    pub fn random_point_around(
+        &self,
+        center: [f32; 2],
+        radius: f32,
+        mut random: impl FnMut() -> f32,
+    ) -> Option<([f32; 2], PolygonId)> {
+        (0..MAX_ATTEMPTS).find_map(|_| {
+            let distance = radius * random().sqrt();
+            let angle = std::f32::consts::TAU * random();
+            let point = [
+                center[0] + distance * angle.cos(),
+                center[1] + distance * angle.sin(),
+            ];
+            self.point_in_polygon_unit(point)
+                .map(|polygon| (point, polygon))
+        })
+    }
+
+    /// Area of a polygon.
+    pub(crate) fn polygon_area(&self, index: usize) -> f32 {
+        let polygon = &self.polygons[index];
+        let n = polygon.vertices.len();
+        let double_area: f32 = (0..n)
+            .map(|i| {
+                let a = self.vertices[polygon.vertices[i]].p();
+                let b = self.vertices[polygon.vertices[(i + 1) % n]].p();
+                a[0] * b[1] - b[0] * a[1]
+            })
+            .sum();
+        double_area.abs() / 2.0
+    }
+
+    /// Pick a random point in a polygon, uniformly distributed, from the triangles fanning from
+    /// its first vertex.
+    fn random_point_in_polygon(
+        &self,
+        polygon: PolygonId,
+        mut random: impl FnMut() -> f32,
+    ) -> [f32; 2] {
+        let points: Vec<[f32; 2]> = self.polygons[polygon.index()]
+            .vertices
+            .iter()
+            .map(|vertex| self.vertices[*vertex].p())
+            .collect();
+        let triangle_area = |b: [f32; 2], c: [f32; 2]| {
+            let a = points[0];
+            ((b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])).abs() / 2.0
+        };
+        let total: f32 = points
+            .windows(2)
+            .skip(1)
+            .map(|pair| triangle_area(pair[0], pair[1]))
+            .sum();
+        let mut target = random() * total;
+        let mut triangle = [points[0], points[0], points[0]];
+        for pair in points.windows(2).skip(1) {
+            triangle = [points[0], pair[0], pair[1]];
+            target -= triangle_area(pair[0], pair[1]);
+            if target < 0.0 {
+                break;
+            }
+        }
+        // uniform in the triangle
+        let (mut u, mut v) = (random(), random());
+        if u + v > 1.0 {
+            (u, v) = (1.0 - u, 1.0 - v);
+        }
+        let [a, b, c] = triangle;
+        [
+            a[0] + u * (b[0] - a[0]) + v * (c[0] - a[0]),
+            a[1] + u * (b[1] - a[1]) + v * (c[1] - a[1]),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::{distance_between, point_in_simple_polygon},
+        maps::random_obstacles,
+        Mesh, PolygonId,
+    };
+
+    fn in_polygon(mesh: &Mesh, polygon: PolygonId, point: [f32; 2]) -> bool {
+        let vertices: Vec<_> = mesh.polygons[polygon.index()]
+            .vertices
+            .iter()
+            .map(|vertex| mesh.vertices[*vertex].p())
+            .collect();
+        point_in_simple_polygon(point, &vertices)
+    }
+
+    /// Numbers in `0.0..1.0` from a linear congruential generator.
+    fn generator(mut seed: u32) -> impl FnMut() -> f32 {
+        move || {
+            seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+            (seed >> 8) as f32 / (1 << 24) as f32
+        }
+    }
+
+    #[test]
+    fn uniform_by_area() {
+        // a big square and a small one next to it
+        let mesh = Mesh::from_grid(4, 3, |x, y| x < 3 || y == 0);
+        let mut random = generator(3);
+        let mut in_small = 0;
+        for _ in 0..2000 {
+            let (point, polygon) = mesh.random_point(&mut random).unwrap();
+            assert!(in_polygon(&mesh, polygon, point));
+            if point[0] > 3.0 {
+                in_small += 1;
+            }
+        }
+        // one tenth of the area
+        assert!((150..250).contains(&in_small), "{}", in_small);
+        assert!(Mesh::default().random_point(&mut random).is_none());
+    }
+
+    #[test]
+    fn restricted_points() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let mut random = generator(11);
+        let some: Vec<_> = (0..mesh.polygons.len())
+            .step_by(7)
+            .map(PolygonId::new)
+            .collect();
+        for _ in 0..200 {
+            let (point, polygon) = mesh
+                .random_point_in(some.iter().copied(), &mut random)
+                .unwrap();
+            assert!(some.contains(&polygon));
+            assert!(in_polygon(&mesh, polygon, point));
+
+            let (point, polygon) = mesh
+                .random_point_around([15.0, 15.0], 4.0, &mut random)
+                .unwrap();
+            assert!(distance_between(point, [15.0, 15.0]) <= 4.0 + 1.0e-5);
+            assert_eq!(mesh.point_in_polygon(point), Some(polygon));
+        }
+        assert!(mesh.random_point_in([], &mut random).is_none());
+        assert!(mesh
+            .random_point_around([-50.0, -50.0], 1.0, &mut random)
+            .is_none());
+    }
+}
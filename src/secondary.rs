@@ -0,0 +1,168 @@
+use crate::{doors::key, Aabb, EdgeId, Mesh, Path, PolygonId, SearchOptions};
+
+impl Mesh {
+    /// Set the secondary cost of a polygon, for each unit of length of the paths going through
+    /// it, like the exposure to enemy fire. Returns `false` if there is no such polygon, or if
+    /// the cost is not finite.
+    ///
+    /// Secondary costs are only used by [`Mesh::path_with_secondary_cost`]. Negative costs are
+    /// raised to `0.0`. Parts of a polygon split or carved by an edit keep its secondary cost, and
+    /// polygons of different costs are not merged.
+    pub fn set_polygon_secondary_cost(&mut self, polygon: PolygonId, cost: f32) -> bool {
+        if !cost.is_finite() {
+            return false;
+        }
+        let Some(current) = self.polygons.get_mut(polygon.index()) else {
+            return false;
+        };
+        let cost = cost.max(0.0);
+        if current.secondary_cost != cost {
+            current.secondary_cost = cost;
+            self.notify_polygons(vec![polygon.index()], Aabb::EMPTY, false);
+        }
+        true
+    }
+
+    /// Set the secondary cost of crossing an edge shared by two polygons, in either direction.
+    /// Returns `false` if the edge is not shared by two polygons, or if the cost is not finite.
+    ///
+    /// Like the costs of the polygons, negative costs are raised to `0.0`. They're kept through
+    /// the edits that don't remove the edge.
+    pub fn set_edge_secondary_cost(&mut self, edge: EdgeId, cost: f32) -> bool {
+        if !cost.is_finite() || self.polygons_of_edge(edge).count() != 2 {
+            return false;
+        }
+        let cost = cost.max(0.0);
+        let changed = if cost == 0.0 {
+            self.secondary_edge_costs.remove(&key(edge)).is_some()
+        } else {
+            self.secondary_edge_costs.insert(key(edge), cost) != Some(cost)
+        };
+        if changed {
+            self.notify_edge(edge);
+        }
+        true
+    }
+
+    /// Secondary cost of crossing an edge, `0.0` if it has none.
+    pub fn edge_secondary_cost(&self, edge: EdgeId) -> f32 {
+        if self.secondary_edge_costs.is_empty() {
+            return 0.0;
+        }
+        self.secondary_edge_costs
+            .get(&key(edge))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Give the polygons the secondary cost of the polygon of `old` their center was in, after the
+    /// mesh was built again from it.
+    pub(crate) fn keep_secondary_costs(&mut self, old: &Mesh) {
+        if old
+            .polygons
+            .iter()
+            .all(|polygon| polygon.secondary_cost == 0.0)
+        {
+            return;
+        }
+        for polygon in &mut self.polygons {
+            let n = polygon.vertices.len() as f32;
+            let center = polygon.vertices.iter().fold([0.0, 0.0], |sum, vertex| {
+                let p = self.vertices[*vertex].p();
+                [sum[0] + p[0] / n, sum[1] + p[1] / n]
+            });
+            polygon.secondary_cost = old
+                .point_in_polygon_unit(center)
+                .map_or(0.0, |origin| old.polygons[origin.index()].secondary_cost);
+        }
+    }
+
+    /// Compute the path from `from` to `to` minimizing its length plus `weight` times its
+    /// secondary cost: the secondary costs of the polygons for each unit of length, and the
+    /// secondary costs of the edges it crosses.
+    ///
+    /// It's a trade-off between two objectives, like the distance and the danger, where `weight`
+    /// is how much length a unit of secondary cost is worth. Like the costs of a
+    /// [`PathFilter`](crate::PathFilter), each segment of the path between two turns costs as much
+    /// for each unit of length as the most expensive polygon it goes through, and paths can turn
+    /// at any vertex between polygons of different costs. The length of the path is its cost.
+    /// Returns `None` if one of the points is not in the mesh, or if `to` can't be reached from
+    /// `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a pillar in the middle of a room, with a sniper watching one side
+    /// let mut mesh = Mesh::from_grid(3, 3, |x, y| (x, y) != (1, 1));
+    /// let exposed = mesh.locate_with_hint([1.5, 0.5], None).unwrap();
+    /// mesh.set_polygon_secondary_cost(exposed, 5.0);
+    /// let (from, to) = ([0.5, 1.5], [2.5, 1.5]);
+    /// assert!(mesh.path_with_secondary_cost(from, to, 0.0).unwrap().path.contains(&[1.0, 1.0]));
+    /// let safe = mesh.path_with_secondary_cost(from, to, 1.0).unwrap();
+    /// assert_eq!(safe.path, vec![[1.0, 2.0], [2.0, 2.0], [2.5, 1.5]]);
+    /// ```
+    pub fn path_with_secondary_cost(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        weight: f32,
+    ) -> Option<Path> {
+        self.path_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions {
+                secondary_weight: weight.max(0.0),
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{maps::grid_mesh, Mesh, PolygonId};
+
+    #[test]
+    fn secondary_costs() {
+        // a corridor of three rooms
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let (from, to) = ([0.5, 0.5], [2.5, 0.5]);
+        assert!(mesh.set_polygon_secondary_cost(PolygonId::new(1), 2.0));
+        assert!(!mesh.set_polygon_secondary_cost(PolygonId::new(3), 2.0));
+        assert!(!mesh.set_polygon_secondary_cost(PolygonId::new(1), f32::INFINITY));
+        assert_eq!(mesh.polygons[1].secondary_cost(), 2.0);
+        // the straight path costs 2.0 for each unit of length
+        let path = mesh.path_with_secondary_cost(from, to, 0.5).unwrap();
+        assert_eq!(path.len, 4.0);
+        assert_eq!(
+            mesh.path_with_secondary_cost(from, to, 0.0),
+            mesh.path(from, to)
+        );
+
+        let edge = mesh
+            .edge_between(PolygonId::new(1), PolygonId::new(2))
+            .unwrap();
+        assert!(mesh.set_edge_secondary_cost([edge[1], edge[0]], 4.0));
+        assert_eq!(mesh.edge_secondary_cost(edge), 4.0);
+        assert!(!mesh.set_edge_secondary_cost([0, 1], 4.0));
+        let path = mesh.path_with_secondary_cost(from, to, 0.5).unwrap();
+        assert_eq!(path.len, 6.0);
+
+        // kept in the binary format
+        let mut bytes = vec![];
+        mesh.save_binary(&mut bytes).unwrap();
+        let loaded = Mesh::load_binary(bytes.as_slice()).unwrap();
+        assert_eq!(loaded.polygons[1].secondary_cost(), 2.0);
+        assert_eq!(loaded.polygons[0].secondary_cost(), 0.0);
+        assert_eq!(loaded.edge_secondary_cost(edge), 4.0);
+        assert_eq!(
+            loaded.path_with_secondary_cost(from, to, 0.5).unwrap().len,
+            6.0
+        );
+
+        assert!(mesh.set_edge_secondary_cost(edge, -1.0));
+        assert_eq!(mesh.edge_secondary_cost(edge), 0.0);
+    }
+}
@@ -0,0 +1,138 @@
+//! Serialization of meshes, enabled with the `serde` feature.
+//!
+//! Only the vertices, the polygons, the state of their edges and the settings of a mesh are
+//! stored. Bounding boxes and the point location grid are computed again when deserializing.
+
+use ::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{EdgeId, Mesh, PointSnap, Polygon, Vertex};
+
+#[derive(Serialize)]
+#[serde(rename = "Mesh")]
+struct MeshRef<'m> {
+    vertices: &'m [Vertex],
+    polygons: &'m [Polygon],
+    output_quantization: Option<f32>,
+    off_mesh_clamp: Option<f32>,
+    point_snap: Option<PointSnap>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    closed_edges: Vec<EdgeId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    one_way_edges: Vec<EdgeId>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    secondary_edge_costs: Vec<(EdgeId, f32)>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Mesh")]
+struct MeshData {
+    vertices: Vec<Vertex>,
+    polygons: Vec<Polygon>,
+    #[serde(default)]
+    output_quantization: Option<f32>,
+    #[serde(default)]
+    off_mesh_clamp: Option<f32>,
+    #[serde(default)]
+    point_snap: Option<PointSnap>,
+    #[serde(default)]
+    closed_edges: Vec<EdgeId>,
+    #[serde(default)]
+    one_way_edges: Vec<EdgeId>,
+    #[serde(default)]
+    secondary_edge_costs: Vec<(EdgeId, f32)>,
+}
+
+/// Edges of a set, sorted so that the same mesh is always serialized the same way.
+fn sorted<'e>(edges: impl Iterator<Item = &'e EdgeId>) -> Vec<EdgeId> {
+    let mut edges: Vec<EdgeId> = edges.copied().collect();
+    edges.sort_unstable();
+    edges
+}
+
+impl Serialize for Mesh {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MeshRef {
+            vertices: &self.vertices,
+            polygons: &self.polygons,
+            output_quantization: self.output_quantization,
+            off_mesh_clamp: self.off_mesh_clamp,
+            point_snap: self.point_snap,
+            closed_edges: sorted(self.closed_edges.iter()),
+            one_way_edges: sorted(self.one_way_edges.iter()),
+            secondary_edge_costs: sorted(self.secondary_edge_costs.keys())
+                .into_iter()
+                .map(|edge| (edge, self.secondary_edge_costs[&edge]))
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Mesh {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = MeshData::deserialize(deserializer)?;
+        let mut mesh = Mesh::new(data.vertices, data.polygons);
+        mesh.set_output_quantization(data.output_quantization);
+        mesh.set_off_mesh_clamp(data.off_mesh_clamp);
+        mesh.set_point_snap(data.point_snap);
+        mesh.split_concave_polygons();
+        for edge in data.closed_edges {
+            if !mesh.set_edge_open(edge, false) {
+                return Err(D::Error::custom("closed edge not between two polygons"));
+            }
+        }
+        for edge in data.one_way_edges {
+            if !mesh.set_edge_one_way(edge, true) {
+                return Err(D::Error::custom("one-way edge not between two polygons"));
+            }
+        }
+        for (edge, cost) in data.secondary_edge_costs {
+            if !mesh.set_edge_secondary_cost(edge, cost) {
+                return Err(D::Error::custom("invalid secondary cost of edge"));
+            }
+        }
+        Ok(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tests::mesh_u_grid, Mesh, Path, PolygonId};
+
+    #[test]
+    fn mesh_round_trip() {
+        let mut mesh = mesh_u_grid();
+        mesh.set_output_quantization(Some(0.5));
+        mesh.set_off_mesh_clamp(Some(0.25));
+        let door = [1, 5];
+        assert!(mesh.set_edge_open(door, false));
+        let drop = [4, 5];
+        assert!(mesh.set_edge_one_way(drop, true));
+        assert!(mesh.set_edge_secondary_cost([2, 6], 3.0));
+        let json = serde_json::to_string(&mesh).unwrap();
+        let loaded: Mesh = serde_json::from_str(&json).unwrap();
+        assert!(mesh.diff(&loaded, 0.0).is_empty());
+        assert_eq!(loaded.output_quantization(), Some(0.5));
+        assert_eq!(loaded.off_mesh_clamp(), Some(0.25));
+        assert!(loaded.is_edge_closed(door));
+        assert!(loaded.can_cross(drop));
+        assert!(!loaded.can_cross([5, 4]));
+        assert_eq!(loaded.edge_secondary_cost([6, 2]), 3.0);
+        assert_eq!(loaded.bounding_box(), mesh.bounding_box());
+        assert_eq!(
+            loaded.locate_batch(&[[2.5, 1.5]]),
+            vec![Some(PolygonId::new(4))]
+        );
+        assert_eq!(
+            loaded.path([0.5, 1.5], [2.5, 1.5]),
+            mesh.path([0.5, 1.5], [2.5, 1.5])
+        );
+    }
+
+    #[test]
+    fn path_round_trip() {
+        let path = mesh_u_grid().path([0.5, 1.5], [2.5, 1.5]).unwrap();
+        let json = serde_json::to_string(&path).unwrap();
+        assert_eq!(serde_json::from_str::<Path>(&json).unwrap(), path);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{Mesh, MeshBuildError, Path};
+
+/// Meshes of the same area for agents of different sizes, each with its walkable area shrunk by
+/// the radius of its agents.
+///
+/// Queries are run on the mesh of the smallest radius that is at least the radius of the agent, so
+/// that units of mixed sizes can share one container.
+///
+/// ```
+/// # use polyanya::{AgentSizes, Mesh};
+/// let outer = [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]];
+/// // a gap of 1.5 between two walls
+/// let holes = [
+///     vec![[0.0, 4.0], [4.25, 4.0], [4.25, 6.0], [0.0, 6.0]],
+///     vec![[5.75, 4.0], [10.0, 4.0], [10.0, 6.0], [5.75, 6.0]],
+/// ];
+/// let mesh = Mesh::from_polygons(&outer, &holes).unwrap();
+/// let sizes = AgentSizes::from_mesh(&mesh, &[0.5, 1.0]).unwrap();
+/// assert!(sizes.path(0.3, [5.0, 2.0], [5.0, 8.0]).is_some());
+/// assert!(sizes.path(0.8, [5.0, 2.0], [5.0, 8.0]).is_none());
+/// // no mesh for agents this large
+/// assert!(sizes.mesh(2.0).is_none());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AgentSizes {
+    /// Meshes by increasing radius.
+    classes: Vec<(f32, Mesh)>,
+}
+
+impl AgentSizes {
+    /// Create a container without meshes.
+    pub fn new() -> Self {
+        AgentSizes::default()
+    }
+
+    /// Build the meshes for agents of each radius from a mesh for agents without a size, with
+    /// [`Mesh::inflate_obstacles`].
+    pub fn from_mesh(mesh: &Mesh, radii: &[f32]) -> Result<Self, MeshBuildError> {
+        let mut sizes = AgentSizes::new();
+        for radius in radii {
+            sizes.insert(*radius, mesh.inflate_obstacles(*radius)?);
+        }
+        Ok(sizes)
+    }
+
+    /// Add the mesh for agents of a radius, or replace it, returning the mesh it had.
+    pub fn insert(&mut self, radius: f32, mesh: Mesh) -> Option<Mesh> {
+        match self
+            .classes
+            .binary_search_by(|(other, _)| other.total_cmp(&radius))
+        {
+            Ok(index) => Some(std::mem::replace(&mut self.classes[index].1, mesh)),
+            Err(index) => {
+                self.classes.insert(index, (radius, mesh));
+                None
+            }
+        }
+    }
+
+    /// Remove the mesh for agents of a radius, returning it.
+    pub fn remove(&mut self, radius: f32) -> Option<Mesh> {
+        let index = self
+            .classes
+            .binary_search_by(|(other, _)| other.total_cmp(&radius))
+            .ok()?;
+        Some(self.classes.remove(index).1)
+    }
+
+    /// Iterate over the radii and their mesh, by increasing radius.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (f32, &Mesh)> + '_ {
+        self.classes.iter().map(|(radius, mesh)| (*radius, mesh))
+    }
+
+    /// The mesh to use for agents of a radius: the one with the smallest radius that is not
+    /// smaller, or `None` if the agents are larger than all of them.
+    pub fn mesh(&self, radius: f32) -> Option<&Mesh> {
+        let index = self.classes.partition_point(|(other, _)| *other < radius);
+        self.classes.get(index).map(|(_, mesh)| mesh)
+    }
+
+    /// Compute the shortest path from `from` to `to` for an agent of a radius, on the mesh given
+    /// by [`AgentSizes::mesh`].
+    ///
+    /// Returns `None` if there is no mesh for agents of this radius, or like [`Mesh::path`].
+    pub fn path(&self, radius: f32, from: [f32; 2], to: [f32; 2]) -> Option<Path> {
+        self.mesh(radius)?.path(from, to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AgentSizes;
+    use crate::maps::grid_mesh;
+
+    #[test]
+    fn classes() {
+        let mut sizes = AgentSizes::new();
+        assert!(sizes.mesh(0.0).is_none());
+        assert!(sizes.insert(1.0, grid_mesh(1, 1, |_, _| true)).is_none());
+        assert!(sizes.insert(0.0, grid_mesh(2, 2, |_, _| true)).is_none());
+        assert!(sizes.insert(0.5, grid_mesh(3, 3, |_, _| true)).is_none());
+        assert_eq!(
+            sizes.iter().map(|(radius, _)| radius).collect::<Vec<_>>(),
+            vec![0.0, 0.5, 1.0]
+        );
+        assert_eq!(sizes.mesh(0.0).unwrap().polygons.len(), 4);
+        assert_eq!(sizes.mesh(0.2).unwrap().polygons.len(), 9);
+        assert_eq!(sizes.mesh(0.5).unwrap().polygons.len(), 9);
+        assert_eq!(sizes.mesh(0.7).unwrap().polygons.len(), 1);
+        assert!(sizes.mesh(1.5).is_none());
+
+        let previous = sizes.insert(0.5, grid_mesh(1, 2, |_, _| true)).unwrap();
+        assert_eq!(previous.polygons.len(), 9);
+        assert_eq!(sizes.remove(0.5).unwrap().polygons.len(), 2);
+        assert!(sizes.remove(0.5).is_none());
+        assert_eq!(sizes.mesh(0.2).unwrap().polygons.len(), 1);
+    }
+}
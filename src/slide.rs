@@ -0,0 +1,174 @@
+use crate::{Aabb, Mesh, PolygonId};
+
+/// Number of walls a movement can slide along before stopping.
+const MAX_SLIDES: usize = 4;
+
+/// Distance kept from the walls a movement stops on, so that it stays in the mesh.
+const WALL_OFFSET: f32 = 1.0e-4;
+
+impl Mesh {
+    /// Move from `from` by `displacement`, stopping at the walls and sliding along them, and
+    /// return the position reached with its polygon, or `None` if `from` is not in the mesh.
+    ///
+    /// Walls are the edges that can't be crossed in the direction of the movement: boundary
+    /// edges, closed edges, and one-way edges the wrong way. The part of the displacement left
+    /// when a wall is hit continues along it, which is what a character moved every frame by the
+    /// input of the player expects.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// // going diagonally into the right wall slides up along it
+    /// let (position, _) = mesh.move_along_surface([8.0, 5.0], [4.0, 2.0]).unwrap();
+    /// assert!((position[0] - 10.0).abs() < 1.0e-3);
+    /// assert!((position[1] - 7.0).abs() < 1.0e-3);
+    /// ```
+    pub fn move_along_surface(
+        &self,
+        from: [f32; 2],
+        displacement: [f32; 2],
+    ) -> Option<([f32; 2], PolygonId)> {
+        let mut polygon = self.point_in_polygon(from)?;
+        let mut position = from;
+        let mut remaining = displacement;
+        for _ in 0..=MAX_SLIDES {
+            if remaining == [0.0, 0.0] {
+                break;
+            }
+            let target = [position[0] + remaining[0], position[1] + remaining[1]];
+            let Some((t, wall)) = self.first_wall(position, target) else {
+                if let Some(reached) = self.point_in_polygon(target) {
+                    position = target;
+                    polygon = reached;
+                }
+                break;
+            };
+            // stop just before the wall
+            let length = remaining[0].hypot(remaining[1]);
+            let t = (t - WALL_OFFSET / length).max(0.0);
+            let stop = [
+                position[0] + remaining[0] * t,
+                position[1] + remaining[1] * t,
+            ];
+            if let Some(reached) = self.point_in_polygon(stop) {
+                position = stop;
+                polygon = reached;
+            }
+            // the rest of the movement goes along the wall
+            let left = [remaining[0] * (1.0 - t), remaining[1] * (1.0 - t)];
+            let along = [wall[1][0] - wall[0][0], wall[1][1] - wall[0][1]];
+            let along_length2 = along[0] * along[0] + along[1] * along[1];
+            let dot = (left[0] * along[0] + left[1] * along[1]) / along_length2;
+            remaining = [along[0] * dot, along[1] * dot];
+        }
+        Some((position, polygon))
+    }
+
+    /// First wall crossed going straight from `from` to `to`, with where along the way.
+    fn first_wall(&self, from: [f32; 2], to: [f32; 2]) -> Option<(f32, [[f32; 2]; 2])> {
+        let cross = |a: [f32; 2], b: [f32; 2], p: [f32; 2]| {
+            (b[0] - a[0]) * (p[1] - a[1]) - (b[1] - a[1]) * (p[0] - a[0])
+        };
+        let segment_aabb = Aabb::from_points([from, to]);
+        let mut first: Option<(f32, [[f32; 2]; 2])> = None;
+        for (index, polygon) in self.polygons.iter().enumerate() {
+            if !self.polygon_aabbs[index].intersects(&segment_aabb) {
+                continue;
+            }
+            let n = polygon.vertices.len() as f32;
+            let inside = polygon
+                .vertices
+                .iter()
+                .map(|vertex| self.vertices[*vertex].p())
+                .fold([0.0, 0.0], |sum, p| [sum[0] + p[0] / n, sum[1] + p[1] / n]);
+            for edge in polygon.edges_index() {
+                // walls can't be crossed leaving this polygon
+                if !self.is_boundary_edge(edge) && self.can_cross(edge) {
+                    continue;
+                }
+                let [a, b] = [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()];
+                // the movement goes from the side of the polygon to the other one
+                let side = cross(a, b, inside);
+                let (start, end) = (cross(a, b, from), cross(a, b, to));
+                if start * side < 0.0 || end * side >= 0.0 {
+                    continue;
+                }
+                let t = start / (start - end);
+                let hit = [
+                    from[0] + (to[0] - from[0]) * t,
+                    from[1] + (to[1] - from[1]) * t,
+                ];
+                let edge_length2 = (b[0] - a[0]).powi(2) + (b[1] - a[1]).powi(2);
+                let u = ((hit[0] - a[0]) * (b[0] - a[0]) + (hit[1] - a[1]) * (b[1] - a[1]))
+                    / edge_length2;
+                if !(-1.0e-5..=1.0 + 1.0e-5).contains(&u) {
+                    continue;
+                }
+                if first.map(|(first, _)| t < first).unwrap_or(true) {
+                    first = Some((t, [a, b]));
+                }
+            }
+        }
+        first
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::distance_between,
+        maps::{grid_mesh, random_obstacles},
+    };
+
+    #[test]
+    fn slide_along_walls() {
+        let mesh = grid_mesh(10, 10, |x, y| (x, y) != (5, 5));
+        // nothing in the way
+        assert_eq!(
+            mesh.move_along_surface([1.0, 1.0], [2.0, 3.0]).unwrap().0,
+            [3.0, 4.0]
+        );
+        // straight into a wall: stops there
+        let (position, polygon) = mesh.move_along_surface([8.0, 1.0], [5.0, 0.0]).unwrap();
+        assert!(distance_between(position, [10.0, 1.0]) < 1.0e-3);
+        assert_eq!(mesh.point_in_polygon(position), Some(polygon));
+        // into the pillar, sliding down along it
+        let (position, _) = mesh.move_along_surface([4.0, 5.5], [2.0, -1.0]).unwrap();
+        assert!(distance_between(position, [5.0, 4.5]) < 1.0e-3);
+        // into a corner
+        let (position, _) = mesh.move_along_surface([9.0, 9.0], [3.0, 3.0]).unwrap();
+        assert!(distance_between(position, [10.0, 10.0]) < 1.0e-3);
+
+        assert!(mesh.move_along_surface([-1.0, 0.0], [1.0, 1.0]).is_none());
+
+        // a closed door
+        let mut mesh = grid_mesh(3, 1, |_, _| true);
+        let door = mesh
+            .edge_between(
+                mesh.point_in_polygon([0.5, 0.5]).unwrap(),
+                mesh.point_in_polygon([1.5, 0.5]).unwrap(),
+            )
+            .unwrap();
+        mesh.set_edge_open(door, false);
+        let (position, _) = mesh.move_along_surface([0.5, 0.5], [2.0, 0.0]).unwrap();
+        assert!(distance_between(position, [1.0, 0.5]) < 1.0e-3);
+        mesh.set_edge_open(door, true);
+        let (position, _) = mesh.move_along_surface([0.5, 0.5], [2.0, 0.0]).unwrap();
+        assert_eq!(position, [2.5, 0.5]);
+    }
+
+    #[test]
+    fn stays_on_the_mesh() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let mut position = [1.5, 1.5];
+        assert!(mesh.point_in_mesh(position));
+        for i in 0..200 {
+            let angle = i as f32 * 0.7;
+            let displacement = [angle.cos() * 3.0, angle.sin() * 3.0];
+            let (next, polygon) = mesh.move_along_surface(position, displacement).unwrap();
+            assert_eq!(mesh.point_in_polygon(next), Some(polygon));
+            assert!(distance_between(position, next) <= 3.0 + 1.0e-3);
+            position = next;
+        }
+    }
+}
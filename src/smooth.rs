@@ -0,0 +1,159 @@
+use crate::{
+    helpers::{distance_between, project_on_segment},
+    Mesh, Path,
+};
+
+/// Number of points of the curve for each segment of the path.
+const CURVE_SAMPLES: usize = 8;
+
+/// Number of times the curve of a segment is flattened towards it before using the straight
+/// segment.
+const MAX_FLATTENING: usize = 4;
+
+impl Mesh {
+    /// Smooth a path starting at `from` into a Catmull-Rom curve through its points, sampled
+    /// as a polyline.
+    ///
+    /// Characters following it turn progressively instead of at once on each corner. The curve
+    /// between two points of the path stays on the mesh and at most `max_deviation` away from
+    /// the straight segment between them: where it wouldn't, it's flattened towards the segment
+    /// until it does. Like the path, the result doesn't include `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall to go around
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y > 5);
+    /// let path = mesh.path([2.0, 2.0], [8.0, 2.0]).unwrap();
+    /// let smooth = mesh.smooth_path([2.0, 2.0], &path, 0.5);
+    /// assert!(smooth.path.len() > path.path.len());
+    /// assert_eq!(smooth.path.last(), Some(&[8.0, 2.0]));
+    /// assert!(smooth.path.iter().all(|point| mesh.point_in_mesh(*point)));
+    /// ```
+    pub fn smooth_path(&self, from: [f32; 2], path: &Path, max_deviation: f32) -> Path {
+        let points: Vec<[f32; 2]> = std::iter::once(from)
+            .chain(path.path.iter().copied())
+            .collect();
+        let mut smooth = vec![];
+        let mut len = 0.0;
+        for i in 0..points.len().saturating_sub(1) {
+            let [p0, p1, p2, p3] = [
+                points[i.saturating_sub(1)],
+                points[i],
+                points[i + 1],
+                points[(i + 2).min(points.len() - 1)],
+            ];
+            let mut weight = 1.0;
+            let curve = loop {
+                let curve: Vec<[f32; 2]> = (1..=CURVE_SAMPLES)
+                    .map(|k| {
+                        let t = k as f32 / CURVE_SAMPLES as f32;
+                        if k == CURVE_SAMPLES {
+                            return p2;
+                        }
+                        lerp(lerp(p1, p2, t), catmull_rom([p0, p1, p2, p3], t), weight)
+                    })
+                    .collect();
+                if weight == 0.0 {
+                    break curve;
+                }
+                let mut last = p1;
+                let valid = curve.iter().all(|point| {
+                    let closest = project_on_segment(*point, [p1, p2]);
+                    let valid = distance_between(*point, closest) <= max_deviation
+                        && self.is_segment_on_mesh(last, *point);
+                    last = *point;
+                    valid
+                });
+                if valid {
+                    break curve;
+                }
+                weight = if weight > 0.5_f32.powi(MAX_FLATTENING as i32) {
+                    weight / 2.0
+                } else {
+                    0.0
+                };
+            };
+            let mut last = p1;
+            for point in curve {
+                len += distance_between(last, point);
+                last = point;
+                smooth.push(point);
+            }
+        }
+        Path { len, path: smooth }
+    }
+}
+
+/// Point at `t` along the segment from `a` to `b`.
+fn lerp(a: [f32; 2], b: [f32; 2], t: f32) -> [f32; 2] {
+    [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+}
+
+/// Point at `t` between `points[1]` and `points[2]` on the uniform Catmull-Rom spline through the
+/// points.
+fn catmull_rom(points: [[f32; 2]; 4], t: f32) -> [f32; 2] {
+    let [p0, p1, p2, p3] = points;
+    let (t2, t3) = (t * t, t * t * t);
+    let coordinate = |i: usize| {
+        0.5 * (2.0 * p1[i]
+            + (p2[i] - p0[i]) * t
+            + (2.0 * p0[i] - 5.0 * p1[i] + 4.0 * p2[i] - p3[i]) * t2
+            + (3.0 * p1[i] - p0[i] - 3.0 * p2[i] + p3[i]) * t3)
+    };
+    [coordinate(0), coordinate(1)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        helpers::{distance_between, project_on_segment},
+        maps::maze,
+        Path,
+    };
+
+    use super::{catmull_rom, CURVE_SAMPLES};
+
+    #[test]
+    fn through_the_points() {
+        let points = [[0.0, 0.0], [1.0, 0.0], [2.0, 1.0], [2.0, 3.0]];
+        assert_eq!(catmull_rom(points, 0.0), [1.0, 0.0]);
+        assert_eq!(catmull_rom(points, 1.0), [2.0, 1.0]);
+    }
+
+    #[test]
+    fn smooth_paths() {
+        let mesh = maze(6, 6, 3);
+        let from = [1.5, 1.5];
+        let path = mesh.path(from, [11.5, 11.5]).unwrap();
+        for max_deviation in [0.0, 0.2, 1.0] {
+            let smooth = mesh.smooth_path(from, &path, max_deviation);
+            assert_eq!(smooth.path.len(), path.path.len() * CURVE_SAMPLES);
+            assert_eq!(smooth.path.last(), path.path.last());
+            assert!(smooth.len >= path.len - 1.0e-3);
+
+            let mut last = from;
+            let mut segment = 0;
+            for (i, point) in smooth.path.iter().enumerate() {
+                let start = if segment == 0 {
+                    from
+                } else {
+                    path.path[segment - 1]
+                };
+                let end = path.path[segment];
+                let closest = project_on_segment(*point, [start, end]);
+                assert!(distance_between(*point, closest) <= max_deviation + 1.0e-5);
+                assert!(mesh.is_segment_on_mesh(last, *point));
+                last = *point;
+                if i % CURVE_SAMPLES == CURVE_SAMPLES - 1 {
+                    segment += 1;
+                }
+            }
+        }
+
+        let empty = Path {
+            len: 0.0,
+            path: vec![],
+        };
+        assert_eq!(mesh.smooth_path(from, &empty, 1.0), empty);
+    }
+}
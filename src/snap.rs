@@ -0,0 +1,180 @@
+use std::f32::consts::TAU;
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Serialize};
+
+use crate::{Aabb, Mesh, PolygonId};
+
+/// Points probed around a point that is not in any polygon, to locate it anyway when it's just
+/// outside of the mesh.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum SnapPattern {
+    /// The 8 points of a square around the point, at the radius along each axis.
+    Square,
+    /// `points` points evenly spread on each of `rings` circles, up to the radius.
+    Circles { points: usize, rings: usize },
+}
+
+/// How far and where points outside of the mesh are looked for when locating them, see
+/// [`Mesh::set_point_snap`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PointSnap {
+    /// Distance from the point to the points probed around it, 0 to not probe any.
+    pub radius: f32,
+    /// Points probed around the point, from the closest.
+    pub pattern: SnapPattern,
+}
+
+impl PointSnap {
+    /// Snapping of a mesh of this size, if not set: a ten thousandth of its largest side, probed on
+    /// a square.
+    ///
+    /// The radius is never smaller than the rounding errors of the coordinates of the mesh, for a
+    /// small mesh far from the origin.
+    pub fn for_bounds(aabb: Aabb) -> Self {
+        if aabb.is_empty() {
+            return PointSnap {
+                radius: 0.0,
+                pattern: SnapPattern::Square,
+            };
+        }
+        let extent = (aabb.max[0] - aabb.min[0]).max(aabb.max[1] - aabb.min[1]);
+        let magnitude = aabb
+            .min
+            .iter()
+            .chain(&aabb.max)
+            .fold(0.0_f32, |magnitude, c| magnitude.max(c.abs()));
+        PointSnap {
+            radius: (extent * 1.0e-4).max(magnitude * f32::EPSILON * 8.0),
+            pattern: SnapPattern::Square,
+        }
+    }
+
+    /// Offsets of the points probed, after the point itself.
+    fn offsets(&self) -> impl Iterator<Item = [f32; 2]> + '_ {
+        let (points, rings) = match self.pattern {
+            SnapPattern::Square => (8, 1),
+            SnapPattern::Circles { points, rings } => (points, rings),
+        };
+        let rings = if self.radius > 0.0 { rings } else { 0 };
+        (1..=rings).flat_map(move |ring| {
+            let radius = self.radius * ring as f32 / rings as f32;
+            (0..points).map(move |i| match self.pattern {
+                SnapPattern::Square => [
+                    [radius, 0.0],
+                    [radius, radius],
+                    [0.0, radius],
+                    [-radius, radius],
+                    [-radius, 0.0],
+                    [-radius, -radius],
+                    [0.0, -radius],
+                    [radius, -radius],
+                ][i],
+                SnapPattern::Circles { .. } => {
+                    let angle = TAU * i as f32 / points as f32;
+                    [radius * angle.cos(), radius * angle.sin()]
+                }
+            })
+        })
+    }
+}
+
+impl Mesh {
+    /// Set how far and where points outside of the mesh are looked for when locating them, or
+    /// go back to the default for the size of the mesh with `None`.
+    ///
+    /// A point that is not in any polygon, for example because of rounding errors, is located in
+    /// the first polygon containing one of the points probed around it. This applies to all the
+    /// queries on the mesh.
+    ///
+    /// ```
+    /// # use polyanya::{Mesh, PointSnap, SnapPattern};
+    /// // agents can stand up to 1 outside of the mesh
+    /// let mut mesh = Mesh::from_grid(10, 10, |_, _| true);
+    /// assert!(!mesh.point_in_mesh([10.5, 5.0]));
+    /// mesh.set_point_snap(Some(PointSnap {
+    ///     radius: 1.0,
+    ///     pattern: SnapPattern::Circles { points: 12, rings: 2 },
+    /// }));
+    /// assert!(mesh.point_in_mesh([10.5, 5.0]));
+    /// ```
+    pub fn set_point_snap(&mut self, snap: Option<PointSnap>) {
+        self.point_snap = snap.filter(|snap| snap.radius >= 0.0);
+    }
+
+    /// How far and where points outside of the mesh are looked for when locating them.
+    pub fn point_snap(&self) -> PointSnap {
+        self.point_snap
+            .unwrap_or_else(|| PointSnap::for_bounds(self.aabb))
+    }
+
+    /// Find the polygon containing a point, probing around it with `snap` instead of the
+    /// snapping of the mesh if it's not in any polygon.
+    pub fn locate_with_snap(&self, point: [f32; 2], snap: PointSnap) -> Option<PolygonId> {
+        if !self.aabb.inflate(snap.radius).contains(point) {
+            return None;
+        }
+        std::iter::once([0.0, 0.0])
+            .chain(snap.offsets())
+            .find_map(|offset| {
+                self.point_in_polygon_unit([point[0] + offset[0], point[1] + offset[1]])
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PointSnap, SnapPattern};
+    use crate::{maps::grid_mesh, Aabb};
+
+    #[test]
+    fn snapping() {
+        let mut mesh = grid_mesh(100, 50, |_, _| true);
+        assert_eq!(
+            mesh.point_snap(),
+            PointSnap {
+                radius: 0.01,
+                pattern: SnapPattern::Square
+            }
+        );
+        assert!(mesh.point_in_mesh([100.005, 25.0]));
+        assert!(mesh.point_in_mesh([100.007, 50.007]));
+        assert!(!mesh.point_in_mesh([100.015, 25.0]));
+
+        let snap = PointSnap {
+            radius: 0.5,
+            pattern: SnapPattern::Circles {
+                points: 16,
+                rings: 5,
+            },
+        };
+        assert!(mesh.locate_with_snap([100.15, 25.0], snap).is_some());
+        assert!(mesh.locate_with_snap([100.45, 25.0], snap).is_some());
+        assert!(mesh.locate_with_snap([100.55, 25.0], snap).is_none());
+        // only the point itself
+        let exact = PointSnap {
+            radius: 0.0,
+            ..snap
+        };
+        assert!(mesh.locate_with_snap([100.005, 25.0], exact).is_none());
+        assert!(mesh.locate_with_snap([99.95, 25.0], exact).is_some());
+
+        mesh.set_point_snap(Some(snap));
+        assert!(mesh.point_in_mesh([100.45, 25.0]));
+        assert_eq!(mesh.point_snap(), snap);
+        mesh.set_point_snap(None);
+        assert!(!mesh.point_in_mesh([100.45, 25.0]));
+
+        // a mesh in centimeters, and the same mesh in meters
+        let bounds = Aabb::from_points([[0.0, 0.0], [5000.0, 2000.0]]);
+        assert_eq!(PointSnap::for_bounds(bounds).radius, 0.5);
+        let bounds = Aabb::from_points([[0.0, 0.0], [50.0, 20.0]]);
+        assert_eq!(PointSnap::for_bounds(bounds).radius, 0.005);
+        // a small mesh far from the origin
+        let bounds = Aabb::from_points([[1.0e6, 0.0], [1.0e6 + 1.0, 1.0]]);
+        assert!(PointSnap::for_bounds(bounds).radius > 0.5);
+        assert_eq!(PointSnap::for_bounds(Aabb::EMPTY).radius, 0.0);
+    }
+}
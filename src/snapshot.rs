@@ -0,0 +1,204 @@
+use std::{
+    ops::Deref,
+    sync::{Arc, Mutex, PoisonError, RwLock},
+};
+
+use crate::Mesh;
+#[cfg(feature = "parallel")]
+use crate::SpawnedPath;
+
+/// An immutable view of a [`SharedMesh`] at one generation, cheap to clone and to send to other
+/// threads.
+///
+/// It derefs to the [`Mesh`], and stays the same while the shared mesh is edited.
+#[derive(Debug, Clone)]
+pub struct MeshSnapshot {
+    mesh: Arc<Mesh>,
+    generation: u64,
+}
+
+impl MeshSnapshot {
+    /// Generation of the shared mesh this snapshot was taken from, incremented by each edit.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], on the rayon thread
+    /// pool, with a future for its result.
+    ///
+    /// Unlike [`Mesh::path_async`], the thread polling the future doesn't do the search, which
+    /// suits executors running on few threads, like the main loop of a game.
+    ///
+    /// ```
+    /// # use std::{future::Future, pin::pin, task::{Context, Poll, Waker}};
+    /// # use polyanya::{Mesh, SharedMesh};
+    /// let shared = SharedMesh::new(Mesh::from_file("meshes/arena.mesh").unwrap());
+    /// let task = shared.snapshot().spawn_path([1.0, 3.0], [4.0, 2.0]);
+    ///
+    /// // usually done by the executor, when the task is woken
+    /// let mut task = pin!(task);
+    /// let path = loop {
+    ///     if let Poll::Ready(path) = task.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+    ///         break path;
+    ///     }
+    /// };
+    /// assert_eq!(path, shared.snapshot().path([1.0, 3.0], [4.0, 2.0]));
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn spawn_path(&self, from: [f32; 2], to: [f32; 2]) -> SpawnedPath {
+        SpawnedPath::spawn(self.clone(), from, to)
+    }
+}
+
+impl Deref for MeshSnapshot {
+    type Target = Mesh;
+
+    fn deref(&self) -> &Mesh {
+        &self.mesh
+    }
+}
+
+/// A mesh shared between threads running queries and threads editing it.
+///
+/// Queries run on [`MeshSnapshot`]s, which are never changed. Edits are applied to a copy of the
+/// mesh that replaces it as a new generation when done, so queries in progress keep a consistent
+/// view and are never blocked by an edit. Edits are applied one at a time.
+///
+/// ```
+/// # use polyanya::{Mesh, SharedMesh};
+/// let shared = SharedMesh::new(Mesh::from_grid(10, 10, |_, _| true));
+/// let before = shared.snapshot();
+/// shared.edit(|mesh| {
+///     mesh.carve(&[[-1.0, 4.0], [11.0, 4.0], [11.0, 5.0], [-1.0, 5.0]])
+///         .unwrap()
+/// });
+/// let after = shared.snapshot();
+/// assert_eq!(after.generation(), before.generation() + 1);
+/// assert!(before.path([5.0, 1.0], [5.0, 9.0]).is_some());
+/// assert!(after.path([5.0, 1.0], [5.0, 9.0]).is_none());
+/// ```
+#[derive(Debug)]
+pub struct SharedMesh {
+    current: RwLock<MeshSnapshot>,
+    /// Held while editing, so that edits don't overwrite each other.
+    editing: Mutex<()>,
+}
+
+impl SharedMesh {
+    /// Share a mesh, as generation `0`.
+    pub fn new(mesh: Mesh) -> Self {
+        SharedMesh {
+            current: RwLock::new(MeshSnapshot {
+                mesh: Arc::new(mesh),
+                generation: 0,
+            }),
+            editing: Mutex::new(()),
+        }
+    }
+
+    /// Take a snapshot of the latest generation of the mesh.
+    pub fn snapshot(&self) -> MeshSnapshot {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// The latest generation of the mesh.
+    pub fn generation(&self) -> u64 {
+        self.current
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generation
+    }
+
+    /// Edit a copy of the latest generation of the mesh, then make it the new generation.
+    ///
+    /// Snapshots taken before keep the previous generation. If `edit` panics, the mesh is left
+    /// unchanged.
+    pub fn edit<R>(&self, edit: impl FnOnce(&mut Mesh) -> R) -> R {
+        let _editing = self.editing.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut mesh = Mesh::clone(&self.snapshot());
+        let result = edit(&mut mesh);
+        self.publish(mesh);
+        result
+    }
+
+    /// Replace the mesh with another one, as a new generation.
+    pub fn replace(&self, mesh: Mesh) {
+        let _editing = self.editing.lock().unwrap_or_else(PoisonError::into_inner);
+        self.publish(mesh);
+    }
+
+    fn publish(&self, mesh: Mesh) {
+        let mut current = self.current.write().unwrap_or_else(PoisonError::into_inner);
+        *current = MeshSnapshot {
+            mesh: Arc::new(mesh),
+            generation: current.generation + 1,
+        };
+    }
+}
+
+impl From<Mesh> for SharedMesh {
+    fn from(mesh: Mesh) -> Self {
+        SharedMesh::new(mesh)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, thread};
+
+    use super::SharedMesh;
+    use crate::maps::grid_mesh;
+
+    #[test]
+    fn concurrent_edits() {
+        let shared = Arc::new(SharedMesh::new(grid_mesh(10, 1, |_, _| true)));
+        let readers: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for _ in 0..50 {
+                        // each snapshot is consistent: the mesh is cut in one place at most
+                        let snapshot = shared.snapshot();
+                        let removed = 10 - snapshot.polygons.len();
+                        assert!(removed <= 1);
+                        let path = snapshot.path([0.5, 0.5], [9.5, 0.5]);
+                        assert_eq!(path.is_some(), removed == 0);
+                        assert!(snapshot.validate().is_empty());
+                    }
+                })
+            })
+            .collect();
+        for _ in 0..20 {
+            let removed = shared.edit(|mesh| {
+                let middle = mesh.point_in_polygon([4.5, 0.5]).unwrap();
+                mesh.remove_polygon(middle).unwrap()
+            });
+            shared.edit(|mesh| mesh.add_polygon(removed.vertices.clone()).unwrap());
+        }
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(shared.generation(), 40);
+
+        // a failed edit leaves the mesh unchanged
+        let before = shared.snapshot();
+        let failed = thread::spawn({
+            let shared = shared.clone();
+            move || {
+                shared.edit(|mesh| -> () {
+                    mesh.polygons.clear();
+                    panic!("edit failed")
+                })
+            }
+        })
+        .join();
+        assert!(failed.is_err());
+        assert_eq!(shared.generation(), 40);
+        assert_eq!(shared.snapshot().polygons.len(), before.polygons.len());
+        shared.replace(grid_mesh(1, 1, |_, _| true));
+        assert_eq!(shared.snapshot().polygons.len(), 1);
+    }
+}
@@ -0,0 +1,258 @@
+//! Stitching of meshes sharing borders into a single mesh.
+
+use hashbrown::HashMap;
+
+use crate::{helpers::distance_between, Aabb, Mesh, MeshBuilder};
+
+/// Vertices of the stitched mesh, with the meshes they come from, found by their position.
+struct Welder {
+    tolerance: f32,
+    points: Vec<[f32; 2]>,
+    sources: Vec<Vec<usize>>,
+    cells: HashMap<(i64, i64), Vec<usize>>,
+}
+
+impl Welder {
+    fn cell(&self, point: [f32; 2]) -> (i64, i64) {
+        (
+            (point[0] / self.tolerance).floor() as i64,
+            (point[1] / self.tolerance).floor() as i64,
+        )
+    }
+
+    /// Identifier of a vertex of mesh `source`, shared with a vertex of another mesh at the same
+    /// position. Vertices of a mesh are never welded together.
+    fn weld(&mut self, point: [f32; 2], source: usize) -> usize {
+        let cell = self.cell(point);
+        let existing = (cell.0 - 1..=cell.0 + 1)
+            .flat_map(|x| (cell.1 - 1..=cell.1 + 1).map(move |y| (x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .find(|id| {
+                !self.sources[**id].contains(&source)
+                    && distance_between(point, self.points[**id]) <= self.tolerance
+            })
+            .copied();
+        match existing {
+            Some(id) => {
+                self.sources[id].push(source);
+                id
+            }
+            None => {
+                let id = self.points.len();
+                self.points.push(point);
+                self.sources.push(vec![source]);
+                self.cells.entry(cell).or_default().push(id);
+                id
+            }
+        }
+    }
+}
+
+/// Stitch meshes into one: vertices of different meshes closer than `tolerance` are merged, and
+/// vertices of a mesh lying on a boundary edge of another mesh are inserted in that edge, so that
+/// polygons on each side of a shared border become neighbours.
+pub(crate) fn stitch<'m>(meshes: impl IntoIterator<Item = &'m Mesh>, tolerance: f32) -> Mesh {
+    let mut welder = Welder {
+        tolerance: tolerance.max(f32::EPSILON),
+        points: vec![],
+        sources: vec![],
+        cells: HashMap::new(),
+    };
+    // vertices of each polygon, and the mesh it comes from
+    let mut polygons: Vec<(Vec<usize>, usize)> = vec![];
+    for (source, mesh) in meshes.into_iter().enumerate() {
+        let ids: Vec<usize> = mesh
+            .vertices
+            .iter()
+            .map(|vertex| welder.weld(vertex.p(), source))
+            .collect();
+        polygons.extend(
+            mesh.polygons
+                .iter()
+                .map(|polygon| (polygon.vertices.iter().map(|v| ids[*v]).collect(), source)),
+        );
+    }
+    let points = welder.points;
+    let sources = welder.sources;
+
+    // edges with a polygon on one side only, and the vertices that could be inserted in them
+    let mut edges: HashMap<(usize, usize), usize> = HashMap::new();
+    for (polygon, (vertices, _)) in polygons.iter().enumerate() {
+        for i in 0..vertices.len() {
+            edges.insert((vertices[i], vertices[(i + 1) % vertices.len()]), polygon);
+        }
+    }
+    let mut boundary = vec![false; points.len()];
+    for (from, to) in edges.keys() {
+        if !edges.contains_key(&(*to, *from)) {
+            boundary[*from] = true;
+            boundary[*to] = true;
+        }
+    }
+    let lengths: Vec<f32> = edges
+        .keys()
+        .filter(|(from, to)| !edges.contains_key(&(*to, *from)))
+        .map(|(from, to)| distance_between(points[*from], points[*to]))
+        .collect();
+    let cell_size = (lengths.iter().sum::<f32>() / lengths.len().max(1) as f32).max(tolerance);
+    let cell = |point: [f32; 2]| {
+        (
+            (point[0] / cell_size).floor() as i64,
+            (point[1] / cell_size).floor() as i64,
+        )
+    };
+    let mut grid: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    for (vertex, point) in points.iter().enumerate() {
+        if boundary[vertex] {
+            grid.entry(cell(*point)).or_default().push(vertex);
+        }
+    }
+
+    let mut stitched = Vec::with_capacity(polygons.len());
+    for (vertices, source) in &polygons {
+        let mut result = Vec::with_capacity(vertices.len());
+        for i in 0..vertices.len() {
+            let (from, to) = (vertices[i], vertices[(i + 1) % vertices.len()]);
+            result.push(from);
+            if edges.contains_key(&(to, from)) {
+                continue;
+            }
+            let (a, b) = (points[from], points[to]);
+            let length = distance_between(a, b);
+            if length <= tolerance {
+                continue;
+            }
+            let (min, max) = (
+                cell([a[0].min(b[0]), a[1].min(b[1])]),
+                cell([a[0].max(b[0]), a[1].max(b[1])]),
+            );
+            let mut inserted: Vec<(f32, usize)> = (min.0..=max.0)
+                .flat_map(|x| (min.1..=max.1).map(move |y| (x, y)))
+                .filter_map(|cell| grid.get(&cell))
+                .flatten()
+                .filter(|vertex| !sources[**vertex].contains(source))
+                .filter_map(|vertex| {
+                    let p = points[*vertex];
+                    let along =
+                        ((p[0] - a[0]) * (b[0] - a[0]) + (p[1] - a[1]) * (b[1] - a[1])) / length;
+                    let across =
+                        ((p[1] - a[1]) * (b[0] - a[0]) - (p[0] - a[0]) * (b[1] - a[1])) / length;
+                    (along > tolerance && along < length - tolerance && across.abs() <= tolerance)
+                        .then_some((along, *vertex))
+                })
+                .collect();
+            inserted.sort_by(|a, b| a.0.total_cmp(&b.0));
+            result.extend(inserted.into_iter().map(|(_, vertex)| vertex));
+        }
+        // merged vertices can make a polygon degenerate
+        result.dedup();
+        if result.len() > 1 && result.first() == result.last() {
+            result.pop();
+        }
+        if result.len() >= 3 && (1..result.len()).all(|k| !result[..k].contains(&result[k])) {
+            stitched.push(result);
+        }
+    }
+    MeshBuilder::from_parts(points, stitched)
+        .build()
+        .expect("building from valid parts can't fail")
+}
+
+impl Mesh {
+    /// Merge meshes authored separately into one mesh, connecting them where they share borders.
+    ///
+    /// Vertices of different meshes at the same position are welded, and vertices of a mesh in
+    /// the middle of a boundary edge of another mesh are inserted in that edge, so that paths can
+    /// cross from one mesh to another. Positions are compared with a tolerance of a hundred
+    /// thousandth of the size of the merged mesh. Polygons keep the order of the meshes.
+    ///
+    /// ```
+    /// # use polyanya::{Mesh, MeshBuilder};
+    /// let square = |x: f32| {
+    ///     MeshBuilder::new()
+    ///         .vertex([x, 0.0])
+    ///         .vertex([x + 1.0, 0.0])
+    ///         .vertex([x + 1.0, 1.0])
+    ///         .vertex([x, 1.0])
+    ///         .polygon([0, 1, 2, 3])
+    ///         .build()
+    ///         .unwrap()
+    /// };
+    /// let mesh = Mesh::merge(&[square(0.0), square(1.0)]);
+    /// assert_eq!(mesh.vertices().len(), 6);
+    /// assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+    /// ```
+    pub fn merge(meshes: &[Mesh]) -> Mesh {
+        let bounds = meshes
+            .iter()
+            .fold(Aabb::EMPTY, |aabb, mesh| aabb.union(&mesh.aabb));
+        let size = (bounds.max[0] - bounds.min[0]).max(bounds.max[1] - bounds.min[1]);
+        stitch(meshes, size.max(0.0) * 1.0e-5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stitch;
+    use crate::{Mesh, MeshBuilder};
+
+    #[test]
+    fn t_junctions() {
+        // one square on the left, two on the right
+        let left = MeshBuilder::new()
+            .vertex([0.0, 0.0])
+            .vertex([1.0, 0.0])
+            .vertex([1.0, 2.0])
+            .vertex([0.0, 2.0])
+            .polygon([0, 1, 2, 3])
+            .build()
+            .unwrap();
+        let right = MeshBuilder::new()
+            .vertex([1.0, 0.0])
+            .vertex([2.0, 0.0])
+            .vertex([2.0, 1.0])
+            .vertex([1.0, 1.0])
+            .vertex([2.0, 2.0])
+            .vertex([1.0, 2.0])
+            .polygon([0, 1, 2, 3])
+            .polygon([3, 2, 4, 5])
+            .build()
+            .unwrap();
+        assert!(left.path([0.5, 0.5], [1.5, 1.5]).is_none());
+
+        let mesh = stitch([&left, &right], 1.0e-4);
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.polygons.len(), 3);
+        assert_eq!(mesh.polygons[0].vertices.len(), 5);
+        assert!(mesh.validate().is_empty());
+        let path = mesh.path([0.5, 0.5], [1.5, 1.5]).unwrap();
+        assert!((path.len - 2.0f32.sqrt()).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn merge() {
+        // the right square is a little off, and a third mesh doesn't touch the others
+        let square = |x: f32, y: f32| {
+            MeshBuilder::new()
+                .vertex([x, y])
+                .vertex([x + 1.0, y])
+                .vertex([x + 1.0, y + 1.0])
+                .vertex([x, y + 1.0])
+                .polygon([0, 1, 2, 3])
+                .build()
+                .unwrap()
+        };
+        let mesh = Mesh::merge(&[
+            square(0.0, 0.0),
+            square(1.0 + 1.0e-6, 0.0),
+            square(5.0, 5.0),
+        ]);
+        assert_eq!(mesh.vertices.len(), 10);
+        assert_eq!(mesh.polygons.len(), 3);
+        assert!(mesh.validate().is_empty());
+        assert!(mesh.path([0.5, 0.5], [1.5, 0.5]).is_some());
+        assert!(mesh.path([0.5, 0.5], [5.5, 5.5]).is_none());
+        assert!(Mesh::merge(&[]).polygons.is_empty());
+    }
+}
@@ -0,0 +1,276 @@
+//! Rendering of meshes, paths and searches as SVG, to inspect failing queries.
+//!
+//! ```
+//! # use polyanya::{svg::Svg, Mesh};
+//! # let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+//! let (from, to) = ([10.0, 10.0], [30.0, 20.0]);
+//! let (path, intervals) = mesh.explore(from, to);
+//! let svg = Svg::new(&mesh)
+//!     .with_intervals(&intervals)
+//!     .with_path(from, &path.unwrap())
+//!     .to_string();
+//! assert!(svg.starts_with("<svg"));
+//! ```
+
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+};
+
+use crate::{Mesh, Path, SearchOptions};
+
+/// An interval expanded during a search: the segment from `left` to `right`, seen from `root`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchInterval {
+    /// Last turning point of the paths going through the interval.
+    pub root: [f32; 2],
+    /// Left end of the interval, seen from the root.
+    pub left: [f32; 2],
+    /// Right end of the interval, seen from the root.
+    pub right: [f32; 2],
+    /// Estimated length of the shortest path going through the interval.
+    pub cost: f32,
+}
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], also returning the
+    /// intervals expanded by the search, in the order they were expanded.
+    pub fn explore(&self, from: [f32; 2], to: [f32; 2]) -> (Option<Path>, Vec<SearchInterval>) {
+        let mut intervals = vec![];
+        let path = self.path_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions::default(),
+            |node| {
+                intervals.push(SearchInterval {
+                    root: node.r,
+                    left: node.i[1],
+                    right: node.i[0],
+                    cost: node.f + node.g,
+                })
+            },
+        );
+        (path, intervals)
+    }
+}
+
+/// An SVG rendering of a mesh, with paths, points and search intervals drawn over it.
+///
+/// The drawing uses the coordinates of the mesh. As `y` goes down in SVG, meshes with `y` going up
+/// are drawn upside down unless [`Svg::flip_y`] is set.
+#[derive(Debug, Clone)]
+pub struct Svg<'m> {
+    mesh: &'m Mesh,
+    paths: Vec<Vec<[f32; 2]>>,
+    points: Vec<[f32; 2]>,
+    intervals: Vec<SearchInterval>,
+    flip_y: bool,
+}
+
+impl<'m> Svg<'m> {
+    /// Render a mesh, with its polygons filled and its boundary edges outlined.
+    pub fn new(mesh: &'m Mesh) -> Self {
+        Svg {
+            mesh,
+            paths: vec![],
+            points: vec![],
+            intervals: vec![],
+            flip_y: false,
+        }
+    }
+
+    /// Draw a path computed from `from`.
+    pub fn with_path(mut self, from: [f32; 2], path: &Path) -> Self {
+        let mut points = Vec::with_capacity(path.path.len() + 1);
+        points.push(from);
+        points.extend(path.path.iter().copied());
+        self.paths.push(points);
+        self
+    }
+
+    /// Draw a point, for example the start of a query that found no path.
+    pub fn with_point(mut self, point: [f32; 2]) -> Self {
+        self.points.push(point);
+        self
+    }
+
+    /// Draw the intervals expanded by a search, as returned by [`Mesh::explore`].
+    pub fn with_intervals(mut self, intervals: &[SearchInterval]) -> Self {
+        self.intervals.extend_from_slice(intervals);
+        self
+    }
+
+    /// Draw with `y` going up.
+    pub fn flip_y(mut self, flip_y: bool) -> Self {
+        self.flip_y = flip_y;
+        self
+    }
+
+    /// Write the SVG document.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+}
+
+/// Coordinates of points in the `points` attribute of a polygon or polyline.
+struct Points<'a>(&'a [[f32; 2]]);
+
+impl Display for Points<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, point) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{},{}", point[0], point[1])?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Svg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let aabb = self.mesh.bounding_box();
+        let size = [aabb.max[0] - aabb.min[0], aabb.max[1] - aabb.min[1]];
+        let margin = size[0].max(size[1]).max(1.0) * 0.02;
+        let radius = margin / 2.0;
+        writeln!(
+            f,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}">"#,
+            aabb.min[0] - margin,
+            aabb.min[1] - margin,
+            size[0] + 2.0 * margin,
+            size[1] + 2.0 * margin
+        )?;
+        if self.flip_y {
+            writeln!(
+                f,
+                r#"<g transform="translate(0 {}) scale(1 -1)">"#,
+                aabb.min[1] + aabb.max[1]
+            )?;
+        } else {
+            writeln!(f, "<g>")?;
+        }
+
+        writeln!(
+            f,
+            r##"<g fill="#e0e8f0" stroke="#b0c0d0" stroke-width="1" vector-effect="non-scaling-stroke">"##
+        )?;
+        for (id, polygon) in self.mesh.iter_polygons() {
+            let points: Vec<_> = polygon
+                .vertices
+                .iter()
+                .map(|vertex| self.mesh.vertices[*vertex].p())
+                .collect();
+            writeln!(
+                f,
+                r#"<polygon id="polygon-{}" points="{}" vector-effect="non-scaling-stroke"/>"#,
+                id,
+                Points(&points)
+            )?;
+        }
+        writeln!(f, "</g>")?;
+
+        writeln!(f, r##"<g stroke="#203040" stroke-width="2">"##)?;
+        for edge in self.mesh.boundary_edges() {
+            let start = self.mesh.vertices[edge[0]].p();
+            let end = self.mesh.vertices[edge[1]].p();
+            writeln!(
+                f,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}" vector-effect="non-scaling-stroke"/>"#,
+                start[0], start[1], end[0], end[1]
+            )?;
+        }
+        writeln!(f, "</g>")?;
+
+        if !self.intervals.is_empty() {
+            writeln!(
+                f,
+                r##"<g fill="#f0a030" fill-opacity="0.15" stroke="#d08020" stroke-width="1">"##
+            )?;
+            for interval in &self.intervals {
+                writeln!(
+                    f,
+                    r#"<polygon points="{}" vector-effect="non-scaling-stroke"><title>{}</title></polygon>"#,
+                    Points(&[interval.root, interval.left, interval.right]),
+                    interval.cost
+                )?;
+            }
+            writeln!(f, "</g>")?;
+        }
+
+        for path in &self.paths {
+            writeln!(
+                f,
+                r##"<polyline points="{}" fill="none" stroke="#d02020" stroke-width="2" vector-effect="non-scaling-stroke"/>"##,
+                Points(path)
+            )?;
+            for point in [path.first(), path.last()].into_iter().flatten() {
+                writeln!(
+                    f,
+                    r##"<circle cx="{}" cy="{}" r="{}" fill="#d02020"/>"##,
+                    point[0], point[1], radius
+                )?;
+            }
+        }
+        for point in &self.points {
+            writeln!(
+                f,
+                r##"<circle cx="{}" cy="{}" r="{}" fill="#2060d0"/>"##,
+                point[0], point[1], radius
+            )?;
+        }
+
+        writeln!(f, "</g>")?;
+        writeln!(f, "</svg>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Svg;
+    use crate::tests::mesh_u_grid;
+
+    #[test]
+    fn explore() {
+        let mesh = mesh_u_grid();
+        let (path, intervals) = mesh.explore([0.5, 0.5], [2.5, 0.5]);
+        assert_eq!(path, mesh.path([0.5, 0.5], [2.5, 0.5]));
+        assert!(!intervals.is_empty());
+        assert!(intervals
+            .windows(2)
+            .all(|pair| pair[0].cost <= pair[1].cost + 1.0e-5));
+
+        let (path, intervals) = mesh.explore([0.5, 0.5], [1.5, 1.5]);
+        assert_eq!(path, None);
+        assert!(intervals.is_empty());
+    }
+
+    #[test]
+    fn render() {
+        let mesh = mesh_u_grid();
+        let from = [0.5, 1.5];
+        let (path, intervals) = mesh.explore(from, [2.5, 1.5]);
+        let svg = Svg::new(&mesh)
+            .with_intervals(&intervals)
+            .with_path(from, &path.unwrap())
+            .with_point([1.5, 1.5])
+            .flip_y(true)
+            .to_string();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert_eq!(svg.matches("<polygon id=").count(), mesh.polygons.len());
+        assert_eq!(svg.matches("<line ").count(), mesh.boundary_edges().count());
+        assert_eq!(svg.matches("<polyline ").count(), 1);
+        assert_eq!(svg.matches("<circle ").count(), 3);
+        assert!(svg.contains("scale(1 -1)"));
+
+        let mut written = vec![];
+        Svg::new(&mesh).write(&mut written).unwrap();
+        assert_eq!(
+            std::str::from_utf8(&written).unwrap(),
+            Svg::new(&mesh).to_string()
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! Import of maps made with [Tiled](https://www.mapeditor.org), enabled with the `tiled` feature.
+//!
+//! Rectangles, ellipses and polygons of the object layers are obstacles inside the bounds of the
+//! map. Obstacles are rasterized on the tile grid: a tile is walkable when its center is outside of
+//! all obstacles, and each walkable tile becomes a polygon of the mesh.
+//!
+//! The mesh uses the pixel coordinates of the map, with `y` going down like in Tiled.
+
+use std::{
+    error::Error,
+    f32::consts::PI,
+    fmt::{self, Display},
+};
+
+use serde_json::Value;
+
+use crate::{helpers::point_in_simple_polygon, maps::scaled_grid_mesh, Mesh};
+
+/// Number of sides of the polygons approximating ellipses.
+const ELLIPSE_SIDES: usize = 16;
+
+/// Error returned when a Tiled map can't be imported.
+#[derive(Debug)]
+pub enum TiledError {
+    /// The `.tmx` file is not valid XML.
+    Xml(roxmltree::Error),
+    /// The `.tmj` or `.json` file is not valid JSON.
+    Json(serde_json::Error),
+    /// An element is missing an attribute.
+    MissingAttribute {
+        element: &'static str,
+        attribute: &'static str,
+    },
+    /// An attribute couldn't be parsed.
+    InvalidAttribute {
+        element: &'static str,
+        attribute: &'static str,
+        found: String,
+    },
+    /// There is no object layer with the requested name.
+    UnknownLayer(String),
+}
+
+impl Display for TiledError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TiledError::Xml(error) => write!(f, "invalid tmx file: {}", error),
+            TiledError::Json(error) => write!(f, "invalid json map: {}", error),
+            TiledError::MissingAttribute { element, attribute } => {
+                write!(f, "{} is missing attribute {}", element, attribute)
+            }
+            TiledError::InvalidAttribute {
+                element,
+                attribute,
+                found,
+            } => write!(
+                f,
+                "{} has invalid attribute {}: {:?}",
+                element, attribute, found
+            ),
+            TiledError::UnknownLayer(name) => write!(f, "no object layer named {:?}", name),
+        }
+    }
+}
+
+impl Error for TiledError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TiledError::Xml(error) => Some(error),
+            TiledError::Json(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<roxmltree::Error> for TiledError {
+    fn from(error: roxmltree::Error) -> Self {
+        TiledError::Xml(error)
+    }
+}
+
+impl From<serde_json::Error> for TiledError {
+    fn from(error: serde_json::Error) -> Self {
+        TiledError::Json(error)
+    }
+}
+
+/// Shape of a Tiled object.
+enum Shape {
+    Rectangle,
+    Ellipse,
+    Polygon(Vec<[f32; 2]>),
+    /// Points and polylines, that don't block anything.
+    Open,
+}
+
+/// An object of an object layer, in map coordinates.
+struct Object {
+    position: [f32; 2],
+    size: [f32; 2],
+    rotation: f32,
+    shape: Shape,
+}
+
+impl Object {
+    /// Outline of the object, or `None` if it doesn't block anything.
+    fn outline(&self) -> Option<Vec<[f32; 2]>> {
+        let [width, height] = self.size;
+        let local = match &self.shape {
+            Shape::Rectangle => vec![[0.0, 0.0], [width, 0.0], [width, height], [0.0, height]],
+            Shape::Ellipse => (0..ELLIPSE_SIDES)
+                .map(|i| {
+                    let angle = 2.0 * PI * i as f32 / ELLIPSE_SIDES as f32;
+                    [
+                        width / 2.0 * (1.0 + angle.cos()),
+                        height / 2.0 * (1.0 + angle.sin()),
+                    ]
+                })
+                .collect(),
+            Shape::Polygon(points) => points.clone(),
+            Shape::Open => return None,
+        };
+        if local.len() < 3 {
+            return None;
+        }
+        // rotation is in degrees, clockwise around the position of the object
+        let (sin, cos) = self.rotation.to_radians().sin_cos();
+        Some(
+            local
+                .into_iter()
+                .map(|[x, y]| {
+                    [
+                        self.position[0] + x * cos - y * sin,
+                        self.position[1] + x * sin + y * cos,
+                    ]
+                })
+                .collect(),
+        )
+    }
+}
+
+/// What's needed from a Tiled map to build a mesh.
+struct TiledMap {
+    width: usize,
+    height: usize,
+    tile: [f32; 2],
+    obstacles: Vec<Vec<[f32; 2]>>,
+}
+
+impl TiledMap {
+    fn mesh(&self) -> Mesh {
+        scaled_grid_mesh(self.width, self.height, [0.0, 0.0], self.tile, |x, y| {
+            let center = [
+                (x as f32 + 0.5) * self.tile[0],
+                (y as f32 + 0.5) * self.tile[1],
+            ];
+            !self
+                .obstacles
+                .iter()
+                .any(|obstacle| point_in_simple_polygon(center, obstacle))
+        })
+    }
+}
+
+/// Import a map in the XML `.tmx` format.
+///
+/// Obstacles are read from the object layer named `layer`, or from all the object layers if it's
+/// `None`.
+pub fn from_tmx(content: &str, layer: Option<&str>) -> Result<Mesh, TiledError> {
+    let document = roxmltree::Document::parse(content)?;
+    let map = document.root_element();
+    let attribute = |node: roxmltree::Node, element, attribute, default: Option<f32>| match (
+        node.attribute(attribute),
+        default,
+    ) {
+        (Some(value), _) => value
+            .parse::<f32>()
+            .map_err(|_| TiledError::InvalidAttribute {
+                element,
+                attribute,
+                found: value.to_string(),
+            }),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(TiledError::MissingAttribute { element, attribute }),
+    };
+
+    let mut obstacles = vec![];
+    let mut found_layer = false;
+    for group in map
+        .descendants()
+        .filter(|node| node.has_tag_name("objectgroup"))
+        .filter(|node| layer.is_none_or(|layer| node.attribute("name") == Some(layer)))
+    {
+        found_layer = true;
+        let offset = [
+            attribute(group, "objectgroup", "offsetx", Some(0.0))?,
+            attribute(group, "objectgroup", "offsety", Some(0.0))?,
+        ];
+        for object in group.children().filter(|node| node.has_tag_name("object")) {
+            let mut position = [
+                offset[0] + attribute(object, "object", "x", None)?,
+                offset[1] + attribute(object, "object", "y", None)?,
+            ];
+            let size = [
+                attribute(object, "object", "width", Some(0.0))?,
+                attribute(object, "object", "height", Some(0.0))?,
+            ];
+            let shape_node = object.children().find(|node| node.is_element());
+            let shape = match shape_node.map(|node| node.tag_name().name()) {
+                Some("ellipse") => Shape::Ellipse,
+                Some("polygon") => {
+                    let points = shape_node
+                        .and_then(|node| node.attribute("points"))
+                        .unwrap_or_default();
+                    Shape::Polygon(parse_points(points)?)
+                }
+                Some("point") | Some("polyline") => Shape::Open,
+                _ => {
+                    // tile objects are anchored at their bottom left corner
+                    if object.attribute("gid").is_some() {
+                        position[1] -= size[1];
+                    }
+                    Shape::Rectangle
+                }
+            };
+            obstacles.extend(
+                Object {
+                    position,
+                    size,
+                    rotation: attribute(object, "object", "rotation", Some(0.0))?,
+                    shape,
+                }
+                .outline(),
+            );
+        }
+    }
+    if let (Some(layer), false) = (layer, found_layer) {
+        return Err(TiledError::UnknownLayer(layer.to_string()));
+    }
+
+    let count = |attribute_name| {
+        attribute(map, "map", attribute_name, None).map(|value| value.max(0.0) as usize)
+    };
+    Ok(TiledMap {
+        width: count("width")?,
+        height: count("height")?,
+        tile: [
+            attribute(map, "map", "tilewidth", None)?,
+            attribute(map, "map", "tileheight", None)?,
+        ],
+        obstacles,
+    }
+    .mesh())
+}
+
+/// Parse the `points` attribute of a polygon, `x,y` pairs separated by spaces.
+fn parse_points(points: &str) -> Result<Vec<[f32; 2]>, TiledError> {
+    points
+        .split_whitespace()
+        .map(|point| {
+            let invalid = || TiledError::InvalidAttribute {
+                element: "polygon",
+                attribute: "points",
+                found: point.to_string(),
+            };
+            let (x, y) = point.split_once(',').ok_or_else(invalid)?;
+            Ok([
+                x.parse().map_err(|_| invalid())?,
+                y.parse().map_err(|_| invalid())?,
+            ])
+        })
+        .collect()
+}
+
+/// Import a map in the JSON format, with the `.tmj` or `.json` extension.
+///
+/// Obstacles are read from the object layer named `layer`, or from all the object layers if it's
+/// `None`.
+pub fn from_json(content: &str, layer: Option<&str>) -> Result<Mesh, TiledError> {
+    let map: Value = serde_json::from_str(content)?;
+    let number = |value: &Value, element, attribute, default: Option<f32>| match (
+        value.get(attribute),
+        default,
+    ) {
+        (Some(Value::Number(number)), _) => Ok(number.as_f64().unwrap_or_default() as f32),
+        (Some(other), _) => Err(TiledError::InvalidAttribute {
+            element,
+            attribute,
+            found: other.to_string(),
+        }),
+        (None, Some(default)) => Ok(default),
+        (None, None) => Err(TiledError::MissingAttribute { element, attribute }),
+    };
+
+    // object layers, including the ones nested in group layers
+    let mut layers = vec![];
+    let mut to_visit: Vec<&Value> = vec![&map];
+    while let Some(parent) = to_visit.pop() {
+        for child in parent["layers"].as_array().into_iter().flatten() {
+            match child["type"].as_str() {
+                Some("group") => to_visit.push(child),
+                Some("objectgroup")
+                    if layer.is_none_or(|layer| child["name"].as_str() == Some(layer)) =>
+                {
+                    layers.push(child)
+                }
+                _ => (),
+            }
+        }
+    }
+    if let (Some(layer), true) = (layer, layers.is_empty()) {
+        return Err(TiledError::UnknownLayer(layer.to_string()));
+    }
+
+    let mut obstacles = vec![];
+    for group in layers {
+        let offset = [
+            number(group, "objectgroup", "offsetx", Some(0.0))?,
+            number(group, "objectgroup", "offsety", Some(0.0))?,
+        ];
+        for object in group["objects"].as_array().into_iter().flatten() {
+            let mut position = [
+                offset[0] + number(object, "object", "x", None)?,
+                offset[1] + number(object, "object", "y", None)?,
+            ];
+            let size = [
+                number(object, "object", "width", Some(0.0))?,
+                number(object, "object", "height", Some(0.0))?,
+            ];
+            let shape = if let Some(points) = object["polygon"].as_array() {
+                Shape::Polygon(
+                    points
+                        .iter()
+                        .map(|point| {
+                            Ok([
+                                number(point, "polygon", "x", None)?,
+                                number(point, "polygon", "y", None)?,
+                            ])
+                        })
+                        .collect::<Result<_, TiledError>>()?,
+                )
+            } else if object["ellipse"].as_bool() == Some(true) {
+                Shape::Ellipse
+            } else if object["point"].as_bool() == Some(true) || object.get("polyline").is_some() {
+                Shape::Open
+            } else {
+                // tile objects are anchored at their bottom left corner
+                if object.get("gid").is_some() {
+                    position[1] -= size[1];
+                }
+                Shape::Rectangle
+            };
+            obstacles.extend(
+                Object {
+                    position,
+                    size,
+                    rotation: number(object, "object", "rotation", Some(0.0))?,
+                    shape,
+                }
+                .outline(),
+            );
+        }
+    }
+
+    let count =
+        |attribute| number(&map, "map", attribute, None).map(|value| value.max(0.0) as usize);
+    Ok(TiledMap {
+        width: count("width")?,
+        height: count("height")?,
+        tile: [
+            number(&map, "map", "tilewidth", None)?,
+            number(&map, "map", "tileheight", None)?,
+        ],
+        obstacles,
+    }
+    .mesh())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_json, from_tmx, TiledError};
+
+    const TMX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" width="4" height="3" tilewidth="16" tileheight="16">
+ <layer id="1" name="ground" width="4" height="3"/>
+ <objectgroup id="2" name="collision">
+  <object id="1" x="16" y="0" width="16" height="32"/>
+  <object id="2" x="48" y="32">
+   <polygon points="0,0 16,0 16,16 0,16"/>
+  </object>
+  <object id="3" x="0" y="40"><point/></object>
+ </objectgroup>
+ <objectgroup id="3" name="spawns">
+  <object id="4" x="0" y="32" width="16" height="16"/>
+ </objectgroup>
+</map>"#;
+
+    const JSON: &str = r#"{
+ "width": 4, "height": 3, "tilewidth": 16, "tileheight": 16,
+ "layers": [
+  {"type": "tilelayer", "name": "ground"},
+  {"type": "group", "name": "walls", "layers": [
+   {"type": "objectgroup", "name": "collision", "objects": [
+    {"id": 1, "x": 16, "y": 0, "width": 16, "height": 32, "rotation": 0},
+    {"id": 2, "x": 48, "y": 32, "width": 0, "height": 0,
+     "polygon": [{"x": 0, "y": 0}, {"x": 16, "y": 0}, {"x": 16, "y": 16}, {"x": 0, "y": 16}]},
+    {"id": 3, "x": 0, "y": 40, "point": true}
+   ]}
+  ]},
+  {"type": "objectgroup", "name": "spawns", "objects": [
+   {"id": 4, "x": 0, "y": 32, "width": 16, "height": 16}
+  ]}
+ ]
+}"#;
+
+    #[test]
+    fn tmx() {
+        let mesh = from_tmx(TMX, Some("collision")).unwrap();
+        assert_eq!(mesh.polygons.len(), 12 - 3);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(!mesh.point_in_mesh([24.0, 8.0]));
+        assert!(mesh.point_in_mesh([8.0, 40.0]));
+        assert!(mesh.path([8.0, 8.0], [40.0, 8.0]).unwrap().len > 32.0);
+        assert_eq!(from_tmx(TMX, None).unwrap().polygons.len(), 12 - 4);
+        assert!(matches!(
+            from_tmx(TMX, Some("missing")),
+            Err(TiledError::UnknownLayer(_))
+        ));
+    }
+
+    #[test]
+    fn json() {
+        let mesh = from_json(JSON, Some("collision")).unwrap();
+        assert_eq!(mesh.polygons.len(), 12 - 3);
+        assert!(!mesh.point_in_mesh([56.0, 40.0]));
+        assert_eq!(from_json(JSON, None).unwrap().polygons.len(), 12 - 4);
+        assert!(matches!(from_json("{", None), Err(TiledError::Json(_))));
+        assert!(matches!(
+            from_json(r#"{"width": 1, "height": 1, "tilewidth": 16}"#, None),
+            Err(TiledError::MissingAttribute {
+                attribute: "tileheight",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn rotated_object() {
+        // a 32x16 rectangle rotated to stand vertically on the second column
+        let tmx = r#"<map width="4" height="3" tilewidth="16" tileheight="16">
+ <objectgroup><object x="32" y="0" width="32" height="16" rotation="90"/></objectgroup>
+</map>"#;
+        let mesh = from_tmx(tmx, None).unwrap();
+        assert!(!mesh.point_in_mesh([24.0, 8.0]));
+        assert!(!mesh.point_in_mesh([24.0, 24.0]));
+        assert!(mesh.point_in_mesh([40.0, 8.0]));
+    }
+}
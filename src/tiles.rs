@@ -0,0 +1,148 @@
+use std::collections::BTreeMap;
+
+use crate::{stitch::stitch, Aabb, Mesh};
+
+/// A mesh made of square tiles, that can be added and removed at runtime, for example to stream
+/// large worlds.
+///
+/// Tile `[x, y]` covers the square from `[x * tile_size, y * tile_size]` to
+/// `[(x + 1) * tile_size, (y + 1) * tile_size]`. Tiles are baked independently, usually with
+/// [`bake::tile_from_triangles`](crate::bake::tile_from_triangles), and stitched along their
+/// borders so that paths can cross from one tile to another.
+///
+/// ```
+/// # use polyanya::{MeshBuilder, TiledMesh};
+/// let square = |x: f32| {
+///     MeshBuilder::new()
+///         .vertex([x, 0.0])
+///         .vertex([x + 1.0, 0.0])
+///         .vertex([x + 1.0, 1.0])
+///         .vertex([x, 1.0])
+///         .polygon([0, 1, 2, 3])
+///         .build()
+///         .unwrap()
+/// };
+/// let mut tiles = TiledMesh::new(1.0);
+/// tiles.insert_tile([0, 0], square(0.0));
+/// tiles.insert_tile([1, 0], square(1.0));
+/// assert!(tiles.mesh().path([0.5, 0.5], [1.5, 0.5]).is_some());
+///
+/// tiles.remove_tile([1, 0]);
+/// assert!(!tiles.mesh().point_in_mesh([1.5, 0.5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TiledMesh {
+    tile_size: f32,
+    tiles: BTreeMap<[i32; 2], Mesh>,
+    mesh: Mesh,
+}
+
+impl TiledMesh {
+    /// Create a mesh without tiles.
+    pub fn new(tile_size: f32) -> Self {
+        TiledMesh {
+            tile_size,
+            tiles: BTreeMap::new(),
+            mesh: Mesh::default(),
+        }
+    }
+
+    /// Create a mesh from tiles, stitching them once.
+    pub fn from_tiles(tile_size: f32, tiles: impl IntoIterator<Item = ([i32; 2], Mesh)>) -> Self {
+        let mut tiled = TiledMesh::new(tile_size);
+        tiled.tiles.extend(tiles);
+        tiled.restitch();
+        tiled
+    }
+
+    /// Width and height of the tiles.
+    pub fn tile_size(&self) -> f32 {
+        self.tile_size
+    }
+
+    /// The tile containing a point.
+    pub fn tile_at(&self, point: [f32; 2]) -> [i32; 2] {
+        point.map(|coordinate| (coordinate / self.tile_size).floor() as i32)
+    }
+
+    /// The area covered by a tile.
+    pub fn tile_bounds(&self, tile: [i32; 2]) -> Aabb {
+        Aabb::new(
+            tile.map(|coordinate| coordinate as f32 * self.tile_size),
+            tile.map(|coordinate| (coordinate + 1) as f32 * self.tile_size),
+        )
+    }
+
+    /// Add a tile, or replace it, returning the mesh it had.
+    pub fn insert_tile(&mut self, tile: [i32; 2], mesh: Mesh) -> Option<Mesh> {
+        let previous = self.tiles.insert(tile, mesh);
+        self.restitch();
+        previous
+    }
+
+    /// Remove a tile, returning its mesh.
+    pub fn remove_tile(&mut self, tile: [i32; 2]) -> Option<Mesh> {
+        let previous = self.tiles.remove(&tile);
+        if previous.is_some() {
+            self.restitch();
+        }
+        previous
+    }
+
+    /// The mesh of a tile.
+    pub fn tile(&self, tile: [i32; 2]) -> Option<&Mesh> {
+        self.tiles.get(&tile)
+    }
+
+    /// Iterate over the tiles and their mesh.
+    pub fn tiles(&self) -> impl ExactSizeIterator<Item = ([i32; 2], &Mesh)> + '_ {
+        self.tiles.iter().map(|(tile, mesh)| (*tile, mesh))
+    }
+
+    /// The mesh made of all the tiles, to find paths on.
+    pub fn mesh(&self) -> &Mesh {
+        &self.mesh
+    }
+
+    fn restitch(&mut self) {
+        self.mesh = stitch(self.tiles.values(), self.tile_size * 1.0e-5);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TiledMesh;
+    use crate::maps::grid_mesh;
+
+    #[test]
+    fn swap_tiles() {
+        let mut tiles = TiledMesh::new(2.0);
+        assert_eq!(tiles.tile_at([3.0, -0.5]), [1, -1]);
+        let tile = |x: f32, y: f32, walkable: fn(usize, usize) -> bool| {
+            let mut mesh = grid_mesh(2, 2, walkable);
+            for vertex in &mut mesh.vertices {
+                vertex.x += x;
+                vertex.y += y;
+            }
+            crate::Mesh::new(mesh.vertices, mesh.polygons)
+        };
+        tiles.insert_tile([0, 0], tile(0.0, 0.0, |_, _| true));
+        tiles.insert_tile([1, 0], tile(2.0, 0.0, |x, _| x == 0));
+        tiles.insert_tile([1, 1], tile(2.0, 2.0, |_, _| true));
+        assert_eq!(tiles.tiles().len(), 3);
+        assert!(tiles.mesh().validate().is_empty());
+        // through the left column of [1, 0], around the corner of [0, 0]
+        let path = tiles.mesh().path([0.5, 0.5], [2.5, 3.5]).unwrap();
+        assert!((path.len - (1.5f32.hypot(1.5) + 0.5f32.hypot(1.5))).abs() < 1.0e-4);
+
+        let previous = tiles.insert_tile([1, 0], tile(2.0, 0.0, |x, _| x == 1));
+        assert!(previous.is_some());
+        assert!(tiles.mesh().path([0.5, 0.5], [2.5, 3.5]).is_none());
+        assert!(tiles.mesh().path([3.5, 0.5], [2.5, 3.5]).is_some());
+
+        assert!(tiles.remove_tile([1, 0]).is_some());
+        assert!(tiles.remove_tile([1, 0]).is_none());
+        assert!(tiles.mesh().path([3.5, 0.5], [2.5, 3.5]).is_none());
+        assert_eq!(tiles.tile_bounds([1, 1]).min, [2.0, 2.0]);
+    }
+}
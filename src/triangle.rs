@@ -0,0 +1,148 @@
+use std::io::{self, BufRead, Read};
+
+use crate::{input::Tokens, Mesh, MeshBuilder, MeshParseError};
+
+/// Lines of a file in one of the formats of Triangle, without comments and empty lines, with their
+/// number for error reporting.
+fn content_lines(
+    reader: impl Read,
+) -> impl Iterator<Item = Result<(usize, String), MeshParseError>> {
+    io::BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) => {
+                let content = line.split('#').next().unwrap_or_default().trim();
+                (!content.is_empty()).then(|| Ok((index + 1, content.to_string())))
+            }
+            Err(error) => Some(Err(error.into())),
+        })
+}
+
+impl Mesh {
+    /// Read a triangulation from the `.node` and `.ele` files written by
+    /// [Triangle](https://www.cs.cmu.edu/~quake/triangle.html).
+    ///
+    /// Attributes and boundary markers are ignored: neighbours and corners are computed from the
+    /// edges shared by triangles. Indices can start at 0 or 1, as long as both files agree.
+    pub fn from_triangle(node: impl Read, ele: impl Read) -> Result<Mesh, MeshParseError> {
+        let mut builder = MeshBuilder::new();
+
+        let mut lines = content_lines(node);
+        let (line, header) = lines.next().ok_or(MeshParseError::UnexpectedEof {
+            expected: "number of vertices",
+        })??;
+        let mut tokens = Tokens::new(line, &header);
+        let nb_vertices: usize = tokens.next("number of vertices")?;
+        let mut first_index = None;
+        for _ in 0..nb_vertices {
+            let (line, content) = lines
+                .next()
+                .ok_or(MeshParseError::UnexpectedEof { expected: "vertex" })??;
+            let mut tokens = Tokens::new(line, &content);
+            let index: usize = tokens.next("vertex index")?;
+            first_index.get_or_insert(index);
+            let point = [
+                tokens.next("vertex x coordinate")?,
+                tokens.next("vertex y coordinate")?,
+            ];
+            builder.add_vertex(point)?;
+        }
+        let first_index = first_index.unwrap_or(0);
+
+        let mut lines = content_lines(ele);
+        let (line, header) = lines.next().ok_or(MeshParseError::UnexpectedEof {
+            expected: "number of triangles",
+        })??;
+        let mut tokens = Tokens::new(line, &header);
+        let nb_triangles: usize = tokens.next("number of triangles")?;
+        for _ in 0..nb_triangles {
+            let (line, content) = lines.next().ok_or(MeshParseError::UnexpectedEof {
+                expected: "triangle",
+            })??;
+            let mut tokens = Tokens::new(line, &content);
+            let _index: usize = tokens.next("triangle index")?;
+            let mut triangle = [0; 3];
+            for vertex in &mut triangle {
+                let index: usize = tokens.next("triangle vertex")?;
+                *vertex = index
+                    .checked_sub(first_index)
+                    .ok_or(MeshParseError::InvalidToken {
+                        line,
+                        expected: "triangle vertex",
+                        found: index.to_string(),
+                    })?;
+            }
+            // Triangle writes counterclockwise triangles, but other tools may not
+            builder.add_polygon_any_order(triangle)?;
+        }
+
+        Ok(builder.build()?)
+    }
+
+    /// Read a triangulation from the files `<basename>.node` and `<basename>.ele` written by
+    /// Triangle.
+    pub fn from_triangle_files(basename: &str) -> Result<Mesh, MeshParseError> {
+        let node = std::fs::File::open(format!("{}.node", basename))?;
+        let ele = std::fs::File::open(format!("{}.ele", basename))?;
+        Mesh::from_triangle(node, ele)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Mesh, MeshBuildError, MeshParseError, PolygonId};
+
+    const NODE: &str = "# square with a vertex in the middle
+5 2 0 1
+1 0.0 0.0 1
+2 2.0 0.0 1
+3 2.0 2.0 1
+4 0.0 2.0 1
+5 1.0 1.0 0
+";
+
+    const ELE: &str = "4 3 0
+1 1 2 5
+2 2 3 5
+3 3 4 5
+# clockwise
+4 1 5 4
+";
+
+    #[test]
+    fn square() {
+        let mesh = Mesh::from_triangle(NODE.as_bytes(), ELE.as_bytes()).unwrap();
+        assert_eq!(mesh.vertices.len(), 5);
+        assert_eq!(mesh.polygons.len(), 4);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!(mesh.vertices[0].is_corner());
+        assert!(!mesh.vertices[4].is_corner());
+        assert_eq!(mesh.interior_edges().count(), 4);
+        assert_eq!(
+            mesh.locate_batch(&[[0.2, 1.0]]),
+            vec![Some(PolygonId::new(3))]
+        );
+        assert!((mesh.path([1.0, 0.2], [1.0, 1.8]).unwrap().len - 1.6).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn invalid_files() {
+        assert!(matches!(
+            Mesh::from_triangle(NODE.as_bytes(), "1 3 0\n1 1 2 9\n".as_bytes()),
+            Err(MeshParseError::InvalidMesh(
+                MeshBuildError::VertexOutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            Mesh::from_triangle(NODE.as_bytes(), "2 3 0\n1 1 2 5\n".as_bytes()),
+            Err(MeshParseError::UnexpectedEof {
+                expected: "triangle"
+            })
+        ));
+        assert!(matches!(
+            Mesh::from_triangle("1 2 0 0\n1 a 0\n".as_bytes(), ELE.as_bytes()),
+            Err(MeshParseError::InvalidToken { line: 2, .. })
+        ));
+    }
+}
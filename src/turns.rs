@@ -0,0 +1,116 @@
+use crate::{helpers::distance_between, Mesh, Path, SearchOptions};
+
+impl Mesh {
+    /// Compute a path from `from` to `to` that turns as little as possible, each turn costing as
+    /// much as `turn_penalty` more length.
+    ///
+    /// A route with fewer turns is preferred when it's at most that much longer per turn saved,
+    /// which looks more natural for vehicles and characters. Paths still turn on the corners of
+    /// the mesh, except for the last turn, that can be anywhere on the way to `to`. The length
+    /// of the path is its actual length, without the penalties. Returns `None` if one of the
+    /// points is not in the mesh, or if `to` can't be reached from `from`.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall between the two points
+    /// let mesh = Mesh::from_grid(12, 12, |x, y| x != 3 || !(3..7).contains(&y));
+    /// let (from, to) = ([1.0, 6.0], [11.0, 6.0]);
+    /// // the shortest path turns on both corners at the end of the wall
+    /// let shortest = mesh.path(from, to).unwrap();
+    /// assert_eq!(shortest.path, vec![[3.0, 7.0], [4.0, 7.0], [11.0, 6.0]]);
+    /// let path = mesh.path_with_turn_penalty(from, to, 1.0).unwrap();
+    /// assert_eq!(path.path.len(), 2);
+    /// assert!(path.len > shortest.len);
+    /// ```
+    pub fn path_with_turn_penalty(
+        &self,
+        from: [f32; 2],
+        to: [f32; 2],
+        turn_penalty: f32,
+    ) -> Option<Path> {
+        let path = self.path_between_polygons(
+            from,
+            to,
+            self.point_in_polygon(from),
+            self.point_in_polygon(to),
+            SearchOptions {
+                turn_penalty: turn_penalty.max(0.0),
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )?;
+        let mut len = 0.0;
+        let mut last = from;
+        for point in &path.path {
+            len += distance_between(last, *point);
+            last = *point;
+        }
+        Some(Path {
+            path: path.path,
+            len,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{random_obstacles, test_points},
+        Mesh, Path,
+    };
+
+    #[test]
+    fn fewer_turns() {
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        let from = [1.5, 1.5];
+        let mut straighter = 0;
+        for to in test_points(40, [0.5, 0.5]) {
+            let shortest = mesh.path(from, to);
+            assert_eq!(mesh.path_with_turn_penalty(from, to, 0.0), shortest);
+            let penalized = mesh.path_with_turn_penalty(from, to, 5.0);
+            assert_eq!(shortest.is_some(), penalized.is_some());
+            let (Some(shortest), Some(penalized)) = (shortest, penalized) else {
+                continue;
+            };
+            // never turning more, and saving at least as much in penalties as the length added
+            let turns = |path: &Path| path.path.len() - 1;
+            assert!(turns(&penalized) <= turns(&shortest));
+            assert!(penalized.len >= shortest.len - 1.0e-3);
+            assert!(
+                penalized.len + turns(&penalized) as f32 * 5.0
+                    <= shortest.len + turns(&shortest) as f32 * 5.0 + 1.0e-3
+            );
+            let mut last = from;
+            for point in &penalized.path {
+                assert!(mesh.is_segment_on_mesh(last, *point));
+                last = *point;
+            }
+            if turns(&penalized) < turns(&shortest) {
+                straighter += 1;
+            }
+        }
+        assert!(straighter > 0);
+    }
+
+    #[test]
+    fn penalties_out_of_range() {
+        // around a wall and back
+        let mesh = Mesh::from_grid(12, 12, |x, y| x != 3 || !(3..7).contains(&y));
+        let (from, to) = ([1.0, 5.0], [5.0, 5.0]);
+        let shortest = mesh.path(from, to);
+        for penalty in [-1.0, f32::NAN] {
+            assert_eq!(mesh.path_with_turn_penalty(from, to, penalty), shortest);
+        }
+        // turns that can't be avoided, whatever they cost
+        let path = mesh
+            .path_with_turn_penalty(from, to, f32::INFINITY)
+            .unwrap();
+        assert_eq!(path.path.last(), Some(&to));
+        assert!(path.len.is_finite());
+
+        assert!(mesh.path_with_turn_penalty([-1.0, 5.0], to, 1.0).is_none());
+        assert!(mesh.path_with_turn_penalty(from, [3.5, 5.0], 1.0).is_none());
+        let straight = mesh.path_with_turn_penalty(from, [1.0, 9.0], 1.0).unwrap();
+        assert_eq!(straight.path, vec![[1.0, 9.0]]);
+    }
+}
@@ -0,0 +1,209 @@
+//! Import of navigation meshes exported from Unity with `NavMesh.CalculateTriangulation`.
+//!
+//! The triangulation is a list of 3D vertices and of vertex indices, three per triangle. Vertices
+//! are projected on the ground plane, with Unity's `x` and `z` coordinates becoming the `x` and `y`
+//! coordinates of the mesh. Vertices closer than a tolerance are merged, as Unity duplicates them on
+//! the borders of its tiles.
+
+use hashbrown::HashMap;
+
+use crate::{helpers::distance_between, Mesh, MeshBuildError, MeshBuilder};
+
+/// Default distance under which two vertices are merged.
+pub const DEFAULT_WELD_TOLERANCE: f32 = 1.0e-3;
+
+/// Build a mesh from the `vertices` and `indices` of a Unity `NavMeshTriangulation`.
+///
+/// Vertices closer than `weld_tolerance` are merged, and triangles that become degenerate are
+/// dropped.
+pub fn from_triangulation(
+    vertices: &[[f32; 3]],
+    indices: &[u32],
+    weld_tolerance: f32,
+) -> Result<Mesh, MeshBuildError> {
+    let mut builder = MeshBuilder::new();
+    let tolerance = weld_tolerance.max(f32::EPSILON);
+    let mut points = vec![];
+    // vertices added to the builder, by cell of a grid of size `tolerance`
+    let mut cells: HashMap<(i64, i64), Vec<usize>> = HashMap::new();
+    let mut welded = Vec::with_capacity(vertices.len());
+    for (index, vertex) in vertices.iter().enumerate() {
+        let point = [vertex[0], vertex[2]];
+        if !point[0].is_finite() || !point[1].is_finite() {
+            return Err(MeshBuildError::NonFiniteVertex { vertex: index });
+        }
+        let cell = (
+            (point[0] / tolerance).floor() as i64,
+            (point[1] / tolerance).floor() as i64,
+        );
+        let existing = (cell.0 - 1..=cell.0 + 1)
+            .flat_map(|x| (cell.1 - 1..=cell.1 + 1).map(move |y| (x, y)))
+            .filter_map(|cell| cells.get(&cell))
+            .flatten()
+            .find(|id| distance_between(point, points[**id]) <= weld_tolerance)
+            .copied();
+        let id = match existing {
+            Some(id) => id,
+            None => {
+                let id = builder.add_vertex(point)?;
+                points.push(point);
+                cells.entry(cell).or_default().push(id);
+                id
+            }
+        };
+        welded.push(id);
+    }
+
+    for (triangle, corners) in indices.chunks_exact(3).enumerate() {
+        let mut ids = [0; 3];
+        for (id, corner) in ids.iter_mut().zip(corners) {
+            *id = *welded
+                .get(*corner as usize)
+                .ok_or(MeshBuildError::VertexOutOfRange {
+                    polygon: triangle,
+                    vertex: *corner as usize,
+                })?;
+        }
+        if ids[0] == ids[1] || ids[1] == ids[2] || ids[0] == ids[2] {
+            continue;
+        }
+        builder.add_polygon_any_order(ids)?;
+    }
+    builder.build()
+}
+
+/// Build a mesh from a triangulation saved as JSON, with the `unity` feature.
+///
+/// The JSON object has the fields of `NavMeshTriangulation`, as written by `JsonUtility.ToJson`:
+/// `vertices`, a list of objects with `x`, `y` and `z` fields, and `indices`, a list of integers.
+#[cfg(feature = "unity")]
+pub fn from_json(content: &str, weld_tolerance: f32) -> Result<Mesh, UnityError> {
+    let triangulation: serde_json::Value = serde_json::from_str(content)?;
+    let invalid = |field| UnityError::InvalidField { field };
+    let vertices = triangulation["vertices"]
+        .as_array()
+        .ok_or(invalid("vertices"))?
+        .iter()
+        .map(|vertex| {
+            let coordinate = |name| {
+                vertex[name]
+                    .as_f64()
+                    .map(|value| value as f32)
+                    .ok_or(invalid("vertices"))
+            };
+            Ok([coordinate("x")?, coordinate("y")?, coordinate("z")?])
+        })
+        .collect::<Result<Vec<_>, UnityError>>()?;
+    let indices = triangulation["indices"]
+        .as_array()
+        .ok_or(invalid("indices"))?
+        .iter()
+        .map(|index| {
+            index
+                .as_u64()
+                .and_then(|index| u32::try_from(index).ok())
+                .ok_or(invalid("indices"))
+        })
+        .collect::<Result<Vec<_>, UnityError>>()?;
+    Ok(from_triangulation(&vertices, &indices, weld_tolerance)?)
+}
+
+/// Error returned when a triangulation saved as JSON can't be imported.
+#[cfg(feature = "unity")]
+#[derive(Debug)]
+pub enum UnityError {
+    /// The file is not valid JSON.
+    Json(serde_json::Error),
+    /// A field is missing or has an unexpected type.
+    InvalidField { field: &'static str },
+    /// The triangulation doesn't describe a valid mesh.
+    InvalidMesh(MeshBuildError),
+}
+
+#[cfg(feature = "unity")]
+impl std::fmt::Display for UnityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnityError::Json(error) => write!(f, "invalid triangulation: {}", error),
+            UnityError::InvalidField { field } => write!(f, "missing or invalid field {}", field),
+            UnityError::InvalidMesh(error) => write!(f, "invalid mesh: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "unity")]
+impl std::error::Error for UnityError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UnityError::Json(error) => Some(error),
+            UnityError::InvalidMesh(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "unity")]
+impl From<serde_json::Error> for UnityError {
+    fn from(error: serde_json::Error) -> Self {
+        UnityError::Json(error)
+    }
+}
+
+#[cfg(feature = "unity")]
+impl From<MeshBuildError> for UnityError {
+    fn from(error: MeshBuildError) -> Self {
+        UnityError::InvalidMesh(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_triangulation, DEFAULT_WELD_TOLERANCE};
+    use crate::MeshBuildError;
+
+    #[test]
+    fn triangulation() {
+        // two tiles of two triangles each, with duplicated vertices on their shared border, and
+        // clockwise triangles when seen from above like in Unity
+        let vertices = [
+            [0.0, 0.5, 0.0],
+            [0.0, 0.5, 1.0],
+            [1.0, 0.5, 1.0],
+            [1.0, 0.5, 0.0],
+            [1.0001, 0.5, 0.0],
+            [1.0001, 0.5, 1.0],
+            [2.0, 0.5, 1.0],
+            [2.0, 0.5, 0.0],
+        ];
+        let indices = [0, 1, 2, 0, 2, 3, 4, 5, 6, 4, 6, 7, 0, 0, 1];
+        let mesh = from_triangulation(&vertices, &indices, DEFAULT_WELD_TOLERANCE).unwrap();
+        assert_eq!(mesh.vertices.len(), 6);
+        assert_eq!(mesh.polygons.len(), 4);
+        assert_eq!(mesh.validate(), vec![]);
+        assert!((mesh.path([0.1, 0.5], [1.9, 0.5]).unwrap().len - 1.8).abs() < 1.0e-5);
+        assert_eq!(
+            from_triangulation(&vertices, &[0, 1, 9], DEFAULT_WELD_TOLERANCE).err(),
+            Some(MeshBuildError::VertexOutOfRange {
+                polygon: 0,
+                vertex: 9
+            })
+        );
+    }
+
+    #[cfg(feature = "unity")]
+    #[test]
+    fn json() {
+        let json = r#"{
+            "vertices": [{"x": 0, "y": 0, "z": 0}, {"x": 0, "y": 0, "z": 1}, {"x": 1, "y": 0, "z": 0}],
+            "indices": [0, 1, 2],
+            "areas": [0]
+        }"#;
+        let mesh = super::from_json(json, DEFAULT_WELD_TOLERANCE).unwrap();
+        assert_eq!(mesh.polygons.len(), 1);
+        assert!(mesh.point_in_mesh([0.2, 0.2]));
+        assert!(matches!(
+            super::from_json(r#"{"vertices": []}"#, DEFAULT_WELD_TOLERANCE),
+            Err(super::UnityError::InvalidField { field: "indices" })
+        ));
+    }
+}
@@ -0,0 +1,360 @@
+use std::fmt::{self, Display};
+
+use hashbrown::HashMap;
+
+use crate::{Aabb, EdgeId, Mesh, PolygonId};
+
+/// Tolerance on cross products under which three points are considered aligned.
+pub(crate) const EPSILON: f32 = 1.0e-6;
+
+/// A problem found by [`Mesh::validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MeshIssue {
+    /// A polygon uses a vertex that doesn't exist.
+    VertexOutOfRange { polygon: usize, vertex: usize },
+    /// A vertex lists a polygon that doesn't exist.
+    PolygonOutOfRange { vertex: usize, polygon: usize },
+    /// A polygon has fewer than 3 vertices, or no area.
+    DegeneratePolygon { polygon: usize },
+    /// The vertices of a polygon are in clockwise order.
+    ClockwisePolygon { polygon: usize },
+    /// A polygon turns clockwise at one of its vertices.
+    NonConvexPolygon { polygon: usize, vertex: usize },
+    /// A polygon uses a vertex that doesn't list it.
+    PolygonMissingFromVertex { polygon: usize, vertex: usize },
+    /// A vertex lists a polygon that doesn't use it.
+    VertexMissingFromPolygon { vertex: usize, polygon: usize },
+    /// An edge is shared by more than two polygons, or by two polygons going the same way.
+    InvalidEdge { edge: EdgeId, polygons: Vec<usize> },
+}
+
+impl Display for MeshIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MeshIssue::VertexOutOfRange { polygon, vertex } => {
+                write!(f, "polygon {} uses unknown vertex {}", polygon, vertex)
+            }
+            MeshIssue::PolygonOutOfRange { vertex, polygon } => {
+                write!(f, "vertex {} lists unknown polygon {}", vertex, polygon)
+            }
+            MeshIssue::DegeneratePolygon { polygon } => {
+                write!(f, "polygon {} is degenerate", polygon)
+            }
+            MeshIssue::ClockwisePolygon { polygon } => {
+                write!(f, "polygon {} is clockwise", polygon)
+            }
+            MeshIssue::NonConvexPolygon { polygon, vertex } => {
+                write!(f, "polygon {} is not convex at vertex {}", polygon, vertex)
+            }
+            MeshIssue::PolygonMissingFromVertex { polygon, vertex } => {
+                write!(f, "polygon {} is missing from vertex {}", polygon, vertex)
+            }
+            MeshIssue::VertexMissingFromPolygon { vertex, polygon } => {
+                write!(f, "vertex {} is missing from polygon {}", vertex, polygon)
+            }
+            MeshIssue::InvalidEdge { edge, polygons } => {
+                write!(f, "edge {:?} is shared by polygons {:?}", edge, polygons)
+            }
+        }
+    }
+}
+
+/// Reverse the polygons in clockwise order, found by their signed area, returning how many were
+/// reversed.
+pub(crate) fn fix_winding(points: &[[f32; 2]], polygons: &mut [Vec<usize>]) -> usize {
+    let mut reversed = 0;
+    for polygon in polygons {
+        let n = polygon.len();
+        let area: f64 = (0..n)
+            .map(|i| {
+                let (a, b) = (points[polygon[i]], points[polygon[(i + 1) % n]]);
+                a[0] as f64 * b[1] as f64 - b[0] as f64 * a[1] as f64
+            })
+            .sum();
+        if area < 0.0 {
+            polygon.reverse();
+            reversed += 1;
+        }
+    }
+    reversed
+}
+
+impl Mesh {
+    /// Check the mesh for problems that would make the search return wrong paths.
+    ///
+    /// This checks that polygons are convex and counterclockwise, that indices are in range, and
+    /// that the polygons listed by each vertex match the polygons using it. An empty list means
+    /// no problem was found.
+    pub fn validate(&self) -> Vec<MeshIssue> {
+        let mut issues = vec![];
+        // polygons using each edge, and if they go along it in increasing index order
+        let mut edges: HashMap<EdgeId, Vec<(usize, bool)>> = HashMap::new();
+
+        for (index, polygon) in self.polygons.iter().enumerate() {
+            if let Some(vertex) = polygon.vertices.iter().find(|v| **v >= self.vertices.len()) {
+                issues.push(MeshIssue::VertexOutOfRange {
+                    polygon: index,
+                    vertex: *vertex,
+                });
+                continue;
+            }
+            for vertex in &polygon.vertices {
+                if !self.vertices[*vertex]
+                    .polygons
+                    .contains(&Some(PolygonId::new(index)))
+                {
+                    issues.push(MeshIssue::PolygonMissingFromVertex {
+                        polygon: index,
+                        vertex: *vertex,
+                    });
+                }
+            }
+            if polygon.vertices.len() < 3 {
+                issues.push(MeshIssue::DegeneratePolygon { polygon: index });
+                continue;
+            }
+            for edge in polygon.edges_index() {
+                let key = [edge[0].min(edge[1]), edge[0].max(edge[1])];
+                edges.entry(key).or_default().push((index, key == edge));
+            }
+
+            let points: Vec<[f32; 2]> = polygon
+                .vertices
+                .iter()
+                .map(|v| self.vertices[*v].p())
+                .collect();
+            let n = points.len();
+            let area: f32 = (0..n)
+                .map(|i| {
+                    let (a, b) = (points[i], points[(i + 1) % n]);
+                    a[0] * b[1] - b[0] * a[1]
+                })
+                .sum();
+            if area.abs() <= EPSILON {
+                issues.push(MeshIssue::DegeneratePolygon { polygon: index });
+                continue;
+            }
+            if area < 0.0 {
+                issues.push(MeshIssue::ClockwisePolygon { polygon: index });
+                continue;
+            }
+            for i in 0..n {
+                let (a, b, c) = (points[(i + n - 1) % n], points[i], points[(i + 1) % n]);
+                let cross = (b[0] - a[0]) * (c[1] - b[1]) - (b[1] - a[1]) * (c[0] - b[0]);
+                if cross < -EPSILON {
+                    issues.push(MeshIssue::NonConvexPolygon {
+                        polygon: index,
+                        vertex: polygon.vertices[i],
+                    });
+                }
+            }
+        }
+
+        for (index, vertex) in self.vertices.iter().enumerate() {
+            for polygon in vertex.polygons.iter().flatten() {
+                match self.polygons.get(polygon.index()) {
+                    None => issues.push(MeshIssue::PolygonOutOfRange {
+                        vertex: index,
+                        polygon: polygon.index(),
+                    }),
+                    Some(p) if !p.vertices.contains(&index) => {
+                        issues.push(MeshIssue::VertexMissingFromPolygon {
+                            vertex: index,
+                            polygon: polygon.index(),
+                        })
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        let mut invalid_edges: Vec<_> = edges
+            .into_iter()
+            .filter(|(_, polygons)| {
+                polygons.len() > 2 || (polygons.len() == 2 && polygons[0].1 == polygons[1].1)
+            })
+            .collect();
+        invalid_edges.sort_unstable_by_key(|(edge, _)| *edge);
+        issues.extend(
+            invalid_edges
+                .into_iter()
+                .map(|(edge, polygons)| MeshIssue::InvalidEdge {
+                    edge,
+                    polygons: polygons.into_iter().map(|(polygon, _)| polygon).collect(),
+                }),
+        );
+
+        issues
+    }
+
+    /// Reverse the polygons in clockwise order, so that all polygons are counterclockwise as the
+    /// search expects. Returns the number of polygons reversed.
+    ///
+    /// The polygons around a vertex are listed in the same order as the polygons are wound, so the
+    /// lists of the vertices with only reversed polygons around them are mirrored too, as in a
+    /// mirrored mesh.
+    ///
+    /// A polygon can also be clockwise because one of its vertices was moved over one of its
+    /// edges. Reversing it would make it overlap its neighbours, so the mesh is checked with
+    /// [`Mesh::validate`] once fixed. If issues are left, the mesh is not changed and they are
+    /// returned.
+    ///
+    /// Meshes built with a [`MeshBuilder`](crate::MeshBuilder) are already fixed. Meshes loaded
+    /// from a file are kept as they are.
+    pub fn fix_winding(&mut self) -> Result<usize, Vec<MeshIssue>> {
+        let points: Vec<[f32; 2]> = self.vertices.iter().map(|vertex| vertex.p()).collect();
+        let mut polygons: Vec<Vec<usize>> = self
+            .polygons
+            .iter()
+            .map(|polygon| polygon.vertices.clone())
+            .collect();
+        let reversed = fix_winding(&points, &mut polygons);
+        if reversed == 0 {
+            return Ok(0);
+        }
+        let changed: Vec<usize> = (0..polygons.len())
+            .filter(|index| self.polygons[*index].vertices != polygons[*index])
+            .collect();
+        let mirrored: Vec<usize> = (0..self.vertices.len())
+            .filter(|vertex| {
+                let mut around = self.vertices[*vertex].polygons.iter().flatten().peekable();
+                around.peek().is_some()
+                    && around.all(|polygon| changed.binary_search(&polygon.index()).is_ok())
+            })
+            .collect();
+
+        let mut previous = polygons;
+        for index in &changed {
+            std::mem::swap(&mut self.polygons[*index].vertices, &mut previous[*index]);
+        }
+        for vertex in &mirrored {
+            self.vertices[*vertex].polygons.reverse();
+        }
+        let issues = self.validate();
+        if !issues.is_empty() {
+            for index in &changed {
+                std::mem::swap(&mut self.polygons[*index].vertices, &mut previous[*index]);
+            }
+            for vertex in &mirrored {
+                self.vertices[*vertex].polygons.reverse();
+            }
+            return Err(issues);
+        }
+        self.notify_polygons(changed, Aabb::EMPTY, false);
+        Ok(reversed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MeshIssue;
+    use crate::{tests::mesh_u_grid, Mesh, PolygonId};
+
+    #[test]
+    fn valid_meshes() {
+        assert_eq!(mesh_u_grid().validate(), vec![]);
+        assert_eq!(
+            Mesh::from_file("meshes/arena-merged.mesh")
+                .unwrap()
+                .validate(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn invalid_mesh() {
+        let mut mesh = mesh_u_grid();
+        mesh.polygons[0].vertices.reverse();
+        mesh.polygons[2].vertices.truncate(2);
+        mesh.vertices[0].polygons.push(Some(PolygonId::new(4)));
+        mesh.vertices[3].polygons.push(Some(PolygonId::new(7)));
+        let issues = mesh.validate();
+        assert!(issues.contains(&MeshIssue::ClockwisePolygon { polygon: 0 }));
+        assert!(issues.contains(&MeshIssue::DegeneratePolygon { polygon: 2 }));
+        assert!(issues.contains(&MeshIssue::VertexMissingFromPolygon {
+            vertex: 0,
+            polygon: 4
+        }));
+        assert!(issues.contains(&MeshIssue::PolygonOutOfRange {
+            vertex: 3,
+            polygon: 7
+        }));
+        assert!(issues.contains(&MeshIssue::InvalidEdge {
+            edge: [1, 5],
+            polygons: vec![0, 1]
+        }));
+    }
+
+    #[test]
+    fn clockwise() {
+        let mut mesh = mesh_u_grid();
+        let path = mesh.path([0.5, 0.5], [2.5, 1.5]);
+        mesh.polygons[0].vertices.reverse();
+        mesh.polygons[3].vertices.reverse();
+        assert!(mesh
+            .validate()
+            .contains(&MeshIssue::ClockwisePolygon { polygon: 3 }));
+        assert_eq!(mesh.fix_winding(), Ok(2));
+        assert_eq!(mesh.validate(), vec![]);
+        assert_eq!(mesh.path([0.5, 0.5], [2.5, 1.5]), path);
+        assert_eq!(mesh.fix_winding(), Ok(0));
+    }
+
+    #[test]
+    fn mirrored() {
+        let mut mesh = mesh_u_grid();
+        let path = mesh.path([0.5, 0.5], [2.5, 1.5]).unwrap();
+        for vertex in &mut mesh.vertices {
+            vertex.y = -vertex.y;
+        }
+        mesh.rebuild_caches();
+        assert_eq!(mesh.fix_winding(), Ok(mesh.polygons.len()));
+        assert_eq!(mesh.validate(), vec![]);
+        let mirrored = mesh.path([0.5, -0.5], [2.5, -1.5]).unwrap();
+        assert!((mirrored.len - path.len).abs() < 1.0e-5);
+    }
+
+    #[test]
+    fn inverted_polygon() {
+        let mut mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+        // move a vertex over the opposite edge of one of its triangles
+        mesh.vertices[71].x = 2.0;
+        mesh.rebuild_caches();
+        assert!(mesh
+            .validate()
+            .contains(&MeshIssue::ClockwisePolygon { polygon: 86 }));
+        let path = mesh.path([1.0, 3.0], [40.0, 40.0]);
+        assert!(path.is_some());
+
+        assert_eq!(
+            mesh.fix_winding(),
+            Err(vec![
+                MeshIssue::InvalidEdge {
+                    edge: [65, 71],
+                    polygons: vec![60, 86]
+                },
+                MeshIssue::InvalidEdge {
+                    edge: [65, 72],
+                    polygons: vec![83, 86]
+                },
+            ])
+        );
+        // the mesh is kept as it was
+        assert!(mesh
+            .validate()
+            .contains(&MeshIssue::ClockwisePolygon { polygon: 86 }));
+        assert_eq!(mesh.path([1.0, 3.0], [40.0, 40.0]), path);
+    }
+
+    #[test]
+    fn non_convex() {
+        let mut mesh = mesh_u_grid();
+        mesh.vertices[5].x = 0.3;
+        mesh.vertices[5].y = 0.3;
+        let issues = mesh.validate();
+        assert!(issues.contains(&MeshIssue::NonConvexPolygon {
+            polygon: 0,
+            vertex: 5
+        }));
+    }
+}
@@ -0,0 +1,195 @@
+use std::f32::consts::PI;
+
+use crate::{helpers::distance_between, Mesh};
+
+/// Angle on each side of a corner at which rays are cast, to see past it.
+const CORNER_OFFSET: f32 = 1.0e-4;
+
+impl Mesh {
+    /// Compute the area visible from a point, as a polygon in counter-clockwise order, or `None`
+    /// if the point is not in the mesh.
+    ///
+    /// Sight is blocked by the edges that can't be crossed, ignoring doors and one-way edges. It
+    /// is computed by casting rays towards the corners of these edges, and on each side of them.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a pillar in the middle of a room
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| (x, y) != (5, 5));
+    /// let visible = mesh.visibility_polygon([1.5, 5.5]).unwrap();
+    /// assert!(visible.contains(&[10.0, 0.0]));
+    /// assert!(!visible.contains(&[10.0, 5.5]));
+    /// ```
+    pub fn visibility_polygon(&self, from: [f32; 2]) -> Option<Vec<[f32; 2]>> {
+        self.visibility(from, 0.0, PI)
+    }
+
+    /// Compute the area visible from a point within `half_angle` radians on each side of
+    /// `direction`, like the sight of a guard, or `None` if the point is not in the mesh.
+    ///
+    /// The polygon starts at `from` and then goes counter-clockwise, see
+    /// [`Mesh::visibility_polygon`]. A `half_angle` of `PI` or more sees all around.
+    pub fn visibility_cone(
+        &self,
+        from: [f32; 2],
+        direction: [f32; 2],
+        half_angle: f32,
+    ) -> Option<Vec<[f32; 2]>> {
+        self.visibility(from, direction[1].atan2(direction[0]), half_angle)
+    }
+
+    /// Visible area from a point between `facing - half_angle` and `facing + half_angle`.
+    fn visibility(&self, from: [f32; 2], facing: f32, half_angle: f32) -> Option<Vec<[f32; 2]>> {
+        self.point_in_polygon(from)?;
+        let walls: Vec<[[f32; 2]; 2]> = self
+            .boundary_edges()
+            .map(|edge| [self.vertices[edge[0]].p(), self.vertices[edge[1]].p()])
+            .collect();
+        let full = half_angle >= PI;
+        // angle relative to where it's facing, between -PI and PI
+        let relative = |angle: f32| {
+            let angle = (angle - facing) % (2.0 * PI);
+            if angle > PI {
+                angle - 2.0 * PI
+            } else if angle < -PI {
+                angle + 2.0 * PI
+            } else {
+                angle
+            }
+        };
+
+        // rays towards each corner, that end on it if nothing is in the way
+        let mut rays: Vec<(f32, Option<[f32; 2]>)> = walls
+            .iter()
+            .flatten()
+            .filter(|corner| **corner != from)
+            .flat_map(|corner| {
+                let angle = relative((corner[1] - from[1]).atan2(corner[0] - from[0]));
+                [
+                    (angle - CORNER_OFFSET, None),
+                    (angle, Some(*corner)),
+                    (angle + CORNER_OFFSET, None),
+                ]
+            })
+            .filter(|(angle, _)| full || angle.abs() <= half_angle)
+            .collect();
+        if !full {
+            rays.extend([(-half_angle, None), (half_angle, None)]);
+        }
+        rays.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut visible: Vec<[f32; 2]> = if full { vec![] } else { vec![from] };
+        for (angle, corner) in rays {
+            // straight to the corner, without rounding the angle towards it
+            let direction = match corner {
+                Some(corner) => [corner[0] - from[0], corner[1] - from[1]],
+                None => [(facing + angle).cos(), (facing + angle).sin()],
+            };
+            let Some(hit) = first_hit(from, direction, &walls) else {
+                continue;
+            };
+            let hit = match corner {
+                Some(corner)
+                    if distance_between(from, hit)
+                        >= distance_between(from, corner) - CORNER_OFFSET =>
+                {
+                    corner
+                }
+                _ => hit,
+            };
+            if visible.last() != Some(&hit) && visible.first() != Some(&hit) {
+                visible.push(hit);
+            }
+        }
+        Some(visible)
+    }
+}
+
+/// First point where a ray from `from` going along `direction` hits a wall.
+///
+/// Walls only stop rays leaving the mesh, towards their right, so that rays from a point on a
+/// wall can go inside.
+fn first_hit(from: [f32; 2], direction: [f32; 2], walls: &[[[f32; 2]; 2]]) -> Option<[f32; 2]> {
+    let cross = |a: [f32; 2], b: [f32; 2]| a[0] * b[1] - a[1] * b[0];
+    walls
+        .iter()
+        .filter_map(|[start, end]| {
+            let along = [end[0] - start[0], end[1] - start[1]];
+            let denom = cross(direction, along);
+            if denom <= 0.0 {
+                return None;
+            }
+            let offset = [start[0] - from[0], start[1] - from[1]];
+            let t = cross(offset, along) / denom;
+            let u = cross(offset, direction) / denom;
+            (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+        })
+        .min_by(|a, b| a.total_cmp(b))
+        .map(|t| [from[0] + direction[0] * t, from[1] + direction[1] * t])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use crate::{
+        helpers::point_in_simple_polygon,
+        maps::{random_obstacles, test_points},
+        Mesh,
+    };
+
+    #[test]
+    fn visible_area() {
+        let mesh = Mesh::from_grid(10, 10, |_, _| true);
+        let visible = mesh.visibility_polygon([3.0, 4.0]).unwrap();
+        for corner in [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]] {
+            assert!(visible.contains(&corner));
+        }
+        assert!(mesh.visibility_polygon([11.0, 4.0]).is_none());
+
+        // looking right, in a cone of 90°
+        let cone = mesh
+            .visibility_cone([5.0, 5.0], [1.0, 0.0], PI / 4.0)
+            .unwrap();
+        assert_eq!(cone[0], [5.0, 5.0]);
+        assert!(point_in_simple_polygon([9.0, 5.0], &cone));
+        assert!(!point_in_simple_polygon([9.0, 9.5], &cone));
+        assert!(!point_in_simple_polygon([2.0, 5.0], &cone));
+
+        // points that can be reached in a straight line are in the visible area
+        let mesh = random_obstacles(30, 30, 25, 5, 2);
+        for from in [[1.5, 1.5], [15.2, 14.7], [28.5, 3.5]] {
+            let Some(visible) = mesh.visibility_polygon(from) else {
+                continue;
+            };
+            for to in test_points(200, [0.3, 0.6]) {
+                if mesh.point_in_mesh(to) && mesh.is_segment_on_mesh(from, to) {
+                    assert!(point_in_simple_polygon(to, &visible), "{:?} {:?}", from, to);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn from_walls_and_narrow_cones() {
+        let mesh = Mesh::from_grid(10, 10, |_, _| true);
+        // from a corner of the mesh, or a point on a wall
+        for from in [[0.0, 0.0], [5.0, 0.0]] {
+            let visible = mesh.visibility_polygon(from).unwrap();
+            for corner in [[10.0, 0.0], [10.0, 10.0], [0.0, 10.0]] {
+                assert!(visible.contains(&corner), "{:?} {:?}", from, visible);
+            }
+        }
+
+        // a cone without width is a single ray
+        let ray = mesh.visibility_cone([5.0, 5.0], [1.0, 0.0], 0.0).unwrap();
+        assert_eq!(ray, vec![[5.0, 5.0], [10.0, 5.0]]);
+        // or all around
+        let around = mesh.visibility_cone([5.0, 5.0], [0.0, 1.0], PI).unwrap();
+        assert!(!around.contains(&[5.0, 5.0]));
+        for corner in [[0.0, 0.0], [10.0, 0.0], [10.0, 10.0], [0.0, 10.0]] {
+            assert!(around.contains(&corner));
+        }
+        assert!(mesh.visibility_cone([-1.0, 5.0], [1.0, 0.0], 1.0).is_none());
+    }
+}
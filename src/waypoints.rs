@@ -0,0 +1,105 @@
+use crate::{counted, Mesh, Path, SearchContext};
+
+/// Path going through several waypoints, as returned by [`Mesh::path_through`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaypointPath {
+    /// The whole path, from the first waypoint to the last one.
+    pub path: Path,
+    /// The path of each leg, from a waypoint to the next one.
+    pub legs: Vec<Path>,
+}
+
+impl Mesh {
+    /// Compute the shortest path going through all the `points` in order, like a patrol route or
+    /// a chain of deliveries.
+    ///
+    /// Each point is located once, and the searches of all the legs share their memory. Returns
+    /// `None` if there are less than two points, if one of them is not in the mesh, or if a leg
+    /// has no path.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// let mesh = Mesh::from_file("meshes/arena.mesh").unwrap();
+    /// let route = mesh.path_through(&[[1.0, 3.0], [4.0, 2.0], [1.0, 3.0]]).unwrap();
+    /// assert_eq!(route.legs.len(), 2);
+    /// assert_eq!(route.legs[0], mesh.path([1.0, 3.0], [4.0, 2.0]).unwrap());
+    /// assert_eq!(route.path.path.last(), Some(&[1.0, 3.0]));
+    /// ```
+    pub fn path_through(&self, points: &[[f32; 2]]) -> Option<WaypointPath> {
+        counted(Option::is_none, || {
+            if points.len() < 2 {
+                return None;
+            }
+            let located: Vec<_> = points
+                .iter()
+                .map(|point| self.locate_or_clamp(*point))
+                .collect();
+            let mut context = SearchContext::new();
+            let mut route = WaypointPath {
+                path: Path {
+                    path: vec![],
+                    len: 0.0,
+                },
+                legs: Vec::with_capacity(points.len() - 1),
+            };
+            for leg in located.windows(2) {
+                let [(from, from_polygon), (to, to_polygon)] = [leg[0], leg[1]];
+                let path = self.path_between_polygons_with_context(
+                    from,
+                    to,
+                    from_polygon,
+                    to_polygon,
+                    &mut context,
+                )?;
+                route.path.path.extend(&path.path);
+                route.path.len += path.len;
+                route.legs.push(path);
+            }
+            Some(route)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::maps::{maze, test_meshes};
+
+    #[test]
+    fn chained_legs() {
+        for mesh in test_meshes() {
+            let points = [
+                [1.5, 1.5],
+                [13.5, 1.5],
+                [13.5, 13.5],
+                [1.5, 13.5],
+                [1.5, 1.5],
+            ];
+            let legs: Vec<_> = points
+                .windows(2)
+                .map(|leg| mesh.path(leg[0], leg[1]))
+                .collect();
+            let Some(legs) = legs.into_iter().collect::<Option<Vec<_>>>() else {
+                assert!(mesh.path_through(&points).is_none());
+                continue;
+            };
+            let route = mesh.path_through(&points).unwrap();
+            assert_eq!(route.legs, legs);
+            let len: f32 = legs.iter().map(|leg| leg.len).sum();
+            assert!((route.path.len - len).abs() < 1.0e-3);
+            assert_eq!(
+                route.path.path.len(),
+                legs.iter().map(|leg| leg.path.len()).sum::<usize>()
+            );
+            // each waypoint is on the path
+            for point in &points[1..] {
+                assert!(route.path.path.contains(point));
+            }
+        }
+
+        let mesh = maze(2, 2, 0);
+        assert!(mesh.path_through(&[[1.5, 1.5]]).is_none());
+        assert!(mesh
+            .path_through(&[[1.5, 1.5], [-1.0, 0.0], [1.5, 1.5]])
+            .is_none());
+    }
+}
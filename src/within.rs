@@ -0,0 +1,96 @@
+use crate::{Mesh, Path, SearchOptions};
+
+impl Mesh {
+    /// Compute the shortest path from `from` to `to` like [`Mesh::path`], if it's not longer
+    /// than `max_len`.
+    ///
+    /// The search gives up on nodes that can't lead to a path short enough, so checking if a
+    /// target is close by walking costs much less than a full search when it isn't. Returns
+    /// `None` if the shortest path is longer, if there is no path, or if `max_len` is NaN.
+    ///
+    /// ```
+    /// # use polyanya::Mesh;
+    /// // a wall between the two points
+    /// let mesh = Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9);
+    /// assert!(mesh.path_within([4.0, 1.0], [6.0, 1.0], 10.0).is_none());
+    /// assert!(mesh.path_within([4.0, 1.0], [6.0, 1.0], 20.0).is_some());
+    /// ```
+    pub fn path_within(&self, from: [f32; 2], to: [f32; 2], max_len: f32) -> Option<Path> {
+        if max_len.is_nan() {
+            return None;
+        }
+        let (from, from_polygon) = self.locate_or_clamp(from);
+        let (to, to_polygon) = self.locate_or_clamp(to);
+        self.path_between_polygons(
+            from,
+            to,
+            from_polygon,
+            to_polygon,
+            SearchOptions {
+                max_len,
+                ..SearchOptions::default()
+            },
+            |_| (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        maps::{maze, test_meshes, test_points},
+        Mesh, SearchOptions,
+    };
+
+    #[test]
+    fn cut_off_long_paths() {
+        for mesh in test_meshes() {
+            let from = [1.5, 1.5];
+            for to in test_points(60, [0.5, 0.5]) {
+                let Some(path) = mesh.path(from, to) else {
+                    assert!(mesh.path_within(from, to, f32::INFINITY).is_none());
+                    continue;
+                };
+                assert_eq!(
+                    mesh.path_within(from, to, path.len + 1.0e-3),
+                    Some(path.clone())
+                );
+                assert!(mesh.path_within(from, to, path.len - 1.0e-3).is_none());
+            }
+        }
+
+        // fewer nodes expanded than a full search
+        let mesh = maze(8, 8, 5);
+        let (from, to) = ([1.5, 1.5], [15.5, 15.5]);
+        let expanded = |max_len| {
+            let mut expanded = 0;
+            mesh.path_between_polygons(
+                from,
+                to,
+                mesh.point_in_polygon(from),
+                mesh.point_in_polygon(to),
+                SearchOptions {
+                    max_len,
+                    ..SearchOptions::default()
+                },
+                |_| expanded += 1,
+            );
+            expanded
+        };
+        assert!(expanded(5.0) < expanded(f32::INFINITY));
+    }
+
+    #[test]
+    fn limits() {
+        // a single polygon
+        let mesh = Mesh::from_grid(10, 1, |_, _| true);
+        let (from, to) = ([0.5, 0.5], [9.5, 0.5]);
+        assert_eq!(mesh.path_within(from, to, 9.0), mesh.path(from, to));
+        assert!(mesh.path_within(from, to, 8.9).is_none());
+        assert_eq!(mesh.path_within(from, from, 0.0).unwrap().len, 0.0);
+        assert!(mesh.path_within(from, to, 0.0).is_none());
+        assert!(mesh.path_within(from, from, -1.0).is_none());
+        assert!(mesh.path_within(from, to, f32::NAN).is_none());
+        assert!(mesh.path_within(from, [20.0, 0.5], f32::INFINITY).is_none());
+    }
+}
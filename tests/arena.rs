@@ -1,8 +1,11 @@
+use std::f32::consts::SQRT_2;
+
 use polyanya::{Mesh, Polygon, Vertex};
 
 macro_rules! assert_delta {
     ($x:expr, $y:expr) => {
         let val = $x;
+        #[allow(clippy::neg_cmp_op_on_partial_ord)]
         if !((val - $y).abs() < 0.0001) {
             assert_eq!(val, $y);
         }
@@ -10,122 +13,122 @@ macro_rules! assert_delta {
 }
 
 fn arena_mesh() -> Mesh {
-    Mesh {
-        vertices: vec![
-            Vertex::new(2, 2, vec![-1, 4]),
-            Vertex::new(1, 3, vec![15, -1]),
-            Vertex::new(2, 3, vec![1, 15, -1, 4, 0, 14]),
-            Vertex::new(3, 2, vec![2, 0, 4, -1, 5]),
-            Vertex::new(3, 1, vec![5, -1]),
-            Vertex::new(15, 1, vec![-1, 2, 5]),
-            Vertex::new(15, 3, vec![6, 3, 14, 0, 2, -1]),
-            Vertex::new(18, 3, vec![95, 37, 6, -1, 12, 7, 92]),
-            Vertex::new(18, 2, vec![-1, 12]),
-            Vertex::new(19, 1, vec![9, -1]),
-            Vertex::new(20, 1, vec![-1, 10, 9]),
-            Vertex::new(20, 2, vec![11, 101, 92, 7, 10, -1]),
-            Vertex::new(19, 2, vec![10, 7, 12, -1, 9]),
-            Vertex::new(23, 2, vec![11, -1, 62, 20, 100]),
-            Vertex::new(23, 1, vec![62, -1]),
-            Vertex::new(23, 8, vec![95, 92, 101, 104, -1]),
-            Vertex::new(24, 7, vec![108, -1, 104, 101, 11, 100]),
-            Vertex::new(24, 8, vec![-1, 104]),
-            Vertex::new(23, 10, vec![105, 106, 37, 95, -1]),
-            Vertex::new(15, 15, vec![37, 106, -1, 99, 91, 3, 6]),
-            Vertex::new(18, 18, vec![-1, 94]),
-            Vertex::new(19, 15, vec![105, 107, 42, -1, 106]),
-            Vertex::new(19, 18, vec![93, 94, -1, 42, 13]),
-            Vertex::new(18, 19, vec![117, 115, 8, -1, 94, 93]),
-            Vertex::new(15, 19, vec![17, 24, 98, 99, -1, 8]),
-            Vertex::new(3, 15, vec![3, 91, -1, 1, 14]),
-            Vertex::new(1, 15, vec![-1, 15, 1]),
-            Vertex::new(3, 18, vec![99, 98, 16, -1, 91]),
-            Vertex::new(2, 18, vec![16, -1]),
-            Vertex::new(2, 23, vec![24, 40, 39, -1, 16, 98]),
-            Vertex::new(1, 23, vec![39, 38, -1]),
-            Vertex::new(3, 48, vec![-1, 18]),
-            Vertex::new(15, 48, vec![18, 27, -1]),
-            Vertex::new(19, 48, vec![-1, 21]),
-            Vertex::new(20, 48, vec![-1, 21, 19]),
-            Vertex::new(24, 48, vec![52, -1]),
-            Vertex::new(24, 47, vec![36, 52, -1, 22, 50]),
-            Vertex::new(23, 46, vec![82, 50, 22, -1, 75, 114]),
-            Vertex::new(23, 47, vec![-1, 22]),
-            Vertex::new(19, 47, vec![19, 21, -1, 23]),
-            Vertex::new(20, 46, vec![47, 75, -1, 19, 23, 111]),
-            Vertex::new(15, 47, vec![112, 111, 23, -1, 27]),
-            Vertex::new(3, 47, vec![30, 112, 27, 18, -1, 26, 25]),
-            Vertex::new(1, 47, vec![28, 26, -1]),
-            Vertex::new(1, 35, vec![-1, 28]),
-            Vertex::new(2, 35, vec![25, 26, 28, -1, 29]),
-            Vertex::new(2, 34, vec![-1, 29]),
-            Vertex::new(3, 34, vec![30, 25, 29, -1, 110, 31]),
-            Vertex::new(1, 31, vec![32, 33, -1]),
-            Vertex::new(3, 31, vec![34, 110, -1, 33]),
-            Vertex::new(3, 30, vec![97, 34, 33, 32, -1]),
-            Vertex::new(1, 30, vec![-1, 32]),
-            Vertex::new(3, 27, vec![17, 97, -1, 41, 40, 24]),
-            Vertex::new(2, 27, vec![-1, 41]),
-            Vertex::new(1, 26, vec![-1, 38]),
-            Vertex::new(2, 26, vec![-1, 38, 39, 40, 41]),
-            Vertex::new(15, 31, vec![115, -1, 31, 110, 34, 97, 17, 8]),
-            Vertex::new(15, 35, vec![-1, 47, 111, 112, 30, 31]),
-            Vertex::new(18, 35, vec![114, 75, 47, -1, 113]),
-            Vertex::new(18, 34, vec![113, -1]),
-            Vertex::new(19, 31, vec![116, -1, 115, 117, 96]),
-            Vertex::new(19, 34, vec![82, 114, 113, -1, 116, 118, 119]),
-            Vertex::new(31, 31, vec![118, 116, 96, 51, 85, -1]),
-            Vertex::new(31, 35, vec![119, 118, -1, 45, 84]),
-            Vertex::new(34, 34, vec![-1, 35]),
-            Vertex::new(34, 35, vec![35, 88, 60, 86, 83, 45, -1]),
-            Vertex::new(35, 34, vec![-1, 90, 87, 88, 35]),
-            Vertex::new(35, 31, vec![-1, 85, 89, 74, 90]),
-            Vertex::new(47, 31, vec![74, 59, -1, 87, 90]),
-            Vertex::new(47, 35, vec![88, 87, -1, 54, 43, 60]),
-            Vertex::new(47, 47, vec![54, 56, -1, 44, 43]),
-            Vertex::new(35, 47, vec![86, 60, 43, 44, 55, -1]),
-            Vertex::new(31, 47, vec![83, 86, -1, 48, 46]),
-            Vertex::new(29, 46, vec![45, 83, 46, 49, -1, 84]),
-            Vertex::new(29, 47, vec![-1, 49]),
-            Vertex::new(30, 47, vec![48, 53, -1, 49, 46]),
-            Vertex::new(26, 46, vec![82, 119, 84, -1, 36, 50]),
-            Vertex::new(26, 48, vec![-1, 52, 36]),
-            Vertex::new(30, 48, vec![53, -1]),
-            Vertex::new(31, 48, vec![-1, 53, 48]),
-            Vertex::new(35, 48, vec![55, -1]),
-            Vertex::new(47, 48, vec![-1, 55, 44]),
-            Vertex::new(48, 47, vec![56, -1]),
-            Vertex::new(48, 35, vec![-1, 56, 54]),
-            Vertex::new(48, 31, vec![59, 58, -1]),
-            Vertex::new(48, 3, vec![-1, 72]),
-            Vertex::new(48, 15, vec![57, 72, -1]),
-            Vertex::new(48, 19, vec![-1, 58]),
-            Vertex::new(47, 19, vec![74, 89, 79, 78, -1, 58, 59]),
-            Vertex::new(47, 15, vec![73, 57, -1, 78, 81]),
-            Vertex::new(34, 19, vec![89, 85, 51, -1, 65, 79]),
-            Vertex::new(31, 19, vec![96, 117, 93, 13, -1, 51]),
-            Vertex::new(31, 15, vec![76, 77, 66, -1, 13, 42, 107]),
-            Vertex::new(34, 18, vec![-1, 65]),
-            Vertex::new(35, 18, vec![-1, 81, 78, 79, 65]),
-            Vertex::new(35, 15, vec![-1, 66, 80, 73, 81]),
-            Vertex::new(26, 10, vec![103, 76, 107, 105, -1]),
-            Vertex::new(26, 7, vec![103, -1, 108, 102, 109]),
-            Vertex::new(26, 3, vec![102, 108, 100, 20, -1]),
-            Vertex::new(26, 1, vec![-1, 20, 62]),
-            Vertex::new(29, 3, vec![109, 102, -1, 63, 67]),
-            Vertex::new(30, 2, vec![61, 67, 63, -1, 64]),
-            Vertex::new(29, 2, vec![-1, 63]),
-            Vertex::new(30, 1, vec![64, -1]),
-            Vertex::new(31, 1, vec![-1, 61, 64]),
-            Vertex::new(31, 3, vec![103, 109, 67, 61, -1, 77, 76]),
-            Vertex::new(34, 3, vec![80, 66, 77, -1, 71, 68]),
-            Vertex::new(34, 2, vec![-1, 71]),
-            Vertex::new(35, 1, vec![70, -1]),
-            Vertex::new(35, 2, vec![70, 69, 68, 71, -1]),
-            Vertex::new(47, 3, vec![73, 80, 68, 69, -1, 72, 57]),
-            Vertex::new(47, 1, vec![70, -1, 69]),
+    Mesh::new(
+        vec![
+            Vertex::new(2.0, 2.0, vec![-1, 4]),
+            Vertex::new(1.0, 3.0, vec![15, -1]),
+            Vertex::new(2.0, 3.0, vec![1, 15, -1, 4, 0, 14]),
+            Vertex::new(3.0, 2.0, vec![2, 0, 4, -1, 5]),
+            Vertex::new(3.0, 1.0, vec![5, -1]),
+            Vertex::new(15.0, 1.0, vec![-1, 2, 5]),
+            Vertex::new(15.0, 3.0, vec![6, 3, 14, 0, 2, -1]),
+            Vertex::new(18.0, 3.0, vec![95, 37, 6, -1, 12, 7, 92]),
+            Vertex::new(18.0, 2.0, vec![-1, 12]),
+            Vertex::new(19.0, 1.0, vec![9, -1]),
+            Vertex::new(20.0, 1.0, vec![-1, 10, 9]),
+            Vertex::new(20.0, 2.0, vec![11, 101, 92, 7, 10, -1]),
+            Vertex::new(19.0, 2.0, vec![10, 7, 12, -1, 9]),
+            Vertex::new(23.0, 2.0, vec![11, -1, 62, 20, 100]),
+            Vertex::new(23.0, 1.0, vec![62, -1]),
+            Vertex::new(23.0, 8.0, vec![95, 92, 101, 104, -1]),
+            Vertex::new(24.0, 7.0, vec![108, -1, 104, 101, 11, 100]),
+            Vertex::new(24.0, 8.0, vec![-1, 104]),
+            Vertex::new(23.0, 10.0, vec![105, 106, 37, 95, -1]),
+            Vertex::new(15.0, 15.0, vec![37, 106, -1, 99, 91, 3, 6]),
+            Vertex::new(18.0, 18.0, vec![-1, 94]),
+            Vertex::new(19.0, 15.0, vec![105, 107, 42, -1, 106]),
+            Vertex::new(19.0, 18.0, vec![93, 94, -1, 42, 13]),
+            Vertex::new(18.0, 19.0, vec![117, 115, 8, -1, 94, 93]),
+            Vertex::new(15.0, 19.0, vec![17, 24, 98, 99, -1, 8]),
+            Vertex::new(3.0, 15.0, vec![3, 91, -1, 1, 14]),
+            Vertex::new(1.0, 15.0, vec![-1, 15, 1]),
+            Vertex::new(3.0, 18.0, vec![99, 98, 16, -1, 91]),
+            Vertex::new(2.0, 18.0, vec![16, -1]),
+            Vertex::new(2.0, 23.0, vec![24, 40, 39, -1, 16, 98]),
+            Vertex::new(1.0, 23.0, vec![39, 38, -1]),
+            Vertex::new(3.0, 48.0, vec![-1, 18]),
+            Vertex::new(15.0, 48.0, vec![18, 27, -1]),
+            Vertex::new(19.0, 48.0, vec![-1, 21]),
+            Vertex::new(20.0, 48.0, vec![-1, 21, 19]),
+            Vertex::new(24.0, 48.0, vec![52, -1]),
+            Vertex::new(24.0, 47.0, vec![36, 52, -1, 22, 50]),
+            Vertex::new(23.0, 46.0, vec![82, 50, 22, -1, 75, 114]),
+            Vertex::new(23.0, 47.0, vec![-1, 22]),
+            Vertex::new(19.0, 47.0, vec![19, 21, -1, 23]),
+            Vertex::new(20.0, 46.0, vec![47, 75, -1, 19, 23, 111]),
+            Vertex::new(15.0, 47.0, vec![112, 111, 23, -1, 27]),
+            Vertex::new(3.0, 47.0, vec![30, 112, 27, 18, -1, 26, 25]),
+            Vertex::new(1.0, 47.0, vec![28, 26, -1]),
+            Vertex::new(1.0, 35.0, vec![-1, 28]),
+            Vertex::new(2.0, 35.0, vec![25, 26, 28, -1, 29]),
+            Vertex::new(2.0, 34.0, vec![-1, 29]),
+            Vertex::new(3.0, 34.0, vec![30, 25, 29, -1, 110, 31]),
+            Vertex::new(1.0, 31.0, vec![32, 33, -1]),
+            Vertex::new(3.0, 31.0, vec![34, 110, -1, 33]),
+            Vertex::new(3.0, 30.0, vec![97, 34, 33, 32, -1]),
+            Vertex::new(1.0, 30.0, vec![-1, 32]),
+            Vertex::new(3.0, 27.0, vec![17, 97, -1, 41, 40, 24]),
+            Vertex::new(2.0, 27.0, vec![-1, 41]),
+            Vertex::new(1.0, 26.0, vec![-1, 38]),
+            Vertex::new(2.0, 26.0, vec![-1, 38, 39, 40, 41]),
+            Vertex::new(15.0, 31.0, vec![115, -1, 31, 110, 34, 97, 17, 8]),
+            Vertex::new(15.0, 35.0, vec![-1, 47, 111, 112, 30, 31]),
+            Vertex::new(18.0, 35.0, vec![114, 75, 47, -1, 113]),
+            Vertex::new(18.0, 34.0, vec![113, -1]),
+            Vertex::new(19.0, 31.0, vec![116, -1, 115, 117, 96]),
+            Vertex::new(19.0, 34.0, vec![82, 114, 113, -1, 116, 118, 119]),
+            Vertex::new(31.0, 31.0, vec![118, 116, 96, 51, 85, -1]),
+            Vertex::new(31.0, 35.0, vec![119, 118, -1, 45, 84]),
+            Vertex::new(34.0, 34.0, vec![-1, 35]),
+            Vertex::new(34.0, 35.0, vec![35, 88, 60, 86, 83, 45, -1]),
+            Vertex::new(35.0, 34.0, vec![-1, 90, 87, 88, 35]),
+            Vertex::new(35.0, 31.0, vec![-1, 85, 89, 74, 90]),
+            Vertex::new(47.0, 31.0, vec![74, 59, -1, 87, 90]),
+            Vertex::new(47.0, 35.0, vec![88, 87, -1, 54, 43, 60]),
+            Vertex::new(47.0, 47.0, vec![54, 56, -1, 44, 43]),
+            Vertex::new(35.0, 47.0, vec![86, 60, 43, 44, 55, -1]),
+            Vertex::new(31.0, 47.0, vec![83, 86, -1, 48, 46]),
+            Vertex::new(29.0, 46.0, vec![45, 83, 46, 49, -1, 84]),
+            Vertex::new(29.0, 47.0, vec![-1, 49]),
+            Vertex::new(30.0, 47.0, vec![48, 53, -1, 49, 46]),
+            Vertex::new(26.0, 46.0, vec![82, 119, 84, -1, 36, 50]),
+            Vertex::new(26.0, 48.0, vec![-1, 52, 36]),
+            Vertex::new(30.0, 48.0, vec![53, -1]),
+            Vertex::new(31.0, 48.0, vec![-1, 53, 48]),
+            Vertex::new(35.0, 48.0, vec![55, -1]),
+            Vertex::new(47.0, 48.0, vec![-1, 55, 44]),
+            Vertex::new(48.0, 47.0, vec![56, -1]),
+            Vertex::new(48.0, 35.0, vec![-1, 56, 54]),
+            Vertex::new(48.0, 31.0, vec![59, 58, -1]),
+            Vertex::new(48.0, 3.0, vec![-1, 72]),
+            Vertex::new(48.0, 15.0, vec![57, 72, -1]),
+            Vertex::new(48.0, 19.0, vec![-1, 58]),
+            Vertex::new(47.0, 19.0, vec![74, 89, 79, 78, -1, 58, 59]),
+            Vertex::new(47.0, 15.0, vec![73, 57, -1, 78, 81]),
+            Vertex::new(34.0, 19.0, vec![89, 85, 51, -1, 65, 79]),
+            Vertex::new(31.0, 19.0, vec![96, 117, 93, 13, -1, 51]),
+            Vertex::new(31.0, 15.0, vec![76, 77, 66, -1, 13, 42, 107]),
+            Vertex::new(34.0, 18.0, vec![-1, 65]),
+            Vertex::new(35.0, 18.0, vec![-1, 81, 78, 79, 65]),
+            Vertex::new(35.0, 15.0, vec![-1, 66, 80, 73, 81]),
+            Vertex::new(26.0, 10.0, vec![103, 76, 107, 105, -1]),
+            Vertex::new(26.0, 7.0, vec![103, -1, 108, 102, 109]),
+            Vertex::new(26.0, 3.0, vec![102, 108, 100, 20, -1]),
+            Vertex::new(26.0, 1.0, vec![-1, 20, 62]),
+            Vertex::new(29.0, 3.0, vec![109, 102, -1, 63, 67]),
+            Vertex::new(30.0, 2.0, vec![61, 67, 63, -1, 64]),
+            Vertex::new(29.0, 2.0, vec![-1, 63]),
+            Vertex::new(30.0, 1.0, vec![64, -1]),
+            Vertex::new(31.0, 1.0, vec![-1, 61, 64]),
+            Vertex::new(31.0, 3.0, vec![103, 109, 67, 61, -1, 77, 76]),
+            Vertex::new(34.0, 3.0, vec![80, 66, 77, -1, 71, 68]),
+            Vertex::new(34.0, 2.0, vec![-1, 71]),
+            Vertex::new(35.0, 1.0, vec![70, -1]),
+            Vertex::new(35.0, 2.0, vec![70, 69, 68, 71, -1]),
+            Vertex::new(47.0, 3.0, vec![73, 80, 68, 69, -1, 72, 57]),
+            Vertex::new(47.0, 1.0, vec![70, -1, 69]),
         ],
-        polygons: vec![
+        vec![
             Polygon::new(3, vec![6, 2, 3, 2, 14, 4]),
             Polygon::new(3, vec![26, 2, 25, -1, 15, 14]),
             Polygon::new(3, vec![6, 3, 5, -1, 0, 5]),
@@ -247,171 +250,171 @@ fn arena_mesh() -> Mesh {
             Polygon::new(3, vec![61, 62, 63, 119, 116, -1]),
             Polygon::new(3, vec![61, 63, 76, 82, 118, 84]),
         ],
-    }
+    )
 }
 
 #[test]
 fn arena_scenario_ref_impl() {
     let arena = arena_mesh();
 
-    assert_delta!(arena.path([1.0, 11.0], [1.0, 12.0]).len, 1.0);
-    assert_delta!(arena.path([1.0, 12.0], [1.0, 10.0]).len, 2.0);
-    assert_delta!(arena.path([1.0, 13.0], [4.0, 12.0]).len, 3.16228);
-    assert_delta!(arena.path([1.0, 3.0], [3.0, 1.0]).len, 3.41421);
-    assert_delta!(arena.path([1.0, 3.0], [4.0, 3.0]).len, 3.0);
-    assert_delta!(arena.path([1.0, 4.0], [4.0, 2.0]).len, 3.60555);
-    assert_delta!(arena.path([1.0, 40.0], [2.0, 39.0]).len, 1.41421);
-    assert_delta!(arena.path([1.0, 41.0], [1.0, 39.0]).len, 2.0);
-    assert_delta!(arena.path([1.0, 41.0], [1.0, 44.0]).len, 3.0);
-    assert_delta!(arena.path([1.0, 42.0], [4.0, 43.0]).len, 3.16228);
-    assert_delta!(arena.path([1.0, 10.0], [7.0, 10.0]).len, 6.0);
-    assert_delta!(arena.path([1.0, 11.0], [1.0, 4.0]).len, 7.0);
-    assert_delta!(arena.path([1.0, 11.0], [7.0, 14.0]).len, 6.7082);
-    assert_delta!(arena.path([1.0, 12.0], [5.0, 7.0]).len, 6.40312);
-    assert_delta!(arena.path([1.0, 12.0], [6.0, 15.0]).len, 5.83095);
-    assert_delta!(arena.path([1.0, 12.0], [8.0, 11.0]).len, 7.07107);
-    assert_delta!(arena.path([1.0, 14.0], [1.0, 9.0]).len, 5.0);
-    assert_delta!(arena.path([1.0, 24.0], [7.0, 26.0]).len, 6.32456);
-    assert_delta!(arena.path([1.0, 25.0], [5.0, 25.0]).len, 4.0);
-    assert_delta!(arena.path([1.0, 35.0], [5.0, 33.0]).len, 4.60555);
-    assert_delta!(arena.path([1.0, 11.0], [4.0, 18.0]).len, 7.63441);
-    assert_delta!(arena.path([1.0, 12.0], [12.0, 14.0]).len, 11.1803);
-    assert_delta!(arena.path([1.0, 13.0], [4.0, 23.0]).len, 10.8907);
-    assert_delta!(arena.path([1.0, 13.0], [5.0, 3.0]).len, 10.7703);
-    assert_delta!(arena.path([1.0, 13.0], [6.0, 7.0]).len, 7.81025);
-    assert_delta!(arena.path([1.0, 13.0], [7.0, 7.0]).len, 8.48528);
-    assert_delta!(arena.path([1.0, 23.0], [7.0, 32.0]).len, 10.8167);
-    assert_delta!(arena.path([1.0, 24.0], [11.0, 25.0]).len, 10.0499);
-    assert_delta!(arena.path([1.0, 24.0], [6.0, 32.0]).len, 9.4365);
-    assert_delta!(arena.path([1.0, 25.0], [9.0, 24.0]).len, 8.06226);
-    assert_delta!(arena.path([1.0, 10.0], [11.0, 19.0]).len, 13.4536);
-    assert_delta!(arena.path([1.0, 10.0], [13.0, 11.0]).len, 12.0416);
-    assert_delta!(arena.path([1.0, 11.0], [10.0, 2.0]).len, 12.7279);
-    assert_delta!(arena.path([1.0, 12.0], [11.0, 21.0]).len, 13.4536);
-    assert_delta!(arena.path([1.0, 12.0], [13.0, 13.0]).len, 12.0416);
-    assert_delta!(arena.path([1.0, 12.0], [14.0, 12.0]).len, 13.0);
-    assert_delta!(arena.path([1.0, 12.0], [6.0, 25.0]).len, 14.0459);
-    assert_delta!(arena.path([1.0, 13.0], [11.0, 3.0]).len, 14.1421);
-    assert_delta!(arena.path([1.0, 13.0], [13.0, 11.0]).len, 12.1655);
-    assert_delta!(arena.path([1.0, 14.0], [6.0, 23.0]).len, 10.7801);
-    assert_delta!(arena.path([1.0, 10.0], [18.0, 11.0]).len, 17.0294);
-    assert_delta!(arena.path([1.0, 11.0], [16.0, 14.0]).len, 15.2971);
-    assert_delta!(arena.path([1.0, 12.0], [14.0, 2.0]).len, 16.4012);
-    assert_delta!(arena.path([1.0, 12.0], [17.0, 13.0]).len, 16.0312);
-    assert_delta!(arena.path([1.0, 12.0], [9.0, 28.0]).len, 17.9234);
-    assert_delta!(arena.path([1.0, 13.0], [4.0, 30.0]).len, 17.8617);
-    assert_delta!(arena.path([1.0, 13.0], [9.0, 26.0]).len, 15.3584);
-    assert_delta!(arena.path([1.0, 14.0], [14.0, 22.0]).len, 15.2745);
-    assert_delta!(arena.path([1.0, 23.0], [10.0, 8.0]).len, 18.0);
-    assert_delta!(arena.path([1.0, 23.0], [14.0, 9.0]).len, 19.4391);
-    assert_delta!(arena.path([1.0, 10.0], [13.0, 29.0]).len, 22.4722);
-    assert_delta!(arena.path([1.0, 10.0], [18.0, 22.0]).len, 20.8087);
-    assert_delta!(arena.path([1.0, 10.0], [19.0, 18.0]).len, 21.0575);
-    assert_delta!(arena.path([1.0, 10.0], [21.0, 2.0]).len, 21.5407);
-    assert_delta!(arena.path([1.0, 10.0], [5.0, 32.0]).len, 22.5024);
-    assert_delta!(arena.path([1.0, 10.0], [6.0, 29.0]).len, 19.703);
-    assert_delta!(arena.path([1.0, 11.0], [20.0, 7.0]).len, 19.4165);
-    assert_delta!(arena.path([1.0, 11.0], [21.0, 17.0]).len, 21.2675);
-    assert_delta!(arena.path([1.0, 11.0], [22.0, 16.0]).len, 21.6014);
-    assert_delta!(arena.path([1.0, 11.0], [8.0, 29.0]).len, 19.3382);
-    assert_delta!(arena.path([1.0, 10.0], [22.0, 22.0]).len, 24.2591);
-    assert_delta!(arena.path([1.0, 10.0], [5.0, 33.0]).len, 23.4959);
-    assert_delta!(arena.path([1.0, 11.0], [10.0, 32.0]).len, 22.8569);
-    assert_delta!(arena.path([1.0, 11.0], [20.0, 31.0]).len, 27.5862);
-    assert_delta!(arena.path([1.0, 11.0], [21.0, 23.0]).len, 23.3238);
-    assert_delta!(arena.path([1.0, 11.0], [24.0, 14.0]).len, 23.1948);
-    assert_delta!(arena.path([1.0, 11.0], [25.0, 4.0]).len, 25.0);
-    assert_delta!(arena.path([1.0, 11.0], [3.0, 36.0]).len, 25.4721);
-    assert_delta!(arena.path([1.0, 12.0], [19.0, 27.0]).len, 23.4307);
-    assert_delta!(arena.path([1.0, 12.0], [2.0, 37.0]).len, 25.7678);
-    assert_delta!(arena.path([1.0, 10.0], [22.0, 31.0]).len, 29.6985);
-    assert_delta!(arena.path([1.0, 10.0], [24.0, 27.0]).len, 28.6007);
-    assert_delta!(arena.path([1.0, 10.0], [28.0, 15.0]).len, 27.4591);
-    assert_delta!(arena.path([1.0, 10.0], [7.0, 39.0]).len, 29.7162);
-    assert_delta!(arena.path([1.0, 11.0], [12.0, 35.0]).len, 26.4038);
-    assert_delta!(arena.path([1.0, 11.0], [28.0, 18.0]).len, 27.9259);
-    assert_delta!(arena.path([1.0, 11.0], [5.0, 40.0]).len, 29.552);
-    assert_delta!(arena.path([1.0, 12.0], [26.0, 3.0]).len, 26.5707);
-    assert_delta!(arena.path([1.0, 12.0], [29.0, 14.0]).len, 28.0713);
-    assert_delta!(arena.path([1.0, 12.0], [29.0, 6.0]).len, 28.6362);
-    assert_delta!(arena.path([1.0, 10.0], [25.0, 36.0]).len, 35.3836);
-    assert_delta!(arena.path([1.0, 10.0], [27.0, 25.0]).len, 30.0597);
-    assert_delta!(arena.path([1.0, 10.0], [32.0, 4.0]).len, 31.5753);
-    assert_delta!(arena.path([1.0, 10.0], [33.0, 4.0]).len, 32.5576);
-    assert_delta!(arena.path([1.0, 11.0], [10.0, 42.0]).len, 32.3648);
-    assert_delta!(arena.path([1.0, 11.0], [27.0, 28.0]).len, 31.0644);
-    assert_delta!(arena.path([1.0, 11.0], [30.0, 2.0]).len, 30.5347);
-    assert_delta!(arena.path([1.0, 11.0], [31.0, 3.0]).len, 31.0483);
-    assert_delta!(arena.path([1.0, 11.0], [5.0, 42.0]).len, 31.5461);
-    assert_delta!(arena.path([1.0, 12.0], [18.0, 37.0]).len, 30.5349);
-    assert_delta!(arena.path([1.0, 10.0], [15.0, 43.0]).len, 35.8469);
-    assert_delta!(arena.path([1.0, 10.0], [21.0, 41.0]).len, 37.1384);
-    assert_delta!(arena.path([1.0, 10.0], [27.0, 37.0]).len, 37.4833);
-    assert_delta!(arena.path([1.0, 10.0], [29.0, 38.0]).len, 39.598);
-    assert_delta!(arena.path([1.0, 10.0], [31.0, 25.0]).len, 33.7313);
-    assert_delta!(arena.path([1.0, 10.0], [38.0, 13.0]).len, 37.1214);
-    assert_delta!(arena.path([1.0, 10.0], [38.0, 6.0]).len, 37.2305);
-    assert_delta!(arena.path([1.0, 10.0], [39.0, 6.0]).len, 38.2281);
-    assert_delta!(arena.path([1.0, 10.0], [40.0, 9.0]).len, 39.0357);
-    assert_delta!(arena.path([1.0, 11.0], [11.0, 43.0]).len, 33.5926);
-    assert_delta!(arena.path([1.0, 10.0], [12.0, 47.0]).len, 38.6267);
-    assert_delta!(arena.path([1.0, 10.0], [14.0, 47.0]).len, 39.223);
-    assert_delta!(arena.path([1.0, 10.0], [16.0, 46.0]).len, 39.0);
-    assert_delta!(arena.path([1.0, 10.0], [28.0, 41.0]).len, 41.1096);
-    assert_delta!(arena.path([1.0, 10.0], [37.0, 21.0]).len, 37.6552);
-    assert_delta!(arena.path([1.0, 10.0], [39.0, 24.0]).len, 40.6133);
-    assert_delta!(arena.path([1.0, 11.0], [16.0, 45.0]).len, 37.1677);
-    assert_delta!(arena.path([1.0, 11.0], [21.0, 43.0]).len, 37.7849);
-    assert_delta!(arena.path([1.0, 11.0], [32.0, 39.0]).len, 41.7732);
-    assert_delta!(arena.path([1.0, 11.0], [34.0, 29.0]).len, 37.5954);
-    assert_delta!(arena.path([1.0, 10.0], [29.0, 43.0]).len, 43.2791);
-    assert_delta!(arena.path([1.0, 10.0], [43.0, 15.0]).len, 42.2966);
-    assert_delta!(arena.path([1.0, 10.0], [43.0, 17.0]).len, 42.6119);
-    assert_delta!(arena.path([1.0, 10.0], [45.0, 10.0]).len, 44.0);
-    assert_delta!(arena.path([1.0, 10.0], [46.0, 15.0]).len, 45.2769);
-    assert_delta!(arena.path([1.0, 10.0], [46.0, 3.0]).len, 45.5412);
-    assert_delta!(arena.path([1.0, 11.0], [30.0, 45.0]).len, 44.7117);
-    assert_delta!(arena.path([1.0, 11.0], [35.0, 41.0]).len, 45.3431);
-    assert_delta!(arena.path([1.0, 11.0], [35.0, 42.0]).len, 46.0109);
-    assert_delta!(arena.path([1.0, 11.0], [43.0, 3.0]).len, 42.7551);
-    assert_delta!(arena.path([1.0, 10.0], [31.0, 46.0]).len, 46.868);
-    assert_delta!(arena.path([1.0, 10.0], [34.0, 46.0]).len, 48.8365);
-    assert_delta!(arena.path([1.0, 10.0], [35.0, 46.0]).len, 49.5177);
-    assert_delta!(arena.path([1.0, 10.0], [36.0, 42.0]).len, 47.4236);
-    assert_delta!(arena.path([1.0, 10.0], [38.0, 45.0]).len, 50.9313);
-    assert_delta!(arena.path([1.0, 10.0], [46.0, 18.0]).len, 45.7674);
-    assert_delta!(arena.path([1.0, 11.0], [37.0, 44.0]).len, 48.8365);
-    assert_delta!(arena.path([1.0, 11.0], [41.0, 35.0]).len, 46.6573);
-    assert_delta!(arena.path([1.0, 11.0], [43.0, 27.0]).len, 45.245);
-    assert_delta!(arena.path([1.0, 11.0], [44.0, 25.0]).len, 45.3649);
-    assert_delta!(arena.path([1.0, 10.0], [41.0, 40.0]).len, 50.2316);
-    assert_delta!(arena.path([1.0, 10.0], [43.0, 40.0]).len, 52.0087);
-    assert_delta!(arena.path([1.0, 11.0], [39.0, 47.0]).len, 52.345);
-    assert_delta!(arena.path([1.0, 11.0], [42.0, 46.0]).len, 53.9073);
-    assert_delta!(arena.path([1.0, 11.0], [45.0, 33.0]).len, 49.2304);
-    assert_delta!(arena.path([1.0, 12.0], [43.0, 43.0]).len, 52.2243);
-    assert_delta!(arena.path([1.0, 12.0], [44.0, 38.0]).len, 50.3504);
-    assert_delta!(arena.path([1.0, 12.0], [46.0, 34.0]).len, 50.0908);
-    assert_delta!(arena.path([1.0, 13.0], [42.0, 40.0]).len, 49.2852);
-    assert_delta!(arena.path([1.0, 14.0], [46.0, 32.0]).len, 48.4665);
-    assert_delta!(arena.path([1.0, 14.0], [44.0, 46.0]).len, 53.6365);
-    assert_delta!(arena.path([1.0, 14.0], [46.0, 43.0]).len, 53.6454);
-    assert_delta!(arena.path([1.0, 35.0], [46.0, 3.0]).len, 55.4059);
-    assert_delta!(arena.path([1.0, 37.0], [43.0, 1.0]).len, 55.3173);
-    assert_delta!(arena.path([1.0, 38.0], [43.0, 3.0]).len, 54.6717);
-    assert_delta!(arena.path([1.0, 38.0], [47.0, 13.0]).len, 52.3546);
-    assert_delta!(arena.path([1.0, 39.0], [47.0, 14.0]).len, 52.3599);
-    assert_delta!(arena.path([1.0, 4.0], [38.0, 47.0]).len, 56.7274);
-    assert_delta!(arena.path([1.0, 4.0], [41.0, 42.0]).len, 55.3523);
-    assert_delta!(arena.path([1.0, 42.0], [44.0, 5.0]).len, 56.7621);
-    assert_delta!(arena.path([1.0, 3.0], [41.0, 47.0]).len, 59.4702);
-    assert_delta!(arena.path([1.0, 3.0], [47.0, 37.0]).len, 57.2423);
-    assert_delta!(arena.path([1.0, 39.0], [46.0, 1.0]).len, 58.8982);
-    assert_delta!(arena.path([1.0, 4.0], [43.0, 46.0]).len, 59.4245);
-    assert_delta!(arena.path([1.0, 4.0], [44.0, 45.0]).len, 59.5469);
-    assert_delta!(arena.path([1.0, 40.0], [47.0, 3.0]).len, 59.0512);
-    assert_delta!(arena.path([1.0, 41.0], [46.0, 2.0]).len, 59.5483);
-    assert_delta!(arena.path([1.0, 45.0], [47.0, 9.0]).len, 58.6718);
-    assert_delta!(arena.path([1.0, 7.0], [47.0, 44.0]).len, 59.3941);
-    assert_delta!(arena.path([1.0, 7.0], [47.0, 46.0]).len, 60.4531);
+    assert_delta!(arena.path([1.0, 11.0], [1.0, 12.0]).unwrap().len, 1.0);
+    assert_delta!(arena.path([1.0, 12.0], [1.0, 10.0]).unwrap().len, 2.0);
+    assert_delta!(arena.path([1.0, 13.0], [4.0, 12.0]).unwrap().len, 3.16228);
+    assert_delta!(arena.path([1.0, 3.0], [3.0, 1.0]).unwrap().len, 3.41421);
+    assert_delta!(arena.path([1.0, 3.0], [4.0, 3.0]).unwrap().len, 3.0);
+    assert_delta!(arena.path([1.0, 4.0], [4.0, 2.0]).unwrap().len, 3.60555);
+    assert_delta!(arena.path([1.0, 40.0], [2.0, 39.0]).unwrap().len, SQRT_2);
+    assert_delta!(arena.path([1.0, 41.0], [1.0, 39.0]).unwrap().len, 2.0);
+    assert_delta!(arena.path([1.0, 41.0], [1.0, 44.0]).unwrap().len, 3.0);
+    assert_delta!(arena.path([1.0, 42.0], [4.0, 43.0]).unwrap().len, 3.16228);
+    assert_delta!(arena.path([1.0, 10.0], [7.0, 10.0]).unwrap().len, 6.0);
+    assert_delta!(arena.path([1.0, 11.0], [1.0, 4.0]).unwrap().len, 7.0);
+    assert_delta!(arena.path([1.0, 11.0], [7.0, 14.0]).unwrap().len, 6.7082);
+    assert_delta!(arena.path([1.0, 12.0], [5.0, 7.0]).unwrap().len, 6.40312);
+    assert_delta!(arena.path([1.0, 12.0], [6.0, 15.0]).unwrap().len, 5.83095);
+    assert_delta!(arena.path([1.0, 12.0], [8.0, 11.0]).unwrap().len, 7.07107);
+    assert_delta!(arena.path([1.0, 14.0], [1.0, 9.0]).unwrap().len, 5.0);
+    assert_delta!(arena.path([1.0, 24.0], [7.0, 26.0]).unwrap().len, 6.32456);
+    assert_delta!(arena.path([1.0, 25.0], [5.0, 25.0]).unwrap().len, 4.0);
+    assert_delta!(arena.path([1.0, 35.0], [5.0, 33.0]).unwrap().len, 4.60555);
+    assert_delta!(arena.path([1.0, 11.0], [4.0, 18.0]).unwrap().len, 7.63441);
+    assert_delta!(arena.path([1.0, 12.0], [12.0, 14.0]).unwrap().len, 11.1803);
+    assert_delta!(arena.path([1.0, 13.0], [4.0, 23.0]).unwrap().len, 10.8907);
+    assert_delta!(arena.path([1.0, 13.0], [5.0, 3.0]).unwrap().len, 10.7703);
+    assert_delta!(arena.path([1.0, 13.0], [6.0, 7.0]).unwrap().len, 7.81025);
+    assert_delta!(arena.path([1.0, 13.0], [7.0, 7.0]).unwrap().len, 8.48528);
+    assert_delta!(arena.path([1.0, 23.0], [7.0, 32.0]).unwrap().len, 10.8167);
+    assert_delta!(arena.path([1.0, 24.0], [11.0, 25.0]).unwrap().len, 10.0499);
+    assert_delta!(arena.path([1.0, 24.0], [6.0, 32.0]).unwrap().len, 9.4365);
+    assert_delta!(arena.path([1.0, 25.0], [9.0, 24.0]).unwrap().len, 8.06226);
+    assert_delta!(arena.path([1.0, 10.0], [11.0, 19.0]).unwrap().len, 13.4536);
+    assert_delta!(arena.path([1.0, 10.0], [13.0, 11.0]).unwrap().len, 12.0416);
+    assert_delta!(arena.path([1.0, 11.0], [10.0, 2.0]).unwrap().len, 12.7279);
+    assert_delta!(arena.path([1.0, 12.0], [11.0, 21.0]).unwrap().len, 13.4536);
+    assert_delta!(arena.path([1.0, 12.0], [13.0, 13.0]).unwrap().len, 12.0416);
+    assert_delta!(arena.path([1.0, 12.0], [14.0, 12.0]).unwrap().len, 13.0);
+    assert_delta!(arena.path([1.0, 12.0], [6.0, 25.0]).unwrap().len, 14.0459);
+    assert_delta!(arena.path([1.0, 13.0], [11.0, 3.0]).unwrap().len, 14.1421);
+    assert_delta!(arena.path([1.0, 13.0], [13.0, 11.0]).unwrap().len, 12.1655);
+    assert_delta!(arena.path([1.0, 14.0], [6.0, 23.0]).unwrap().len, 10.7801);
+    assert_delta!(arena.path([1.0, 10.0], [18.0, 11.0]).unwrap().len, 17.0294);
+    assert_delta!(arena.path([1.0, 11.0], [16.0, 14.0]).unwrap().len, 15.2971);
+    assert_delta!(arena.path([1.0, 12.0], [14.0, 2.0]).unwrap().len, 16.4012);
+    assert_delta!(arena.path([1.0, 12.0], [17.0, 13.0]).unwrap().len, 16.0312);
+    assert_delta!(arena.path([1.0, 12.0], [9.0, 28.0]).unwrap().len, 17.9234);
+    assert_delta!(arena.path([1.0, 13.0], [4.0, 30.0]).unwrap().len, 17.8617);
+    assert_delta!(arena.path([1.0, 13.0], [9.0, 26.0]).unwrap().len, 15.3584);
+    assert_delta!(arena.path([1.0, 14.0], [14.0, 22.0]).unwrap().len, 15.2745);
+    assert_delta!(arena.path([1.0, 23.0], [10.0, 8.0]).unwrap().len, 18.0);
+    assert_delta!(arena.path([1.0, 23.0], [14.0, 9.0]).unwrap().len, 19.4391);
+    assert_delta!(arena.path([1.0, 10.0], [13.0, 29.0]).unwrap().len, 22.4722);
+    assert_delta!(arena.path([1.0, 10.0], [18.0, 22.0]).unwrap().len, 20.8087);
+    assert_delta!(arena.path([1.0, 10.0], [19.0, 18.0]).unwrap().len, 21.0575);
+    assert_delta!(arena.path([1.0, 10.0], [21.0, 2.0]).unwrap().len, 21.5407);
+    assert_delta!(arena.path([1.0, 10.0], [5.0, 32.0]).unwrap().len, 22.5024);
+    assert_delta!(arena.path([1.0, 10.0], [6.0, 29.0]).unwrap().len, 19.703);
+    assert_delta!(arena.path([1.0, 11.0], [20.0, 7.0]).unwrap().len, 19.4165);
+    assert_delta!(arena.path([1.0, 11.0], [21.0, 17.0]).unwrap().len, 21.2675);
+    assert_delta!(arena.path([1.0, 11.0], [22.0, 16.0]).unwrap().len, 21.6014);
+    assert_delta!(arena.path([1.0, 11.0], [8.0, 29.0]).unwrap().len, 19.3382);
+    assert_delta!(arena.path([1.0, 10.0], [22.0, 22.0]).unwrap().len, 24.2591);
+    assert_delta!(arena.path([1.0, 10.0], [5.0, 33.0]).unwrap().len, 23.4959);
+    assert_delta!(arena.path([1.0, 11.0], [10.0, 32.0]).unwrap().len, 22.8569);
+    assert_delta!(arena.path([1.0, 11.0], [20.0, 31.0]).unwrap().len, 27.5862);
+    assert_delta!(arena.path([1.0, 11.0], [21.0, 23.0]).unwrap().len, 23.3238);
+    assert_delta!(arena.path([1.0, 11.0], [24.0, 14.0]).unwrap().len, 23.1948);
+    assert_delta!(arena.path([1.0, 11.0], [25.0, 4.0]).unwrap().len, 25.0);
+    assert_delta!(arena.path([1.0, 11.0], [3.0, 36.0]).unwrap().len, 25.4721);
+    assert_delta!(arena.path([1.0, 12.0], [19.0, 27.0]).unwrap().len, 23.4307);
+    assert_delta!(arena.path([1.0, 12.0], [2.0, 37.0]).unwrap().len, 25.7678);
+    assert_delta!(arena.path([1.0, 10.0], [22.0, 31.0]).unwrap().len, 29.6985);
+    assert_delta!(arena.path([1.0, 10.0], [24.0, 27.0]).unwrap().len, 28.6007);
+    assert_delta!(arena.path([1.0, 10.0], [28.0, 15.0]).unwrap().len, 27.4591);
+    assert_delta!(arena.path([1.0, 10.0], [7.0, 39.0]).unwrap().len, 29.7162);
+    assert_delta!(arena.path([1.0, 11.0], [12.0, 35.0]).unwrap().len, 26.4038);
+    assert_delta!(arena.path([1.0, 11.0], [28.0, 18.0]).unwrap().len, 27.9259);
+    assert_delta!(arena.path([1.0, 11.0], [5.0, 40.0]).unwrap().len, 29.552);
+    assert_delta!(arena.path([1.0, 12.0], [26.0, 3.0]).unwrap().len, 26.5707);
+    assert_delta!(arena.path([1.0, 12.0], [29.0, 14.0]).unwrap().len, 28.0713);
+    assert_delta!(arena.path([1.0, 12.0], [29.0, 6.0]).unwrap().len, 28.6362);
+    assert_delta!(arena.path([1.0, 10.0], [25.0, 36.0]).unwrap().len, 35.3836);
+    assert_delta!(arena.path([1.0, 10.0], [27.0, 25.0]).unwrap().len, 30.0597);
+    assert_delta!(arena.path([1.0, 10.0], [32.0, 4.0]).unwrap().len, 31.5753);
+    assert_delta!(arena.path([1.0, 10.0], [33.0, 4.0]).unwrap().len, 32.5576);
+    assert_delta!(arena.path([1.0, 11.0], [10.0, 42.0]).unwrap().len, 32.3648);
+    assert_delta!(arena.path([1.0, 11.0], [27.0, 28.0]).unwrap().len, 31.0644);
+    assert_delta!(arena.path([1.0, 11.0], [30.0, 2.0]).unwrap().len, 30.5347);
+    assert_delta!(arena.path([1.0, 11.0], [31.0, 3.0]).unwrap().len, 31.0483);
+    assert_delta!(arena.path([1.0, 11.0], [5.0, 42.0]).unwrap().len, 31.5461);
+    assert_delta!(arena.path([1.0, 12.0], [18.0, 37.0]).unwrap().len, 30.5349);
+    assert_delta!(arena.path([1.0, 10.0], [15.0, 43.0]).unwrap().len, 35.8469);
+    assert_delta!(arena.path([1.0, 10.0], [21.0, 41.0]).unwrap().len, 37.1384);
+    assert_delta!(arena.path([1.0, 10.0], [27.0, 37.0]).unwrap().len, 37.4833);
+    assert_delta!(arena.path([1.0, 10.0], [29.0, 38.0]).unwrap().len, 39.598);
+    assert_delta!(arena.path([1.0, 10.0], [31.0, 25.0]).unwrap().len, 33.7313);
+    assert_delta!(arena.path([1.0, 10.0], [38.0, 13.0]).unwrap().len, 37.1214);
+    assert_delta!(arena.path([1.0, 10.0], [38.0, 6.0]).unwrap().len, 37.2305);
+    assert_delta!(arena.path([1.0, 10.0], [39.0, 6.0]).unwrap().len, 38.2281);
+    assert_delta!(arena.path([1.0, 10.0], [40.0, 9.0]).unwrap().len, 39.0357);
+    assert_delta!(arena.path([1.0, 11.0], [11.0, 43.0]).unwrap().len, 33.5926);
+    assert_delta!(arena.path([1.0, 10.0], [12.0, 47.0]).unwrap().len, 38.6267);
+    assert_delta!(arena.path([1.0, 10.0], [14.0, 47.0]).unwrap().len, 39.223);
+    assert_delta!(arena.path([1.0, 10.0], [16.0, 46.0]).unwrap().len, 39.0);
+    assert_delta!(arena.path([1.0, 10.0], [28.0, 41.0]).unwrap().len, 41.1096);
+    assert_delta!(arena.path([1.0, 10.0], [37.0, 21.0]).unwrap().len, 37.6552);
+    assert_delta!(arena.path([1.0, 10.0], [39.0, 24.0]).unwrap().len, 40.6133);
+    assert_delta!(arena.path([1.0, 11.0], [16.0, 45.0]).unwrap().len, 37.1677);
+    assert_delta!(arena.path([1.0, 11.0], [21.0, 43.0]).unwrap().len, 37.7849);
+    assert_delta!(arena.path([1.0, 11.0], [32.0, 39.0]).unwrap().len, 41.7732);
+    assert_delta!(arena.path([1.0, 11.0], [34.0, 29.0]).unwrap().len, 37.5954);
+    assert_delta!(arena.path([1.0, 10.0], [29.0, 43.0]).unwrap().len, 43.2791);
+    assert_delta!(arena.path([1.0, 10.0], [43.0, 15.0]).unwrap().len, 42.2966);
+    assert_delta!(arena.path([1.0, 10.0], [43.0, 17.0]).unwrap().len, 42.6119);
+    assert_delta!(arena.path([1.0, 10.0], [45.0, 10.0]).unwrap().len, 44.0);
+    assert_delta!(arena.path([1.0, 10.0], [46.0, 15.0]).unwrap().len, 45.2769);
+    assert_delta!(arena.path([1.0, 10.0], [46.0, 3.0]).unwrap().len, 45.5412);
+    assert_delta!(arena.path([1.0, 11.0], [30.0, 45.0]).unwrap().len, 44.7117);
+    assert_delta!(arena.path([1.0, 11.0], [35.0, 41.0]).unwrap().len, 45.3431);
+    assert_delta!(arena.path([1.0, 11.0], [35.0, 42.0]).unwrap().len, 46.0109);
+    assert_delta!(arena.path([1.0, 11.0], [43.0, 3.0]).unwrap().len, 42.7551);
+    assert_delta!(arena.path([1.0, 10.0], [31.0, 46.0]).unwrap().len, 46.868);
+    assert_delta!(arena.path([1.0, 10.0], [34.0, 46.0]).unwrap().len, 48.8365);
+    assert_delta!(arena.path([1.0, 10.0], [35.0, 46.0]).unwrap().len, 49.5177);
+    assert_delta!(arena.path([1.0, 10.0], [36.0, 42.0]).unwrap().len, 47.4236);
+    assert_delta!(arena.path([1.0, 10.0], [38.0, 45.0]).unwrap().len, 50.9313);
+    assert_delta!(arena.path([1.0, 10.0], [46.0, 18.0]).unwrap().len, 45.7674);
+    assert_delta!(arena.path([1.0, 11.0], [37.0, 44.0]).unwrap().len, 48.8365);
+    assert_delta!(arena.path([1.0, 11.0], [41.0, 35.0]).unwrap().len, 46.6573);
+    assert_delta!(arena.path([1.0, 11.0], [43.0, 27.0]).unwrap().len, 45.245);
+    assert_delta!(arena.path([1.0, 11.0], [44.0, 25.0]).unwrap().len, 45.3649);
+    assert_delta!(arena.path([1.0, 10.0], [41.0, 40.0]).unwrap().len, 50.2316);
+    assert_delta!(arena.path([1.0, 10.0], [43.0, 40.0]).unwrap().len, 52.0087);
+    assert_delta!(arena.path([1.0, 11.0], [39.0, 47.0]).unwrap().len, 52.345);
+    assert_delta!(arena.path([1.0, 11.0], [42.0, 46.0]).unwrap().len, 53.9073);
+    assert_delta!(arena.path([1.0, 11.0], [45.0, 33.0]).unwrap().len, 49.2304);
+    assert_delta!(arena.path([1.0, 12.0], [43.0, 43.0]).unwrap().len, 52.2243);
+    assert_delta!(arena.path([1.0, 12.0], [44.0, 38.0]).unwrap().len, 50.3504);
+    assert_delta!(arena.path([1.0, 12.0], [46.0, 34.0]).unwrap().len, 50.0908);
+    assert_delta!(arena.path([1.0, 13.0], [42.0, 40.0]).unwrap().len, 49.2852);
+    assert_delta!(arena.path([1.0, 14.0], [46.0, 32.0]).unwrap().len, 48.4665);
+    assert_delta!(arena.path([1.0, 14.0], [44.0, 46.0]).unwrap().len, 53.6365);
+    assert_delta!(arena.path([1.0, 14.0], [46.0, 43.0]).unwrap().len, 53.6454);
+    assert_delta!(arena.path([1.0, 35.0], [46.0, 3.0]).unwrap().len, 55.4059);
+    assert_delta!(arena.path([1.0, 37.0], [43.0, 1.0]).unwrap().len, 55.3173);
+    assert_delta!(arena.path([1.0, 38.0], [43.0, 3.0]).unwrap().len, 54.6717);
+    assert_delta!(arena.path([1.0, 38.0], [47.0, 13.0]).unwrap().len, 52.3546);
+    assert_delta!(arena.path([1.0, 39.0], [47.0, 14.0]).unwrap().len, 52.3599);
+    assert_delta!(arena.path([1.0, 4.0], [38.0, 47.0]).unwrap().len, 56.7274);
+    assert_delta!(arena.path([1.0, 4.0], [41.0, 42.0]).unwrap().len, 55.3523);
+    assert_delta!(arena.path([1.0, 42.0], [44.0, 5.0]).unwrap().len, 56.7621);
+    assert_delta!(arena.path([1.0, 3.0], [41.0, 47.0]).unwrap().len, 59.4702);
+    assert_delta!(arena.path([1.0, 3.0], [47.0, 37.0]).unwrap().len, 57.2423);
+    assert_delta!(arena.path([1.0, 39.0], [46.0, 1.0]).unwrap().len, 58.8982);
+    assert_delta!(arena.path([1.0, 4.0], [43.0, 46.0]).unwrap().len, 59.4245);
+    assert_delta!(arena.path([1.0, 4.0], [44.0, 45.0]).unwrap().len, 59.5469);
+    assert_delta!(arena.path([1.0, 40.0], [47.0, 3.0]).unwrap().len, 59.0512);
+    assert_delta!(arena.path([1.0, 41.0], [46.0, 2.0]).unwrap().len, 59.5483);
+    assert_delta!(arena.path([1.0, 45.0], [47.0, 9.0]).unwrap().len, 58.6718);
+    assert_delta!(arena.path([1.0, 7.0], [47.0, 44.0]).unwrap().len, 59.3941);
+    assert_delta!(arena.path([1.0, 7.0], [47.0, 46.0]).unwrap().len, 60.4531);
 }
@@ -0,0 +1,103 @@
+#![cfg(feature = "counters")]
+
+use std::sync::Mutex;
+
+use polyanya::{counters, Mesh};
+
+// counters are shared by the whole process: tests checking them can't run at the same time
+static COUNTING: Mutex<()> = Mutex::new(());
+
+/// Counters after running `query`, from zero.
+fn count(query: impl FnOnce()) -> counters::Counters {
+    let _lock = COUNTING
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    counters::reset();
+    query();
+    counters::reset()
+}
+
+// two rooms separated by a wall, with a door at the top
+fn rooms() -> Mesh {
+    Mesh::from_grid(10, 10, |x, y| x != 5 || y == 9)
+}
+
+#[test]
+fn path() {
+    let mesh = rooms();
+    let counted = count(|| {
+        assert!(mesh.path([1.0, 1.0], [8.0, 1.0]).is_some());
+    });
+    assert_eq!(counted.queries, 1);
+    assert_eq!(counted.failures, 0);
+    assert!(counted.nodes_expanded > 0);
+
+    // going to a neighbour polygon expands no node: the search stops when reaching it
+    let counted = count(|| {
+        assert!(mesh.path([1.0, 1.0], [1.0, 9.5]).is_some());
+    });
+    assert_eq!(counted.queries, 1);
+    assert_eq!(counted.nodes_expanded, 0);
+}
+
+#[test]
+fn failures() {
+    let walled = Mesh::from_grid(10, 10, |x, _| x != 5);
+    let counted = count(|| {
+        assert!(walled.path([1.0, 1.0], [8.0, 1.0]).is_none());
+        assert!(walled.path([1.0, 1.0], [-1.0, 1.0]).is_none());
+        assert!(walled
+            .path_through(&[[1.0, 1.0], [2.0, 2.0], [8.0, 1.0]])
+            .is_none());
+        assert!(walled
+            .anytime_path([1.0, 1.0], [8.0, 1.0], 2.0)
+            .next()
+            .is_none());
+    });
+    assert_eq!(counted.queries, 4);
+    assert_eq!(counted.failures, 4);
+}
+
+#[test]
+fn searches_without_target_do_not_fail() {
+    let mesh = rooms();
+    let counted = count(|| {
+        assert!(mesh.distance_field([1.0, 1.0]).is_some());
+        assert!(mesh.flow_field([1.0, 1.0]).is_some());
+        assert!(mesh.reachable_within([1.0, 1.0], 5.0).is_some());
+        let matrix = mesh.distance_matrix(&[[1.0, 1.0], [8.0, 8.0]], &[[8.0, 1.0], [-1.0, 1.0]]);
+        assert_eq!(matrix[0][1], None);
+    });
+    assert_eq!(counted.queries, 4);
+    assert_eq!(counted.failures, 0);
+    assert!(counted.nodes_expanded > 0);
+}
+
+#[test]
+fn once_per_call() {
+    let mesh = rooms();
+    let counted = count(|| {
+        assert_eq!(mesh.anytime_path([1.0, 1.0], [8.0, 1.0], 3.0).count(), 1);
+        assert!(!mesh.alternative_paths([1.0, 1.0], [8.0, 1.0], 3).is_empty());
+        assert!(mesh
+            .path_through(&[[1.0, 1.0], [8.0, 1.0], [1.0, 8.0]])
+            .is_some());
+        assert!(mesh.path_or_closest([1.0, 1.0], [8.0, 1.0]).is_some());
+    });
+    assert_eq!(counted.queries, 4);
+    assert_eq!(counted.failures, 0);
+}
+
+#[test]
+fn moving_target() {
+    let mesh = rooms();
+    let mut chase = mesh.moving_target_search([1.0, 1.0]);
+    let counted = count(|| {
+        assert!(chase.path_to([8.0, 1.0]).is_some());
+        assert!(chase.path_to([8.0, 2.0]).is_some());
+        assert!(chase.path_to([-1.0, 2.0]).is_none());
+    });
+    assert_eq!(counted.queries, 3);
+    assert_eq!(counted.failures, 1);
+    assert_eq!(counted.nodes_expanded, chase.expanded() as u64);
+}